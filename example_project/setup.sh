@@ -1,5 +1,4 @@
 # Clone all repositories used for the example projects
-git clone --depth=1 https://github.com/ghdl/ghdl.git
 git clone --depth=1 https://github.com/UVVM/UVVM.git
 git clone --depth=1 https://github.com/VUnit/vunit.git
 git clone --depth=1 https://github.com/VLSI-EDA/PoC.git
@@ -1,4 +0,0 @@
--- Package env as defined by IEEE 1076-2008
-
-package env is
-end package;
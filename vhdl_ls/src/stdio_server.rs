@@ -35,6 +35,24 @@ pub fn start() {
         Ok(serde_json::to_value(result).map_err(|_| jsonrpc_core::Error::internal_error())?)
     });
 
+    let server = lang_server.clone();
+    io.add_method("textDocument/hover", move |params: Params| {
+        let result = server
+            .lock()
+            .unwrap()
+            .text_document_hover(&params.parse()?)?;
+        Ok(serde_json::to_value(result).map_err(|_| jsonrpc_core::Error::internal_error())?)
+    });
+
+    let server = lang_server.clone();
+    io.add_method("textDocument/codeAction", move |params: Params| {
+        let result = server
+            .lock()
+            .unwrap()
+            .text_document_code_action(&params.parse()?)?;
+        Ok(serde_json::to_value(result).map_err(|_| jsonrpc_core::Error::internal_error())?)
+    });
+
     let server = lang_server.clone();
     io.add_method("shutdown", move |params: Params| {
         server.lock().unwrap().shutdown_server(params.parse()?)?;
@@ -70,6 +88,15 @@ pub fn start() {
             .text_document_did_open_notification(&params.parse().unwrap())
     });
 
+    let server = lang_server.clone();
+    io.add_method("textDocument/foldingRange", move |params: Params| {
+        let result = server
+            .lock()
+            .unwrap()
+            .text_document_folding_range(&params.parse()?)?;
+        Ok(serde_json::to_value(result).map_err(|_| jsonrpc_core::Error::internal_error())?)
+    });
+
     // Spawn thread to read requests from stdin
     spawn(move || {
         let stdin = io::stdin();
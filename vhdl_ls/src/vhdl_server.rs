@@ -4,8 +4,8 @@
 //
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
-use self::languageserver_types::*;
-use languageserver_types;
+use self::lsp_types::*;
+use lsp_types;
 use serde;
 
 use self::url::Url;
@@ -14,10 +14,10 @@ use url;
 use self::fnv::FnvHashMap;
 use fnv;
 use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 
 use self::vhdl_parser::{Config, Message, Project, Severity, Source, SrcPos};
-use std::io;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use vhdl_parser;
 
 pub trait RpcChannel {
@@ -79,14 +79,44 @@ impl<T: RpcChannel + Clone> VHDLServer<T> {
         self.mut_server()
             .text_document_did_open_notification(&params)
     }
+
+    pub fn text_document_folding_range(
+        &mut self,
+        params: &FoldingRangeParams,
+    ) -> jsonrpc_core::Result<Vec<FoldingRange>> {
+        Ok(self.mut_server().text_document_folding_range(params))
+    }
+
+    pub fn text_document_hover(
+        &mut self,
+        params: &TextDocumentPositionParams,
+    ) -> jsonrpc_core::Result<Option<Hover>> {
+        Ok(self.mut_server().text_document_hover(params))
+    }
+
+    pub fn text_document_code_action(
+        &mut self,
+        params: &CodeActionParams,
+    ) -> jsonrpc_core::Result<Vec<CodeAction>> {
+        Ok(self.mut_server().text_document_code_action(params))
+    }
 }
 
 struct InitializedVHDLServer<T: RpcChannel> {
     rpc_channel: T,
     init_params: InitializeParams,
-    config: io::Result<Config>,
+    // The merged config of all config files that have been read
+    config: Config,
+    // Config files which have been read, successfully or not
+    config_files: Vec<PathBuf>,
+    config_errors: Vec<String>,
+    config_warnings: Vec<String>,
     project: Project,
+    // The latest contents of opened files
+    open_sources: FnvHashMap<String, Source>,
     files_with_notifications: FnvHashMap<Url, ()>,
+    // Published diagnostics which can be fixed by replacing their range with a text
+    quick_fixes: FnvHashMap<Url, Vec<(Diagnostic, String)>>,
 }
 
 impl<T: RpcChannel + Clone> InitializedVHDLServer<T> {
@@ -94,34 +124,52 @@ impl<T: RpcChannel + Clone> InitializedVHDLServer<T> {
         rpc_channel: T,
         init_params: InitializeParams,
     ) -> jsonrpc_core::Result<(InitializedVHDLServer<T>, InitializeResult)> {
-        let config = init_params
+        let root_path = init_params
             .root_uri
             .as_ref()
-            .ok_or_else(|| io::Error::new(io::ErrorKind::Other, "initializeParams.rootUri not set"))
+            .ok_or_else(|| "initializeParams.rootUri not set".to_owned())
             .and_then(|root_uri| {
                 root_uri.to_file_path().map_err(|_| {
-                    io::Error::new(
-                        io::ErrorKind::Other,
-                        format!(
-                            "initializeParams.rootUri {:?} not a valid file path",
-                            root_uri
-                        ),
+                    format!(
+                        "initializeParams.rootUri {:?} not a valid file path",
+                        root_uri
                     )
                 })
-            })
-            .and_then(|root_path| {
-                let config_file = root_path.join("vhdl_ls.toml");
-                Config::read_file_path(&config_file)
             });
 
-        let server = InitializedVHDLServer {
+        let mut server = InitializedVHDLServer {
             rpc_channel,
             init_params,
-            config,
+            config: Config::default(),
+            config_files: Vec::new(),
+            config_errors: Vec::new(),
+            config_warnings: Vec::new(),
             project: Project::new(),
+            open_sources: FnvHashMap::default(),
             files_with_notifications: FnvHashMap::default(),
+            quick_fixes: FnvHashMap::default(),
         };
 
+        // Config files in order of increasing precedence
+        if let Some(file_name) = user_config_file() {
+            if file_name.exists() {
+                server.read_config_file(file_name);
+            }
+        }
+
+        if let Some(file_name) = std::env::var_os("VHDL_LS_CONFIG") {
+            server.read_config_file(PathBuf::from(file_name));
+        }
+
+        match root_path {
+            Ok(root_path) => {
+                if let Some(file_name) = find_config_file(&root_path) {
+                    server.read_config_file(file_name);
+                }
+            }
+            Err(err) => server.config_errors.push(err),
+        }
+
         let result = InitializeResult {
             capabilities: ServerCapabilities {
                 /// Defines how text documents are synced.
@@ -130,7 +178,7 @@ impl<T: RpcChannel + Clone> InitializedVHDLServer<T> {
                 )),
 
                 /// The server provides hover support.
-                hover_provider: None,
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
 
                 /// The server provides completion support.
                 completion_provider: None,
@@ -160,7 +208,7 @@ impl<T: RpcChannel + Clone> InitializedVHDLServer<T> {
                 workspace_symbol_provider: None,
 
                 /// The server provides code actions.
-                code_action_provider: None,
+                code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
 
                 /// The server provides code lens.
                 code_lens_provider: None,
@@ -181,14 +229,17 @@ impl<T: RpcChannel + Clone> InitializedVHDLServer<T> {
                 color_provider: None,
 
                 /// The server provides folding provider support.
-                folding_range_provider: None,
+                folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
 
                 /// The server provides execute command support.
                 execute_command_provider: None,
 
                 /// Workspace specific server capabilities
                 workspace: None,
+
+                ..ServerCapabilities::default()
             },
+            server_info: None,
         };
 
         Ok((server, result))
@@ -220,15 +271,38 @@ impl<T: RpcChannel + Clone> InitializedVHDLServer<T> {
 
         let mut files_with_notifications =
             std::mem::replace(&mut self.files_with_notifications, FnvHashMap::default());
+        self.quick_fixes.clear();
+        let mut diagnostics_by_uri: FnvHashMap<Url, Vec<Diagnostic>> = FnvHashMap::default();
         for (file_uri, messages) in messages_by_uri(messages).into_iter() {
             let mut diagnostics = Vec::new();
+            let mut quick_fixes = Vec::new();
             for message in messages {
-                diagnostics.push(to_diagnostic(message));
+                let replacement = message.replacement.clone();
+                let diagnostic = to_diagnostic(message);
+                if let Some(replacement) = replacement {
+                    quick_fixes.push((diagnostic.clone(), replacement));
+                }
+                diagnostics.push(diagnostic);
             }
 
+            if !quick_fixes.is_empty() {
+                self.quick_fixes.insert(file_uri.clone(), quick_fixes);
+            }
+            diagnostics_by_uri.insert(file_uri, diagnostics);
+        }
+
+        for region in self.project.disabled_regions() {
+            diagnostics_by_uri
+                .entry(file_name_to_uri(region.source.file_name()))
+                .or_default()
+                .push(disabled_region_diagnostic(region));
+        }
+
+        for (file_uri, diagnostics) in diagnostics_by_uri.into_iter() {
             let publish_diagnostics = PublishDiagnosticsParams {
                 uri: file_uri.clone(),
                 diagnostics,
+                version: None,
             };
 
             self.rpc_channel
@@ -243,6 +317,7 @@ impl<T: RpcChannel + Clone> InitializedVHDLServer<T> {
                 let publish_diagnostics = PublishDiagnosticsParams {
                     uri: file_uri.clone(),
                     diagnostics: vec![],
+                    version: None,
                 };
 
                 self.rpc_channel
@@ -261,6 +336,7 @@ impl<T: RpcChannel + Clone> InitializedVHDLServer<T> {
 
         // @TODO log error to client
         self.project.update_source(&file_name, &source).unwrap();
+        self.open_sources.insert(file_name, source);
         self.publish_diagnostics();
     }
 
@@ -274,37 +350,120 @@ impl<T: RpcChannel + Clone> InitializedVHDLServer<T> {
         );
     }
 
-    pub fn initialized_notification(&mut self, _params: &InitializedParams) {
-        match &self.config {
-            Err(ref err) => {
-                self.window_show_message(
-                    MessageType::Warning,
+    fn window_log_message(&self, typ: MessageType, message: impl Into<String>) {
+        self.rpc_channel.send_notification(
+            "window/logMessage",
+            LogMessageParams {
+                typ,
+                message: message.into(),
+            },
+        );
+    }
+
+    /// Read a config file with higher precedence than all previously read config files
+    /// Errors are reported to the client later on
+    fn read_config_file(&mut self, file_name: PathBuf) -> bool {
+        if self.config_files.contains(&file_name) {
+            return false;
+        }
+
+        let result = Config::read_file_path(&file_name);
+        self.config_files.push(file_name.clone());
+        match result {
+            Ok(mut config) => {
+                for warning in config.take_warnings() {
+                    self.config_warnings.push(format!(
+                        "Warning in config file {}: {}",
+                        file_name.to_string_lossy(),
+                        warning
+                    ));
+                }
+                self.config.append(config);
+                true
+            }
+            Err(err) => {
+                self.config_errors.push(format!(
+                    "Failed to read config file {}: {}",
+                    file_name.to_string_lossy(),
+                    err
+                ));
+                false
+            }
+        }
+    }
+
+    fn show_config_messages(&mut self) {
+        for error in std::mem::take(&mut self.config_errors) {
+            self.window_show_message(MessageType::Error, error);
+        }
+        for warning in std::mem::take(&mut self.config_warnings) {
+            self.window_show_message(MessageType::Warning, warning);
+        }
+    }
+
+    /// Create a new project from the config while keeping the contents of opened files
+    fn load_project(&mut self) {
+        let mut libraries: Vec<_> = self.config.iter_libraries().collect();
+        libraries.sort_by_key(|library| library.name());
+        for library in libraries {
+            if let Some(config_file) = library.config_file() {
+                self.window_log_message(
+                    MessageType::Info,
                     format!(
-                        "Found no vhdl_ls.toml config file in the root path: {}",
-                        err
+                        "Library {} is defined in {}",
+                        library.name(),
+                        config_file.to_string_lossy()
                     ),
                 );
-                self.window_show_message(
-                    MessageType::Warning,
-                    "Semantic analysis disabled, will perform syntax checking only",
-                );
-            }
-            Ok(config) => {
-                // @TODO read num_threads from config file
-                let num_threads = 4;
-                // @TODO send error to client
-                let mut errors = Vec::new();
-                let project = Project::from_config(&config, num_threads, &mut errors);
-                self.project = project;
-                for error in errors {
-                    self.window_show_message(MessageType::Error, error.to_string());
-                }
             }
         }
 
+        // @TODO read num_threads from config file
+        let num_threads = 4;
+        let mut errors = Vec::new();
+        self.project = Project::from_config(&self.config, num_threads, &mut errors);
+        for error in errors {
+            self.window_show_message(MessageType::Error, error.to_string());
+        }
+
+        for (file_name, source) in self.open_sources.iter() {
+            // @TODO log error to client
+            self.project.update_source(file_name, source).unwrap();
+        }
+    }
+
+    pub fn initialized_notification(&mut self, _params: &InitializedParams) {
+        self.show_config_messages();
+
+        if self.config.iter_libraries().next().is_none() {
+            self.window_show_message(
+                MessageType::Warning,
+                "Found no vhdl_ls.toml config file in the root path or its parent folders",
+            );
+            self.window_show_message(
+                MessageType::Warning,
+                "Semantic analysis disabled, will perform syntax checking only",
+            );
+        } else {
+            self.load_project();
+        }
+
         self.publish_diagnostics();
     }
 
+    /// Search for a config file upward from the folder of an opened file
+    /// The project is re-created when a config file that has not been read before is found
+    fn discover_config_file(&mut self, file_name: &str) {
+        let config_file = Path::new(file_name).parent().and_then(find_config_file);
+
+        if let Some(config_file) = config_file {
+            if self.read_config_file(config_file) {
+                self.load_project();
+            }
+            self.show_config_messages();
+        }
+    }
+
     pub fn text_document_did_change_notification(&mut self, params: &DidChangeTextDocumentParams) {
         self.parse_and_publish_diagnostics(
             &params.text_document.uri,
@@ -313,42 +472,141 @@ impl<T: RpcChannel + Clone> InitializedVHDLServer<T> {
     }
 
     pub fn text_document_did_open_notification(&mut self, params: &DidOpenTextDocumentParams) {
+        self.discover_config_file(&uri_to_file_name(&params.text_document.uri));
         self.parse_and_publish_diagnostics(&params.text_document.uri, &params.text_document.text);
     }
+
+    /// Code which is disabled by conditional analysis or between translate_off and translate_on pragmas may be folded
+    pub fn text_document_folding_range(&self, params: &FoldingRangeParams) -> Vec<FoldingRange> {
+        let file_name = uri_to_file_name(&params.text_document.uri);
+        self.project
+            .inactive_regions(&file_name)
+            .iter()
+            .chain(self.project.translate_off_regions(&file_name))
+            .filter_map(region_to_folding_range)
+            .collect()
+    }
+
+    /// The static value of a constant is shown when hovering its declaration
+    pub fn text_document_hover(&self, params: &TextDocumentPositionParams) -> Option<Hover> {
+        let file_name = uri_to_file_name(&params.text_document.uri);
+        let (pos, value) = self
+            .project
+            .static_values(&file_name)
+            .iter()
+            .find(|(pos, _)| range_contains(&srcpos_to_range(pos), &params.position))?;
+
+        Some(Hover {
+            contents: HoverContents::Scalar(MarkedString::LanguageString(LanguageString {
+                language: "vhdl".to_owned(),
+                value: format!("-- = {}", value),
+            })),
+            range: Some(srcpos_to_range(pos)),
+        })
+    }
+
+    /// Diagnostics such as a mismatching end label can be fixed by replacing their text
+    pub fn text_document_code_action(&self, params: &CodeActionParams) -> Vec<CodeAction> {
+        let uri = &params.text_document.uri;
+        let quick_fixes = match self.quick_fixes.get(uri) {
+            Some(quick_fixes) => quick_fixes,
+            None => return Vec::new(),
+        };
+
+        quick_fixes
+            .iter()
+            .filter(|(diagnostic, _)| ranges_overlap(&diagnostic.range, &params.range))
+            .map(|(diagnostic, replacement)| {
+                let title = if replacement.is_empty() {
+                    "Remove".to_owned()
+                } else {
+                    format!("Replace with {}", replacement)
+                };
+
+                let mut changes = HashMap::new();
+                changes.insert(
+                    uri.clone(),
+                    vec![TextEdit::new(diagnostic.range, replacement.clone())],
+                );
+
+                CodeAction {
+                    title,
+                    kind: Some(CodeActionKind::QUICKFIX),
+                    diagnostics: Some(vec![diagnostic.clone()]),
+                    edit: Some(WorkspaceEdit {
+                        changes: Some(changes),
+                        ..WorkspaceEdit::default()
+                    }),
+                    command: None,
+                    is_preferred: Some(true),
+                    disabled: None,
+                    data: None,
+                }
+            })
+            .collect()
+    }
 }
 
-fn srcpos_to_range(srcpos: &SrcPos) -> Range {
-    let contents = srcpos.source.contents().unwrap();
-    let mut start = None;
-    let mut end = None;
+/// The user level config file which typically contains shared libraries such as ieee
+fn user_config_file() -> Option<PathBuf> {
+    if let Some(config_home) = std::env::var_os("XDG_CONFIG_HOME") {
+        return Some(PathBuf::from(config_home).join("vhdl_ls.toml"));
+    }
 
-    let mut cursor = Position {
-        line: 0,
-        character: 0,
-    };
-    for (i, byte) in contents.bytes.iter().enumerate() {
-        if i == srcpos.start {
-            start = Some(cursor);
-        }
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config").join("vhdl_ls.toml"))
+}
 
-        if i == srcpos.start + srcpos.length {
-            end = Some(cursor);
-        }
+/// Search for a vhdl_ls.toml file in the folder and all of its parent folders
+fn find_config_file(folder: &Path) -> Option<PathBuf> {
+    folder
+        .ancestors()
+        .map(|folder| folder.join("vhdl_ls.toml"))
+        .find(|file_name| file_name.is_file())
+}
 
-        if *byte == b'\n' {
-            cursor.line += 1;
-            cursor.character = 0;
-        } else {
-            cursor.character += 1;
-        };
+fn to_lsp_position(position: vhdl_parser::Position) -> Position {
+    Position {
+        line: position.line as u32,
+        character: position.character as u32,
     }
+}
 
+fn srcpos_to_range(srcpos: &SrcPos) -> Range {
+    let range = srcpos.range();
     Range {
-        start: start.unwrap_or(cursor),
-        end: end.unwrap_or(cursor),
+        start: to_lsp_position(range.start),
+        end: to_lsp_position(range.end),
     }
 }
 
+fn range_contains(range: &Range, position: &Position) -> bool {
+    let position = (position.line, position.character);
+    (range.start.line, range.start.character) <= position
+        && position < (range.end.line, range.end.character)
+}
+
+fn ranges_overlap(lhs: &Range, rhs: &Range) -> bool {
+    let start = |range: &Range| (range.start.line, range.start.character);
+    let end = |range: &Range| (range.end.line, range.end.character);
+    start(lhs) <= end(rhs) && start(rhs) <= end(lhs)
+}
+
+/// The folded lines end before the directive or pragma which enables code again
+fn region_to_folding_range(srcpos: &SrcPos) -> Option<FoldingRange> {
+    let range = srcpos.range();
+    if range.end.line <= range.start.line + 1 {
+        return None;
+    }
+
+    Some(FoldingRange {
+        start_line: range.start.line as u32,
+        start_character: None,
+        end_line: (range.end.line - 1) as u32,
+        end_character: None,
+        kind: Some(FoldingRangeKind::Region),
+    })
+}
+
 fn messages_by_uri(messages: Vec<Message>) -> FnvHashMap<Url, Vec<Message>> {
     let mut map: FnvHashMap<Url, Vec<Message>> = FnvHashMap::default();
 
@@ -413,10 +671,25 @@ fn to_diagnostic(message: Message) -> Diagnostic {
     Diagnostic {
         range: srcpos_to_range(&message.pos),
         severity: Some(severity),
-        code: None,
+        code: Some(NumberOrString::String(message.code.code().to_owned())),
         source: Some("vhdl ls".to_owned()),
         message: message.message,
         related_information,
+        tags: None,
+        code_description: None,
+        data: None,
+    }
+}
+
+/// Code which is disabled is shown as unnecessary, clients typically render it faded out
+fn disabled_region_diagnostic(region: &SrcPos) -> Diagnostic {
+    Diagnostic {
+        range: srcpos_to_range(region),
+        severity: Some(DiagnosticSeverity::Hint),
+        source: Some("vhdl ls".to_owned()),
+        message: "Inactive code".to_owned(),
+        tags: Some(vec![DiagnosticTag::Unnecessary]),
+        ..Diagnostic::default()
     }
 }
 
@@ -534,8 +807,10 @@ mod tests {
             workspace: None,
             text_document: None,
             experimental: None,
+            ..ClientCapabilities::default()
         };
 
+        #[allow(deprecated)]
         let initialize_params = InitializeParams {
             process_id: None,
             root_path: None,
@@ -544,6 +819,8 @@ mod tests {
             capabilities,
             trace: None,
             workspace_folders: None,
+            client_info: None,
+            locale: None,
         };
 
         server
@@ -643,23 +920,49 @@ end entity ent2;
 
         let publish_diagnostics = PublishDiagnosticsParams {
             uri: file_url.clone(),
-            diagnostics: vec![Diagnostic {
-                range: Range {
-                    start: Position {
-                        line: 2,
-                        character: "end entity ".len() as u64,
+            version: None,
+            diagnostics: vec![
+                Diagnostic {
+                    range: Range {
+                        start: Position {
+                            line: 1,
+                            character: "entity ".len() as u32,
+                        },
+                        end: Position {
+                            line: 1,
+                            character: "entity ent".len() as u32,
+                        },
                     },
-                    end: Position {
-                        line: 2,
-                        character: "end entity ent2".len() as u64,
+                    code: Some(NumberOrString::String("E0002".to_owned())),
+                    severity: Some(DiagnosticSeverity::Hint),
+                    source: Some("vhdl ls".to_owned()),
+                    message: "related: Opening identifier".to_owned(),
+                    related_information: None,
+                    tags: None,
+                    code_description: None,
+                    data: None,
+                },
+                Diagnostic {
+                    range: Range {
+                        start: Position {
+                            line: 2,
+                            character: "end entity ".len() as u32,
+                        },
+                        end: Position {
+                            line: 2,
+                            character: "end entity ent2".len() as u32,
+                        },
                     },
+                    code: Some(NumberOrString::String("E0002".to_owned())),
+                    severity: Some(DiagnosticSeverity::Error),
+                    source: Some("vhdl ls".to_owned()),
+                    message: "End identifier mismatch, expected ent".to_owned(),
+                    related_information: None,
+                    tags: None,
+                    code_description: None,
+                    data: None,
                 },
-                code: None,
-                severity: Some(DiagnosticSeverity::Error),
-                source: Some("vhdl ls".to_owned()),
-                message: "End identifier mismatch, expected ent".to_owned(),
-                related_information: None,
-            }],
+            ],
         };
 
         mock.expect_notification("textDocument/publishDiagnostics", publish_diagnostics);
@@ -674,7 +977,7 @@ end entity ent;
         let did_change = DidChangeTextDocumentParams {
             text_document: VersionedTextDocumentIdentifier {
                 uri: file_url.clone(),
-                version: Some(1),
+                version: 1,
             },
             content_changes: vec![TextDocumentContentChangeEvent {
                 range: None,
@@ -685,6 +988,7 @@ end entity ent;
 
         let publish_diagnostics = PublishDiagnosticsParams {
             uri: file_url.clone(),
+            version: None,
             diagnostics: vec![],
         };
 
@@ -732,28 +1036,367 @@ lib.files = [
 
         let publish_diagnostics = PublishDiagnosticsParams {
             uri: file_uri.clone(),
+            version: None,
             diagnostics: vec![Diagnostic {
                 range: Range {
                     start: Position {
                         line: 3,
-                        character: "architecture rtl of ".len() as u64,
+                        character: "architecture rtl of ".len() as u32,
                     },
                     end: Position {
                         line: 3,
-                        character: "architecture rtl of ent2".len() as u64,
+                        character: "architecture rtl of ent2".len() as u32,
                     },
                 },
-                code: None,
+                code: Some(NumberOrString::String("E0102".to_owned())),
                 severity: Some(DiagnosticSeverity::Error),
                 source: Some("vhdl ls".to_owned()),
                 message: "No entity \'ent2\' within library \'lib\'".to_owned(),
                 related_information: None,
+                tags: None,
+                code_description: None,
+                data: None,
             }],
         };
 
+        mock.expect_notification_contains("window/logMessage", "Library lib is defined in");
+        mock.expect_notification("textDocument/publishDiagnostics", publish_diagnostics);
+
+        initialize_server(&mut server, root_uri);
+    }
+
+    #[test]
+    fn folding_range_of_inactive_code() {
+        let mock = RpcMock::new();
+        let mut server = VHDLServer::new(mock.clone());
+        let (_tempdir, root_uri) = temp_root_uri();
+        let file_uri = write_file(
+            &root_uri,
+            "file.vhd",
+            "\
+`if TOOL_NAME = \"other\" then
+entity ent is
+  not vhdl
+end entity;
+`else
+entity ent is
+end entity;
+`end if
+",
+        );
+
+        write_config(
+            &root_uri,
+            "
+standard = '2019'
+
+[libraries]
+lib.files = ['file.vhd']
+
+[conditional_analysis]
+TOOL_NAME = 'vhdl_ls'
+",
+        );
+
+        mock.expect_notification_contains("window/logMessage", "Library lib is defined in");
+        let publish_diagnostics = PublishDiagnosticsParams {
+            uri: file_uri.clone(),
+            version: None,
+            diagnostics: vec![Diagnostic {
+                range: Range {
+                    start: Position {
+                        line: 0,
+                        character: "`if TOOL_NAME = \"other\" then".len() as u32,
+                    },
+                    end: Position {
+                        line: 4,
+                        character: 0,
+                    },
+                },
+                severity: Some(DiagnosticSeverity::Hint),
+                source: Some("vhdl ls".to_owned()),
+                message: "Inactive code".to_owned(),
+                tags: Some(vec![DiagnosticTag::Unnecessary]),
+                ..Diagnostic::default()
+            }],
+        };
         mock.expect_notification("textDocument/publishDiagnostics", publish_diagnostics);
+        initialize_server(&mut server, root_uri);
+
+        let folding_ranges = server
+            .text_document_folding_range(&FoldingRangeParams {
+                text_document: TextDocumentIdentifier { uri: file_uri },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            })
+            .unwrap();
+        assert_eq!(
+            folding_ranges,
+            vec![FoldingRange {
+                start_line: 0,
+                start_character: None,
+                end_line: 3,
+                end_character: None,
+                kind: Some(FoldingRangeKind::Region),
+            }]
+        );
+    }
+
+    #[test]
+    fn hover_shows_static_value_of_constant() {
+        let mock = RpcMock::new();
+        let mut server = VHDLServer::new(mock.clone());
+        let (_tempdir, root_uri) = temp_root_uri();
+        let file_uri = write_file(
+            &root_uri,
+            "file.vhd",
+            "\
+library ieee;
+use ieee.math_real.all;
+
+entity ent is
+  generic (ADDR_W : natural := 10);
+end entity;
+
+architecture a of ent is
+  constant DEPTH : natural := 2**ADDR_W;
+  constant WIDTH : natural := integer(ceil(log2(real(DEPTH + 1))));
+begin
+end architecture;
+",
+        );
+
+        write_config(
+            &root_uri,
+            "
+[libraries]
+lib.files = ['file.vhd']
+",
+        );
 
+        mock.expect_notification_contains("window/logMessage", "Library lib is defined in");
         initialize_server(&mut server, root_uri);
+
+        let mut hover = |line, character| {
+            server
+                .text_document_hover(&TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: file_uri.clone(),
+                    },
+                    position: Position { line, character },
+                })
+                .unwrap()
+        };
+
+        let value_of = |hover: Option<Hover>| match hover.map(|hover| hover.contents) {
+            Some(HoverContents::Scalar(MarkedString::LanguageString(string))) => Some(string.value),
+            _ => None,
+        };
+
+        assert_eq!(value_of(hover(8, 12)), Some("-- = 1024".to_owned()));
+        assert_eq!(value_of(hover(9, 11)), Some("-- = 11".to_owned()));
+        assert_eq!(value_of(hover(8, 2)), None);
+    }
+
+    #[test]
+    fn hover_shows_static_value_with_supplied_generic() {
+        let mock = RpcMock::new();
+        let mut server = VHDLServer::new(mock.clone());
+        let (_tempdir, root_uri) = temp_root_uri();
+        let file_uri = write_file(
+            &root_uri,
+            "file.vhd",
+            "\
+entity ent is
+  generic (ADDR_W : natural := 10);
+end entity;
+
+architecture a of ent is
+  constant DEPTH : natural := 2**ADDR_W;
+begin
+end architecture;
+",
+        );
+
+        write_config(
+            &root_uri,
+            "
+[libraries]
+lib.files = ['file.vhd']
+
+[generics]
+ent.addr_w = 12
+",
+        );
+
+        mock.expect_notification_contains("window/logMessage", "Library lib is defined in");
+        initialize_server(&mut server, root_uri);
+
+        let hover = server
+            .text_document_hover(&TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: file_uri },
+                position: Position {
+                    line: 5,
+                    character: 12,
+                },
+            })
+            .unwrap()
+            .map(|hover| hover.contents);
+
+        match hover {
+            Some(HoverContents::Scalar(MarkedString::LanguageString(string))) => {
+                assert_eq!(string.value, "-- = 4096");
+            }
+            _ => panic!("Expected a hover with the value of DEPTH"),
+        }
+    }
+
+    #[test]
+    fn code_action_fixes_end_identifier_mismatch() {
+        let mock = RpcMock::new();
+        let mut server = VHDLServer::new(mock.clone());
+        let (_tempdir, root_uri) = temp_root_uri();
+        let file_uri = write_file(
+            &root_uri,
+            "file.vhd",
+            "\
+entity ent is
+end entity ent2;
+",
+        );
+
+        write_config(
+            &root_uri,
+            "
+[libraries]
+lib.files = ['file.vhd']
+",
+        );
+
+        mock.expect_notification_contains("window/logMessage", "Library lib is defined in");
+        mock.expect_notification_contains(
+            "textDocument/publishDiagnostics",
+            "End identifier mismatch, expected ent",
+        );
+        initialize_server(&mut server, root_uri);
+
+        let range = |line, start, end| Range {
+            start: Position {
+                line,
+                character: start,
+            },
+            end: Position {
+                line,
+                character: end,
+            },
+        };
+
+        let mut code_action = |range| {
+            server
+                .text_document_code_action(&CodeActionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: file_uri.clone(),
+                    },
+                    range,
+                    context: CodeActionContext {
+                        diagnostics: Vec::new(),
+                        only: None,
+                    },
+                    work_done_progress_params: WorkDoneProgressParams::default(),
+                    partial_result_params: PartialResultParams::default(),
+                })
+                .unwrap()
+        };
+
+        let actions = code_action(range(1, 12, 12));
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].title, "Replace with ent");
+        assert_eq!(actions[0].kind, Some(CodeActionKind::QUICKFIX));
+
+        let mut changes = HashMap::new();
+        changes.insert(
+            file_uri.clone(),
+            vec![TextEdit::new(range(1, 11, 15), "ent".to_owned())],
+        );
+        assert_eq!(
+            actions[0].edit,
+            Some(WorkspaceEdit {
+                changes: Some(changes),
+                ..WorkspaceEdit::default()
+            })
+        );
+
+        assert!(code_action(range(0, 0, 3)).is_empty());
+    }
+
+    #[test]
+    fn folding_range_of_translate_off_code() {
+        let mock = RpcMock::new();
+        let mut server = VHDLServer::new(mock.clone());
+        let (_tempdir, root_uri) = temp_root_uri();
+        let file_uri = write_file(
+            &root_uri,
+            "file.vhd",
+            "\
+entity ent is
+end entity;
+-- synthesis translate_off
+entity tb is
+end entity;
+-- synthesis translate_on
+",
+        );
+
+        write_config(
+            &root_uri,
+            "
+[libraries]
+lib.files = ['file.vhd']
+",
+        );
+
+        mock.expect_notification_contains("window/logMessage", "Library lib is defined in");
+        let publish_diagnostics = PublishDiagnosticsParams {
+            uri: file_uri.clone(),
+            version: None,
+            diagnostics: vec![Diagnostic {
+                range: Range {
+                    start: Position {
+                        line: 2,
+                        character: 0,
+                    },
+                    end: Position {
+                        line: 5,
+                        character: 0,
+                    },
+                },
+                severity: Some(DiagnosticSeverity::Hint),
+                source: Some("vhdl ls".to_owned()),
+                message: "Inactive code".to_owned(),
+                tags: Some(vec![DiagnosticTag::Unnecessary]),
+                ..Diagnostic::default()
+            }],
+        };
+        mock.expect_notification("textDocument/publishDiagnostics", publish_diagnostics);
+        initialize_server(&mut server, root_uri);
+
+        let folding_ranges = server
+            .text_document_folding_range(&FoldingRangeParams {
+                text_document: TextDocumentIdentifier { uri: file_uri },
+                work_done_progress_params: WorkDoneProgressParams::default(),
+                partial_result_params: PartialResultParams::default(),
+            })
+            .unwrap();
+        assert_eq!(
+            folding_ranges,
+            vec![FoldingRange {
+                start_line: 2,
+                start_character: None,
+                end_line: 4,
+                end_character: None,
+                kind: Some(FoldingRangeKind::Region),
+            }]
+        );
     }
 
     #[test]
@@ -768,6 +1411,7 @@ lib.files = [
 [libraries
 ",
         );
+        mock.expect_notification_contains("window/showMessage", "Failed to read config file");
         mock.expect_notification_contains(
             "window/showMessage",
             "Found no vhdl_ls.toml config file in the root path",
@@ -795,8 +1439,76 @@ lib.files = [
 ",
         );
 
+        mock.expect_notification_contains("window/logMessage", "Library lib is defined in");
         mock.expect_notification_contains("window/showMessage", "missing_file.vhd");
         initialize_server(&mut server, root_uri);
     }
 
+    #[test]
+    fn initialize_with_config_pattern_without_matches() {
+        let mock = RpcMock::new();
+        let mut server = VHDLServer::new(mock.clone());
+        let (_tempdir, root_uri) = temp_root_uri();
+
+        write_config(
+            &root_uri,
+            "
+[libraries]
+lib.files = ['src/*.vhd']
+",
+        );
+
+        mock.expect_notification_contains("window/showMessage", "matches no files");
+        mock.expect_notification_contains("window/logMessage", "Library lib is defined in");
+        initialize_server(&mut server, root_uri);
+    }
+
+    #[test]
+    fn did_open_discovers_config_in_parent_folder() {
+        let mock = RpcMock::new();
+        let mut server = VHDLServer::new(mock.clone());
+        let (_tempdir, root_uri) = temp_root_uri();
+
+        mock.expect_notification_contains(
+            "window/showMessage",
+            "Found no vhdl_ls.toml config file in the root path",
+        );
+        mock.expect_notification_contains(
+            "window/showMessage",
+            "Semantic analysis disabled, will perform syntax checking only",
+        );
+        initialize_server(&mut server, root_uri.clone());
+
+        std::fs::create_dir_all(root_uri.to_file_path().unwrap().join("sub/src")).unwrap();
+        let code = "\
+architecture rtl of ent2 is
+begin
+end;
+";
+        let file_uri = write_file(&root_uri, "sub/src/file.vhd", code);
+        write_file(
+            &root_uri,
+            "sub/vhdl_ls.toml",
+            "
+[libraries]
+lib.files = ['src/file.vhd']
+",
+        );
+
+        let did_open = DidOpenTextDocumentParams {
+            text_document: TextDocumentItem {
+                uri: file_uri.clone(),
+                language_id: "vhdl".to_owned(),
+                version: 0,
+                text: code.to_owned(),
+            },
+        };
+
+        mock.expect_notification_contains("window/logMessage", "sub/vhdl_ls.toml");
+        mock.expect_notification_contains(
+            "textDocument/publishDiagnostics",
+            "No entity 'ent2' within library 'lib'",
+        );
+        server.text_document_did_open_notification(&did_open);
+    }
 }
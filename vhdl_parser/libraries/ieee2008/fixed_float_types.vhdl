@@ -0,0 +1,15 @@
+-- Package fixed_float_types as defined by IEEE 1076-2008
+-- Only the package declaration is provided, it is used for semantic analysis
+
+package fixed_float_types is
+
+  type fixed_round_style_type is (fixed_round, fixed_truncate);
+
+  type fixed_overflow_style_type is (fixed_saturate, fixed_wrap);
+
+  type round_type is (round_nearest,   -- Default, nearest LSB '0'
+                      round_inf,       -- Round toward positive infinity
+                      round_neginf,    -- Round toward negative infinity
+                      round_zero);     -- Round toward zero (truncate)
+
+end package fixed_float_types;
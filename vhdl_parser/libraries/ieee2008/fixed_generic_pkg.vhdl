@@ -0,0 +1,303 @@
+-- Package fixed_generic_pkg as defined by IEEE 1076-2008
+-- Only the package declaration is provided, it is used for semantic analysis
+
+library IEEE;
+use STD.TEXTIO.all;
+use IEEE.STD_LOGIC_1164.all;
+use IEEE.NUMERIC_STD.all;
+use IEEE.fixed_float_types.all;
+
+package fixed_generic_pkg is
+  generic (
+    fixed_round_style    : fixed_round_style_type    := fixed_round;
+    fixed_overflow_style : fixed_overflow_style_type := fixed_saturate;
+    fixed_guard_bits     : NATURAL                   := 3;
+    no_warning           : BOOLEAN                   := false
+    );
+
+  type UNRESOLVED_ufixed is array (INTEGER range <>) of STD_ULOGIC;
+  type UNRESOLVED_sfixed is array (INTEGER range <>) of STD_ULOGIC;
+
+  alias U_ufixed is UNRESOLVED_ufixed;
+  alias U_sfixed is UNRESOLVED_sfixed;
+
+  subtype ufixed is (resolved) UNRESOLVED_ufixed;
+  subtype sfixed is (resolved) UNRESOLVED_sfixed;
+
+  function "+" (l, r : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function "-" (l, r : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function "*" (l, r : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function "/" (l, r : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function "rem" (l, r : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function "mod" (l, r : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function divide (l, r : UNRESOLVED_ufixed;
+    constant round_style : fixed_round_style_type := fixed_round_style;
+    constant guard_bits  : NATURAL := fixed_guard_bits) return UNRESOLVED_ufixed;
+  function reciprocal (arg : UNRESOLVED_ufixed;
+    constant round_style : fixed_round_style_type := fixed_round_style;
+    constant guard_bits  : NATURAL := fixed_guard_bits) return UNRESOLVED_ufixed;
+  function remainder (l, r : UNRESOLVED_ufixed;
+    constant round_style : fixed_round_style_type := fixed_round_style;
+    constant guard_bits  : NATURAL := fixed_guard_bits) return UNRESOLVED_ufixed;
+  function modulo (l, r : UNRESOLVED_ufixed;
+    constant round_style : fixed_round_style_type := fixed_round_style;
+    constant guard_bits  : NATURAL := fixed_guard_bits) return UNRESOLVED_ufixed;
+  procedure add_carry (L, R : in UNRESOLVED_ufixed; c_in : in STD_ULOGIC;
+    result : out UNRESOLVED_ufixed; c_out : out STD_ULOGIC);
+  function scalb (y : UNRESOLVED_ufixed; N : INTEGER) return UNRESOLVED_ufixed;
+  function scalb (y : UNRESOLVED_ufixed; N : UNRESOLVED_SIGNED) return UNRESOLVED_ufixed;
+  function find_leftmost (arg : UNRESOLVED_ufixed; y : STD_ULOGIC) return INTEGER;
+  function find_rightmost (arg : UNRESOLVED_ufixed; y : STD_ULOGIC) return INTEGER;
+  function "=" (l, r : UNRESOLVED_ufixed) return BOOLEAN;
+  function "/=" (l, r : UNRESOLVED_ufixed) return BOOLEAN;
+  function "<" (l, r : UNRESOLVED_ufixed) return BOOLEAN;
+  function ">" (l, r : UNRESOLVED_ufixed) return BOOLEAN;
+  function "<=" (l, r : UNRESOLVED_ufixed) return BOOLEAN;
+  function ">=" (l, r : UNRESOLVED_ufixed) return BOOLEAN;
+  function "?=" (l, r : UNRESOLVED_ufixed) return STD_ULOGIC;
+  function "?/=" (l, r : UNRESOLVED_ufixed) return STD_ULOGIC;
+  function "?<" (l, r : UNRESOLVED_ufixed) return STD_ULOGIC;
+  function "?>" (l, r : UNRESOLVED_ufixed) return STD_ULOGIC;
+  function "?<=" (l, r : UNRESOLVED_ufixed) return STD_ULOGIC;
+  function "?>=" (l, r : UNRESOLVED_ufixed) return STD_ULOGIC;
+  function std_match (l, r : UNRESOLVED_ufixed) return BOOLEAN;
+  function maximum (l, r : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function minimum (l, r : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function "sll" (ARG : UNRESOLVED_ufixed; COUNT : INTEGER) return UNRESOLVED_ufixed;
+  function "srl" (ARG : UNRESOLVED_ufixed; COUNT : INTEGER) return UNRESOLVED_ufixed;
+  function "rol" (ARG : UNRESOLVED_ufixed; COUNT : INTEGER) return UNRESOLVED_ufixed;
+  function "ror" (ARG : UNRESOLVED_ufixed; COUNT : INTEGER) return UNRESOLVED_ufixed;
+  function "sla" (ARG : UNRESOLVED_ufixed; COUNT : INTEGER) return UNRESOLVED_ufixed;
+  function "sra" (ARG : UNRESOLVED_ufixed; COUNT : INTEGER) return UNRESOLVED_ufixed;
+  function shift_left (ARG : UNRESOLVED_ufixed; COUNT : NATURAL) return UNRESOLVED_ufixed;
+  function shift_right (ARG : UNRESOLVED_ufixed; COUNT : NATURAL) return UNRESOLVED_ufixed;
+  function "not" (l : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function "and" (l, r : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function "or" (l, r : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function "nand" (l, r : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function "nor" (l, r : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function "xor" (l, r : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function "xnor" (l, r : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function "and" (l : UNRESOLVED_ufixed) return STD_ULOGIC;
+  function "or" (l : UNRESOLVED_ufixed) return STD_ULOGIC;
+  function "nand" (l : UNRESOLVED_ufixed) return STD_ULOGIC;
+  function "nor" (l : UNRESOLVED_ufixed) return STD_ULOGIC;
+  function "xor" (l : UNRESOLVED_ufixed) return STD_ULOGIC;
+  function "xnor" (l : UNRESOLVED_ufixed) return STD_ULOGIC;
+  function resize (arg : UNRESOLVED_ufixed; constant left_index : INTEGER; constant right_index : INTEGER;
+    constant overflow_style : fixed_overflow_style_type := fixed_overflow_style;
+    constant round_style : fixed_round_style_type := fixed_round_style) return UNRESOLVED_ufixed;
+  function resize (arg : UNRESOLVED_ufixed; size_res : UNRESOLVED_ufixed;
+    constant overflow_style : fixed_overflow_style_type := fixed_overflow_style;
+    constant round_style : fixed_round_style_type := fixed_round_style) return UNRESOLVED_ufixed;
+  function to_real (arg : UNRESOLVED_ufixed) return REAL;
+  function to_integer (arg : UNRESOLVED_ufixed;
+    constant overflow_style : fixed_overflow_style_type := fixed_overflow_style;
+    constant round_style : fixed_round_style_type := fixed_round_style) return INTEGER;
+  function to_slv (arg : UNRESOLVED_ufixed) return STD_LOGIC_VECTOR;
+  alias to_StdLogicVector is to_slv [UNRESOLVED_ufixed return STD_LOGIC_VECTOR];
+  alias to_Std_Logic_Vector is to_slv [UNRESOLVED_ufixed return STD_LOGIC_VECTOR];
+  function to_sulv (arg : UNRESOLVED_ufixed) return STD_ULOGIC_VECTOR;
+  alias to_StdULogicVector is to_sulv [UNRESOLVED_ufixed return STD_ULOGIC_VECTOR];
+  alias to_Std_ULogic_Vector is to_sulv [UNRESOLVED_ufixed return STD_ULOGIC_VECTOR];
+  function to_01 (s : UNRESOLVED_ufixed; constant XMAP : STD_ULOGIC := '0') return UNRESOLVED_ufixed;
+  function Is_X (arg : UNRESOLVED_ufixed) return BOOLEAN;
+  function To_X01 (arg : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function To_X01Z (arg : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function To_UX01 (arg : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function saturate (left_index, right_index : INTEGER) return UNRESOLVED_ufixed;
+  function saturate (size_res : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function to_string (value : UNRESOLVED_ufixed) return STRING;
+  alias to_bstring is to_string [UNRESOLVED_ufixed return STRING];
+  alias TO_BINARY_STRING is TO_STRING [UNRESOLVED_ufixed return STRING];
+  function to_ostring (value : UNRESOLVED_ufixed) return STRING;
+  alias TO_OCTAL_STRING is TO_OSTRING [UNRESOLVED_ufixed return STRING];
+  function to_hstring (value : UNRESOLVED_ufixed) return STRING;
+  alias TO_HEX_STRING is TO_HSTRING [UNRESOLVED_ufixed return STRING];
+  procedure WRITE (L : inout LINE; VALUE : in UNRESOLVED_ufixed;
+    JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  procedure READ (L : inout LINE; VALUE : out UNRESOLVED_ufixed);
+  procedure READ (L : inout LINE; VALUE : out UNRESOLVED_ufixed; GOOD : out BOOLEAN);
+  procedure OWRITE (L : inout LINE; VALUE : in UNRESOLVED_ufixed;
+    JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  procedure OREAD (L : inout LINE; VALUE : out UNRESOLVED_ufixed);
+  procedure OREAD (L : inout LINE; VALUE : out UNRESOLVED_ufixed; GOOD : out BOOLEAN);
+  procedure HWRITE (L : inout LINE; VALUE : in UNRESOLVED_ufixed;
+    JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  procedure HREAD (L : inout LINE; VALUE : out UNRESOLVED_ufixed);
+  procedure HREAD (L : inout LINE; VALUE : out UNRESOLVED_ufixed; GOOD : out BOOLEAN);
+  function from_string (bstring : STRING; size_res : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function from_ostring (ostring : STRING; size_res : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function from_hstring (hstring : STRING; size_res : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+
+  function "abs" (arg : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function "-" (arg : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function "+" (l, r : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function "-" (l, r : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function "*" (l, r : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function "/" (l, r : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function "rem" (l, r : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function "mod" (l, r : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function divide (l, r : UNRESOLVED_sfixed;
+    constant round_style : fixed_round_style_type := fixed_round_style;
+    constant guard_bits  : NATURAL := fixed_guard_bits) return UNRESOLVED_sfixed;
+  function reciprocal (arg : UNRESOLVED_sfixed;
+    constant round_style : fixed_round_style_type := fixed_round_style;
+    constant guard_bits  : NATURAL := fixed_guard_bits) return UNRESOLVED_sfixed;
+  function remainder (l, r : UNRESOLVED_sfixed;
+    constant round_style : fixed_round_style_type := fixed_round_style;
+    constant guard_bits  : NATURAL := fixed_guard_bits) return UNRESOLVED_sfixed;
+  function modulo (l, r : UNRESOLVED_sfixed;
+    constant round_style : fixed_round_style_type := fixed_round_style;
+    constant guard_bits  : NATURAL := fixed_guard_bits) return UNRESOLVED_sfixed;
+  procedure add_carry (L, R : in UNRESOLVED_sfixed; c_in : in STD_ULOGIC;
+    result : out UNRESOLVED_sfixed; c_out : out STD_ULOGIC);
+  function scalb (y : UNRESOLVED_sfixed; N : INTEGER) return UNRESOLVED_sfixed;
+  function scalb (y : UNRESOLVED_sfixed; N : UNRESOLVED_SIGNED) return UNRESOLVED_sfixed;
+  function Is_Negative (arg : UNRESOLVED_sfixed) return BOOLEAN;
+  function find_leftmost (arg : UNRESOLVED_sfixed; y : STD_ULOGIC) return INTEGER;
+  function find_rightmost (arg : UNRESOLVED_sfixed; y : STD_ULOGIC) return INTEGER;
+  function "=" (l, r : UNRESOLVED_sfixed) return BOOLEAN;
+  function "/=" (l, r : UNRESOLVED_sfixed) return BOOLEAN;
+  function "<" (l, r : UNRESOLVED_sfixed) return BOOLEAN;
+  function ">" (l, r : UNRESOLVED_sfixed) return BOOLEAN;
+  function "<=" (l, r : UNRESOLVED_sfixed) return BOOLEAN;
+  function ">=" (l, r : UNRESOLVED_sfixed) return BOOLEAN;
+  function "?=" (l, r : UNRESOLVED_sfixed) return STD_ULOGIC;
+  function "?/=" (l, r : UNRESOLVED_sfixed) return STD_ULOGIC;
+  function "?<" (l, r : UNRESOLVED_sfixed) return STD_ULOGIC;
+  function "?>" (l, r : UNRESOLVED_sfixed) return STD_ULOGIC;
+  function "?<=" (l, r : UNRESOLVED_sfixed) return STD_ULOGIC;
+  function "?>=" (l, r : UNRESOLVED_sfixed) return STD_ULOGIC;
+  function std_match (l, r : UNRESOLVED_sfixed) return BOOLEAN;
+  function maximum (l, r : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function minimum (l, r : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function "sll" (ARG : UNRESOLVED_sfixed; COUNT : INTEGER) return UNRESOLVED_sfixed;
+  function "srl" (ARG : UNRESOLVED_sfixed; COUNT : INTEGER) return UNRESOLVED_sfixed;
+  function "rol" (ARG : UNRESOLVED_sfixed; COUNT : INTEGER) return UNRESOLVED_sfixed;
+  function "ror" (ARG : UNRESOLVED_sfixed; COUNT : INTEGER) return UNRESOLVED_sfixed;
+  function "sla" (ARG : UNRESOLVED_sfixed; COUNT : INTEGER) return UNRESOLVED_sfixed;
+  function "sra" (ARG : UNRESOLVED_sfixed; COUNT : INTEGER) return UNRESOLVED_sfixed;
+  function shift_left (ARG : UNRESOLVED_sfixed; COUNT : NATURAL) return UNRESOLVED_sfixed;
+  function shift_right (ARG : UNRESOLVED_sfixed; COUNT : NATURAL) return UNRESOLVED_sfixed;
+  function "not" (l : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function "and" (l, r : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function "or" (l, r : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function "nand" (l, r : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function "nor" (l, r : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function "xor" (l, r : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function "xnor" (l, r : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function "and" (l : UNRESOLVED_sfixed) return STD_ULOGIC;
+  function "or" (l : UNRESOLVED_sfixed) return STD_ULOGIC;
+  function "nand" (l : UNRESOLVED_sfixed) return STD_ULOGIC;
+  function "nor" (l : UNRESOLVED_sfixed) return STD_ULOGIC;
+  function "xor" (l : UNRESOLVED_sfixed) return STD_ULOGIC;
+  function "xnor" (l : UNRESOLVED_sfixed) return STD_ULOGIC;
+  function resize (arg : UNRESOLVED_sfixed; constant left_index : INTEGER; constant right_index : INTEGER;
+    constant overflow_style : fixed_overflow_style_type := fixed_overflow_style;
+    constant round_style : fixed_round_style_type := fixed_round_style) return UNRESOLVED_sfixed;
+  function resize (arg : UNRESOLVED_sfixed; size_res : UNRESOLVED_sfixed;
+    constant overflow_style : fixed_overflow_style_type := fixed_overflow_style;
+    constant round_style : fixed_round_style_type := fixed_round_style) return UNRESOLVED_sfixed;
+  function to_real (arg : UNRESOLVED_sfixed) return REAL;
+  function to_integer (arg : UNRESOLVED_sfixed;
+    constant overflow_style : fixed_overflow_style_type := fixed_overflow_style;
+    constant round_style : fixed_round_style_type := fixed_round_style) return INTEGER;
+  function to_slv (arg : UNRESOLVED_sfixed) return STD_LOGIC_VECTOR;
+  alias to_StdLogicVector is to_slv [UNRESOLVED_sfixed return STD_LOGIC_VECTOR];
+  alias to_Std_Logic_Vector is to_slv [UNRESOLVED_sfixed return STD_LOGIC_VECTOR];
+  function to_sulv (arg : UNRESOLVED_sfixed) return STD_ULOGIC_VECTOR;
+  alias to_StdULogicVector is to_sulv [UNRESOLVED_sfixed return STD_ULOGIC_VECTOR];
+  alias to_Std_ULogic_Vector is to_sulv [UNRESOLVED_sfixed return STD_ULOGIC_VECTOR];
+  function to_01 (s : UNRESOLVED_sfixed; constant XMAP : STD_ULOGIC := '0') return UNRESOLVED_sfixed;
+  function Is_X (arg : UNRESOLVED_sfixed) return BOOLEAN;
+  function To_X01 (arg : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function To_X01Z (arg : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function To_UX01 (arg : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function saturate (left_index, right_index : INTEGER) return UNRESOLVED_sfixed;
+  function saturate (size_res : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function to_string (value : UNRESOLVED_sfixed) return STRING;
+  alias to_bstring is to_string [UNRESOLVED_sfixed return STRING];
+  alias TO_BINARY_STRING is TO_STRING [UNRESOLVED_sfixed return STRING];
+  function to_ostring (value : UNRESOLVED_sfixed) return STRING;
+  alias TO_OCTAL_STRING is TO_OSTRING [UNRESOLVED_sfixed return STRING];
+  function to_hstring (value : UNRESOLVED_sfixed) return STRING;
+  alias TO_HEX_STRING is TO_HSTRING [UNRESOLVED_sfixed return STRING];
+  procedure WRITE (L : inout LINE; VALUE : in UNRESOLVED_sfixed;
+    JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  procedure READ (L : inout LINE; VALUE : out UNRESOLVED_sfixed);
+  procedure READ (L : inout LINE; VALUE : out UNRESOLVED_sfixed; GOOD : out BOOLEAN);
+  procedure OWRITE (L : inout LINE; VALUE : in UNRESOLVED_sfixed;
+    JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  procedure OREAD (L : inout LINE; VALUE : out UNRESOLVED_sfixed);
+  procedure OREAD (L : inout LINE; VALUE : out UNRESOLVED_sfixed; GOOD : out BOOLEAN);
+  procedure HWRITE (L : inout LINE; VALUE : in UNRESOLVED_sfixed;
+    JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  procedure HREAD (L : inout LINE; VALUE : out UNRESOLVED_sfixed);
+  procedure HREAD (L : inout LINE; VALUE : out UNRESOLVED_sfixed; GOOD : out BOOLEAN);
+  function from_string (bstring : STRING; size_res : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function from_ostring (ostring : STRING; size_res : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function from_hstring (hstring : STRING; size_res : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+
+  function ufixed_high (left_index, right_index : INTEGER;
+                        operation : CHARACTER := 'X';
+                        left_index2, right_index2 : INTEGER := 0) return INTEGER;
+  function ufixed_low (left_index, right_index : INTEGER;
+                       operation : CHARACTER := 'X';
+                       left_index2, right_index2 : INTEGER := 0) return INTEGER;
+  function sfixed_high (left_index, right_index : INTEGER;
+                        operation : CHARACTER := 'X';
+                        left_index2, right_index2 : INTEGER := 0) return INTEGER;
+  function sfixed_low (left_index, right_index : INTEGER;
+                       operation : CHARACTER := 'X';
+                       left_index2, right_index2 : INTEGER := 0) return INTEGER;
+
+  function to_ufixed (arg : NATURAL; constant left_index : INTEGER; constant right_index : INTEGER := 0;
+                      constant overflow_style : fixed_overflow_style_type := fixed_overflow_style;
+                      constant round_style : fixed_round_style_type := fixed_round_style) return UNRESOLVED_ufixed;
+  function to_ufixed (arg : REAL; constant left_index : INTEGER; constant right_index : INTEGER;
+                      constant overflow_style : fixed_overflow_style_type := fixed_overflow_style;
+                      constant round_style : fixed_round_style_type := fixed_round_style;
+                      constant guard_bits : NATURAL := fixed_guard_bits) return UNRESOLVED_ufixed;
+  function to_ufixed (arg : UNRESOLVED_UNSIGNED; constant left_index : INTEGER; constant right_index : INTEGER := 0;
+                      constant overflow_style : fixed_overflow_style_type := fixed_overflow_style;
+                      constant round_style : fixed_round_style_type := fixed_round_style) return UNRESOLVED_ufixed;
+  function to_ufixed (arg : UNRESOLVED_UNSIGNED) return UNRESOLVED_ufixed;
+  function to_ufixed (arg : STD_ULOGIC_VECTOR; constant left_index : INTEGER; constant right_index : INTEGER) return UNRESOLVED_ufixed;
+  function to_ufixed (arg : STD_ULOGIC_VECTOR; size_res : UNRESOLVED_ufixed) return UNRESOLVED_ufixed;
+  function to_ufixed (arg : UNRESOLVED_sfixed) return UNRESOLVED_ufixed;
+
+  function to_sfixed (arg : INTEGER; constant left_index : INTEGER; constant right_index : INTEGER := 0;
+                      constant overflow_style : fixed_overflow_style_type := fixed_overflow_style;
+                      constant round_style : fixed_round_style_type := fixed_round_style) return UNRESOLVED_sfixed;
+  function to_sfixed (arg : REAL; constant left_index : INTEGER; constant right_index : INTEGER;
+                      constant overflow_style : fixed_overflow_style_type := fixed_overflow_style;
+                      constant round_style : fixed_round_style_type := fixed_round_style;
+                      constant guard_bits : NATURAL := fixed_guard_bits) return UNRESOLVED_sfixed;
+  function to_sfixed (arg : UNRESOLVED_SIGNED; constant left_index : INTEGER; constant right_index : INTEGER := 0;
+                      constant overflow_style : fixed_overflow_style_type := fixed_overflow_style;
+                      constant round_style : fixed_round_style_type := fixed_round_style) return UNRESOLVED_sfixed;
+  function to_sfixed (arg : UNRESOLVED_SIGNED) return UNRESOLVED_sfixed;
+  function to_sfixed (arg : STD_ULOGIC_VECTOR; constant left_index : INTEGER; constant right_index : INTEGER) return UNRESOLVED_sfixed;
+  function to_sfixed (arg : STD_ULOGIC_VECTOR; size_res : UNRESOLVED_sfixed) return UNRESOLVED_sfixed;
+  function to_sfixed (arg : UNRESOLVED_ufixed) return UNRESOLVED_sfixed;
+
+  function to_unsigned (arg : UNRESOLVED_ufixed; constant size : NATURAL;
+                        constant overflow_style : fixed_overflow_style_type := fixed_overflow_style;
+                        constant round_style : fixed_round_style_type := fixed_round_style) return UNRESOLVED_UNSIGNED;
+  function to_signed (arg : UNRESOLVED_sfixed; constant size : NATURAL;
+                      constant overflow_style : fixed_overflow_style_type := fixed_overflow_style;
+                      constant round_style : fixed_round_style_type := fixed_round_style) return UNRESOLVED_SIGNED;
+
+  function to_UFix (arg : STD_ULOGIC_VECTOR; width : NATURAL; fraction : NATURAL) return UNRESOLVED_ufixed;
+  function to_SFix (arg : STD_ULOGIC_VECTOR; width : NATURAL; fraction : NATURAL) return UNRESOLVED_sfixed;
+  function UFix_high (width, fraction : NATURAL; operation : CHARACTER := 'X';
+                      width2, fraction2 : NATURAL := 0) return INTEGER;
+  function UFix_low (width, fraction : NATURAL; operation : CHARACTER := 'X';
+                     width2, fraction2 : NATURAL := 0) return INTEGER;
+  function SFix_high (width, fraction : NATURAL; operation : CHARACTER := 'X';
+                      width2, fraction2 : NATURAL := 0) return INTEGER;
+  function SFix_low (width, fraction : NATURAL; operation : CHARACTER := 'X';
+                     width2, fraction2 : NATURAL := 0) return INTEGER;
+
+end package fixed_generic_pkg;
@@ -0,0 +1,312 @@
+-- Package float_generic_pkg as defined by IEEE 1076-2008
+-- Only the package declaration is provided, it is used for semantic analysis
+
+library IEEE;
+use STD.TEXTIO.all;
+use IEEE.STD_LOGIC_1164.all;
+use IEEE.NUMERIC_STD.all;
+use IEEE.fixed_float_types.all;
+
+package float_generic_pkg is
+  generic (
+    float_exponent_width : POSITIVE   := 8;
+    float_fraction_width : POSITIVE   := 23;
+    float_round_style    : round_type := round_nearest;
+    float_denormalize    : BOOLEAN    := true;
+    float_check_error    : BOOLEAN    := true;
+    float_guard_bits     : NATURAL    := 3;
+    no_warning           : BOOLEAN    := false;
+    package fixed_pkg is new IEEE.fixed_generic_pkg generic map (<>)
+    );
+
+  use fixed_pkg.all;
+
+  type UNRESOLVED_float is array (INTEGER range <>) of STD_ULOGIC;
+  alias U_float is UNRESOLVED_float;
+  subtype float is (resolved) UNRESOLVED_float;
+
+  subtype UNRESOLVED_float32 is UNRESOLVED_float (8 downto -23);
+  alias U_float32 is UNRESOLVED_float32;
+  subtype float32 is float (8 downto -23);
+  subtype UNRESOLVED_float64 is UNRESOLVED_float (11 downto -52);
+  alias U_float64 is UNRESOLVED_float64;
+  subtype float64 is float (11 downto -52);
+  subtype UNRESOLVED_float128 is UNRESOLVED_float (15 downto -112);
+  alias U_float128 is UNRESOLVED_float128;
+  subtype float128 is float (15 downto -112);
+
+  type valid_fpstate is (nan,              -- Signaling NaN (C FP_NAN)
+                         quiet_nan,        -- Quiet NaN (C FP_NAN)
+                         neg_inf,          -- Negative infinity (C IS_INF)
+                         neg_normal,       -- negative normalized nonzero
+                         neg_denormal,     -- negative denormalized (FP_SUBNORMAL)
+                         neg_zero,         -- -0 (FP_ZERO)
+                         pos_zero,         -- +0 (FP_ZERO)
+                         pos_denormal,     -- Positive denormalized (FP_SUBNORMAL)
+                         pos_normal,       -- positive normalized nonzero
+                         pos_inf,          -- positive infinity
+                         isx);             -- at least one input is unknown
+
+  function Classfp (x : UNRESOLVED_float; check_error : BOOLEAN := float_check_error) return valid_fpstate;
+
+  function "abs" (arg : UNRESOLVED_float) return UNRESOLVED_float;
+  function "-" (arg : UNRESOLVED_float) return UNRESOLVED_float;
+
+  function "+" (l, r : UNRESOLVED_float) return UNRESOLVED_float;
+  function "-" (l, r : UNRESOLVED_float) return UNRESOLVED_float;
+  function "*" (l, r : UNRESOLVED_float) return UNRESOLVED_float;
+  function "/" (l, r : UNRESOLVED_float) return UNRESOLVED_float;
+  function "rem" (l, r : UNRESOLVED_float) return UNRESOLVED_float;
+  function "mod" (l, r : UNRESOLVED_float) return UNRESOLVED_float;
+  function add (l, r : UNRESOLVED_float;
+    constant round_style : round_type := float_round_style;
+    constant guard : NATURAL := float_guard_bits;
+    constant check_error : BOOLEAN := float_check_error;
+    constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function subtract (l, r : UNRESOLVED_float;
+    constant round_style : round_type := float_round_style;
+    constant guard : NATURAL := float_guard_bits;
+    constant check_error : BOOLEAN := float_check_error;
+    constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function multiply (l, r : UNRESOLVED_float;
+    constant round_style : round_type := float_round_style;
+    constant guard : NATURAL := float_guard_bits;
+    constant check_error : BOOLEAN := float_check_error;
+    constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function divide (l, r : UNRESOLVED_float;
+    constant round_style : round_type := float_round_style;
+    constant guard : NATURAL := float_guard_bits;
+    constant check_error : BOOLEAN := float_check_error;
+    constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function remainder (l, r : UNRESOLVED_float;
+    constant round_style : round_type := float_round_style;
+    constant guard : NATURAL := float_guard_bits;
+    constant check_error : BOOLEAN := float_check_error;
+    constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function modulo (l, r : UNRESOLVED_float;
+    constant round_style : round_type := float_round_style;
+    constant guard : NATURAL := float_guard_bits;
+    constant check_error : BOOLEAN := float_check_error;
+    constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function reciprocal (arg : UNRESOLVED_float;
+    constant round_style : round_type := float_round_style;
+    constant guard : NATURAL := float_guard_bits;
+    constant check_error : BOOLEAN := float_check_error;
+    constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function dividebyp2 (l, r : UNRESOLVED_float;
+    constant round_style : round_type := float_round_style;
+    constant guard : NATURAL := float_guard_bits;
+    constant check_error : BOOLEAN := float_check_error;
+    constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function mac (l, r, c : UNRESOLVED_float;
+    constant round_style : round_type := float_round_style;
+    constant guard : NATURAL := float_guard_bits;
+    constant check_error : BOOLEAN := float_check_error;
+    constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function sqrt (arg : UNRESOLVED_float;
+    constant round_style : round_type := float_round_style;
+    constant guard : NATURAL := float_guard_bits;
+    constant check_error : BOOLEAN := float_check_error;
+    constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function Is_Negative (arg : UNRESOLVED_float) return BOOLEAN;
+  function "=" (l, r : UNRESOLVED_float) return BOOLEAN;
+  function "/=" (l, r : UNRESOLVED_float) return BOOLEAN;
+  function "<" (l, r : UNRESOLVED_float) return BOOLEAN;
+  function ">" (l, r : UNRESOLVED_float) return BOOLEAN;
+  function "<=" (l, r : UNRESOLVED_float) return BOOLEAN;
+  function ">=" (l, r : UNRESOLVED_float) return BOOLEAN;
+  function "?=" (l, r : UNRESOLVED_float) return STD_ULOGIC;
+  function "?/=" (l, r : UNRESOLVED_float) return STD_ULOGIC;
+  function "?<" (l, r : UNRESOLVED_float) return STD_ULOGIC;
+  function "?>" (l, r : UNRESOLVED_float) return STD_ULOGIC;
+  function "?<=" (l, r : UNRESOLVED_float) return STD_ULOGIC;
+  function "?>=" (l, r : UNRESOLVED_float) return STD_ULOGIC;
+  function std_match (l, r : UNRESOLVED_float) return BOOLEAN;
+  function find_rightmost (arg : UNRESOLVED_float; y : STD_ULOGIC) return INTEGER;
+  function find_leftmost (arg : UNRESOLVED_float; y : STD_ULOGIC) return INTEGER;
+  function maximum (l, r : UNRESOLVED_float) return UNRESOLVED_float;
+  function minimum (l, r : UNRESOLVED_float) return UNRESOLVED_float;
+  function "not" (l : UNRESOLVED_float) return UNRESOLVED_float;
+  function "and" (l, r : UNRESOLVED_float) return UNRESOLVED_float;
+  function "or" (l, r : UNRESOLVED_float) return UNRESOLVED_float;
+  function "nand" (l, r : UNRESOLVED_float) return UNRESOLVED_float;
+  function "nor" (l, r : UNRESOLVED_float) return UNRESOLVED_float;
+  function "xor" (l, r : UNRESOLVED_float) return UNRESOLVED_float;
+  function "xnor" (l, r : UNRESOLVED_float) return UNRESOLVED_float;
+  function "and" (l : UNRESOLVED_float) return STD_ULOGIC;
+  function "or" (l : UNRESOLVED_float) return STD_ULOGIC;
+  function "nand" (l : UNRESOLVED_float) return STD_ULOGIC;
+  function "nor" (l : UNRESOLVED_float) return STD_ULOGIC;
+  function "xor" (l : UNRESOLVED_float) return STD_ULOGIC;
+  function "xnor" (l : UNRESOLVED_float) return STD_ULOGIC;
+
+  function resize (arg : UNRESOLVED_float;
+                   constant exponent_width : NATURAL := float_exponent_width;
+                   constant fraction_width : NATURAL := float_fraction_width;
+                   constant round_style : round_type := float_round_style;
+                   constant check_error : BOOLEAN := float_check_error;
+                   constant denormalize_in : BOOLEAN := float_denormalize;
+                   constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function resize (arg : UNRESOLVED_float; size_res : UNRESOLVED_float;
+                   constant round_style : round_type := float_round_style;
+                   constant check_error : BOOLEAN := float_check_error;
+                   constant denormalize_in : BOOLEAN := float_denormalize;
+                   constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function to_float32 (arg : UNRESOLVED_float;
+                       constant round_style : round_type := float_round_style;
+                       constant check_error : BOOLEAN := float_check_error;
+                       constant denormalize_in : BOOLEAN := float_denormalize;
+                       constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float32;
+  function to_float64 (arg : UNRESOLVED_float;
+                       constant round_style : round_type := float_round_style;
+                       constant check_error : BOOLEAN := float_check_error;
+                       constant denormalize_in : BOOLEAN := float_denormalize;
+                       constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float64;
+  function to_float128 (arg : UNRESOLVED_float;
+                        constant round_style : round_type := float_round_style;
+                        constant check_error : BOOLEAN := float_check_error;
+                        constant denormalize_in : BOOLEAN := float_denormalize;
+                        constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float128;
+
+  function to_float (arg : INTEGER;
+                     constant exponent_width : NATURAL := float_exponent_width;
+                     constant fraction_width : NATURAL := float_fraction_width;
+                     constant round_style : round_type := float_round_style) return UNRESOLVED_float;
+  function to_float (arg : REAL;
+                     constant exponent_width : NATURAL := float_exponent_width;
+                     constant fraction_width : NATURAL := float_fraction_width;
+                     constant round_style : round_type := float_round_style;
+                     constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function to_float (arg : UNRESOLVED_UNSIGNED;
+                     constant exponent_width : NATURAL := float_exponent_width;
+                     constant fraction_width : NATURAL := float_fraction_width;
+                     constant round_style : round_type := float_round_style) return UNRESOLVED_float;
+  function to_float (arg : UNRESOLVED_SIGNED;
+                     constant exponent_width : NATURAL := float_exponent_width;
+                     constant fraction_width : NATURAL := float_fraction_width;
+                     constant round_style : round_type := float_round_style) return UNRESOLVED_float;
+  function to_float (arg : UNRESOLVED_ufixed;
+                     constant exponent_width : NATURAL := float_exponent_width;
+                     constant fraction_width : NATURAL := float_fraction_width;
+                     constant round_style : round_type := float_round_style;
+                     constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function to_float (arg : UNRESOLVED_sfixed;
+                     constant exponent_width : NATURAL := float_exponent_width;
+                     constant fraction_width : NATURAL := float_fraction_width;
+                     constant round_style : round_type := float_round_style;
+                     constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function to_float (arg : STD_ULOGIC_VECTOR;
+                     constant exponent_width : NATURAL := float_exponent_width;
+                     constant fraction_width : NATURAL := float_fraction_width) return UNRESOLVED_float;
+  function to_float (arg : INTEGER; size_res : UNRESOLVED_float;
+                     constant round_style : round_type := float_round_style) return UNRESOLVED_float;
+  function to_float (arg : REAL; size_res : UNRESOLVED_float;
+                     constant round_style : round_type := float_round_style;
+                     constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function to_float (arg : UNRESOLVED_UNSIGNED; size_res : UNRESOLVED_float;
+                     constant round_style : round_type := float_round_style) return UNRESOLVED_float;
+  function to_float (arg : UNRESOLVED_SIGNED; size_res : UNRESOLVED_float;
+                     constant round_style : round_type := float_round_style) return UNRESOLVED_float;
+  function to_float (arg : STD_ULOGIC_VECTOR; size_res : UNRESOLVED_float) return UNRESOLVED_float;
+  function to_float (arg : UNRESOLVED_ufixed; size_res : UNRESOLVED_float;
+                     constant round_style : round_type := float_round_style;
+                     constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function to_float (arg : UNRESOLVED_sfixed; size_res : UNRESOLVED_float;
+                     constant round_style : round_type := float_round_style;
+                     constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+
+  function to_unsigned (arg : UNRESOLVED_float; constant size : NATURAL;
+                        constant round_style : round_type := float_round_style;
+                        constant check_error : BOOLEAN := float_check_error) return UNRESOLVED_UNSIGNED;
+  function to_signed (arg : UNRESOLVED_float; constant size : NATURAL;
+                      constant round_style : round_type := float_round_style;
+                      constant check_error : BOOLEAN := float_check_error) return UNRESOLVED_SIGNED;
+  function to_ufixed (arg : UNRESOLVED_float;
+                      constant left_index : INTEGER;
+                      constant right_index : INTEGER;
+                      constant overflow_style : fixed_overflow_style_type := fixed_overflow_style;
+                      constant round_style : fixed_round_style_type := fixed_round_style;
+                      constant check_error : BOOLEAN := float_check_error;
+                      constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_ufixed;
+  function to_sfixed (arg : UNRESOLVED_float;
+                      constant left_index : INTEGER;
+                      constant right_index : INTEGER;
+                      constant overflow_style : fixed_overflow_style_type := fixed_overflow_style;
+                      constant round_style : fixed_round_style_type := fixed_round_style;
+                      constant check_error : BOOLEAN := float_check_error;
+                      constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_sfixed;
+  function to_real (arg : UNRESOLVED_float;
+                    constant check_error : BOOLEAN := float_check_error;
+                    constant denormalize : BOOLEAN := float_denormalize) return REAL;
+  function to_integer (arg : UNRESOLVED_float;
+                       constant round_style : round_type := float_round_style;
+                       constant check_error : BOOLEAN := float_check_error) return INTEGER;
+  function realtobits (arg : REAL) return STD_ULOGIC_VECTOR;
+  function bitstoreal (arg : STD_ULOGIC_VECTOR) return REAL;
+
+  function to_slv (arg : UNRESOLVED_float) return STD_LOGIC_VECTOR;
+  alias to_StdLogicVector is to_slv [UNRESOLVED_float return STD_LOGIC_VECTOR];
+  alias to_Std_Logic_Vector is to_slv [UNRESOLVED_float return STD_LOGIC_VECTOR];
+  function to_sulv (arg : UNRESOLVED_float) return STD_ULOGIC_VECTOR;
+  alias to_StdULogicVector is to_sulv [UNRESOLVED_float return STD_ULOGIC_VECTOR];
+  alias to_Std_ULogic_Vector is to_sulv [UNRESOLVED_float return STD_ULOGIC_VECTOR];
+
+  function to_01 (arg : UNRESOLVED_float; XMAP : STD_LOGIC := '0') return UNRESOLVED_float;
+  function Is_X (arg : UNRESOLVED_float) return BOOLEAN;
+  function to_X01 (arg : UNRESOLVED_float) return UNRESOLVED_float;
+  function to_X01Z (arg : UNRESOLVED_float) return UNRESOLVED_float;
+  function to_UX01 (arg : UNRESOLVED_float) return UNRESOLVED_float;
+
+  function break_number (arg : UNRESOLVED_float; denormalize : BOOLEAN := float_denormalize;
+                         check_error : BOOLEAN := float_check_error) return UNRESOLVED_ufixed;
+  function Copysign (x, y : UNRESOLVED_float) return UNRESOLVED_float;
+  function Scalb (y : UNRESOLVED_float; N : INTEGER;
+                  constant round_style : round_type := float_round_style;
+                  constant check_error : BOOLEAN := float_check_error;
+                  constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function Logb (x : UNRESOLVED_float) return INTEGER;
+  function Nextafter (x, y : UNRESOLVED_float;
+                      constant check_error : BOOLEAN := float_check_error;
+                      constant denormalize : BOOLEAN := float_denormalize) return UNRESOLVED_float;
+  function Unordered (x, y : UNRESOLVED_float) return BOOLEAN;
+  function Finite (x : UNRESOLVED_float) return BOOLEAN;
+  function Isnan (x : UNRESOLVED_float) return BOOLEAN;
+
+  function zerofp (constant exponent_width : NATURAL := float_exponent_width;
+                   constant fraction_width : NATURAL := float_fraction_width) return UNRESOLVED_float;
+  function nanfp (constant exponent_width : NATURAL := float_exponent_width;
+                  constant fraction_width : NATURAL := float_fraction_width) return UNRESOLVED_float;
+  function qnanfp (constant exponent_width : NATURAL := float_exponent_width;
+                   constant fraction_width : NATURAL := float_fraction_width) return UNRESOLVED_float;
+  function pos_inffp (constant exponent_width : NATURAL := float_exponent_width;
+                      constant fraction_width : NATURAL := float_fraction_width) return UNRESOLVED_float;
+  function neg_inffp (constant exponent_width : NATURAL := float_exponent_width;
+                      constant fraction_width : NATURAL := float_fraction_width) return UNRESOLVED_float;
+  function neg_zerofp (constant exponent_width : NATURAL := float_exponent_width;
+                       constant fraction_width : NATURAL := float_fraction_width) return UNRESOLVED_float;
+
+  function to_string (value : UNRESOLVED_float) return STRING;
+  alias to_bstring is to_string [UNRESOLVED_float return STRING];
+  alias TO_BINARY_STRING is TO_STRING [UNRESOLVED_float return STRING];
+  function to_ostring (value : UNRESOLVED_float) return STRING;
+  alias TO_OCTAL_STRING is TO_OSTRING [UNRESOLVED_float return STRING];
+  function to_hstring (value : UNRESOLVED_float) return STRING;
+  alias TO_HEX_STRING is TO_HSTRING [UNRESOLVED_float return STRING];
+  function from_string (bstring : STRING; size_res : UNRESOLVED_float) return UNRESOLVED_float;
+  function from_ostring (ostring : STRING; size_res : UNRESOLVED_float) return UNRESOLVED_float;
+  function from_hstring (hstring : STRING; size_res : UNRESOLVED_float) return UNRESOLVED_float;
+
+  procedure WRITE (L : inout LINE; VALUE : in UNRESOLVED_float;
+                   JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  procedure READ (L : inout LINE; VALUE : out UNRESOLVED_float);
+  procedure READ (L : inout LINE; VALUE : out UNRESOLVED_float; GOOD : out BOOLEAN);
+  procedure OWRITE (L : inout LINE; VALUE : in UNRESOLVED_float;
+                    JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  procedure OREAD (L : inout LINE; VALUE : out UNRESOLVED_float);
+  procedure OREAD (L : inout LINE; VALUE : out UNRESOLVED_float; GOOD : out BOOLEAN);
+  procedure HWRITE (L : inout LINE; VALUE : in UNRESOLVED_float;
+                    JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  procedure HREAD (L : inout LINE; VALUE : out UNRESOLVED_float);
+  procedure HREAD (L : inout LINE; VALUE : out UNRESOLVED_float; GOOD : out BOOLEAN);
+
+end package float_generic_pkg;
@@ -0,0 +1,15 @@
+-- Package float_pkg as defined by IEEE 1076-2008
+
+library IEEE;
+
+package float_pkg is new IEEE.float_generic_pkg
+  generic map (
+    float_exponent_width => 8,
+    float_fraction_width => 23,
+    float_round_style    => IEEE.fixed_float_types.round_nearest,
+    float_denormalize    => true,
+    float_check_error    => true,
+    float_guard_bits     => 3,
+    no_warning           => false,
+    fixed_pkg            => IEEE.fixed_pkg
+    );
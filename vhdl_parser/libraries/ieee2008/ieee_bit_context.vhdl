@@ -0,0 +1,6 @@
+-- Context ieee_bit_context as defined by IEEE 1076-2008
+
+context IEEE_BIT_CONTEXT is
+  library IEEE;
+  use IEEE.NUMERIC_BIT.all;
+end context IEEE_BIT_CONTEXT;
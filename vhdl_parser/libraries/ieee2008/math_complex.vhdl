@@ -0,0 +1,97 @@
+-- Package math_complex as defined by IEEE 1076-2008
+-- Only the package declaration is provided, it is used for semantic analysis
+
+use WORK.MATH_REAL.all;
+
+package MATH_COMPLEX is
+  constant CopyRightNotice : STRING
+    := "Copyright 1996 IEEE. All rights reserved.";
+
+  type COMPLEX is
+    record
+      RE : REAL;
+      IM : REAL;
+    end record;
+
+  subtype POSITIVE_REAL is REAL range 0.0 to REAL'HIGH;
+
+  subtype PRINCIPAL_VALUE is REAL range -MATH_PI to MATH_PI;
+
+  type COMPLEX_POLAR is
+    record
+      MAG : POSITIVE_REAL;
+      ARG : PRINCIPAL_VALUE;
+    end record;
+
+  constant MATH_CBASE_1 : COMPLEX := COMPLEX'(1.0, 0.0);
+  constant MATH_CBASE_J : COMPLEX := COMPLEX'(0.0, 1.0);
+  constant MATH_CZERO : COMPLEX := COMPLEX'(0.0, 0.0);
+
+  function "=" (L : in COMPLEX_POLAR; R : in COMPLEX_POLAR) return BOOLEAN;
+  function "/=" (L : in COMPLEX_POLAR; R : in COMPLEX_POLAR) return BOOLEAN;
+
+  function CMPLX (X : in REAL; Y : in REAL := 0.0) return COMPLEX;
+  function GET_PRINCIPAL_VALUE (X : in REAL) return PRINCIPAL_VALUE;
+  function COMPLEX_TO_POLAR (Z : in COMPLEX) return COMPLEX_POLAR;
+  function POLAR_TO_COMPLEX (Z : in COMPLEX_POLAR) return COMPLEX;
+
+  function "ABS" (Z : in COMPLEX) return POSITIVE_REAL;
+  function "ABS" (Z : in COMPLEX_POLAR) return POSITIVE_REAL;
+  function ARG (Z : in COMPLEX) return PRINCIPAL_VALUE;
+  function ARG (Z : in COMPLEX_POLAR) return PRINCIPAL_VALUE;
+
+  function "-" (Z : in COMPLEX) return COMPLEX;
+  function "-" (Z : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function CONJ (Z : in COMPLEX) return COMPLEX;
+  function CONJ (Z : in COMPLEX_POLAR) return COMPLEX_POLAR;
+
+  function SQRT (Z : in COMPLEX) return COMPLEX;
+  function SQRT (Z : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function EXP (Z : in COMPLEX) return COMPLEX;
+  function EXP (Z : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function LOG (Z : in COMPLEX) return COMPLEX;
+  function LOG2 (Z : in COMPLEX) return COMPLEX;
+  function LOG10 (Z : in COMPLEX) return COMPLEX;
+  function LOG (Z : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function LOG2 (Z : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function LOG10 (Z : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function LOG (Z : in COMPLEX; BASE : in REAL) return COMPLEX;
+  function LOG (Z : in COMPLEX_POLAR; BASE : in REAL) return COMPLEX_POLAR;
+
+  function SIN (Z : in COMPLEX) return COMPLEX;
+  function SIN (Z : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function COS (Z : in COMPLEX) return COMPLEX;
+  function COS (Z : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function SINH (Z : in COMPLEX) return COMPLEX;
+  function SINH (Z : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function COSH (Z : in COMPLEX) return COMPLEX;
+  function COSH (Z : in COMPLEX_POLAR) return COMPLEX_POLAR;
+
+  function "+" (L : in COMPLEX; R : in COMPLEX) return COMPLEX;
+  function "+" (L : in REAL; R : in COMPLEX) return COMPLEX;
+  function "+" (L : in COMPLEX; R : in REAL) return COMPLEX;
+  function "+" (L : in COMPLEX_POLAR; R : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function "+" (L : in REAL; R : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function "+" (L : in COMPLEX_POLAR; R : in REAL) return COMPLEX_POLAR;
+
+  function "-" (L : in COMPLEX; R : in COMPLEX) return COMPLEX;
+  function "-" (L : in REAL; R : in COMPLEX) return COMPLEX;
+  function "-" (L : in COMPLEX; R : in REAL) return COMPLEX;
+  function "-" (L : in COMPLEX_POLAR; R : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function "-" (L : in REAL; R : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function "-" (L : in COMPLEX_POLAR; R : in REAL) return COMPLEX_POLAR;
+
+  function "*" (L : in COMPLEX; R : in COMPLEX) return COMPLEX;
+  function "*" (L : in REAL; R : in COMPLEX) return COMPLEX;
+  function "*" (L : in COMPLEX; R : in REAL) return COMPLEX;
+  function "*" (L : in COMPLEX_POLAR; R : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function "*" (L : in REAL; R : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function "*" (L : in COMPLEX_POLAR; R : in REAL) return COMPLEX_POLAR;
+
+  function "/" (L : in COMPLEX; R : in COMPLEX) return COMPLEX;
+  function "/" (L : in REAL; R : in COMPLEX) return COMPLEX;
+  function "/" (L : in COMPLEX; R : in REAL) return COMPLEX;
+  function "/" (L : in COMPLEX_POLAR; R : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function "/" (L : in REAL; R : in COMPLEX_POLAR) return COMPLEX_POLAR;
+  function "/" (L : in COMPLEX_POLAR; R : in REAL) return COMPLEX_POLAR;
+end package MATH_COMPLEX;
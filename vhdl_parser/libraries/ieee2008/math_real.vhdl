@@ -0,0 +1,62 @@
+-- Package math_real as defined by IEEE 1076-2008
+-- Only the package declaration is provided, it is used for semantic analysis
+
+package MATH_REAL is
+  constant CopyRightNotice : STRING
+    := "Copyright 1996 IEEE. All rights reserved.";
+
+  constant MATH_E : REAL := 2.71828_18284_59045_23536;
+  constant MATH_1_OVER_E : REAL := 0.36787_94411_71442_32160;
+  constant MATH_PI : REAL := 3.14159_26535_89793_23846;
+  constant MATH_2_PI : REAL := 6.28318_53071_79586_47693;
+  constant MATH_1_OVER_PI : REAL := 0.31830_98861_83790_67154;
+  constant MATH_PI_OVER_2 : REAL := 1.57079_63267_94896_61923;
+  constant MATH_PI_OVER_3 : REAL := 1.04719_75511_96597_74615;
+  constant MATH_PI_OVER_4 : REAL := 0.78539_81633_97448_30962;
+  constant MATH_3_PI_OVER_2 : REAL := 4.71238_89803_84689_85769;
+  constant MATH_LOG_OF_2 : REAL := 0.69314_71805_59945_30942;
+  constant MATH_LOG_OF_10 : REAL := 2.30258_50929_94045_68402;
+  constant MATH_LOG2_OF_E : REAL := 1.44269_50408_88963_4074;
+  constant MATH_LOG10_OF_E : REAL := 0.43429_44819_03251_82765;
+  constant MATH_SQRT_2 : REAL := 1.41421_35623_73095_04880;
+  constant MATH_1_OVER_SQRT_2 : REAL := 0.70710_67811_86547_52440;
+  constant MATH_SQRT_PI : REAL := 1.77245_38509_05516_02730;
+  constant MATH_DEG_TO_RAD : REAL := 0.01745_32925_19943_29577;
+  constant MATH_RAD_TO_DEG : REAL := 57.29577_95130_82320_87680;
+
+  function SIGN (X : in REAL) return REAL;
+  function CEIL (X : in REAL) return REAL;
+  function FLOOR (X : in REAL) return REAL;
+  function ROUND (X : in REAL) return REAL;
+  function TRUNC (X : in REAL) return REAL;
+  function "MOD" (X, Y : in REAL) return REAL;
+  function REALMAX (X, Y : in REAL) return REAL;
+  function REALMIN (X, Y : in REAL) return REAL;
+
+  procedure UNIFORM (variable SEED1, SEED2 : inout POSITIVE; variable X : out REAL);
+
+  function SQRT (X : in REAL) return REAL;
+  function CBRT (X : in REAL) return REAL;
+  function "**" (X : in INTEGER; Y : in REAL) return REAL;
+  function "**" (X : in REAL; Y : in REAL) return REAL;
+  function EXP (X : in REAL) return REAL;
+  function LOG (X : in REAL) return REAL;
+  function LOG2 (X : in REAL) return REAL;
+  function LOG10 (X : in REAL) return REAL;
+  function LOG (X : in REAL; BASE : in REAL) return REAL;
+
+  function SIN (X : in REAL) return REAL;
+  function COS (X : in REAL) return REAL;
+  function TAN (X : in REAL) return REAL;
+  function ARCSIN (X : in REAL) return REAL;
+  function ARCCOS (X : in REAL) return REAL;
+  function ARCTAN (Y : in REAL) return REAL;
+  function ARCTAN (Y : in REAL; X : in REAL) return REAL;
+
+  function SINH (X : in REAL) return REAL;
+  function COSH (X : in REAL) return REAL;
+  function TANH (X : in REAL) return REAL;
+  function ARCSINH (X : in REAL) return REAL;
+  function ARCCOSH (X : in REAL) return REAL;
+  function ARCTANH (Y : in REAL) return REAL;
+end package MATH_REAL;
@@ -0,0 +1,320 @@
+-- Package numeric_bit as defined by IEEE 1076-2008
+-- Only the package declaration is provided, it is used for semantic analysis
+
+use STD.TEXTIO.all;
+
+package NUMERIC_BIT is
+  constant CopyRightNotice : STRING
+    := "Copyright 2008 IEEE. All rights reserved.";
+
+  type UNSIGNED is array (NATURAL range <>) of BIT;
+  type SIGNED is array (NATURAL range <>) of BIT;
+
+  function "abs" (ARG : SIGNED) return SIGNED;
+  function "-" (ARG : SIGNED) return SIGNED;
+
+  function "+" (L, R : UNSIGNED) return UNSIGNED;
+  function "+" (L, R : SIGNED) return SIGNED;
+  function "+" (L : UNSIGNED; R : NATURAL) return UNSIGNED;
+  function "+" (L : NATURAL; R : UNSIGNED) return UNSIGNED;
+  function "+" (L : INTEGER; R : SIGNED) return SIGNED;
+  function "+" (L : SIGNED; R : INTEGER) return SIGNED;
+  function "+" (L : UNSIGNED; R : BIT) return UNSIGNED;
+  function "+" (L : BIT; R : UNSIGNED) return UNSIGNED;
+  function "+" (L : SIGNED; R : BIT) return SIGNED;
+  function "+" (L : BIT; R : SIGNED) return SIGNED;
+
+  function "-" (L, R : UNSIGNED) return UNSIGNED;
+  function "-" (L, R : SIGNED) return SIGNED;
+  function "-" (L : UNSIGNED; R : NATURAL) return UNSIGNED;
+  function "-" (L : NATURAL; R : UNSIGNED) return UNSIGNED;
+  function "-" (L : SIGNED; R : INTEGER) return SIGNED;
+  function "-" (L : INTEGER; R : SIGNED) return SIGNED;
+  function "-" (L : UNSIGNED; R : BIT) return UNSIGNED;
+  function "-" (L : BIT; R : UNSIGNED) return UNSIGNED;
+  function "-" (L : SIGNED; R : BIT) return SIGNED;
+  function "-" (L : BIT; R : SIGNED) return SIGNED;
+
+  function "*" (L, R : UNSIGNED) return UNSIGNED;
+  function "*" (L, R : SIGNED) return SIGNED;
+  function "*" (L : UNSIGNED; R : NATURAL) return UNSIGNED;
+  function "*" (L : NATURAL; R : UNSIGNED) return UNSIGNED;
+  function "*" (L : SIGNED; R : INTEGER) return SIGNED;
+  function "*" (L : INTEGER; R : SIGNED) return SIGNED;
+
+  function "/" (L, R : UNSIGNED) return UNSIGNED;
+  function "/" (L, R : SIGNED) return SIGNED;
+  function "/" (L : UNSIGNED; R : NATURAL) return UNSIGNED;
+  function "/" (L : NATURAL; R : UNSIGNED) return UNSIGNED;
+  function "/" (L : SIGNED; R : INTEGER) return SIGNED;
+  function "/" (L : INTEGER; R : SIGNED) return SIGNED;
+
+  function "rem" (L, R : UNSIGNED) return UNSIGNED;
+  function "rem" (L, R : SIGNED) return SIGNED;
+  function "rem" (L : UNSIGNED; R : NATURAL) return UNSIGNED;
+  function "rem" (L : NATURAL; R : UNSIGNED) return UNSIGNED;
+  function "rem" (L : SIGNED; R : INTEGER) return SIGNED;
+  function "rem" (L : INTEGER; R : SIGNED) return SIGNED;
+
+  function "mod" (L, R : UNSIGNED) return UNSIGNED;
+  function "mod" (L, R : SIGNED) return SIGNED;
+  function "mod" (L : UNSIGNED; R : NATURAL) return UNSIGNED;
+  function "mod" (L : NATURAL; R : UNSIGNED) return UNSIGNED;
+  function "mod" (L : SIGNED; R : INTEGER) return SIGNED;
+  function "mod" (L : INTEGER; R : SIGNED) return SIGNED;
+
+  function find_leftmost (ARG : UNSIGNED; Y : BIT) return INTEGER;
+  function find_leftmost (ARG : SIGNED; Y : BIT) return INTEGER;
+  function find_rightmost (ARG : UNSIGNED; Y : BIT) return INTEGER;
+  function find_rightmost (ARG : SIGNED; Y : BIT) return INTEGER;
+
+  function ">" (L, R : UNSIGNED) return BOOLEAN;
+  function ">" (L, R : SIGNED) return BOOLEAN;
+  function ">" (L : NATURAL; R : UNSIGNED) return BOOLEAN;
+  function ">" (L : INTEGER; R : SIGNED) return BOOLEAN;
+  function ">" (L : UNSIGNED; R : NATURAL) return BOOLEAN;
+  function ">" (L : SIGNED; R : INTEGER) return BOOLEAN;
+
+  function "<" (L, R : UNSIGNED) return BOOLEAN;
+  function "<" (L, R : SIGNED) return BOOLEAN;
+  function "<" (L : NATURAL; R : UNSIGNED) return BOOLEAN;
+  function "<" (L : INTEGER; R : SIGNED) return BOOLEAN;
+  function "<" (L : UNSIGNED; R : NATURAL) return BOOLEAN;
+  function "<" (L : SIGNED; R : INTEGER) return BOOLEAN;
+
+  function "<=" (L, R : UNSIGNED) return BOOLEAN;
+  function "<=" (L, R : SIGNED) return BOOLEAN;
+  function "<=" (L : NATURAL; R : UNSIGNED) return BOOLEAN;
+  function "<=" (L : INTEGER; R : SIGNED) return BOOLEAN;
+  function "<=" (L : UNSIGNED; R : NATURAL) return BOOLEAN;
+  function "<=" (L : SIGNED; R : INTEGER) return BOOLEAN;
+
+  function ">=" (L, R : UNSIGNED) return BOOLEAN;
+  function ">=" (L, R : SIGNED) return BOOLEAN;
+  function ">=" (L : NATURAL; R : UNSIGNED) return BOOLEAN;
+  function ">=" (L : INTEGER; R : SIGNED) return BOOLEAN;
+  function ">=" (L : UNSIGNED; R : NATURAL) return BOOLEAN;
+  function ">=" (L : SIGNED; R : INTEGER) return BOOLEAN;
+
+  function "=" (L, R : UNSIGNED) return BOOLEAN;
+  function "=" (L, R : SIGNED) return BOOLEAN;
+  function "=" (L : NATURAL; R : UNSIGNED) return BOOLEAN;
+  function "=" (L : INTEGER; R : SIGNED) return BOOLEAN;
+  function "=" (L : UNSIGNED; R : NATURAL) return BOOLEAN;
+  function "=" (L : SIGNED; R : INTEGER) return BOOLEAN;
+
+  function "/=" (L, R : UNSIGNED) return BOOLEAN;
+  function "/=" (L, R : SIGNED) return BOOLEAN;
+  function "/=" (L : NATURAL; R : UNSIGNED) return BOOLEAN;
+  function "/=" (L : INTEGER; R : SIGNED) return BOOLEAN;
+  function "/=" (L : UNSIGNED; R : NATURAL) return BOOLEAN;
+  function "/=" (L : SIGNED; R : INTEGER) return BOOLEAN;
+
+  function MINIMUM (L, R : UNSIGNED) return UNSIGNED;
+  function MINIMUM (L, R : SIGNED) return SIGNED;
+  function MINIMUM (L : NATURAL; R : UNSIGNED) return UNSIGNED;
+  function MINIMUM (L : INTEGER; R : SIGNED) return SIGNED;
+  function MINIMUM (L : UNSIGNED; R : NATURAL) return UNSIGNED;
+  function MINIMUM (L : SIGNED; R : INTEGER) return SIGNED;
+
+  function MAXIMUM (L, R : UNSIGNED) return UNSIGNED;
+  function MAXIMUM (L, R : SIGNED) return SIGNED;
+  function MAXIMUM (L : NATURAL; R : UNSIGNED) return UNSIGNED;
+  function MAXIMUM (L : INTEGER; R : SIGNED) return SIGNED;
+  function MAXIMUM (L : UNSIGNED; R : NATURAL) return UNSIGNED;
+  function MAXIMUM (L : SIGNED; R : INTEGER) return SIGNED;
+
+  function "?>" (L, R : UNSIGNED) return BIT;
+  function "?>" (L, R : SIGNED) return BIT;
+  function "?>" (L : NATURAL; R : UNSIGNED) return BIT;
+  function "?>" (L : INTEGER; R : SIGNED) return BIT;
+  function "?>" (L : UNSIGNED; R : NATURAL) return BIT;
+  function "?>" (L : SIGNED; R : INTEGER) return BIT;
+
+  function "?<" (L, R : UNSIGNED) return BIT;
+  function "?<" (L, R : SIGNED) return BIT;
+  function "?<" (L : NATURAL; R : UNSIGNED) return BIT;
+  function "?<" (L : INTEGER; R : SIGNED) return BIT;
+  function "?<" (L : UNSIGNED; R : NATURAL) return BIT;
+  function "?<" (L : SIGNED; R : INTEGER) return BIT;
+
+  function "?<=" (L, R : UNSIGNED) return BIT;
+  function "?<=" (L, R : SIGNED) return BIT;
+  function "?<=" (L : NATURAL; R : UNSIGNED) return BIT;
+  function "?<=" (L : INTEGER; R : SIGNED) return BIT;
+  function "?<=" (L : UNSIGNED; R : NATURAL) return BIT;
+  function "?<=" (L : SIGNED; R : INTEGER) return BIT;
+
+  function "?>=" (L, R : UNSIGNED) return BIT;
+  function "?>=" (L, R : SIGNED) return BIT;
+  function "?>=" (L : NATURAL; R : UNSIGNED) return BIT;
+  function "?>=" (L : INTEGER; R : SIGNED) return BIT;
+  function "?>=" (L : UNSIGNED; R : NATURAL) return BIT;
+  function "?>=" (L : SIGNED; R : INTEGER) return BIT;
+
+  function "?=" (L, R : UNSIGNED) return BIT;
+  function "?=" (L, R : SIGNED) return BIT;
+  function "?=" (L : NATURAL; R : UNSIGNED) return BIT;
+  function "?=" (L : INTEGER; R : SIGNED) return BIT;
+  function "?=" (L : UNSIGNED; R : NATURAL) return BIT;
+  function "?=" (L : SIGNED; R : INTEGER) return BIT;
+
+  function "?/=" (L, R : UNSIGNED) return BIT;
+  function "?/=" (L, R : SIGNED) return BIT;
+  function "?/=" (L : NATURAL; R : UNSIGNED) return BIT;
+  function "?/=" (L : INTEGER; R : SIGNED) return BIT;
+  function "?/=" (L : UNSIGNED; R : NATURAL) return BIT;
+  function "?/=" (L : SIGNED; R : INTEGER) return BIT;
+
+  function SHIFT_LEFT (ARG : UNSIGNED; COUNT : NATURAL) return UNSIGNED;
+  function SHIFT_RIGHT (ARG : UNSIGNED; COUNT : NATURAL) return UNSIGNED;
+  function SHIFT_LEFT (ARG : SIGNED; COUNT : NATURAL) return SIGNED;
+  function SHIFT_RIGHT (ARG : SIGNED; COUNT : NATURAL) return SIGNED;
+
+  function ROTATE_LEFT (ARG : UNSIGNED; COUNT : NATURAL) return UNSIGNED;
+  function ROTATE_RIGHT (ARG : UNSIGNED; COUNT : NATURAL) return UNSIGNED;
+  function ROTATE_LEFT (ARG : SIGNED; COUNT : NATURAL) return SIGNED;
+  function ROTATE_RIGHT (ARG : SIGNED; COUNT : NATURAL) return SIGNED;
+
+  function "sll" (ARG : UNSIGNED; COUNT : INTEGER) return UNSIGNED;
+  function "sll" (ARG : SIGNED; COUNT : INTEGER) return SIGNED;
+  function "srl" (ARG : UNSIGNED; COUNT : INTEGER) return UNSIGNED;
+  function "srl" (ARG : SIGNED; COUNT : INTEGER) return SIGNED;
+  function "rol" (ARG : UNSIGNED; COUNT : INTEGER) return UNSIGNED;
+  function "rol" (ARG : SIGNED; COUNT : INTEGER) return SIGNED;
+  function "ror" (ARG : UNSIGNED; COUNT : INTEGER) return UNSIGNED;
+  function "ror" (ARG : SIGNED; COUNT : INTEGER) return SIGNED;
+  function "sla" (ARG : UNSIGNED; COUNT : INTEGER) return UNSIGNED;
+  function "sla" (ARG : SIGNED; COUNT : INTEGER) return SIGNED;
+  function "sra" (ARG : UNSIGNED; COUNT : INTEGER) return UNSIGNED;
+  function "sra" (ARG : SIGNED; COUNT : INTEGER) return SIGNED;
+
+  function RESIZE (ARG : SIGNED; NEW_SIZE : NATURAL) return SIGNED;
+  function RESIZE (ARG : UNSIGNED; NEW_SIZE : NATURAL) return UNSIGNED;
+  function RESIZE (ARG, SIZE_RES : UNSIGNED) return UNSIGNED;
+  function RESIZE (ARG, SIZE_RES : SIGNED) return SIGNED;
+
+  function TO_INTEGER (ARG : UNSIGNED) return NATURAL;
+  function TO_INTEGER (ARG : SIGNED) return INTEGER;
+
+  function TO_UNSIGNED (ARG, SIZE : NATURAL) return UNSIGNED;
+  function TO_SIGNED (ARG : INTEGER; SIZE : NATURAL) return SIGNED;
+  function TO_UNSIGNED (ARG : NATURAL; SIZE_RES : UNSIGNED) return UNSIGNED;
+  function TO_SIGNED (ARG : INTEGER; SIZE_RES : SIGNED) return SIGNED;
+
+  function "not" (L : UNSIGNED) return UNSIGNED;
+  function "and" (L, R : UNSIGNED) return UNSIGNED;
+  function "or" (L, R : UNSIGNED) return UNSIGNED;
+  function "nand" (L, R : UNSIGNED) return UNSIGNED;
+  function "nor" (L, R : UNSIGNED) return UNSIGNED;
+  function "xor" (L, R : UNSIGNED) return UNSIGNED;
+  function "xnor" (L, R : UNSIGNED) return UNSIGNED;
+  function "not" (L : SIGNED) return SIGNED;
+  function "and" (L, R : SIGNED) return SIGNED;
+  function "or" (L, R : SIGNED) return SIGNED;
+  function "nand" (L, R : SIGNED) return SIGNED;
+  function "nor" (L, R : SIGNED) return SIGNED;
+  function "xor" (L, R : SIGNED) return SIGNED;
+  function "xnor" (L, R : SIGNED) return SIGNED;
+
+  function "and" (L : BIT; R : UNSIGNED) return UNSIGNED;
+  function "and" (L : UNSIGNED; R : BIT) return UNSIGNED;
+  function "or" (L : BIT; R : UNSIGNED) return UNSIGNED;
+  function "or" (L : UNSIGNED; R : BIT) return UNSIGNED;
+  function "xor" (L : BIT; R : UNSIGNED) return UNSIGNED;
+  function "xor" (L : UNSIGNED; R : BIT) return UNSIGNED;
+  function "and" (L : BIT; R : SIGNED) return SIGNED;
+  function "and" (L : SIGNED; R : BIT) return SIGNED;
+  function "or" (L : BIT; R : SIGNED) return SIGNED;
+  function "or" (L : SIGNED; R : BIT) return SIGNED;
+  function "xor" (L : BIT; R : SIGNED) return SIGNED;
+  function "xor" (L : SIGNED; R : BIT) return SIGNED;
+
+  function RISING_EDGE (signal S : BIT) return BOOLEAN;
+  function FALLING_EDGE (signal S : BIT) return BOOLEAN;
+
+  function "and" (L : SIGNED) return BIT;
+  function "nand" (L : SIGNED) return BIT;
+  function "or" (L : SIGNED) return BIT;
+  function "nor" (L : SIGNED) return BIT;
+  function "xor" (L : SIGNED) return BIT;
+  function "xnor" (L : SIGNED) return BIT;
+  function "and" (L : UNSIGNED) return BIT;
+  function "nand" (L : UNSIGNED) return BIT;
+  function "or" (L : UNSIGNED) return BIT;
+  function "nor" (L : UNSIGNED) return BIT;
+  function "xor" (L : UNSIGNED) return BIT;
+  function "xnor" (L : UNSIGNED) return BIT;
+
+  alias TO_BSTRING is TO_STRING [UNSIGNED return STRING];
+  alias TO_BSTRING is TO_STRING [SIGNED return STRING];
+  alias TO_BINARY_STRING is TO_STRING [UNSIGNED return STRING];
+  alias TO_BINARY_STRING is TO_STRING [SIGNED return STRING];
+
+  function TO_OSTRING (value : UNSIGNED) return STRING;
+  function TO_OSTRING (value : SIGNED) return STRING;
+  alias TO_OCTAL_STRING is TO_OSTRING [UNSIGNED return STRING];
+  alias TO_OCTAL_STRING is TO_OSTRING [SIGNED return STRING];
+
+  function TO_HSTRING (value : UNSIGNED) return STRING;
+  function TO_HSTRING (value : SIGNED) return STRING;
+  alias TO_HEX_STRING is TO_HSTRING [UNSIGNED return STRING];
+  alias TO_HEX_STRING is TO_HSTRING [SIGNED return STRING];
+
+  procedure READ (L : inout LINE; VALUE : out UNSIGNED; GOOD : out BOOLEAN);
+  procedure READ (L : inout LINE; VALUE : out UNSIGNED);
+  procedure READ (L : inout LINE; VALUE : out SIGNED; GOOD : out BOOLEAN);
+  procedure READ (L : inout LINE; VALUE : out SIGNED);
+
+  procedure WRITE (L : inout LINE; VALUE : in UNSIGNED;
+                   JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  procedure WRITE (L : inout LINE; VALUE : in SIGNED;
+                   JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+
+  alias BREAD is READ [LINE, UNSIGNED, BOOLEAN];
+  alias BREAD is READ [LINE, SIGNED, BOOLEAN];
+  alias BREAD is READ [LINE, UNSIGNED];
+  alias BREAD is READ [LINE, SIGNED];
+  alias BINARY_READ is READ [LINE, UNSIGNED, BOOLEAN];
+  alias BINARY_READ is READ [LINE, SIGNED, BOOLEAN];
+  alias BINARY_READ is READ [LINE, UNSIGNED];
+  alias BINARY_READ is READ [LINE, SIGNED];
+
+  procedure OREAD (L : inout LINE; VALUE : out UNSIGNED; GOOD : out BOOLEAN);
+  procedure OREAD (L : inout LINE; VALUE : out SIGNED; GOOD : out BOOLEAN);
+  procedure OREAD (L : inout LINE; VALUE : out UNSIGNED);
+  procedure OREAD (L : inout LINE; VALUE : out SIGNED);
+  alias OCTAL_READ is OREAD [LINE, UNSIGNED, BOOLEAN];
+  alias OCTAL_READ is OREAD [LINE, SIGNED, BOOLEAN];
+  alias OCTAL_READ is OREAD [LINE, UNSIGNED];
+  alias OCTAL_READ is OREAD [LINE, SIGNED];
+
+  procedure HREAD (L : inout LINE; VALUE : out UNSIGNED; GOOD : out BOOLEAN);
+  procedure HREAD (L : inout LINE; VALUE : out SIGNED; GOOD : out BOOLEAN);
+  procedure HREAD (L : inout LINE; VALUE : out UNSIGNED);
+  procedure HREAD (L : inout LINE; VALUE : out SIGNED);
+  alias HEX_READ is HREAD [LINE, UNSIGNED, BOOLEAN];
+  alias HEX_READ is HREAD [LINE, SIGNED, BOOLEAN];
+  alias HEX_READ is HREAD [LINE, UNSIGNED];
+  alias HEX_READ is HREAD [LINE, SIGNED];
+
+  alias BWRITE is WRITE [LINE, UNSIGNED, SIDE, WIDTH];
+  alias BWRITE is WRITE [LINE, SIGNED, SIDE, WIDTH];
+  alias BINARY_WRITE is WRITE [LINE, UNSIGNED, SIDE, WIDTH];
+  alias BINARY_WRITE is WRITE [LINE, SIGNED, SIDE, WIDTH];
+
+  procedure OWRITE (L : inout LINE; VALUE : in UNSIGNED;
+                    JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  procedure OWRITE (L : inout LINE; VALUE : in SIGNED;
+                    JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  alias OCTAL_WRITE is OWRITE [LINE, UNSIGNED, SIDE, WIDTH];
+  alias OCTAL_WRITE is OWRITE [LINE, SIGNED, SIDE, WIDTH];
+
+  procedure HWRITE (L : inout LINE; VALUE : in UNSIGNED;
+                    JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  procedure HWRITE (L : inout LINE; VALUE : in SIGNED;
+                    JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  alias HEX_WRITE is HWRITE [LINE, UNSIGNED, SIDE, WIDTH];
+  alias HEX_WRITE is HWRITE [LINE, SIGNED, SIDE, WIDTH];
+
+end package NUMERIC_BIT;
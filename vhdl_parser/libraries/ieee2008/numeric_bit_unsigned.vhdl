@@ -0,0 +1,106 @@
+-- Package numeric_bit_unsigned as defined by IEEE 1076-2008
+-- Only the package declaration is provided, it is used for semantic analysis
+
+package NUMERIC_BIT_UNSIGNED is
+
+  function "+" (L, R : BIT_VECTOR) return BIT_VECTOR;
+  function "+" (L : BIT_VECTOR; R : NATURAL) return BIT_VECTOR;
+  function "+" (L : NATURAL; R : BIT_VECTOR) return BIT_VECTOR;
+
+  function "-" (L, R : BIT_VECTOR) return BIT_VECTOR;
+  function "-" (L : BIT_VECTOR; R : NATURAL) return BIT_VECTOR;
+  function "-" (L : NATURAL; R : BIT_VECTOR) return BIT_VECTOR;
+
+  function "*" (L, R : BIT_VECTOR) return BIT_VECTOR;
+  function "*" (L : BIT_VECTOR; R : NATURAL) return BIT_VECTOR;
+  function "*" (L : NATURAL; R : BIT_VECTOR) return BIT_VECTOR;
+
+  function "/" (L, R : BIT_VECTOR) return BIT_VECTOR;
+  function "/" (L : BIT_VECTOR; R : NATURAL) return BIT_VECTOR;
+  function "/" (L : NATURAL; R : BIT_VECTOR) return BIT_VECTOR;
+
+  function "rem" (L, R : BIT_VECTOR) return BIT_VECTOR;
+  function "rem" (L : BIT_VECTOR; R : NATURAL) return BIT_VECTOR;
+  function "rem" (L : NATURAL; R : BIT_VECTOR) return BIT_VECTOR;
+
+  function "mod" (L, R : BIT_VECTOR) return BIT_VECTOR;
+  function "mod" (L : BIT_VECTOR; R : NATURAL) return BIT_VECTOR;
+  function "mod" (L : NATURAL; R : BIT_VECTOR) return BIT_VECTOR;
+
+  function find_leftmost (ARG : BIT_VECTOR; Y : BIT) return INTEGER;
+  function find_rightmost (ARG : BIT_VECTOR; Y : BIT) return INTEGER;
+
+  function ">" (L, R : BIT_VECTOR) return BOOLEAN;
+  function ">" (L : NATURAL; R : BIT_VECTOR) return BOOLEAN;
+  function ">" (L : BIT_VECTOR; R : NATURAL) return BOOLEAN;
+
+  function "<" (L, R : BIT_VECTOR) return BOOLEAN;
+  function "<" (L : NATURAL; R : BIT_VECTOR) return BOOLEAN;
+  function "<" (L : BIT_VECTOR; R : NATURAL) return BOOLEAN;
+
+  function "<=" (L, R : BIT_VECTOR) return BOOLEAN;
+  function "<=" (L : NATURAL; R : BIT_VECTOR) return BOOLEAN;
+  function "<=" (L : BIT_VECTOR; R : NATURAL) return BOOLEAN;
+
+  function ">=" (L, R : BIT_VECTOR) return BOOLEAN;
+  function ">=" (L : NATURAL; R : BIT_VECTOR) return BOOLEAN;
+  function ">=" (L : BIT_VECTOR; R : NATURAL) return BOOLEAN;
+
+  function "=" (L, R : BIT_VECTOR) return BOOLEAN;
+  function "=" (L : NATURAL; R : BIT_VECTOR) return BOOLEAN;
+  function "=" (L : BIT_VECTOR; R : NATURAL) return BOOLEAN;
+
+  function "/=" (L, R : BIT_VECTOR) return BOOLEAN;
+  function "/=" (L : NATURAL; R : BIT_VECTOR) return BOOLEAN;
+  function "/=" (L : BIT_VECTOR; R : NATURAL) return BOOLEAN;
+
+  function "?>" (L, R : BIT_VECTOR) return BIT;
+  function "?>" (L : NATURAL; R : BIT_VECTOR) return BIT;
+  function "?>" (L : BIT_VECTOR; R : NATURAL) return BIT;
+
+  function "?<" (L, R : BIT_VECTOR) return BIT;
+  function "?<" (L : NATURAL; R : BIT_VECTOR) return BIT;
+  function "?<" (L : BIT_VECTOR; R : NATURAL) return BIT;
+
+  function "?<=" (L, R : BIT_VECTOR) return BIT;
+  function "?<=" (L : NATURAL; R : BIT_VECTOR) return BIT;
+  function "?<=" (L : BIT_VECTOR; R : NATURAL) return BIT;
+
+  function "?>=" (L, R : BIT_VECTOR) return BIT;
+  function "?>=" (L : NATURAL; R : BIT_VECTOR) return BIT;
+  function "?>=" (L : BIT_VECTOR; R : NATURAL) return BIT;
+
+  function "?=" (L, R : BIT_VECTOR) return BIT;
+  function "?=" (L : NATURAL; R : BIT_VECTOR) return BIT;
+  function "?=" (L : BIT_VECTOR; R : NATURAL) return BIT;
+
+  function "?/=" (L, R : BIT_VECTOR) return BIT;
+  function "?/=" (L : NATURAL; R : BIT_VECTOR) return BIT;
+  function "?/=" (L : BIT_VECTOR; R : NATURAL) return BIT;
+
+  function MINIMUM (L, R : BIT_VECTOR) return BIT_VECTOR;
+  function MINIMUM (L : NATURAL; R : BIT_VECTOR) return BIT_VECTOR;
+  function MINIMUM (L : BIT_VECTOR; R : NATURAL) return BIT_VECTOR;
+
+  function MAXIMUM (L, R : BIT_VECTOR) return BIT_VECTOR;
+  function MAXIMUM (L : NATURAL; R : BIT_VECTOR) return BIT_VECTOR;
+  function MAXIMUM (L : BIT_VECTOR; R : NATURAL) return BIT_VECTOR;
+
+  function SHIFT_LEFT (ARG : BIT_VECTOR; COUNT : NATURAL) return BIT_VECTOR;
+  function SHIFT_RIGHT (ARG : BIT_VECTOR; COUNT : NATURAL) return BIT_VECTOR;
+  function ROTATE_LEFT (ARG : BIT_VECTOR; COUNT : NATURAL) return BIT_VECTOR;
+  function ROTATE_RIGHT (ARG : BIT_VECTOR; COUNT : NATURAL) return BIT_VECTOR;
+
+  function RESIZE (ARG : BIT_VECTOR; NEW_SIZE : NATURAL) return BIT_VECTOR;
+  function RESIZE (ARG, SIZE_RES : BIT_VECTOR) return BIT_VECTOR;
+
+  function TO_INTEGER (ARG : BIT_VECTOR) return NATURAL;
+  alias To_Integer_unsigned is TO_INTEGER [BIT_VECTOR return NATURAL];
+  alias To_Int is TO_INTEGER [BIT_VECTOR return NATURAL];
+
+  function To_BitVector (ARG, SIZE : NATURAL) return BIT_VECTOR;
+  function To_BitVector (ARG : NATURAL; SIZE_RES : BIT_VECTOR) return BIT_VECTOR;
+  alias To_Bit_Vector is To_BitVector [NATURAL, NATURAL return BIT_VECTOR];
+  alias To_BV is To_BitVector [NATURAL, NATURAL return BIT_VECTOR];
+
+end package NUMERIC_BIT_UNSIGNED;
@@ -0,0 +1,343 @@
+-- Package numeric_std as defined by IEEE 1076-2008
+-- Only the package declaration is provided, it is used for semantic analysis
+
+library IEEE;
+use IEEE.STD_LOGIC_1164.all;
+use STD.TEXTIO.all;
+
+package NUMERIC_STD is
+  constant CopyRightNotice : STRING
+    := "Copyright 2008 IEEE. All rights reserved.";
+
+  type UNRESOLVED_UNSIGNED is array (NATURAL range <>) of STD_ULOGIC;
+  type UNRESOLVED_SIGNED is array (NATURAL range <>) of STD_ULOGIC;
+
+  alias U_UNSIGNED is UNRESOLVED_UNSIGNED;
+  alias U_SIGNED is UNRESOLVED_SIGNED;
+
+  subtype UNSIGNED is (resolved) UNRESOLVED_UNSIGNED;
+  subtype SIGNED is (resolved) UNRESOLVED_SIGNED;
+
+  function "abs" (ARG : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "-" (ARG : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+
+  function "+" (L, R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "+" (L, R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "+" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return UNRESOLVED_UNSIGNED;
+  function "+" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "+" (L : INTEGER; R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "+" (L : UNRESOLVED_SIGNED; R : INTEGER) return UNRESOLVED_SIGNED;
+  function "+" (L : UNRESOLVED_UNSIGNED; R : STD_ULOGIC) return UNRESOLVED_UNSIGNED;
+  function "+" (L : STD_ULOGIC; R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "+" (L : UNRESOLVED_SIGNED; R : STD_ULOGIC) return UNRESOLVED_SIGNED;
+  function "+" (L : STD_ULOGIC; R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+
+  function "-" (L, R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "-" (L, R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "-" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return UNRESOLVED_UNSIGNED;
+  function "-" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "-" (L : UNRESOLVED_SIGNED; R : INTEGER) return UNRESOLVED_SIGNED;
+  function "-" (L : INTEGER; R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "-" (L : UNRESOLVED_UNSIGNED; R : STD_ULOGIC) return UNRESOLVED_UNSIGNED;
+  function "-" (L : STD_ULOGIC; R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "-" (L : UNRESOLVED_SIGNED; R : STD_ULOGIC) return UNRESOLVED_SIGNED;
+  function "-" (L : STD_ULOGIC; R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+
+  function "*" (L, R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "*" (L, R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "*" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return UNRESOLVED_UNSIGNED;
+  function "*" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "*" (L : UNRESOLVED_SIGNED; R : INTEGER) return UNRESOLVED_SIGNED;
+  function "*" (L : INTEGER; R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+
+  function "/" (L, R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "/" (L, R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "/" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return UNRESOLVED_UNSIGNED;
+  function "/" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "/" (L : UNRESOLVED_SIGNED; R : INTEGER) return UNRESOLVED_SIGNED;
+  function "/" (L : INTEGER; R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+
+  function "rem" (L, R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "rem" (L, R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "rem" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return UNRESOLVED_UNSIGNED;
+  function "rem" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "rem" (L : UNRESOLVED_SIGNED; R : INTEGER) return UNRESOLVED_SIGNED;
+  function "rem" (L : INTEGER; R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+
+  function "mod" (L, R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "mod" (L, R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "mod" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return UNRESOLVED_UNSIGNED;
+  function "mod" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "mod" (L : UNRESOLVED_SIGNED; R : INTEGER) return UNRESOLVED_SIGNED;
+  function "mod" (L : INTEGER; R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+
+  function find_leftmost (ARG : UNRESOLVED_UNSIGNED; Y : STD_ULOGIC) return INTEGER;
+  function find_leftmost (ARG : UNRESOLVED_SIGNED; Y : STD_ULOGIC) return INTEGER;
+  function find_rightmost (ARG : UNRESOLVED_UNSIGNED; Y : STD_ULOGIC) return INTEGER;
+  function find_rightmost (ARG : UNRESOLVED_SIGNED; Y : STD_ULOGIC) return INTEGER;
+
+  function ">" (L, R : UNRESOLVED_UNSIGNED) return BOOLEAN;
+  function ">" (L, R : UNRESOLVED_SIGNED) return BOOLEAN;
+  function ">" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return BOOLEAN;
+  function ">" (L : INTEGER; R : UNRESOLVED_SIGNED) return BOOLEAN;
+  function ">" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return BOOLEAN;
+  function ">" (L : UNRESOLVED_SIGNED; R : INTEGER) return BOOLEAN;
+
+  function "<" (L, R : UNRESOLVED_UNSIGNED) return BOOLEAN;
+  function "<" (L, R : UNRESOLVED_SIGNED) return BOOLEAN;
+  function "<" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return BOOLEAN;
+  function "<" (L : INTEGER; R : UNRESOLVED_SIGNED) return BOOLEAN;
+  function "<" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return BOOLEAN;
+  function "<" (L : UNRESOLVED_SIGNED; R : INTEGER) return BOOLEAN;
+
+  function "<=" (L, R : UNRESOLVED_UNSIGNED) return BOOLEAN;
+  function "<=" (L, R : UNRESOLVED_SIGNED) return BOOLEAN;
+  function "<=" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return BOOLEAN;
+  function "<=" (L : INTEGER; R : UNRESOLVED_SIGNED) return BOOLEAN;
+  function "<=" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return BOOLEAN;
+  function "<=" (L : UNRESOLVED_SIGNED; R : INTEGER) return BOOLEAN;
+
+  function ">=" (L, R : UNRESOLVED_UNSIGNED) return BOOLEAN;
+  function ">=" (L, R : UNRESOLVED_SIGNED) return BOOLEAN;
+  function ">=" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return BOOLEAN;
+  function ">=" (L : INTEGER; R : UNRESOLVED_SIGNED) return BOOLEAN;
+  function ">=" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return BOOLEAN;
+  function ">=" (L : UNRESOLVED_SIGNED; R : INTEGER) return BOOLEAN;
+
+  function "=" (L, R : UNRESOLVED_UNSIGNED) return BOOLEAN;
+  function "=" (L, R : UNRESOLVED_SIGNED) return BOOLEAN;
+  function "=" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return BOOLEAN;
+  function "=" (L : INTEGER; R : UNRESOLVED_SIGNED) return BOOLEAN;
+  function "=" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return BOOLEAN;
+  function "=" (L : UNRESOLVED_SIGNED; R : INTEGER) return BOOLEAN;
+
+  function "/=" (L, R : UNRESOLVED_UNSIGNED) return BOOLEAN;
+  function "/=" (L, R : UNRESOLVED_SIGNED) return BOOLEAN;
+  function "/=" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return BOOLEAN;
+  function "/=" (L : INTEGER; R : UNRESOLVED_SIGNED) return BOOLEAN;
+  function "/=" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return BOOLEAN;
+  function "/=" (L : UNRESOLVED_SIGNED; R : INTEGER) return BOOLEAN;
+
+  function MINIMUM (L, R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function MINIMUM (L, R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function MINIMUM (L : NATURAL; R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function MINIMUM (L : INTEGER; R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function MINIMUM (L : UNRESOLVED_UNSIGNED; R : NATURAL) return UNRESOLVED_UNSIGNED;
+  function MINIMUM (L : UNRESOLVED_SIGNED; R : INTEGER) return UNRESOLVED_SIGNED;
+
+  function MAXIMUM (L, R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function MAXIMUM (L, R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function MAXIMUM (L : NATURAL; R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function MAXIMUM (L : INTEGER; R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function MAXIMUM (L : UNRESOLVED_UNSIGNED; R : NATURAL) return UNRESOLVED_UNSIGNED;
+  function MAXIMUM (L : UNRESOLVED_SIGNED; R : INTEGER) return UNRESOLVED_SIGNED;
+
+  function "?>" (L, R : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "?>" (L, R : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "?>" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "?>" (L : INTEGER; R : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "?>" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return STD_ULOGIC;
+  function "?>" (L : UNRESOLVED_SIGNED; R : INTEGER) return STD_ULOGIC;
+
+  function "?<" (L, R : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "?<" (L, R : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "?<" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "?<" (L : INTEGER; R : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "?<" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return STD_ULOGIC;
+  function "?<" (L : UNRESOLVED_SIGNED; R : INTEGER) return STD_ULOGIC;
+
+  function "?<=" (L, R : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "?<=" (L, R : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "?<=" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "?<=" (L : INTEGER; R : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "?<=" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return STD_ULOGIC;
+  function "?<=" (L : UNRESOLVED_SIGNED; R : INTEGER) return STD_ULOGIC;
+
+  function "?>=" (L, R : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "?>=" (L, R : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "?>=" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "?>=" (L : INTEGER; R : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "?>=" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return STD_ULOGIC;
+  function "?>=" (L : UNRESOLVED_SIGNED; R : INTEGER) return STD_ULOGIC;
+
+  function "?=" (L, R : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "?=" (L, R : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "?=" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "?=" (L : INTEGER; R : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "?=" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return STD_ULOGIC;
+  function "?=" (L : UNRESOLVED_SIGNED; R : INTEGER) return STD_ULOGIC;
+
+  function "?/=" (L, R : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "?/=" (L, R : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "?/=" (L : NATURAL; R : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "?/=" (L : INTEGER; R : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "?/=" (L : UNRESOLVED_UNSIGNED; R : NATURAL) return STD_ULOGIC;
+  function "?/=" (L : UNRESOLVED_SIGNED; R : INTEGER) return STD_ULOGIC;
+
+  function SHIFT_LEFT (ARG : UNRESOLVED_UNSIGNED; COUNT : NATURAL) return UNRESOLVED_UNSIGNED;
+  function SHIFT_RIGHT (ARG : UNRESOLVED_UNSIGNED; COUNT : NATURAL) return UNRESOLVED_UNSIGNED;
+  function SHIFT_LEFT (ARG : UNRESOLVED_SIGNED; COUNT : NATURAL) return UNRESOLVED_SIGNED;
+  function SHIFT_RIGHT (ARG : UNRESOLVED_SIGNED; COUNT : NATURAL) return UNRESOLVED_SIGNED;
+
+  function ROTATE_LEFT (ARG : UNRESOLVED_UNSIGNED; COUNT : NATURAL) return UNRESOLVED_UNSIGNED;
+  function ROTATE_RIGHT (ARG : UNRESOLVED_UNSIGNED; COUNT : NATURAL) return UNRESOLVED_UNSIGNED;
+  function ROTATE_LEFT (ARG : UNRESOLVED_SIGNED; COUNT : NATURAL) return UNRESOLVED_SIGNED;
+  function ROTATE_RIGHT (ARG : UNRESOLVED_SIGNED; COUNT : NATURAL) return UNRESOLVED_SIGNED;
+
+  function "sll" (ARG : UNRESOLVED_UNSIGNED; COUNT : INTEGER) return UNRESOLVED_UNSIGNED;
+  function "sll" (ARG : UNRESOLVED_SIGNED; COUNT : INTEGER) return UNRESOLVED_SIGNED;
+  function "srl" (ARG : UNRESOLVED_UNSIGNED; COUNT : INTEGER) return UNRESOLVED_UNSIGNED;
+  function "srl" (ARG : UNRESOLVED_SIGNED; COUNT : INTEGER) return UNRESOLVED_SIGNED;
+  function "rol" (ARG : UNRESOLVED_UNSIGNED; COUNT : INTEGER) return UNRESOLVED_UNSIGNED;
+  function "rol" (ARG : UNRESOLVED_SIGNED; COUNT : INTEGER) return UNRESOLVED_SIGNED;
+  function "ror" (ARG : UNRESOLVED_UNSIGNED; COUNT : INTEGER) return UNRESOLVED_UNSIGNED;
+  function "ror" (ARG : UNRESOLVED_SIGNED; COUNT : INTEGER) return UNRESOLVED_SIGNED;
+  function "sla" (ARG : UNRESOLVED_UNSIGNED; COUNT : INTEGER) return UNRESOLVED_UNSIGNED;
+  function "sla" (ARG : UNRESOLVED_SIGNED; COUNT : INTEGER) return UNRESOLVED_SIGNED;
+  function "sra" (ARG : UNRESOLVED_UNSIGNED; COUNT : INTEGER) return UNRESOLVED_UNSIGNED;
+  function "sra" (ARG : UNRESOLVED_SIGNED; COUNT : INTEGER) return UNRESOLVED_SIGNED;
+
+  function RESIZE (ARG : UNRESOLVED_SIGNED; NEW_SIZE : NATURAL) return UNRESOLVED_SIGNED;
+  function RESIZE (ARG : UNRESOLVED_UNSIGNED; NEW_SIZE : NATURAL) return UNRESOLVED_UNSIGNED;
+  function RESIZE (ARG, SIZE_RES : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function RESIZE (ARG, SIZE_RES : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+
+  function TO_INTEGER (ARG : UNRESOLVED_UNSIGNED) return NATURAL;
+  function TO_INTEGER (ARG : UNRESOLVED_SIGNED) return INTEGER;
+
+  function TO_UNSIGNED (ARG, SIZE : NATURAL) return UNRESOLVED_UNSIGNED;
+  function TO_SIGNED (ARG : INTEGER; SIZE : NATURAL) return UNRESOLVED_SIGNED;
+  function TO_UNSIGNED (ARG : NATURAL; SIZE_RES : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function TO_SIGNED (ARG : INTEGER; SIZE_RES : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+
+  function "not" (L : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "and" (L, R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "or" (L, R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "nand" (L, R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "nor" (L, R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "xor" (L, R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "xnor" (L, R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "not" (L : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "and" (L, R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "or" (L, R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "nand" (L, R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "nor" (L, R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "xor" (L, R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "xnor" (L, R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+
+  function "and" (L : STD_ULOGIC; R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "and" (L : UNRESOLVED_UNSIGNED; R : STD_ULOGIC) return UNRESOLVED_UNSIGNED;
+  function "or" (L : STD_ULOGIC; R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "or" (L : UNRESOLVED_UNSIGNED; R : STD_ULOGIC) return UNRESOLVED_UNSIGNED;
+  function "xor" (L : STD_ULOGIC; R : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function "xor" (L : UNRESOLVED_UNSIGNED; R : STD_ULOGIC) return UNRESOLVED_UNSIGNED;
+  function "and" (L : STD_ULOGIC; R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "and" (L : UNRESOLVED_SIGNED; R : STD_ULOGIC) return UNRESOLVED_SIGNED;
+  function "or" (L : STD_ULOGIC; R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "or" (L : UNRESOLVED_SIGNED; R : STD_ULOGIC) return UNRESOLVED_SIGNED;
+  function "xor" (L : STD_ULOGIC; R : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function "xor" (L : UNRESOLVED_SIGNED; R : STD_ULOGIC) return UNRESOLVED_SIGNED;
+
+  function "and" (L : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "nand" (L : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "or" (L : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "nor" (L : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "xor" (L : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "xnor" (L : UNRESOLVED_SIGNED) return STD_ULOGIC;
+  function "and" (L : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "nand" (L : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "or" (L : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "nor" (L : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "xor" (L : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+  function "xnor" (L : UNRESOLVED_UNSIGNED) return STD_ULOGIC;
+
+  function STD_MATCH (L, R : STD_ULOGIC) return BOOLEAN;
+  function STD_MATCH (L, R : UNRESOLVED_UNSIGNED) return BOOLEAN;
+  function STD_MATCH (L, R : UNRESOLVED_SIGNED) return BOOLEAN;
+  function STD_MATCH (L, R : STD_ULOGIC_VECTOR) return BOOLEAN;
+
+  function TO_01 (S : UNRESOLVED_UNSIGNED; XMAP : STD_ULOGIC := '0') return UNRESOLVED_UNSIGNED;
+  function TO_01 (S : UNRESOLVED_SIGNED; XMAP : STD_ULOGIC := '0') return UNRESOLVED_SIGNED;
+
+  function TO_X01 (S : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function TO_X01 (S : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function TO_X01Z (S : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function TO_X01Z (S : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+  function TO_UX01 (S : UNRESOLVED_SIGNED) return UNRESOLVED_SIGNED;
+  function TO_UX01 (S : UNRESOLVED_UNSIGNED) return UNRESOLVED_UNSIGNED;
+
+  function IS_X (S : UNRESOLVED_UNSIGNED) return BOOLEAN;
+  function IS_X (S : UNRESOLVED_SIGNED) return BOOLEAN;
+
+  alias TO_BSTRING is TO_STRING [UNRESOLVED_UNSIGNED return STRING];
+  alias TO_BSTRING is TO_STRING [UNRESOLVED_SIGNED return STRING];
+  alias TO_BINARY_STRING is TO_STRING [UNRESOLVED_UNSIGNED return STRING];
+  alias TO_BINARY_STRING is TO_STRING [UNRESOLVED_SIGNED return STRING];
+
+  function TO_OSTRING (value : UNRESOLVED_UNSIGNED) return STRING;
+  function TO_OSTRING (value : UNRESOLVED_SIGNED) return STRING;
+  alias TO_OCTAL_STRING is TO_OSTRING [UNRESOLVED_UNSIGNED return STRING];
+  alias TO_OCTAL_STRING is TO_OSTRING [UNRESOLVED_SIGNED return STRING];
+
+  function TO_HSTRING (value : UNRESOLVED_UNSIGNED) return STRING;
+  function TO_HSTRING (value : UNRESOLVED_SIGNED) return STRING;
+  alias TO_HEX_STRING is TO_HSTRING [UNRESOLVED_UNSIGNED return STRING];
+  alias TO_HEX_STRING is TO_HSTRING [UNRESOLVED_SIGNED return STRING];
+
+  procedure READ (L : inout LINE; VALUE : out UNRESOLVED_UNSIGNED; GOOD : out BOOLEAN);
+  procedure READ (L : inout LINE; VALUE : out UNRESOLVED_UNSIGNED);
+  procedure READ (L : inout LINE; VALUE : out UNRESOLVED_SIGNED; GOOD : out BOOLEAN);
+  procedure READ (L : inout LINE; VALUE : out UNRESOLVED_SIGNED);
+
+  procedure WRITE (L : inout LINE; VALUE : in UNRESOLVED_UNSIGNED;
+                   JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  procedure WRITE (L : inout LINE; VALUE : in UNRESOLVED_SIGNED;
+                   JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+
+  alias BREAD is READ [LINE, UNRESOLVED_UNSIGNED, BOOLEAN];
+  alias BREAD is READ [LINE, UNRESOLVED_SIGNED, BOOLEAN];
+  alias BREAD is READ [LINE, UNRESOLVED_UNSIGNED];
+  alias BREAD is READ [LINE, UNRESOLVED_SIGNED];
+  alias BINARY_READ is READ [LINE, UNRESOLVED_UNSIGNED, BOOLEAN];
+  alias BINARY_READ is READ [LINE, UNRESOLVED_SIGNED, BOOLEAN];
+  alias BINARY_READ is READ [LINE, UNRESOLVED_UNSIGNED];
+  alias BINARY_READ is READ [LINE, UNRESOLVED_SIGNED];
+
+  procedure OREAD (L : inout LINE; VALUE : out UNRESOLVED_UNSIGNED; GOOD : out BOOLEAN);
+  procedure OREAD (L : inout LINE; VALUE : out UNRESOLVED_SIGNED; GOOD : out BOOLEAN);
+  procedure OREAD (L : inout LINE; VALUE : out UNRESOLVED_UNSIGNED);
+  procedure OREAD (L : inout LINE; VALUE : out UNRESOLVED_SIGNED);
+  alias OCTAL_READ is OREAD [LINE, UNRESOLVED_UNSIGNED, BOOLEAN];
+  alias OCTAL_READ is OREAD [LINE, UNRESOLVED_SIGNED, BOOLEAN];
+  alias OCTAL_READ is OREAD [LINE, UNRESOLVED_UNSIGNED];
+  alias OCTAL_READ is OREAD [LINE, UNRESOLVED_SIGNED];
+
+  procedure HREAD (L : inout LINE; VALUE : out UNRESOLVED_UNSIGNED; GOOD : out BOOLEAN);
+  procedure HREAD (L : inout LINE; VALUE : out UNRESOLVED_SIGNED; GOOD : out BOOLEAN);
+  procedure HREAD (L : inout LINE; VALUE : out UNRESOLVED_UNSIGNED);
+  procedure HREAD (L : inout LINE; VALUE : out UNRESOLVED_SIGNED);
+  alias HEX_READ is HREAD [LINE, UNRESOLVED_UNSIGNED, BOOLEAN];
+  alias HEX_READ is HREAD [LINE, UNRESOLVED_SIGNED, BOOLEAN];
+  alias HEX_READ is HREAD [LINE, UNRESOLVED_UNSIGNED];
+  alias HEX_READ is HREAD [LINE, UNRESOLVED_SIGNED];
+
+  alias BWRITE is WRITE [LINE, UNRESOLVED_UNSIGNED, SIDE, WIDTH];
+  alias BWRITE is WRITE [LINE, UNRESOLVED_SIGNED, SIDE, WIDTH];
+  alias BINARY_WRITE is WRITE [LINE, UNRESOLVED_UNSIGNED, SIDE, WIDTH];
+  alias BINARY_WRITE is WRITE [LINE, UNRESOLVED_SIGNED, SIDE, WIDTH];
+
+  procedure OWRITE (L : inout LINE; VALUE : in UNRESOLVED_UNSIGNED;
+                    JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  procedure OWRITE (L : inout LINE; VALUE : in UNRESOLVED_SIGNED;
+                    JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  alias OCTAL_WRITE is OWRITE [LINE, UNRESOLVED_UNSIGNED, SIDE, WIDTH];
+  alias OCTAL_WRITE is OWRITE [LINE, UNRESOLVED_SIGNED, SIDE, WIDTH];
+
+  procedure HWRITE (L : inout LINE; VALUE : in UNRESOLVED_UNSIGNED;
+                    JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  procedure HWRITE (L : inout LINE; VALUE : in UNRESOLVED_SIGNED;
+                    JUSTIFIED : in SIDE := right; FIELD : in WIDTH := 0);
+  alias HEX_WRITE is HWRITE [LINE, UNRESOLVED_UNSIGNED, SIDE, WIDTH];
+  alias HEX_WRITE is HWRITE [LINE, UNRESOLVED_SIGNED, SIDE, WIDTH];
+
+end package NUMERIC_STD;
@@ -0,0 +1,112 @@
+-- Package numeric_std_unsigned as defined by IEEE 1076-2008
+-- Only the package declaration is provided, it is used for semantic analysis
+
+library IEEE;
+use IEEE.STD_LOGIC_1164.all;
+
+package NUMERIC_STD_UNSIGNED is
+
+  function "+" (L, R : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+  function "+" (L : STD_ULOGIC_VECTOR; R : NATURAL) return STD_ULOGIC_VECTOR;
+  function "+" (L : NATURAL; R : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+
+  function "-" (L, R : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+  function "-" (L : STD_ULOGIC_VECTOR; R : NATURAL) return STD_ULOGIC_VECTOR;
+  function "-" (L : NATURAL; R : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+
+  function "*" (L, R : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+  function "*" (L : STD_ULOGIC_VECTOR; R : NATURAL) return STD_ULOGIC_VECTOR;
+  function "*" (L : NATURAL; R : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+
+  function "/" (L, R : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+  function "/" (L : STD_ULOGIC_VECTOR; R : NATURAL) return STD_ULOGIC_VECTOR;
+  function "/" (L : NATURAL; R : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+
+  function "rem" (L, R : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+  function "rem" (L : STD_ULOGIC_VECTOR; R : NATURAL) return STD_ULOGIC_VECTOR;
+  function "rem" (L : NATURAL; R : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+
+  function "mod" (L, R : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+  function "mod" (L : STD_ULOGIC_VECTOR; R : NATURAL) return STD_ULOGIC_VECTOR;
+  function "mod" (L : NATURAL; R : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+
+  function find_leftmost (ARG : STD_ULOGIC_VECTOR; Y : STD_ULOGIC) return INTEGER;
+  function find_rightmost (ARG : STD_ULOGIC_VECTOR; Y : STD_ULOGIC) return INTEGER;
+
+  function ">" (L, R : STD_ULOGIC_VECTOR) return BOOLEAN;
+  function ">" (L : NATURAL; R : STD_ULOGIC_VECTOR) return BOOLEAN;
+  function ">" (L : STD_ULOGIC_VECTOR; R : NATURAL) return BOOLEAN;
+
+  function "<" (L, R : STD_ULOGIC_VECTOR) return BOOLEAN;
+  function "<" (L : NATURAL; R : STD_ULOGIC_VECTOR) return BOOLEAN;
+  function "<" (L : STD_ULOGIC_VECTOR; R : NATURAL) return BOOLEAN;
+
+  function "<=" (L, R : STD_ULOGIC_VECTOR) return BOOLEAN;
+  function "<=" (L : NATURAL; R : STD_ULOGIC_VECTOR) return BOOLEAN;
+  function "<=" (L : STD_ULOGIC_VECTOR; R : NATURAL) return BOOLEAN;
+
+  function ">=" (L, R : STD_ULOGIC_VECTOR) return BOOLEAN;
+  function ">=" (L : NATURAL; R : STD_ULOGIC_VECTOR) return BOOLEAN;
+  function ">=" (L : STD_ULOGIC_VECTOR; R : NATURAL) return BOOLEAN;
+
+  function "=" (L, R : STD_ULOGIC_VECTOR) return BOOLEAN;
+  function "=" (L : NATURAL; R : STD_ULOGIC_VECTOR) return BOOLEAN;
+  function "=" (L : STD_ULOGIC_VECTOR; R : NATURAL) return BOOLEAN;
+
+  function "/=" (L, R : STD_ULOGIC_VECTOR) return BOOLEAN;
+  function "/=" (L : NATURAL; R : STD_ULOGIC_VECTOR) return BOOLEAN;
+  function "/=" (L : STD_ULOGIC_VECTOR; R : NATURAL) return BOOLEAN;
+
+  function "?>" (L, R : STD_ULOGIC_VECTOR) return STD_ULOGIC;
+  function "?>" (L : NATURAL; R : STD_ULOGIC_VECTOR) return STD_ULOGIC;
+  function "?>" (L : STD_ULOGIC_VECTOR; R : NATURAL) return STD_ULOGIC;
+
+  function "?<" (L, R : STD_ULOGIC_VECTOR) return STD_ULOGIC;
+  function "?<" (L : NATURAL; R : STD_ULOGIC_VECTOR) return STD_ULOGIC;
+  function "?<" (L : STD_ULOGIC_VECTOR; R : NATURAL) return STD_ULOGIC;
+
+  function "?<=" (L, R : STD_ULOGIC_VECTOR) return STD_ULOGIC;
+  function "?<=" (L : NATURAL; R : STD_ULOGIC_VECTOR) return STD_ULOGIC;
+  function "?<=" (L : STD_ULOGIC_VECTOR; R : NATURAL) return STD_ULOGIC;
+
+  function "?>=" (L, R : STD_ULOGIC_VECTOR) return STD_ULOGIC;
+  function "?>=" (L : NATURAL; R : STD_ULOGIC_VECTOR) return STD_ULOGIC;
+  function "?>=" (L : STD_ULOGIC_VECTOR; R : NATURAL) return STD_ULOGIC;
+
+  function "?=" (L, R : STD_ULOGIC_VECTOR) return STD_ULOGIC;
+  function "?=" (L : NATURAL; R : STD_ULOGIC_VECTOR) return STD_ULOGIC;
+  function "?=" (L : STD_ULOGIC_VECTOR; R : NATURAL) return STD_ULOGIC;
+
+  function "?/=" (L, R : STD_ULOGIC_VECTOR) return STD_ULOGIC;
+  function "?/=" (L : NATURAL; R : STD_ULOGIC_VECTOR) return STD_ULOGIC;
+  function "?/=" (L : STD_ULOGIC_VECTOR; R : NATURAL) return STD_ULOGIC;
+
+  function MINIMUM (L, R : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+  function MINIMUM (L : NATURAL; R : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+  function MINIMUM (L : STD_ULOGIC_VECTOR; R : NATURAL) return STD_ULOGIC_VECTOR;
+
+  function MAXIMUM (L, R : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+  function MAXIMUM (L : NATURAL; R : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+  function MAXIMUM (L : STD_ULOGIC_VECTOR; R : NATURAL) return STD_ULOGIC_VECTOR;
+
+  function SHIFT_LEFT (ARG : STD_ULOGIC_VECTOR; COUNT : NATURAL) return STD_ULOGIC_VECTOR;
+  function SHIFT_RIGHT (ARG : STD_ULOGIC_VECTOR; COUNT : NATURAL) return STD_ULOGIC_VECTOR;
+  function ROTATE_LEFT (ARG : STD_ULOGIC_VECTOR; COUNT : NATURAL) return STD_ULOGIC_VECTOR;
+  function ROTATE_RIGHT (ARG : STD_ULOGIC_VECTOR; COUNT : NATURAL) return STD_ULOGIC_VECTOR;
+
+  function RESIZE (ARG : STD_ULOGIC_VECTOR; NEW_SIZE : NATURAL) return STD_ULOGIC_VECTOR;
+  function RESIZE (ARG, SIZE_RES : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+
+  function TO_INTEGER (ARG : STD_ULOGIC_VECTOR) return NATURAL;
+  alias To_Integer_unsigned is TO_INTEGER [STD_ULOGIC_VECTOR return NATURAL];
+  alias To_Int is TO_INTEGER [STD_ULOGIC_VECTOR return NATURAL];
+
+  function To_StdLogicVector (ARG, SIZE : NATURAL) return STD_ULOGIC_VECTOR;
+  function To_StdLogicVector (ARG : NATURAL; SIZE_RES : STD_ULOGIC_VECTOR) return STD_ULOGIC_VECTOR;
+  alias To_Std_Logic_Vector is To_StdLogicVector [NATURAL, NATURAL return STD_ULOGIC_VECTOR];
+  alias To_SLV is To_StdLogicVector [NATURAL, NATURAL return STD_ULOGIC_VECTOR];
+  function To_StdULogicVector (ARG, SIZE : NATURAL) return STD_ULOGIC_VECTOR;
+  alias To_Std_ULogic_Vector is To_StdULogicVector [NATURAL, NATURAL return STD_ULOGIC_VECTOR];
+  alias To_SULV is To_StdULogicVector [NATURAL, NATURAL return STD_ULOGIC_VECTOR];
+
+end package NUMERIC_STD_UNSIGNED;
@@ -0,0 +1,5 @@
+-- Package std_logic_textio as defined by IEEE 1076-2008
+-- The package is empty since the declarations were moved to std_logic_1164
+
+package std_logic_textio is
+end package std_logic_textio;
@@ -5,14 +5,17 @@
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
 use crate::ast::AliasDeclaration;
-use crate::message::ParseResult;
+use crate::message::{MessageHandler, ParseResult};
 use crate::names::{parse_designator, parse_name};
 use crate::subprogram::parse_signature;
 use crate::subtype_indication::parse_subtype_indication;
 use crate::tokenizer::Kind::*;
 use crate::tokenstream::TokenStream;
 
-pub fn parse_alias_declaration(stream: &mut TokenStream) -> ParseResult<AliasDeclaration> {
+pub fn parse_alias_declaration(
+    stream: &mut TokenStream,
+    messages: &mut dyn MessageHandler,
+) -> ParseResult<AliasDeclaration> {
     stream.expect_kind(Alias)?;
     let designator = parse_designator(stream)?;
     let subtype_indication = {
@@ -28,7 +31,7 @@ pub fn parse_alias_declaration(stream: &mut TokenStream) -> ParseResult<AliasDec
 
     let signature = {
         if stream.peek_kind()? == Some(LeftSquare) {
-            Some(parse_signature(stream)?)
+            Some(parse_signature(stream, messages)?)
         } else {
             None
         }
@@ -53,7 +56,7 @@ mod tests {
     fn parse_simple_alias() {
         let code = Code::new("alias foo is name;");
         assert_eq!(
-            code.with_stream(parse_alias_declaration),
+            code.with_stream_no_messages(parse_alias_declaration),
             AliasDeclaration {
                 designator: code.s1("foo").designator(),
                 subtype_indication: None,
@@ -67,7 +70,7 @@ mod tests {
     fn parse_alias_with_subtype_indication() {
         let code = Code::new("alias foo : vector(0 to 1) is name;");
         assert_eq!(
-            code.with_stream(parse_alias_declaration),
+            code.with_stream_no_messages(parse_alias_declaration),
             AliasDeclaration {
                 designator: code.s1("foo").designator(),
                 subtype_indication: Some(code.s1("vector(0 to 1)").subtype_indication()),
@@ -81,7 +84,7 @@ mod tests {
     fn parse_alias_with_signature() {
         let code = Code::new("alias foo is name [return natural];");
         assert_eq!(
-            code.with_stream(parse_alias_declaration),
+            code.with_stream_no_messages(parse_alias_declaration),
             AliasDeclaration {
                 designator: code.s1("foo").designator(),
                 subtype_indication: None,
@@ -98,7 +101,7 @@ mod tests {
         let designator = code.s1("\"and\"").designator();
 
         assert_eq!(
-            code.with_stream(parse_alias_declaration),
+            code.with_stream_no_messages(parse_alias_declaration),
             AliasDeclaration {
                 designator,
                 subtype_indication: None,
@@ -115,7 +118,7 @@ mod tests {
         let designator = code.s1("'c'").designator();
 
         assert_eq!(
-            code.with_stream(parse_alias_declaration),
+            code.with_stream_no_messages(parse_alias_declaration),
             AliasDeclaration {
                 designator,
                 subtype_indication: None,
@@ -124,5 +127,4 @@ mod tests {
             }
         );
     }
-
 }
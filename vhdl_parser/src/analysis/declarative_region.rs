@@ -5,7 +5,7 @@
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 use super::library::{EntityDesignUnit, Library, PackageDesignUnit};
 use crate::ast::*;
-use crate::message::{Message, MessageHandler};
+use crate::message::{Message, MessageCode, MessageHandler};
 use crate::source::{SrcPos, WithPos};
 
 use self::fnv::FnvHashMap;
@@ -38,7 +38,8 @@ impl<'a> PrimaryUnitData<'a> {
 pub enum AnyDeclaration<'a> {
     Declaration(&'a Declaration),
     Element(&'a ElementDeclaration),
-    Enum(&'a WithPos<EnumerationLiteral>),
+    Enum(&'a TypeDeclaration, &'a WithPos<EnumerationLiteral>),
+    PhysicalUnit(&'a PhysicalTypeDeclaration, &'a Ident),
     Interface(&'a InterfaceDeclaration),
     Library(&'a Library),
     Package(&'a Library, &'a PackageDesignUnit),
@@ -47,6 +48,7 @@ pub enum AnyDeclaration<'a> {
     Configuration(&'a DesignUnit<ConfigurationDeclaration>),
     PackageInstance(&'a Library, &'a DesignUnit<PackageInstantiation>),
     LocalPackageInstance(&'a Ident, Arc<PrimaryUnitData<'a>>),
+    PslParameter(&'a Ident),
 }
 
 impl<'a> AnyDeclaration<'a> {
@@ -135,15 +137,25 @@ impl<'a> VisibleDeclaration<'a> {
         }
     }
 
-    fn error(&self, messages: &mut dyn MessageHandler, message: impl Into<String>) {
+    fn error(
+        &self,
+        messages: &mut dyn MessageHandler,
+        message: impl Into<String>,
+        code: MessageCode,
+    ) {
         if let Some(ref pos) = self.decl_pos {
-            messages.push(Message::error(pos, message));
+            messages.push(Message::error(pos, message, code));
         }
     }
 
-    fn hint(&self, messages: &mut dyn MessageHandler, message: impl Into<String>) {
+    fn hint(
+        &self,
+        messages: &mut dyn MessageHandler,
+        message: impl Into<String>,
+        code: MessageCode,
+    ) {
         if let Some(ref pos) = self.decl_pos {
-            messages.push(Message::hint(pos, message));
+            messages.push(Message::hint(pos, message, code));
         }
     }
 
@@ -205,6 +217,9 @@ pub struct DeclarativeRegion<'r, 'a: 'r> {
     parent: Option<ParentRegion<'r, 'a>>,
     visible: FnvHashMap<Designator, VisibleDeclaration<'a>>,
     decls: FnvHashMap<Designator, VisibleDeclaration<'a>>,
+    /// Further overloads of the visible and declared designators, the first one is in visible or decls
+    visible_overloads: FnvHashMap<Designator, Vec<VisibleDeclaration<'a>>>,
+    decl_overloads: FnvHashMap<Designator, Vec<VisibleDeclaration<'a>>>,
     kind: RegionKind,
 }
 
@@ -214,6 +229,8 @@ impl<'r, 'a: 'r> DeclarativeRegion<'r, 'a> {
             parent: parent.map(|parent| ParentRegion::Borrowed(parent)),
             visible: FnvHashMap::default(),
             decls: FnvHashMap::default(),
+            visible_overloads: FnvHashMap::default(),
+            decl_overloads: FnvHashMap::default(),
             kind: RegionKind::Other,
         }
     }
@@ -237,6 +254,8 @@ impl<'r, 'a: 'r> DeclarativeRegion<'r, 'a> {
             parent,
             visible: self.visible,
             decls: self.decls,
+            visible_overloads: self.visible_overloads,
+            decl_overloads: self.decl_overloads,
             kind: self.kind,
         }
     }
@@ -255,6 +274,8 @@ impl<'r, 'a: 'r> DeclarativeRegion<'r, 'a> {
             parent: Some(ParentRegion::Borrowed(parent)),
             visible: self.visible,
             decls: self.decls,
+            visible_overloads: self.visible_overloads,
+            decl_overloads: self.decl_overloads,
             kind,
         }
     }
@@ -271,8 +292,9 @@ impl<'r, 'a: 'r> DeclarativeRegion<'r, 'a> {
                         "Missing full type declaration of incomplete type '{}'",
                         &decl.designator
                     ),
+                    MessageCode::MissingFullDeclaration,
                 );
-                decl.hint(messages, "The full type declaration shall occur immediately within the same declarative part");
+                decl.hint(messages, "The full type declaration shall occur immediately within the same declarative part", MessageCode::MissingFullDeclaration);
             }
         }
 
@@ -287,12 +309,13 @@ impl<'r, 'a: 'r> DeclarativeRegion<'r, 'a> {
         for decl in self.decls.values() {
             if decl.decl.is_deferred_constant() {
                 to_remove.push(decl.designator.clone());
-                decl.error(messages, format!("Deferred constant '{}' lacks corresponding full constant declaration in package body", &decl.designator));
+                decl.error(messages, format!("Deferred constant '{}' lacks corresponding full constant declaration in package body", &decl.designator), MessageCode::MissingFullDeclaration);
             } else if decl.decl.is_protected_type() {
                 to_remove.push(decl.designator.clone());
                 decl.error(
                     messages,
                     format!("Missing body for protected type '{}'", &decl.designator),
+                    MessageCode::MissingFullDeclaration,
                 );
             }
         }
@@ -312,6 +335,7 @@ impl<'r, 'a: 'r> DeclarativeRegion<'r, 'a> {
             decl.error(
                 messages,
                 "Deferred constants are only allowed in package declarations (not body)",
+                MessageCode::IllegalDeferredConstant,
             );
         }
 
@@ -324,7 +348,7 @@ impl<'r, 'a: 'r> DeclarativeRegion<'r, 'a> {
                         if self.kind != RegionKind::PackageBody
                             && decl.decl.is_non_deferred_constant()
                         {
-                            decl.error(messages, "Full declaration of deferred constant is only allowed in a package body");
+                            decl.error(messages, "Full declaration of deferred constant is only allowed in a package body", MessageCode::IllegalDeferredConstant);
                         }
 
                         std::mem::replace(old_decl, decl);
@@ -332,6 +356,7 @@ impl<'r, 'a: 'r> DeclarativeRegion<'r, 'a> {
                         let mut msg = Message::error(
                             pos,
                             format!("Duplicate declaration of '{}'", decl.designator),
+                            MessageCode::DuplicateDeclaration,
                         );
 
                         if let Some(ref old_pos) = old_decl.decl_pos {
@@ -340,6 +365,11 @@ impl<'r, 'a: 'r> DeclarativeRegion<'r, 'a> {
 
                         messages.push(msg)
                     }
+                } else {
+                    self.decl_overloads
+                        .entry(decl.designator.clone())
+                        .or_default()
+                        .push(decl);
                 }
             }
             Entry::Vacant(entry) => {
@@ -347,6 +377,7 @@ impl<'r, 'a: 'r> DeclarativeRegion<'r, 'a> {
                     decl.error(
                         messages,
                         format!("No declaration of protected type '{}'", &decl.designator),
+                        MessageCode::UnresolvedName,
                     );
                 } else {
                     entry.insert(decl);
@@ -371,11 +402,29 @@ impl<'r, 'a: 'r> DeclarativeRegion<'r, 'a> {
 
     pub fn make_potentially_visible(&mut self, decl: impl Into<VisibleDeclaration<'a>>) {
         let decl = decl.into();
-        self.visible.insert(decl.designator.clone(), decl);
+        match self.visible.entry(decl.designator.clone()) {
+            Entry::Occupied(ref entry) if decl.may_overload && entry.get().may_overload => {
+                self.visible_overloads
+                    .entry(decl.designator.clone())
+                    .or_default()
+                    .push(decl);
+            }
+            Entry::Occupied(mut entry) => {
+                self.visible_overloads.remove(&decl.designator);
+                entry.insert(decl);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(decl);
+            }
+        }
     }
 
     pub fn make_all_potentially_visible(&mut self, region: &DeclarativeRegion<'_, 'a>) {
-        for decl in region.decls.values() {
+        for decl in region
+            .decls
+            .values()
+            .chain(region.decl_overloads.values().flatten())
+        {
             self.make_potentially_visible(decl.clone());
         }
     }
@@ -396,4 +445,52 @@ impl<'r, 'a: 'r> DeclarativeRegion<'r, 'a> {
                     .and_then(|parent| parent.lookup(designator, inside))
             })
     }
+
+    /// All overloads of a designator which are visible from this region
+    /// A declaration which may not be overloaded hides the declarations of outer regions
+    pub fn lookup_overloads(
+        &self,
+        designator: &Designator,
+        inside: bool,
+    ) -> Vec<&VisibleDeclaration<'a>> {
+        let mut overloads = Vec::new();
+        self.collect_overloads(designator, inside, &mut overloads);
+        overloads
+    }
+
+    fn collect_overloads<'s>(
+        &'s self,
+        designator: &Designator,
+        inside: bool,
+        overloads: &mut Vec<&'s VisibleDeclaration<'a>>,
+    ) {
+        let immediate = self
+            .decls
+            .get(designator)
+            .map(|decl| (decl, self.decl_overloads.get(designator)))
+            .or_else(|| {
+                if inside {
+                    self.visible
+                        .get(designator)
+                        .map(|decl| (decl, self.visible_overloads.get(designator)))
+                } else {
+                    None
+                }
+            });
+
+        if let Some((decl, others)) = immediate {
+            if !decl.may_overload {
+                if overloads.is_empty() {
+                    overloads.push(decl);
+                }
+                return;
+            }
+            overloads.push(decl);
+            overloads.extend(others.into_iter().flatten());
+        }
+
+        if let Some(ref parent) = self.parent {
+            parent.collect_overloads(designator, inside, overloads);
+        }
+    }
 }
@@ -13,11 +13,12 @@ use std::collections::hash_map::Entry;
 use crate::ast::{
     AnyDesignUnit, ArchitectureBody, ConfigurationDeclaration, ContextDeclaration, DesignFile,
     DesignUnit, Designator, EntityDeclaration, HasIdent, Ident, PackageBody, PackageDeclaration,
-    PackageInstantiation, PrimaryUnit, SecondaryUnit, SelectedName,
+    PackageInstantiation, PrimaryUnit, SecondaryUnit, SelectedName, VerificationUnit,
 };
-use crate::message::{Message, MessageHandler};
+use crate::message::{Message, MessageCode, MessageHandler};
 use crate::source::{SrcPos, WithPos};
 use crate::symbol_table::Symbol;
+use crate::vhdl_standard::VHDLStandard;
 
 impl EntityDesignUnit {
     fn add_architecture(
@@ -34,6 +35,7 @@ impl EntityDesignUnit {
                         &architecture.name(),
                         self.entity.name(),
                     ),
+                    MessageCode::DuplicateDesignUnit,
                 ));
             }
             Entry::Vacant(entry) => {
@@ -50,6 +52,7 @@ impl EntityDesignUnit {
                                 &architecture.name(),
                                 self.entity.name()
                             ),
+                            MessageCode::SecondaryUnitBeforePrimary,
                         ));
                     }
                 };
@@ -73,6 +76,7 @@ impl EntityDesignUnit {
                         &configuration.name(),
                         self.entity.name(),
                     ),
+                    MessageCode::DuplicateDesignUnit,
                 ));
             }
             Entry::Vacant(entry) => {
@@ -89,6 +93,7 @@ impl EntityDesignUnit {
                                 &configuration.name(),
                                 self.entity.name()
                             ),
+                            MessageCode::SecondaryUnitBeforePrimary,
                         ));
                     }
                 };
@@ -112,6 +117,7 @@ impl PackageDesignUnit {
                     "Duplicate package body of package '{}'",
                     self.package.name(),
                 ),
+                MessageCode::DuplicateDesignUnit,
             ));
         } else {
             {
@@ -126,6 +132,7 @@ impl PackageDesignUnit {
                             "Package body declared before package '{}'",
                             self.package.name()
                         ),
+                        MessageCode::SecondaryUnitBeforePrimary,
                     ));
                 }
             }
@@ -163,6 +170,8 @@ pub struct Library {
     packages: FnvHashMap<Symbol, PackageDesignUnit>,
     package_instances: FnvHashMap<Symbol, DesignUnit<PackageInstantiation>>,
     contexts: FnvHashMap<Symbol, ContextDeclaration>,
+    verification_units: FnvHashMap<Symbol, DesignUnit<VerificationUnit>>,
+    standard: VHDLStandard,
 }
 
 impl<'a> Library {
@@ -177,6 +186,7 @@ impl<'a> Library {
         let mut packages = FnvHashMap::default();
         let mut package_instances = FnvHashMap::default();
         let mut contexts = FnvHashMap::default();
+        let mut verification_units = FnvHashMap::default();
         let mut architectures = Vec::new();
         let mut package_bodies = Vec::new();
         let mut configurations = Vec::new();
@@ -195,7 +205,7 @@ impl<'a> Library {
                                         "A primary unit has already been declared with name '{}' in library '{}'",
                                         entry.key(),
                                         name
-                                    )).related(entry.get(), "Previously defined here");
+                                    ), MessageCode::DuplicateDesignUnit).related(entry.get(), "Previously defined here");
                                 messages.push(msg);
                             }
                             Entry::Vacant(entry) => match primary {
@@ -228,6 +238,10 @@ impl<'a> Library {
                                     entry.insert(context.ident().pos.clone());
                                     contexts.insert(context.name().clone(), context);
                                 }
+                                PrimaryUnit::VerificationUnit(vunit) => {
+                                    entry.insert(vunit.ident().pos.clone());
+                                    verification_units.insert(vunit.name().clone(), vunit);
+                                }
 
                                 PrimaryUnit::Configuration(config) => {
                                     configurations.push(config);
@@ -255,6 +269,7 @@ impl<'a> Library {
                         "No entity '{}' within library '{}'",
                         architecture.unit.entity_name.item, name
                     ),
+                    MessageCode::MissingPrimaryUnit,
                 ));
             }
         }
@@ -266,6 +281,7 @@ impl<'a> Library {
                 messages.push(Message::error(
                     &body.ident(),
                     format!("No package '{}' within library '{}'", &body.name(), name),
+                    MessageCode::MissingPrimaryUnit,
                 ));
             }
         }
@@ -281,6 +297,7 @@ impl<'a> Library {
                             messages.push(Message::error(
                                 libname,
                                 format!("Configuration must be within the same library '{}' as the corresponding entity", name),
+                                MessageCode::ConfigurationLibraryMismatch,
                             ));
                             continue;
                         } else {
@@ -300,6 +317,7 @@ impl<'a> Library {
                     messages.push(Message::error(
                         entname.pos,
                         format!("No entity '{}' within library '{}'", entname.item, name),
+                        MessageCode::MissingPrimaryUnit,
                     ));
                     continue;
                 }
@@ -322,10 +340,20 @@ impl<'a> Library {
             packages,
             package_instances,
             contexts,
+            verification_units,
+            standard: VHDLStandard::default(),
         }
     }
 
-    #[cfg(test)]
+    /// The standard which the design units of the library are analyzed according to
+    pub fn standard(&self) -> VHDLStandard {
+        self.standard
+    }
+
+    pub fn set_standard(&mut self, standard: VHDLStandard) {
+        self.standard = standard;
+    }
+
     pub fn entity(&'a self, name: &Symbol) -> Option<&'a EntityDesignUnit> {
         self.entities.get(name)
     }
@@ -373,10 +401,14 @@ impl<'a> Library {
     pub fn contexts(&self) -> impl Iterator<Item = &ContextDeclaration> {
         self.contexts.values()
     }
+
+    pub fn verification_units(&self) -> impl Iterator<Item = &DesignUnit<VerificationUnit>> {
+        self.verification_units.values()
+    }
 }
 
 /// Extract library_name.entity_name for entity name in configurations
-fn to_entity_name(
+pub fn to_entity_name(
     selected_name: &WithPos<SelectedName>,
 ) -> Result<(Option<Ident>, Ident), Message> {
     match selected_name.item {
@@ -398,12 +430,15 @@ fn to_entity_name(
         }
         _ => {}
     }
-    Err(
-        Message::error(&selected_name, "Invalid selected name for entity").related(
-            &selected_name,
-            "Entity name must be of the form library.entity_name or entity_name",
-        ),
+    Err(Message::error(
+        &selected_name,
+        "Invalid selected name for entity",
+        MessageCode::InvalidSelectedName,
     )
+    .related(
+        &selected_name,
+        "Entity name must be of the form library.entity_name or entity_name",
+    ))
 }
 
 pub struct DesignRoot {
@@ -491,6 +526,7 @@ end package body;
             vec![Message::error(
                 code.s1("pkg"),
                 "No package 'pkg' within library 'libname'",
+                MessageCode::MissingPrimaryUnit,
             )],
         );
     }
@@ -512,6 +548,7 @@ end architecture;
             vec![Message::error(
                 code.s1("ent"),
                 "No entity 'ent' within library 'libname'",
+                MessageCode::MissingPrimaryUnit,
             )],
         );
     }
@@ -536,6 +573,7 @@ end architecture;
             vec![Message::error(
                 code.s("pkg", 2),
                 "No entity 'pkg' within library 'libname'",
+                MessageCode::MissingPrimaryUnit,
             )],
         );
     }
@@ -566,6 +604,7 @@ end package body;
             vec![Message::error(
                 code.s("entname", 2),
                 "No package 'entname' within library 'libname'",
+                MessageCode::MissingPrimaryUnit,
             )],
         );
     }
@@ -592,6 +631,7 @@ end package body;
             vec![Message::error(
                 code.s("pkg", 3),
                 "Duplicate package body of package 'pkg'",
+                MessageCode::DuplicateDesignUnit,
             )],
         );
     }
@@ -629,19 +669,19 @@ package pkg is new gpkg generic map (const => foo);
             vec![
                 Message::error(
                     code.s("pkg", 2),
-                    "A primary unit has already been declared with name 'pkg' in library 'libname'"
+                    "A primary unit has already been declared with name 'pkg' in library 'libname'", MessageCode::DuplicateDesignUnit
                 ).related(code.s("pkg", 1), "Previously defined here"),
                 Message::error(
                     code.s("entname", 2),
-                    "A primary unit has already been declared with name 'entname' in library 'libname'"
+                    "A primary unit has already been declared with name 'entname' in library 'libname'", MessageCode::DuplicateDesignUnit
                 ).related(code.s("entname", 1), "Previously defined here"),
                 Message::error(
                     code.s("pkg", 3),
-                    "A primary unit has already been declared with name 'pkg' in library 'libname'"
+                    "A primary unit has already been declared with name 'pkg' in library 'libname'", MessageCode::DuplicateDesignUnit
                 ).related(code.s("pkg", 1), "Previously defined here"),
                 Message::error(
                     code.s("pkg", 4),
-                    "A primary unit has already been declared with name 'pkg' in library 'libname'"
+                    "A primary unit has already been declared with name 'pkg' in library 'libname'", MessageCode::DuplicateDesignUnit
                 ).related(code.s("pkg", 1), "Previously defined here"),
             ]
         );
@@ -676,10 +716,12 @@ end entity;
                 Message::error(
                     code.s("pkg", 1),
                     "Package body declared before package 'pkg'",
+                    MessageCode::SecondaryUnitBeforePrimary,
                 ),
                 Message::error(
                     code.s("rtl", 1),
                     "Architecture 'rtl' declared before entity 'entname'",
+                    MessageCode::SecondaryUnitBeforePrimary,
                 ),
             ],
         );
@@ -751,6 +793,7 @@ end architecture;
             vec![Message::error(
                 code.s("rtl", 2),
                 "Duplicate architecture 'rtl' of entity 'ent'",
+                MessageCode::DuplicateDesignUnit,
             )],
         );
     }
@@ -954,6 +997,7 @@ end configuration;
             vec![Message::error(
                 code.s("cfg", 2),
                 "Duplicate configuration 'cfg' of entity 'ent'",
+                MessageCode::DuplicateDesignUnit,
             )],
         );
         assert_eq!(entity.configurations, configurations);
@@ -991,6 +1035,7 @@ end entity;
             vec![Message::error(
                 code.s("cfg", 1),
                 "Configuration 'cfg' declared before entity 'ent'",
+                MessageCode::SecondaryUnitBeforePrimary,
             )],
         );
         assert_eq!(entity.configurations, configurations);
@@ -1013,6 +1058,7 @@ end configuration;
             vec![Message::error(
                 code.s("ent", 1),
                 "No entity 'ent' within library 'libname'",
+                MessageCode::MissingPrimaryUnit,
             )],
         );
     }
@@ -1034,6 +1080,7 @@ end configuration;
             vec![Message::error(
                 code.s("lib2", 1),
                 "Configuration must be within the same library 'libname' as the corresponding entity",
+                MessageCode::ConfigurationLibraryMismatch,
             )],
         );
     }
@@ -1052,13 +1099,15 @@ end configuration;
 
         check_messages(
             messages,
-            vec![
-                Message::error(code.s1("lib2.pkg.ent"), "Invalid selected name for entity")
-                    .related(
-                        code.s1("lib2.pkg.ent"),
-                        "Entity name must be of the form library.entity_name or entity_name",
-                    ),
-            ],
+            vec![Message::error(
+                code.s1("lib2.pkg.ent"),
+                "Invalid selected name for entity",
+                MessageCode::InvalidSelectedName,
+            )
+            .related(
+                code.s1("lib2.pkg.ent"),
+                "Entity name must be of the form library.entity_name or entity_name",
+            )],
         );
     }
 
@@ -1080,5 +1129,4 @@ package ipkg is new work.lib.gpkg generic map (const => 1);
             })
         );
     }
-
 }
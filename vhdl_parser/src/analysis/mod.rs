@@ -7,5 +7,7 @@
 mod declarative_region;
 mod library;
 mod semantic;
+mod static_expression;
 pub use self::library::{DesignRoot, Library};
 pub use self::semantic::Analyzer;
+pub use self::static_expression::Value;
@@ -7,12 +7,15 @@
 use super::declarative_region::{
     AnyDeclaration, DeclarativeRegion, PrimaryUnitData, VisibleDeclaration,
 };
-use super::library::{DesignRoot, Library, PackageDesignUnit};
+use super::library::{to_entity_name, DesignRoot, EntityDesignUnit, Library, PackageDesignUnit};
+use super::static_expression::{StaticContext, StaticEvaluator, Value};
 use crate::ast::{HasIdent, *};
+use crate::generic_values::GenericValues;
 use crate::latin_1::Latin1String;
-use crate::message::{Message, MessageHandler};
+use crate::message::{Message, MessageCode, MessageHandler};
 use crate::source::{SrcPos, WithPos};
 use crate::symbol_table::{Symbol, SymbolTable};
+use crate::vhdl_standard::VHDLStandard;
 use std::cell::RefCell;
 use std::collections::hash_map::Entry;
 use std::sync::Arc;
@@ -52,6 +55,7 @@ impl CircularDependencyError {
                     "Found circular dependency when referencing '{}.{}'",
                     dependency.library_name, dependency.primary_unit_name
                 ),
+                MessageCode::CircularDependency,
             ));
         }
     }
@@ -186,6 +190,8 @@ impl<'a> AnalysisContext<'a> {
 pub struct Analyzer<'a> {
     work_sym: Symbol,
     std_sym: Symbol,
+    ieee_sym: Symbol,
+    math_real_sym: Symbol,
     standard_designator: Designator,
     standard_sym: Symbol,
     root: &'a DesignRoot,
@@ -193,6 +199,13 @@ pub struct Analyzer<'a> {
     /// DeclarativeRegion for each library containing the primary units
     library_regions: FnvHashMap<Symbol, DeclarativeRegion<'a, 'a>>,
     analysis_context: AnalysisContext<'a>,
+
+    /// The values of generics supplied at elaboration
+    generic_values: GenericValues,
+    /// The values of the generics of the entity being analyzed when elaborated
+    elaboration_generics: RefCell<FnvHashMap<Symbol, Value>>,
+    /// The static value of each constant declaration
+    static_values: RefCell<Vec<(SrcPos, Value)>>,
 }
 
 impl<'r, 'a: 'r> Analyzer<'a> {
@@ -262,14 +275,55 @@ impl<'r, 'a: 'r> Analyzer<'a> {
         Analyzer {
             work_sym: symtab.insert(&Latin1String::new(b"work")),
             std_sym: symtab.insert(&Latin1String::new(b"std")),
+            ieee_sym: symtab.insert(&Latin1String::new(b"ieee")),
+            math_real_sym: symtab.insert(&Latin1String::new(b"math_real")),
             standard_designator: Designator::Identifier(standard_sym.clone()),
             standard_sym,
             root,
             library_regions,
             analysis_context: AnalysisContext::new(),
+            generic_values: GenericValues::new(),
+            elaboration_generics: RefCell::new(FnvHashMap::default()),
+            static_values: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Generics take the supplied value or else the value of their default expression
+    pub fn with_generic_values(mut self, generic_values: &GenericValues) -> Analyzer<'a> {
+        self.generic_values = generic_values.clone();
+        self
+    }
+
+    /// The static value of each constant declaration with the source position of its identifier
+    pub fn take_static_values(&self) -> Vec<(SrcPos, Value)> {
+        std::mem::take(&mut *self.static_values.borrow_mut())
+    }
+
+    fn record_static_value(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        ident: &Ident,
+        expr: &Expression,
+    ) {
+        let generics = self.elaboration_generics.borrow();
+        let value = self
+            .static_evaluator(region)
+            .with_generics(&generics)
+            .evaluate(expr);
+        if let Some(value) = value {
+            self.static_values
+                .borrow_mut()
+                .push((ident.pos.clone(), value));
         }
     }
 
+    fn static_evaluator<'s>(
+        &'s self,
+        region: &'s DeclarativeRegion<'s, 'a>,
+    ) -> StaticEvaluator<'s, 'a> {
+        StaticEvaluator::new(region).with_context(self)
+    }
+
     /// Returns the VisibleDeclaration or None if it was not a selected name
     /// Returns error message if a name was not declared
     /// @TODO We only lookup selected names since other names such as slice and index require typechecking
@@ -287,6 +341,7 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                             return Err(Message::error(
                                 prefix.as_ref(),
                                 "'.all' may not be the prefix of a selected name",
+                                MessageCode::InvalidSelectedName,
                             ))
                         }
                         others => return Ok(others),
@@ -306,6 +361,7 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                                     "No primary unit '{}' within '{}'",
                                     suffix.item, &library.name
                                 ),
+                                MessageCode::UnresolvedName,
                             ))
                         }
                     }
@@ -331,11 +387,16 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                                         &library.name,
                                         package.package.name()
                                     ),
+                                    MessageCode::UnresolvedName,
                                 ))
                             }
                         } else {
                             // Circular dependency, message will never be used
-                            Err(Message::error(&prefix.pos, ""))
+                            Err(Message::error(
+                                &prefix.pos,
+                                "",
+                                MessageCode::CircularDependency,
+                            ))
                         }
                     }
 
@@ -356,11 +417,16 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                                         &library.name,
                                         instance.unit.name()
                                     ),
+                                    MessageCode::UnresolvedName,
                                 ))
                             }
                         } else {
                             // Circular dependency, message will never be used
-                            Err(Message::error(&prefix.pos, ""))
+                            Err(Message::error(
+                                &prefix.pos,
+                                "",
+                                MessageCode::CircularDependency,
+                            ))
                         }
                     }
 
@@ -374,6 +440,7 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                                     "No declaration of '{}' within package instance '{}'",
                                     suffix.item, &instance_name.item
                                 ),
+                                MessageCode::UnresolvedName,
                             ))
                         }
                     }
@@ -389,6 +456,7 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                 LookupResult::AllWithin(..) => Err(Message::error(
                     prefix.as_ref(),
                     "'.all' may not be the prefix of a selected name",
+                    MessageCode::InvalidSelectedName,
                 )),
                 others => Ok(others),
             },
@@ -399,6 +467,7 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                     Err(Message::error(
                         &name.pos,
                         format!("No declaration of '{}'", designator),
+                        MessageCode::UnresolvedName,
                     ))
                 }
             }
@@ -445,6 +514,13 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                     messages,
                 );
             }
+            InterfaceDeclaration::View(ref view_decl) => {
+                self.analyze_mode_view_indication(region, &view_decl.view, messages);
+                region.add(
+                    VisibleDeclaration::new(&view_decl.ident, AnyDeclaration::Interface(decl)),
+                    messages,
+                );
+            }
             InterfaceDeclaration::Package(ref instance) => {
                 match self.analyze_package_instance_name(region, &instance.package_name) {
                     Ok(package_region) => region.add(
@@ -487,6 +563,7 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                 Err(Message::error(
                     &type_mark_name.pos,
                     "Invalid name for type mark",
+                    MessageCode::InvalidSelectedName,
                 ))
             }
         }
@@ -500,6 +577,34 @@ impl<'r, 'a: 'r> Analyzer<'a> {
     ) {
         if let Err(msg) = self.lookup_type_mark(region, &subtype_indication.type_mark) {
             messages.push(msg);
+            return;
+        }
+
+        if let Some(WithPos {
+            item: SubtypeConstraint::Range(ref range),
+            ..
+        }) = subtype_indication.constraint
+        {
+            check_range_constraint(
+                &self.static_evaluator(region),
+                &subtype_indication.type_mark,
+                range,
+                messages,
+            );
+        }
+    }
+
+    fn analyze_mode_view_indication(
+        &self,
+        region: &mut DeclarativeRegion<'_, 'a>,
+        view: &'a ModeViewIndication,
+        messages: &mut dyn MessageHandler,
+    ) {
+        if let Err(msg) = self.lookup_selected_name(region, &view.name) {
+            messages.push(msg);
+        }
+        if let Some(ref subtype_indication) = view.subtype_indication {
+            self.analyze_subtype_indicaton(region, subtype_indication, messages);
         }
     }
 
@@ -509,12 +614,22 @@ impl<'r, 'a: 'r> Analyzer<'a> {
         subprogram: &'a SubprogramDeclaration,
         messages: &mut dyn MessageHandler,
     ) {
-        let mut region = DeclarativeRegion::new(Some(parent));
+        // The generic types of a subprogram are visible in its parameter list and return type
+        let generic_list = match subprogram {
+            SubprogramDeclaration::Function(fun) => &fun.generic_list,
+            SubprogramDeclaration::Procedure(proc) => &proc.generic_list,
+        };
+        let mut generic_region = DeclarativeRegion::new(Some(parent));
+        if let Some(ref generic_list) = generic_list {
+            self.analyze_interface_list(&mut generic_region, generic_list, messages);
+        }
+
+        let mut region = DeclarativeRegion::new(Some(&generic_region));
 
         match subprogram {
             SubprogramDeclaration::Function(fun) => {
                 self.analyze_interface_list(&mut region, &fun.parameter_list, messages);
-                if let Err(msg) = self.lookup_type_mark(&parent, &fun.return_type) {
+                if let Err(msg) = self.lookup_type_mark(&generic_region, &fun.return_type) {
                     messages.push(msg);
                 }
             }
@@ -523,6 +638,7 @@ impl<'r, 'a: 'r> Analyzer<'a> {
             }
         }
         region.close_both(messages);
+        generic_region.close_both(messages);
     }
 
     fn analyze_declaration(
@@ -547,6 +663,17 @@ impl<'r, 'a: 'r> Analyzer<'a> {
             }
             Declaration::Object(ref object_decl) => {
                 self.analyze_subtype_indicaton(region, &object_decl.subtype_indication, messages);
+                if let Some(ref expr) = object_decl.expression {
+                    check_array_length(
+                        &self.static_evaluator(region),
+                        &object_decl.subtype_indication,
+                        expr,
+                        messages,
+                    );
+                    if object_decl.class == ObjectClass::Constant {
+                        self.record_static_value(region, &object_decl.ident, &expr.item);
+                    }
+                }
                 region.add(
                     VisibleDeclaration::new(&object_decl.ident, AnyDeclaration::Declaration(decl)),
                     messages,
@@ -590,8 +717,30 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                         messages,
                     );
                 }
-                // @TODO Ignored for now
-                Attribute::Specification(..) => {}
+                Attribute::Specification(ref attr_spec) => {
+                    self.analyze_attribute_specification(region, attr_spec, messages);
+                }
+            },
+            Declaration::Group(ref group) => match group {
+                Group::Template(ref template_decl) => {
+                    region.add(
+                        VisibleDeclaration::new(
+                            &template_decl.ident,
+                            AnyDeclaration::Declaration(decl),
+                        ),
+                        messages,
+                    );
+                }
+                Group::Declaration(ref group_decl) => {
+                    self.analyze_group_declaration(region, group_decl, messages);
+                    region.add(
+                        VisibleDeclaration::new(
+                            &group_decl.ident,
+                            AnyDeclaration::Declaration(decl),
+                        ),
+                        messages,
+                    );
+                }
             },
             Declaration::SubprogramBody(body) => {
                 region.add(
@@ -636,11 +785,66 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                     }
                 }
             }
-            Declaration::Configuration(..) => {}
-            Declaration::Type(TypeDeclaration {
-                ref ident,
-                def: TypeDefinition::Enumeration(ref enumeration),
-            }) => {
+            Declaration::Configuration(ref config_spec) => {
+                if let Err(msg) = self
+                    .lookup_selected_name(region, &config_spec.spec.component_name.clone().into())
+                {
+                    messages.push(msg);
+                }
+                self.analyze_binding_indication(region, &config_spec.bind_ind, messages);
+            }
+            Declaration::Disconnection(ref disconnection) => {
+                if let GuardedSignalList::Names(ref names) = disconnection.signals {
+                    for name in names.iter() {
+                        self.analyze_name_names(region, &name.item, &name.pos, messages);
+                    }
+                }
+                if let Err(msg) = self.lookup_type_mark(region, &disconnection.type_mark) {
+                    messages.push(msg);
+                }
+            }
+            Declaration::Psl(ref psl_decl) => match psl_decl {
+                PslDeclaration::DefaultClock(ref clock) => {
+                    self.analyze_expression_names(region, clock, messages);
+                }
+                PslDeclaration::Property(ref property_decl) => {
+                    region.add(
+                        VisibleDeclaration::new(
+                            &property_decl.ident,
+                            AnyDeclaration::Declaration(decl),
+                        ),
+                        messages,
+                    );
+                    let mut region = DeclarativeRegion::new(Some(region));
+                    self.analyze_psl_parameters(&mut region, &property_decl.parameters, messages);
+                    self.analyze_psl_property(&region, &property_decl.property, messages);
+                }
+                PslDeclaration::Sequence(ref sequence_decl) => {
+                    region.add(
+                        VisibleDeclaration::new(
+                            &sequence_decl.ident,
+                            AnyDeclaration::Declaration(decl),
+                        ),
+                        messages,
+                    );
+                    let mut region = DeclarativeRegion::new(Some(region));
+                    self.analyze_psl_parameters(&mut region, &sequence_decl.parameters, messages);
+                    self.analyze_psl_sere(&region, &sequence_decl.sequence, messages);
+                }
+            },
+            Declaration::View(ref view_decl) => {
+                self.analyze_subtype_indicaton(region, &view_decl.subtype_indication, messages);
+                region.add(
+                    VisibleDeclaration::new(&view_decl.ident, AnyDeclaration::Declaration(decl)),
+                    messages,
+                );
+            }
+            Declaration::Type(
+                ref type_decl @ TypeDeclaration {
+                    ref ident,
+                    def: TypeDefinition::Enumeration(ref enumeration),
+                },
+            ) => {
                 region.add(
                     VisibleDeclaration::new(ident, AnyDeclaration::Declaration(decl)),
                     messages,
@@ -649,7 +853,7 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                     region.add(
                         VisibleDeclaration::new(
                             literal.clone().map_into(|lit| lit.into_designator()),
-                            AnyDeclaration::Enum(literal),
+                            AnyDeclaration::Enum(type_decl, literal),
                         )
                         .with_overload(true),
                         messages,
@@ -676,6 +880,14 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                                         region, subprogram, messages,
                                     );
                                 }
+                                // Private variables are only visible within the protected type
+                                ProtectedTypeDeclarativeItem::PrivateVariable(object_decl) => {
+                                    self.analyze_subtype_indicaton(
+                                        region,
+                                        &object_decl.subtype_indication,
+                                        messages,
+                                    );
+                                }
                             }
                         }
                     }
@@ -702,129 +914,546 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                     TypeDefinition::Subtype(ref subtype_indication) => {
                         self.analyze_subtype_indicaton(region, subtype_indication, messages);
                     }
+                    TypeDefinition::Range(ref range) => {
+                        let evaluator = self.static_evaluator(region);
+                        check_null_range(&evaluator, range, messages);
+                        check_range_type_definition(&evaluator, range, messages);
+                    }
+                    TypeDefinition::Physical(ref physical) => {
+                        check_null_range(&self.static_evaluator(region), &physical.range, messages);
+                        let units = std::iter::once(&physical.primary_unit)
+                            .chain(physical.secondary_units.iter().map(|(unit, _)| unit));
+                        for unit in units {
+                            region.add(
+                                VisibleDeclaration::new(
+                                    unit,
+                                    AnyDeclaration::PhysicalUnit(physical, unit),
+                                ),
+                                messages,
+                            );
+                        }
+                    }
                     _ => {}
                 }
             }
         }
     }
 
-    fn analyze_declarative_part(
+    /// The entity named by an attribute specification shall be declared before it with the given class
+    fn analyze_attribute_specification(
         &self,
-        region: &mut DeclarativeRegion<'_, 'a>,
-        declarations: &'a [Declaration],
+        region: &DeclarativeRegion<'_, 'a>,
+        attr_spec: &AttributeSpecification,
+        messages: &mut dyn MessageHandler,
+    ) {
+        let tag = match attr_spec.entity_name {
+            EntityName::Name(ref tag) => tag,
+            EntityName::All | EntityName::Others => {
+                return;
+            }
+        };
+
+        // Design units and labels are not declared within the region of the specification
+        // They are checked by analyze_unit_and_label_attribute_specifications
+        if is_unit_or_label_class(attr_spec.entity_class) {
+            return;
+        }
+
+        let designator = &tag.designator;
+        let overloads = region.lookup_overloads(&designator.item, false);
+        if overloads.is_empty() {
+            messages.push(Message::error(
+                designator,
+                format!("No declaration of '{}'", designator.item),
+                MessageCode::UnresolvedName,
+            ));
+        } else if !overloads
+            .iter()
+            .any(|visible_decl| is_of_entity_class(visible_decl, attr_spec.entity_class))
+        {
+            messages.push(Message::error(
+                designator,
+                format!(
+                    "'{}' is not of entity class {}",
+                    designator.item, attr_spec.entity_class
+                ),
+                MessageCode::EntityClassMismatch,
+            ));
+        }
+    }
+
+    /// The attribute specifications of a declarative part which name design units or statement labels
+    /// A design unit shall be the one enclosing the declarative part, a label shall be one of its statements
+    fn analyze_unit_and_label_attribute_specifications(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        unit: Option<(EntityClass, &Symbol)>,
+        declarations: &[Declaration],
+        statements: &[LabeledConcurrentStatement],
         messages: &mut dyn MessageHandler,
     ) {
         for decl in declarations.iter() {
-            self.analyze_declaration(region, decl, messages);
+            if let Declaration::Attribute(Attribute::Specification(ref attr_spec)) = decl {
+                self.analyze_unit_or_label_attribute_specification(
+                    region, unit, attr_spec, statements, messages,
+                );
+            }
         }
     }
 
-    fn analyze_use_clause(
+    fn analyze_unit_or_label_attribute_specification(
         &self,
-        region: &mut DeclarativeRegion<'_, 'a>,
-        use_clause: &UseClause,
-        use_pos: &SrcPos,
+        region: &DeclarativeRegion<'_, 'a>,
+        unit: Option<(EntityClass, &Symbol)>,
+        attr_spec: &AttributeSpecification,
+        statements: &[LabeledConcurrentStatement],
         messages: &mut dyn MessageHandler,
     ) {
-        for name in use_clause.name_list.iter() {
-            match name.item {
-                Name::Selected(..) => {}
-                Name::SelectedAll(..) => {}
+        let designator = match attr_spec.entity_name {
+            EntityName::Name(ref tag) if is_unit_or_label_class(attr_spec.entity_class) => {
+                &tag.designator
+            }
+            _ => {
+                return;
+            }
+        };
+
+        if attr_spec.entity_class == EntityClass::Label {
+            let is_label = statements.iter().any(|statement| {
+                statement
+                    .label
+                    .as_ref()
+                    .map(|ident| Designator::Identifier(ident.item.clone()))
+                    .as_ref()
+                    == Some(&designator.item)
+            });
+
+            if !is_label {
+                messages.push(Message::error(
+                    designator,
+                    format!("No statement labeled '{}'", designator.item),
+                    MessageCode::UnresolvedName,
+                ));
+            }
+            return;
+        }
+
+        let is_enclosing_unit = unit.is_some_and(|(class, name)| {
+            class == attr_spec.entity_class
+                && designator.item == Designator::Identifier(name.clone())
+        });
+
+        // A package instantiation is also of entity class package
+        let is_package_instance = attr_spec.entity_class == EntityClass::Package
+            && region
+                .lookup(&designator.item, false)
+                .is_some_and(|visible_decl| is_of_entity_class(visible_decl, EntityClass::Package));
+
+        if !is_enclosing_unit && !is_package_instance {
+            messages.push(Message::error(
+                designator,
+                format!(
+                    "'{}' does not denote the enclosing {}",
+                    designator.item, attr_spec.entity_class
+                ),
+                MessageCode::EntityClassMismatch,
+            ));
+        }
+    }
+
+    /// The template of a group declaration shall denote a group template
+    fn analyze_group_declaration(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        group_decl: &GroupDeclaration,
+        messages: &mut dyn MessageHandler,
+    ) {
+        match self.lookup_type_mark(region, &group_decl.template_name) {
+            Ok(visible_decl) => match visible_decl.decl {
+                AnyDeclaration::Declaration(Declaration::Group(Group::Template(template))) => {
+                    self.analyze_group_constituents(region, template, group_decl, messages);
+                }
                 _ => {
                     messages.push(Message::error(
-                        &use_pos,
-                        "Use clause must be a selected name",
+                        &group_decl.template_name,
+                        format!(
+                            "'{}' does not denote a group template",
+                            group_decl.template_name.item
+                        ),
+                        MessageCode::EntityClassMismatch,
                     ));
-                    continue;
                 }
+            },
+            Err(msg) => {
+                messages.push(msg);
             }
+        }
+    }
 
-            match self.lookup_selected_name(&region, &name) {
-                Ok(LookupResult::Single(visible_decl)) => {
-                    region.make_potentially_visible(visible_decl);
-                }
-                Ok(LookupResult::AllWithin(prefix, visible_decl)) => {
-                    match visible_decl.decl {
-                        AnyDeclaration::Library(ref library) => {
-                            region
-                                .make_all_potentially_visible(&self.library_regions[&library.name]);
-                        }
-                        AnyDeclaration::Package(ref library, ref package) => {
-                            if package.is_generic() {
-                                messages.push(uninstantiated_package_prefix_error(
-                                    &prefix.pos,
-                                    library,
-                                    package,
-                                ));
-                            } else if let Ok(data) =
-                                self.get_package_result(Some(prefix.pos.clone()), library, package)
-                            {
-                                region.make_all_potentially_visible(&data.region);
-                            } else {
-                                // Circular dependency, return
-                                return;
-                            }
-                        }
-                        AnyDeclaration::PackageInstance(ref library, ref package) => {
-                            if let Ok(data) = self.analyze_package_instance_unit(
-                                Some(prefix.pos.clone()),
-                                library,
-                                package,
-                            ) {
-                                region.make_all_potentially_visible(&data.region);
-                            } else {
-                                // Circular dependency, return
-                                return;
-                            }
-                        }
-                        AnyDeclaration::LocalPackageInstance(_, ref data) => {
-                            region.make_all_potentially_visible(&data.region);
-                        }
-                        // @TODO handle others
-                        _ => {}
-                    }
+    /// Resolve the constituents of a group and check them against the entity classes of its template
+    fn analyze_group_constituents(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        template: &GroupTemplateDeclaration,
+        group_decl: &GroupDeclaration,
+        messages: &mut dyn MessageHandler,
+    ) {
+        for (idx, constituent) in group_decl.constituents.iter().enumerate() {
+            // The last entry may be repeated when followed by <>
+            let entity_class = match template
+                .entries
+                .get(idx)
+                .or_else(|| template.entries.last().filter(|entry| entry.is_box))
+            {
+                Some(entry) => entry.entity_class,
+                None => {
+                    return;
                 }
-                Ok(LookupResult::Unfinished) => {}
-                Ok(LookupResult::NotSelected) => {
+            };
+
+            // Design units and labels are not declared within the region of the group
+            if is_unit_or_label_class(entity_class) {
+                continue;
+            }
+
+            if let Name::Designator(ref designator) = constituent.item {
+                let overloads = region.lookup_overloads(designator, true);
+                if overloads.is_empty() {
                     messages.push(Message::error(
-                        &use_pos,
-                        "Use clause must be a selected name",
+                        constituent,
+                        format!("No declaration of '{}'", designator),
+                        MessageCode::UnresolvedName,
+                    ));
+                } else if !overloads
+                    .iter()
+                    .any(|visible_decl| is_of_entity_class(visible_decl, entity_class))
+                {
+                    messages.push(Message::error(
+                        constituent,
+                        format!("'{}' is not of entity class {}", designator, entity_class),
+                        MessageCode::EntityClassMismatch,
                     ));
                 }
-                Err(msg) => {
-                    messages.push(msg);
-                }
+            } else {
+                self.analyze_name_names(region, &constituent.item, &constituent.pos, messages);
             }
         }
     }
 
-    fn analyze_context_clause(
+    /// Resolve the names within an expression
+    /// @TODO Only used for PSL, names in VHDL expressions require typechecking
+    fn analyze_expression_names(
         &self,
-        region: &mut DeclarativeRegion<'_, 'a>,
-        context_clause: &[WithPos<ContextItem>],
+        region: &DeclarativeRegion<'_, 'a>,
+        expr: &WithPos<Expression>,
         messages: &mut dyn MessageHandler,
     ) {
-        for context_item in context_clause.iter() {
-            match context_item.item {
-                ContextItem::Library(LibraryClause { ref name_list }) => {
-                    for library_name in name_list.iter() {
-                        if self.work_sym == library_name.item {
-                            messages.push(Message::hint(
-                                &library_name,
-                                "Library clause not necessary for current working library",
-                            ))
-                        } else if let Some(library) = self.root.get_library(&library_name.item) {
-                            region.make_library_visible(&library.name, library);
-                        } else {
-                            messages.push(Message::error(
-                                &library_name,
-                                format!("No such library '{}'", library_name.item),
-                            ));
+        match expr.item {
+            Expression::Binary(_, ref left, ref right) => {
+                self.analyze_expression_names(region, left, messages);
+                self.analyze_expression_names(region, right, messages);
+            }
+            Expression::Unary(_, ref expr) => {
+                self.analyze_expression_names(region, expr, messages);
+            }
+            Expression::Aggregate(ref assocs) => {
+                // Choices may be record element names
+                for assoc in assocs.iter() {
+                    match assoc {
+                        ElementAssociation::Positional(ref expr)
+                        | ElementAssociation::Named(_, ref expr) => {
+                            self.analyze_expression_names(region, expr, messages);
                         }
                     }
                 }
-                ContextItem::Use(ref use_clause) => {
-                    self.analyze_use_clause(region, use_clause, &context_item.pos, messages);
+            }
+            Expression::Qualified(ref qexpr) => {
+                self.analyze_expression_names(region, &qexpr.expr, messages);
+            }
+            Expression::Name(ref name) => {
+                self.analyze_name_names(region, name, &expr.pos, messages);
+            }
+            Expression::Literal(..) | Expression::New(..) => {}
+        }
+    }
+
+    fn analyze_name_names(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        name: &Name,
+        pos: &SrcPos,
+        messages: &mut dyn MessageHandler,
+    ) {
+        match name {
+            Name::Designator(..) | Name::Selected(..) => {
+                let name = WithPos::from(name.clone(), pos.clone());
+                if let Err(msg) = self.lookup_selected_name(region, &name) {
+                    messages.push(msg);
+                }
+            }
+            Name::Indexed(ref prefix, ref indexes) => {
+                self.analyze_name_names(region, &prefix.item, &prefix.pos, messages);
+                for index in indexes.iter() {
+                    self.analyze_expression_names(region, index, messages);
+                }
+            }
+            Name::Slice(ref prefix, ..) => {
+                self.analyze_name_names(region, &prefix.item, &prefix.pos, messages);
+            }
+            Name::Attribute(ref attr) => {
+                self.analyze_name_names(region, &attr.name.item, &attr.name.pos, messages);
+            }
+            Name::FunctionCall(ref call) => {
+                if !is_psl_builtin_function(&call.name.item) {
+                    self.analyze_name_names(region, &call.name.item, &call.name.pos, messages);
+                }
+                for param in call.parameters.iter() {
+                    if let ActualPart::Expression(ref expr) = param.actual.item {
+                        let expr = WithPos::from(expr.clone(), param.actual.pos.clone());
+                        self.analyze_expression_names(region, &expr, messages);
+                    }
+                }
+            }
+            Name::SelectedAll(..) | Name::External(..) => {}
+        }
+    }
+
+    fn analyze_psl_parameters(
+        &self,
+        region: &mut DeclarativeRegion<'_, 'a>,
+        parameters: &'a [PslParameter],
+        messages: &mut dyn MessageHandler,
+    ) {
+        for parameter in parameters.iter() {
+            for ident in parameter.idents.iter() {
+                region.add(
+                    VisibleDeclaration::new(ident, AnyDeclaration::PslParameter(ident)),
+                    messages,
+                );
+            }
+        }
+    }
+
+    fn analyze_psl_count(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        count: &PslCount,
+        messages: &mut dyn MessageHandler,
+    ) {
+        match count {
+            PslCount::Number(ref count) => {
+                self.analyze_expression_names(region, count, messages);
+            }
+            PslCount::Range(ref low, ref high) => {
+                self.analyze_expression_names(region, low, messages);
+                if let Some(ref high) = high {
+                    self.analyze_expression_names(region, high, messages);
+                }
+            }
+        }
+    }
+
+    fn analyze_psl_sere(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        sere: &WithPos<PslSere>,
+        messages: &mut dyn MessageHandler,
+    ) {
+        match sere.item {
+            PslSere::Boolean(ref expr) => {
+                self.analyze_expression_names(region, expr, messages);
+            }
+            PslSere::Binary(_, ref left, ref right) => {
+                self.analyze_psl_sere(region, left, messages);
+                self.analyze_psl_sere(region, right, messages);
+            }
+            PslSere::Repetition(ref sere, ref repetition) => {
+                if let Some(ref sere) = sere {
+                    self.analyze_psl_sere(region, sere, messages);
+                }
+                if let Some(ref count) = repetition.count {
+                    self.analyze_psl_count(region, count, messages);
+                }
+            }
+        }
+    }
+
+    /// Resolve the names of signals and other objects used in a PSL property
+    fn analyze_psl_property(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        property: &WithPos<PslProperty>,
+        messages: &mut dyn MessageHandler,
+    ) {
+        match property.item {
+            PslProperty::Sequence { ref sere, .. } => {
+                self.analyze_psl_sere(region, sere, messages);
+            }
+            PslProperty::Unary(ref op, ref property) => {
+                match op {
+                    PslUnaryOperator::Next {
+                        count: Some(ref count),
+                        ..
+                    } => {
+                        self.analyze_expression_names(region, count, messages);
+                    }
+                    PslUnaryOperator::NextRange { ref count, .. } => {
+                        self.analyze_psl_count(region, count, messages);
+                    }
+                    PslUnaryOperator::NextEvent {
+                        ref condition,
+                        ref count,
+                        ..
+                    } => {
+                        self.analyze_expression_names(region, condition, messages);
+                        if let Some(ref count) = count {
+                            self.analyze_psl_count(region, count, messages);
+                        }
+                    }
+                    _ => {}
+                }
+                self.analyze_psl_property(region, property, messages);
+            }
+            PslProperty::Binary(_, ref left, ref right) => {
+                self.analyze_psl_property(region, left, messages);
+                self.analyze_psl_property(region, right, messages);
+            }
+            PslProperty::SuffixImplication {
+                ref sequence,
+                ref property,
+                ..
+            } => {
+                self.analyze_psl_sere(region, sequence, messages);
+                self.analyze_psl_property(region, property, messages);
+            }
+            PslProperty::Clocked(ref property, ref clock) => {
+                self.analyze_psl_property(region, property, messages);
+                self.analyze_expression_names(region, clock, messages);
+            }
+        }
+    }
+
+    fn analyze_declarative_part(
+        &self,
+        region: &mut DeclarativeRegion<'_, 'a>,
+        declarations: &'a [Declaration],
+        messages: &mut dyn MessageHandler,
+    ) {
+        for decl in declarations.iter() {
+            self.analyze_declaration(region, decl, messages);
+        }
+    }
+
+    fn analyze_use_clause(
+        &self,
+        region: &mut DeclarativeRegion<'_, 'a>,
+        use_clause: &UseClause,
+        use_pos: &SrcPos,
+        messages: &mut dyn MessageHandler,
+    ) {
+        for name in use_clause.name_list.iter() {
+            match name.item {
+                Name::Selected(..) => {}
+                Name::SelectedAll(..) => {}
+                _ => {
+                    messages.push(Message::error(
+                        &use_pos,
+                        "Use clause must be a selected name",
+                        MessageCode::InvalidSelectedName,
+                    ));
+                    continue;
+                }
+            }
+
+            match self.lookup_selected_name(&region, &name) {
+                Ok(LookupResult::Single(visible_decl)) => {
+                    region.make_potentially_visible(visible_decl);
+                }
+                Ok(LookupResult::AllWithin(prefix, visible_decl)) => {
+                    match visible_decl.decl {
+                        AnyDeclaration::Library(ref library) => {
+                            region
+                                .make_all_potentially_visible(&self.library_regions[&library.name]);
+                        }
+                        AnyDeclaration::Package(ref library, ref package) => {
+                            if package.is_generic() {
+                                messages.push(uninstantiated_package_prefix_error(
+                                    &prefix.pos,
+                                    library,
+                                    package,
+                                ));
+                            } else if let Ok(data) =
+                                self.get_package_result(Some(prefix.pos.clone()), library, package)
+                            {
+                                region.make_all_potentially_visible(&data.region);
+                            } else {
+                                // Circular dependency, return
+                                return;
+                            }
+                        }
+                        AnyDeclaration::PackageInstance(ref library, ref package) => {
+                            if let Ok(data) = self.analyze_package_instance_unit(
+                                Some(prefix.pos.clone()),
+                                library,
+                                package,
+                            ) {
+                                region.make_all_potentially_visible(&data.region);
+                            } else {
+                                // Circular dependency, return
+                                return;
+                            }
+                        }
+                        AnyDeclaration::LocalPackageInstance(_, ref data) => {
+                            region.make_all_potentially_visible(&data.region);
+                        }
+                        // @TODO handle others
+                        _ => {}
+                    }
+                }
+                Ok(LookupResult::Unfinished) => {}
+                Ok(LookupResult::NotSelected) => {
+                    messages.push(Message::error(
+                        &use_pos,
+                        "Use clause must be a selected name",
+                        MessageCode::InvalidSelectedName,
+                    ));
+                }
+                Err(msg) => {
+                    messages.push(msg);
+                }
+            }
+        }
+    }
+
+    fn analyze_context_clause(
+        &self,
+        region: &mut DeclarativeRegion<'_, 'a>,
+        context_clause: &[WithPos<ContextItem>],
+        messages: &mut dyn MessageHandler,
+    ) {
+        for context_item in context_clause.iter() {
+            match context_item.item {
+                ContextItem::Library(LibraryClause { ref name_list }) => {
+                    for library_name in name_list.iter() {
+                        if self.work_sym == library_name.item {
+                            messages.push(Message::hint(
+                                &library_name,
+                                "Library clause not necessary for current working library",
+                                MessageCode::UnnecessaryLibraryClause,
+                            ))
+                        } else if let Some(library) = self.root.get_library(&library_name.item) {
+                            region.make_library_visible(&library.name, library);
+                        } else {
+                            messages.push(Message::error(
+                                &library_name,
+                                format!("No such library '{}'", library_name.item),
+                                MessageCode::MissingLibrary,
+                            ));
+                        }
+                    }
+                }
+                ContextItem::Use(ref use_clause) => {
+                    self.analyze_use_clause(region, use_clause, &context_item.pos, messages);
                 }
                 ContextItem::Context(ContextReference { ref name_list }) => {
                     for name in name_list {
@@ -834,6 +1463,7 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                                 messages.push(Message::error(
                                     &context_item,
                                     "Context reference must be a selected name",
+                                    MessageCode::InvalidSelectedName,
                                 ));
                                 continue;
                             }
@@ -865,6 +1495,7 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                                                     "'{}' does not denote a context declaration",
                                                     &suffix.item
                                                 ),
+                                                MessageCode::InvalidSelectedName,
                                             ));
                                         }
                                     }
@@ -878,6 +1509,7 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                                 messages.push(Message::error(
                                     &context_item,
                                     "Context reference must be a selected name",
+                                    MessageCode::InvalidSelectedName,
                                 ));
                             }
                             Err(msg) => {
@@ -909,10 +1541,103 @@ impl<'r, 'a: 'r> Analyzer<'a> {
 
         if let Some(ref decl) = body.decl {
             self.analyze_declarative_part(&mut region, &decl, messages);
+            self.analyze_unit_and_label_attribute_specifications(
+                &region,
+                None,
+                decl,
+                &body.statements,
+                messages,
+            );
         }
         self.analyze_concurrent_part(&region, &body.statements, messages);
     }
 
+    /// Resolve the prefix of an assignment target
+    /// Index expressions are not resolved since they may refer to loop parameters
+    fn analyze_target_names(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        target: &WithPos<Target>,
+        messages: &mut dyn MessageHandler,
+    ) {
+        if let Target::Name(ref name) = target.item {
+            self.analyze_target_prefix(region, name, &target.pos, messages);
+        }
+    }
+
+    fn analyze_target_prefix(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        name: &Name,
+        pos: &SrcPos,
+        messages: &mut dyn MessageHandler,
+    ) {
+        match name {
+            Name::Designator(..) | Name::Selected(..) => {
+                let name = WithPos::from(name.clone(), pos.clone());
+                if let Err(msg) = self.lookup_selected_name(region, &name) {
+                    messages.push(msg);
+                }
+            }
+            Name::Indexed(ref prefix, ..) | Name::Slice(ref prefix, ..) => {
+                self.analyze_target_prefix(region, &prefix.item, &prefix.pos, messages);
+            }
+            // An indexed name is parsed as a function call
+            Name::FunctionCall(ref call) => {
+                self.analyze_target_prefix(region, &call.name.item, &call.name.pos, messages);
+            }
+            _ => {}
+        }
+    }
+
+    /// @TODO Only the targets of force and release assignments are resolved for now
+    fn analyze_sequential_statement(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        statement: &'a LabeledSequentialStatement,
+        messages: &mut dyn MessageHandler,
+    ) {
+        match statement.statement {
+            SequentialStatement::SignalForceAssignment(ref assign) => {
+                self.analyze_target_names(region, &assign.target, messages);
+            }
+            SequentialStatement::SignalReleaseAssignment(ref assign) => {
+                self.analyze_target_names(region, &assign.target, messages);
+            }
+            SequentialStatement::If(ref ifstmt) => {
+                for conditional in ifstmt.conditionals.iter() {
+                    self.analyze_sequential_part(region, &conditional.item, messages);
+                }
+                if let Some(ref else_item) = ifstmt.else_item {
+                    self.analyze_sequential_part(region, else_item, messages);
+                }
+            }
+            SequentialStatement::Case(ref case_stmt) => {
+                for alternative in case_stmt.alternatives.iter() {
+                    self.analyze_sequential_part(region, &alternative.item, messages);
+                }
+            }
+            SequentialStatement::Loop(ref loop_stmt) => {
+                self.analyze_sequential_part(region, &loop_stmt.statements, messages);
+            }
+            SequentialStatement::Error(ref statements) => {
+                self.analyze_sequential_part(region, statements, messages);
+            }
+            _ => {}
+        }
+    }
+
+    fn analyze_sequential_part(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        statements: &'a [LabeledSequentialStatement],
+        messages: &mut dyn MessageHandler,
+    ) {
+        for statement in statements.iter() {
+            self.analyze_sequential_statement(region, statement, messages);
+        }
+    }
+
     fn analyze_concurrent_statement(
         &self,
         parent: &DeclarativeRegion<'_, 'a>,
@@ -923,13 +1648,26 @@ impl<'r, 'a: 'r> Analyzer<'a> {
             ConcurrentStatement::Block(ref block) => {
                 let mut region = DeclarativeRegion::new(Some(parent));
                 self.analyze_declarative_part(&mut region, &block.decl, messages);
+                self.analyze_unit_and_label_attribute_specifications(
+                    &region,
+                    None,
+                    &block.decl,
+                    &block.statements,
+                    messages,
+                );
                 self.analyze_concurrent_part(&region, &block.statements, messages);
             }
             ConcurrentStatement::Process(ref process) => {
                 let mut region = DeclarativeRegion::new(Some(parent));
                 self.analyze_declarative_part(&mut region, &process.decl, messages);
+                self.analyze_sequential_part(&region, &process.statements, messages);
             }
             ConcurrentStatement::ForGenerate(ref gen) => {
+                check_null_discrete_range(
+                    &self.static_evaluator(parent),
+                    &gen.discrete_range,
+                    messages,
+                );
                 self.analyze_generate_body(parent, &gen.body, messages);
             }
             ConcurrentStatement::IfGenerate(ref gen) => {
@@ -945,6 +1683,9 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                     self.analyze_generate_body(parent, &alternative.item, messages);
                 }
             }
+            ConcurrentStatement::PslDirective(ref directive) => {
+                self.analyze_psl_property(parent, &directive.property, messages);
+            }
             _ => {}
         }
     }
@@ -967,6 +1708,13 @@ impl<'r, 'a: 'r> Analyzer<'a> {
         messages: &mut dyn MessageHandler,
     ) {
         self.analyze_declarative_part(entity_region, &architecture.decl, messages);
+        self.analyze_unit_and_label_attribute_specifications(
+            entity_region,
+            Some((EntityClass::Architecture, &architecture.ident.item)),
+            &architecture.decl,
+            &architecture.statements,
+            messages,
+        );
         self.analyze_concurrent_part(entity_region, &architecture.statements, messages);
     }
 
@@ -978,11 +1726,35 @@ impl<'r, 'a: 'r> Analyzer<'a> {
     ) {
         if let Some(ref list) = entity.generic_clause {
             self.analyze_interface_list(region, list, messages);
+            let entity_name = entity.ident.item.name_utf8();
+            let supplied = list
+                .iter()
+                .filter_map(|generic| match generic {
+                    InterfaceDeclaration::Object(ref object_decl)
+                        if object_decl.class == ObjectClass::Constant =>
+                    {
+                        let value = self
+                            .generic_values
+                            .value(&entity_name, &object_decl.ident.item.name_utf8())?;
+                        Some((object_decl.ident.item.clone(), value.clone()))
+                    }
+                    _ => None,
+                })
+                .collect();
+            *self.elaboration_generics.borrow_mut() =
+                self.static_evaluator(region).generic_values(list, supplied);
         }
         if let Some(ref list) = entity.port_clause {
             self.analyze_interface_list(region, list, messages);
         }
         self.analyze_declarative_part(region, &entity.decl, messages);
+        self.analyze_unit_and_label_attribute_specifications(
+            region,
+            Some((EntityClass::Entity, &entity.ident.item)),
+            &entity.decl,
+            &entity.statements,
+            messages,
+        );
         self.analyze_concurrent_part(region, &entity.statements, messages);
     }
 
@@ -996,7 +1768,7 @@ impl<'r, 'a: 'r> Analyzer<'a> {
     ) {
         region.make_library_visible(&self.work_sym, work);
 
-        // @TODO maybe add warning if standard library is missing
+        // The bundled std library is always present unless replaced by a library of the same name
         if let Some(library) = self.root.get_library(&self.std_sym) {
             region.make_library_visible(&self.std_sym, library);
 
@@ -1012,8 +1784,6 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                     .expect("Found circular dependency when using STD.STANDARD package")
                     .region;
                 region.make_all_potentially_visible(standard_pkg_region);
-            } else {
-                panic!("Could not find package standard");
             }
         }
     }
@@ -1028,6 +1798,13 @@ impl<'r, 'a: 'r> Analyzer<'a> {
             self.analyze_interface_list(&mut region, list, messages);
         }
         self.analyze_declarative_part(&mut region, &package.decl, messages);
+        self.analyze_unit_and_label_attribute_specifications(
+            &region,
+            Some((EntityClass::Package, &package.ident.item)),
+            &package.decl,
+            &[],
+            messages,
+        );
         region
     }
 
@@ -1086,10 +1863,17 @@ impl<'r, 'a: 'r> Analyzer<'a> {
         if let Some(ref body) = package.body {
             let mut root_region = primary_region
                 .clone_parent()
-                .expect("Expected parent region");;
+                .expect("Expected parent region");
             self.analyze_context_clause(&mut root_region, &body.context_clause, messages);
             let mut region = primary_region.clone().into_extended(&root_region);
             self.analyze_declarative_part(&mut region, &body.unit.decl, messages);
+            self.analyze_unit_and_label_attribute_specifications(
+                &region,
+                None,
+                &body.unit.decl,
+                &[],
+                messages,
+            );
             region.close_both(messages);
         }
     }
@@ -1145,6 +1929,7 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                                     "'Could not instantiate package '{}.{}' with circular dependency'",
                                     &library.name, package.package.name()
                                 ),
+                                MessageCode::CircularDependency,
                             ));
                         }
                     }
@@ -1155,6 +1940,7 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                         "'{}' is not an uninstantiated generic package",
                         &visible_decl.designator
                     ),
+                    MessageCode::InvalidSelectedName,
                 ))
             }
             _ => {
@@ -1163,6 +1949,7 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                 Err(Message::error(
                     &package_name.pos,
                     "Invalid selected name for generic package",
+                    MessageCode::InvalidSelectedName,
                 ))
             }
         }
@@ -1210,6 +1997,8 @@ impl<'r, 'a: 'r> Analyzer<'a> {
     }
 
     pub fn analyze_library(&self, library: &'a Library, messages: &mut dyn MessageHandler) {
+        self.check_library_standard(library, messages);
+
         for package in library.packages() {
             self.analyze_package(library, package, messages);
         }
@@ -1249,16 +2038,543 @@ impl<'r, 'a: 'r> Analyzer<'a> {
                 self.analyze_architecture_body(&mut region, &architecture.unit, messages);
                 region.close_both(messages);
             }
+            self.elaboration_generics.borrow_mut().clear();
+        }
+
+        for entity in library.entities() {
+            for configuration in entity.configurations() {
+                self.analyze_configuration(library, entity, configuration, messages);
+            }
+        }
+
+        for vunit in library.verification_units() {
+            self.analyze_verification_unit(library, vunit, messages);
+        }
+    }
+
+    /// The entity and optional architecture which a verification unit is bound to
+    fn lookup_verification_unit_binding(
+        &self,
+        library: &'a Library,
+        binding: &VerificationUnitBinding,
+        messages: &mut dyn MessageHandler,
+    ) -> Option<(
+        &'a EntityDesignUnit,
+        Option<&'a DesignUnit<ArchitectureBody>>,
+    )> {
+        let (library_name, entity_name) = match to_entity_name(&binding.entity_name) {
+            Ok(names) => names,
+            Err(msg) => {
+                messages.push(msg);
+                return None;
+            }
+        };
+
+        let library = match library_name {
+            Some(ref library_name) if library_name.item != self.work_sym => {
+                if let Some(library) = self.root.get_library(&library_name.item) {
+                    library
+                } else {
+                    messages.push(Message::error(
+                        library_name,
+                        format!("No such library '{}'", library_name.item),
+                        MessageCode::MissingLibrary,
+                    ));
+                    return None;
+                }
+            }
+            _ => library,
+        };
+
+        let entity = if let Some(entity) = library.entity(&entity_name.item) {
+            entity
+        } else {
+            messages.push(Message::error(
+                &entity_name,
+                format!(
+                    "No entity '{}' within library '{}'",
+                    entity_name.item, library.name
+                ),
+                MessageCode::MissingPrimaryUnit,
+            ));
+            return None;
+        };
+
+        if let Some(ref architecture_name) = binding.architecture_name {
+            if let Some(architecture) = entity.architectures.get(&architecture_name.item) {
+                Some((entity, Some(architecture)))
+            } else {
+                messages.push(Message::error(
+                    architecture_name,
+                    format!(
+                        "No architecture '{}' of entity '{}.{}'",
+                        architecture_name.item,
+                        library.name,
+                        entity.entity.name()
+                    ),
+                    MessageCode::UnresolvedName,
+                ));
+                None
+            }
+        } else {
+            Some((entity, None))
+        }
+    }
+
+    /// The declarations of the bound entity and architecture are visible within a verification unit
+    fn analyze_verification_unit(
+        &self,
+        library: &'a Library,
+        vunit: &'a DesignUnit<VerificationUnit>,
+        messages: &mut dyn MessageHandler,
+    ) {
+        let bound =
+            vunit.unit.binding.as_ref().and_then(|binding| {
+                self.lookup_verification_unit_binding(library, binding, messages)
+            });
+
+        // Messages of the bound design units are reported when they are analyzed
+        let mut ignored = Vec::new();
+
+        let mut root_region = DeclarativeRegion::new(None);
+        self.add_implicit_context_clause(&mut root_region, library);
+        if let Some((entity, architecture)) = bound {
+            self.analyze_context_clause(
+                &mut root_region,
+                &entity.entity.context_clause,
+                &mut ignored,
+            );
+            if let Some(architecture) = architecture {
+                self.analyze_context_clause(
+                    &mut root_region,
+                    &architecture.context_clause,
+                    &mut ignored,
+                );
+            }
+        }
+        self.analyze_context_clause(&mut root_region, &vunit.context_clause, messages);
+
+        let mut design_region = DeclarativeRegion::new(Some(&root_region));
+        if let Some((entity, architecture)) = bound {
+            self.analyze_entity_declaration(&mut design_region, &entity.entity.unit, &mut ignored);
+            if let Some(architecture) = architecture {
+                self.analyze_declarative_part(
+                    &mut design_region,
+                    &architecture.unit.decl,
+                    &mut ignored,
+                );
+            }
+        }
+
+        let mut region = DeclarativeRegion::new(Some(&design_region));
+        self.analyze_declarative_part(&mut region, &vunit.unit.decl, messages);
+        self.analyze_concurrent_part(&region, &vunit.unit.statements, messages);
+        region.close_both(messages);
+    }
+
+    /// The entity which a configuration declaration is a configuration of
+    fn configuration_entity(
+        &self,
+        configuration: &DesignUnit<ConfigurationDeclaration>,
+    ) -> Option<&'a EntityDesignUnit> {
+        for library in self.root.iter_libraries() {
+            for entity in library.entities() {
+                if entity
+                    .configurations()
+                    .any(|other| std::ptr::eq(other, configuration))
+                {
+                    return Some(entity);
+                }
+            }
+        }
+        None
+    }
+
+    /// The entity denoted by the entity aspect of a binding indication
+    fn lookup_entity_aspect(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        entity_aspect: &EntityAspect,
+        messages: &mut dyn MessageHandler,
+    ) -> Option<&'a EntityDesignUnit> {
+        let (unit_name, is_configuration) = match entity_aspect {
+            EntityAspect::Entity(ref entity_name, ..) => (entity_name, false),
+            EntityAspect::Configuration(ref config_name) => (config_name, true),
+            EntityAspect::Open => {
+                return None;
+            }
+        };
+
+        let name = unit_name.clone().into();
+        let visible_decl = match self.lookup_selected_name(region, &name) {
+            Ok(LookupResult::Single(visible_decl)) => visible_decl,
+            Ok(_) => {
+                return None;
+            }
+            Err(msg) => {
+                messages.push(msg);
+                return None;
+            }
+        };
+
+        let entity = match visible_decl.decl {
+            AnyDeclaration::Entity(entity) if !is_configuration => Some(entity),
+            AnyDeclaration::Configuration(configuration) if is_configuration => {
+                return self.configuration_entity(configuration);
+            }
+            _ => None,
+        };
+
+        if entity.is_none() {
+            messages.push(Message::error(
+                unit_name,
+                format!(
+                    "'{}' does not denote {}",
+                    unit_name.item,
+                    if is_configuration {
+                        "a configuration"
+                    } else {
+                        "an entity"
+                    }
+                ),
+                MessageCode::BindingMismatch,
+            ));
+        }
+        entity
+    }
+
+    /// Check that a binding indication denotes an existing entity and architecture
+    /// and that the formals of the generic and port maps are within the entity interface
+    fn analyze_binding_indication(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        bind_ind: &BindingIndication,
+        messages: &mut dyn MessageHandler,
+    ) {
+        let entity = match bind_ind.entity_aspect {
+            Some(ref entity_aspect) => {
+                match self.lookup_entity_aspect(region, entity_aspect, messages) {
+                    Some(entity) => entity,
+                    None => {
+                        return;
+                    }
+                }
+            }
+            // @TODO default binding of the component
+            None => {
+                return;
+            }
+        };
+
+        if let Some(EntityAspect::Entity(_, Some(ref architecture_name))) = bind_ind.entity_aspect {
+            if !entity.architectures.contains_key(&architecture_name.item) {
+                messages.push(Message::error(
+                    architecture_name,
+                    format!(
+                        "No architecture '{}' of entity '{}'",
+                        architecture_name.item,
+                        entity.entity.name()
+                    ),
+                    MessageCode::UnresolvedName,
+                ));
+            }
+        }
+
+        let entity_decl = &entity.entity.unit;
+        if let Some(ref generic_map) = bind_ind.generic_map {
+            check_binding_map(
+                entity_decl,
+                entity_decl.generic_clause.as_ref(),
+                generic_map,
+                "generic",
+                messages,
+            );
+        }
+        if let Some(ref port_map) = bind_ind.port_map {
+            check_binding_map(
+                entity_decl,
+                entity_decl.port_clause.as_ref(),
+                port_map,
+                "port",
+                messages,
+            );
+        }
+    }
+
+    /// Check that the instance labels of a component specification denote component instances
+    fn analyze_component_specification(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        spec: &ComponentSpecification,
+        statements: &[LabeledConcurrentStatement],
+        messages: &mut dyn MessageHandler,
+    ) {
+        if let Err(msg) = self.lookup_selected_name(region, &spec.component_name.clone().into()) {
+            messages.push(msg);
+        }
+
+        if let InstantiationList::Labels(ref labels) = spec.instantiation_list {
+            for label in labels.iter() {
+                let is_instance = statements.iter().any(|statement| {
+                    if let ConcurrentStatement::Instance(..) = statement.statement {
+                        statement.label.as_ref().map(|ident| &ident.item) == Some(&label.item)
+                    } else {
+                        false
+                    }
+                });
+
+                if !is_instance {
+                    messages.push(Message::error(
+                        label,
+                        format!("No component instance '{}'", label.item),
+                        MessageCode::UnresolvedName,
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Check the items of a block configuration against the statements of the block
+    /// The region contains the declarations of the block
+    fn analyze_block_configuration(
+        &self,
+        parent: &DeclarativeRegion<'_, 'a>,
+        block_config: &'a BlockConfiguration,
+        statements: &'a [LabeledConcurrentStatement],
+        messages: &mut dyn MessageHandler,
+    ) {
+        let mut region = DeclarativeRegion::new(Some(parent));
+        for use_clause in block_config.use_clauses.iter() {
+            self.analyze_use_clause(&mut region, &use_clause.item, &use_clause.pos, messages);
+        }
+
+        for item in block_config.items.iter() {
+            match item {
+                ConfigurationItem::Block(ref block_config) => {
+                    self.analyze_nested_block_configuration(
+                        &region,
+                        block_config,
+                        statements,
+                        messages,
+                    );
+                }
+                ConfigurationItem::Component(ref component_config) => {
+                    self.analyze_component_specification(
+                        &region,
+                        &component_config.spec,
+                        statements,
+                        messages,
+                    );
+                    if let Some(ref bind_ind) = component_config.bind_ind {
+                        self.analyze_binding_indication(&region, bind_ind, messages);
+                    }
+                    // @TODO the block configuration of the bound architecture
+                }
+            }
+        }
+    }
+
+    /// A block configuration of a block or generate statement
+    fn analyze_nested_block_configuration(
+        &self,
+        parent: &DeclarativeRegion<'_, 'a>,
+        block_config: &'a BlockConfiguration,
+        statements: &'a [LabeledConcurrentStatement],
+        messages: &mut dyn MessageHandler,
+    ) {
+        let block_spec = &block_config.block_spec;
+        let label = match base_designator(&block_spec.item) {
+            Some(Designator::Identifier(ref label)) => label,
+            _ => {
+                return;
+            }
+        };
+
+        let statement = statements
+            .iter()
+            .find(|statement| statement.label.as_ref().map(|ident| &ident.item) == Some(label));
+
+        let (decl, statements) = match statement.map(|statement| &statement.statement) {
+            Some(ConcurrentStatement::Block(ref block)) => {
+                (Some(&block.decl), block.statements.as_slice())
+            }
+            Some(ConcurrentStatement::ForGenerate(ref gen)) => {
+                (gen.body.decl.as_ref(), gen.body.statements.as_slice())
+            }
+            Some(ConcurrentStatement::IfGenerate(ref gen)) => {
+                let bodies: Vec<&GenerateBody> = gen
+                    .conditionals
+                    .iter()
+                    .map(|conditional| &conditional.item)
+                    .chain(gen.else_item.iter())
+                    .collect();
+                let body = generate_alternative(block_spec, &bodies);
+                (body.decl.as_ref(), body.statements.as_slice())
+            }
+            Some(ConcurrentStatement::CaseGenerate(ref gen)) => {
+                let bodies: Vec<&GenerateBody> = gen
+                    .alternatives
+                    .iter()
+                    .map(|alternative| &alternative.item)
+                    .collect();
+                let body = generate_alternative(block_spec, &bodies);
+                (body.decl.as_ref(), body.statements.as_slice())
+            }
+            _ => {
+                messages.push(Message::error(
+                    block_spec,
+                    format!("No block or generate statement '{}'", label),
+                    MessageCode::UnresolvedName,
+                ));
+                return;
+            }
+        };
+
+        // Messages of the block declarations are reported when the architecture is analyzed
+        let mut ignored = Vec::new();
+        let mut region = DeclarativeRegion::new(Some(parent));
+        if let Some(decl) = decl {
+            self.analyze_declarative_part(&mut region, decl, &mut ignored);
+        }
+        self.analyze_block_configuration(&region, block_config, statements, messages);
+    }
+
+    /// The block configuration of a configuration declaration shall denote an architecture of the entity
+    fn analyze_configuration(
+        &self,
+        library: &'a Library,
+        entity: &'a EntityDesignUnit,
+        configuration: &'a DesignUnit<ConfigurationDeclaration>,
+        messages: &mut dyn MessageHandler,
+    ) {
+        let block_config = &configuration.unit.block_config;
+        let architecture = match base_designator(&block_config.block_spec.item) {
+            Some(Designator::Identifier(ref name)) => {
+                let architecture = entity.architectures.get(name);
+                if architecture.is_none() {
+                    messages.push(Message::error(
+                        &block_config.block_spec,
+                        format!(
+                            "No architecture '{}' of entity '{}'",
+                            name,
+                            entity.entity.name()
+                        ),
+                        MessageCode::UnresolvedName,
+                    ));
+                }
+                architecture
+            }
+            _ => None,
+        };
+
+        // Messages of the entity and architecture are reported when they are analyzed
+        let mut ignored = Vec::new();
+
+        let mut root_region = DeclarativeRegion::new(None);
+        self.add_implicit_context_clause(&mut root_region, library);
+        self.analyze_context_clause(
+            &mut root_region,
+            &entity.entity.context_clause,
+            &mut ignored,
+        );
+        if let Some(architecture) = architecture {
+            self.analyze_context_clause(
+                &mut root_region,
+                &architecture.context_clause,
+                &mut ignored,
+            );
+        }
+        self.analyze_context_clause(&mut root_region, &configuration.context_clause, messages);
+
+        let mut design_region = DeclarativeRegion::new(Some(&root_region));
+        self.analyze_entity_declaration(&mut design_region, &entity.entity.unit, &mut ignored);
+        if let Some(architecture) = architecture {
+            self.analyze_declarative_part(
+                &mut design_region,
+                &architecture.unit.decl,
+                &mut ignored,
+            );
+        }
+
+        let mut region = DeclarativeRegion::new(Some(&design_region));
+        for item in configuration.unit.decl.iter() {
+            match item {
+                ConfigurationDeclarativeItem::Use(ref use_clause) => {
+                    self.analyze_use_clause(
+                        &mut region,
+                        &use_clause.item,
+                        &use_clause.pos,
+                        messages,
+                    );
+                }
+                ConfigurationDeclarativeItem::Attribute(ref attr_spec) => {
+                    self.analyze_attribute_specification(&region, attr_spec, messages);
+                    self.analyze_unit_or_label_attribute_specification(
+                        &region,
+                        Some((EntityClass::Configuration, &configuration.unit.ident.item)),
+                        attr_spec,
+                        &[],
+                        messages,
+                    );
+                }
+                ConfigurationDeclarativeItem::Group(ref group_decl) => {
+                    self.analyze_group_declaration(&region, group_decl, messages);
+                }
+            }
+        }
+
+        if let Some(architecture) = architecture {
+            self.analyze_block_configuration(
+                &region,
+                block_config,
+                &architecture.unit.statements,
+                messages,
+            );
+        }
+    }
+
+    /// Report design units which are not allowed in the standard of the library
+    fn check_library_standard(&self, library: &Library, messages: &mut dyn MessageHandler) {
+        let standard = library.standard();
+        if standard >= VHDLStandard::VHDL2008 {
+            return;
+        }
+
+        let error = |pos: &SrcPos, construct: &str| {
+            Message::error(
+                pos,
+                format!(
+                    "{} requires {} or later, the selected standard is {}",
+                    construct,
+                    VHDLStandard::VHDL2008,
+                    standard
+                ),
+                MessageCode::UnsupportedInStandard,
+            )
+        };
+
+        for package in library.packages() {
+            if package.is_generic() {
+                messages.push(error(&package.package.ident().pos, "Generic package"));
+            }
+        }
+
+        for package_instance in library.package_instances() {
+            messages.push(error(
+                &package_instance.ident().pos,
+                "Package instantiation",
+            ));
         }
     }
 
     pub fn analyze(&self, messages: &mut dyn MessageHandler) {
         // Analyze standard library first
         if let Some(library) = self.root.get_library(&self.std_sym) {
-            let standard_package = library
-                .package(&self.standard_sym)
-                .expect("Failed to find package STD.STANDARD");
-            self.analyze_package(library, standard_package, messages);
+            // A std library replacing the bundled one may lack STD.STANDARD
+            // its names are then reported as unresolved where they are used
+            if let Some(standard_package) = library.package(&self.standard_sym) {
+                self.analyze_package(library, standard_package, messages);
+            }
             for package in library.packages() {
                 if *package.package.name() != self.standard_sym {
                     self.analyze_package(library, package, messages);
@@ -1277,6 +2593,81 @@ impl<'r, 'a: 'r> Analyzer<'a> {
     }
 }
 
+impl<'a> StaticContext<'a> for Analyzer<'a> {
+    fn package_data(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        prefix: &SelectedName,
+    ) -> Option<Arc<PrimaryUnitData<'a>>> {
+        let decl = match prefix {
+            SelectedName::Designator(ref designator) => {
+                region.lookup(designator, true)?.decl.clone()
+            }
+            SelectedName::Selected(ref library_name, ref suffix) => {
+                let library = match library_name.item {
+                    SelectedName::Designator(ref designator) => {
+                        match region.lookup(designator, true)?.decl {
+                            AnyDeclaration::Library(library) => library,
+                            _ => {
+                                return None;
+                            }
+                        }
+                    }
+                    SelectedName::Selected(..) => {
+                        return None;
+                    }
+                };
+                self.library_regions
+                    .get(&library.name)?
+                    .lookup(&suffix.item, false)?
+                    .decl
+                    .clone()
+            }
+        };
+
+        match decl {
+            AnyDeclaration::Package(library, package) if !package.is_generic() => {
+                self.get_package_result(None, library, package).ok()
+            }
+            AnyDeclaration::PackageInstance(library, instance) => self
+                .analyze_package_instance_unit(None, library, instance)
+                .ok(),
+            AnyDeclaration::LocalPackageInstance(_, data) => Some(data),
+            _ => None,
+        }
+    }
+
+    fn math_real_package(&self) -> Option<&'a PackageDesignUnit> {
+        self.root
+            .get_library(&self.ieee_sym)?
+            .package(&self.math_real_sym)
+    }
+}
+
+/// PSL 1.1 5.2.3 Built-in functions which are not declared in VHDL
+fn is_psl_builtin_function(name: &Name) -> bool {
+    if let Name::Designator(Designator::Identifier(ref sym)) = name {
+        let name = sym.name_utf8().to_lowercase();
+        [
+            "prev",
+            "next",
+            "stable",
+            "rose",
+            "fell",
+            "ended",
+            "isunknown",
+            "countones",
+            "onehot",
+            "onehot0",
+            "nondet",
+            "nondet_vector",
+        ]
+        .contains(&name.as_str())
+    } else {
+        false
+    }
+}
+
 fn uninstantiated_package_prefix_error(
     prefix: &SrcPos,
     library: &Library,
@@ -1289,21 +2680,432 @@ fn uninstantiated_package_prefix_error(
             &library.name,
             package.package.name()
         ),
+        MessageCode::InvalidSelectedName,
     )
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::message::Message;
-    use crate::test_util::{check_messages, check_no_messages, Code, CodeBuilder};
+/// True if the declaration belongs to the entity class of an attribute specification
+fn is_of_entity_class(visible_decl: &VisibleDeclaration<'_>, entity_class: EntityClass) -> bool {
+    let object_class = |class: ObjectClass| match class {
+        ObjectClass::Signal => EntityClass::Signal,
+        ObjectClass::Constant => EntityClass::Constant,
+        ObjectClass::Variable | ObjectClass::SharedVariable => EntityClass::Variable,
+    };
+    let subprogram_class = |subpgm: &SubprogramDeclaration| match subpgm {
+        SubprogramDeclaration::Procedure(..) => EntityClass::Procedure,
+        SubprogramDeclaration::Function(..) => EntityClass::Function,
+    };
+
+    let class = match visible_decl.decl {
+        AnyDeclaration::Declaration(decl) => match decl {
+            Declaration::Object(ref object_decl) => object_class(object_decl.class),
+            Declaration::File(..) => EntityClass::File,
+            Declaration::Type(TypeDeclaration {
+                def: TypeDefinition::Subtype(..),
+                ..
+            }) => EntityClass::Subtype,
+            Declaration::Type(..) => EntityClass::Type,
+            Declaration::Component(..) => EntityClass::Component,
+            Declaration::SubprogramDeclaration(ref subpgm) => subprogram_class(subpgm),
+            Declaration::SubprogramBody(ref body) => subprogram_class(&body.specification),
+            Declaration::Group(Group::Declaration(..)) => EntityClass::Group,
+            Declaration::Package(..) => EntityClass::Package,
+            Declaration::Psl(PslDeclaration::Property(..)) => EntityClass::Property,
+            Declaration::Psl(PslDeclaration::Sequence(..)) => EntityClass::Sequence,
+            // The class of an alias is the class of the aliased entity
+            Declaration::Alias(..) => {
+                return true;
+            }
+            _ => {
+                return false;
+            }
+        },
+        AnyDeclaration::Interface(decl) => match decl {
+            InterfaceDeclaration::Object(ref object_decl) => object_class(object_decl.class),
+            InterfaceDeclaration::File(..) => EntityClass::File,
+            InterfaceDeclaration::Type(..) => EntityClass::Type,
+            InterfaceDeclaration::Subprogram(ref subpgm, ..) => subprogram_class(subpgm),
+            InterfaceDeclaration::Package(..) => EntityClass::Package,
+            InterfaceDeclaration::View(..) => {
+                return false;
+            }
+        },
+        AnyDeclaration::Enum(..) => EntityClass::Literal,
+        AnyDeclaration::PhysicalUnit(..) => EntityClass::Units,
+        AnyDeclaration::LocalPackageInstance(..) => EntityClass::Package,
+        _ => {
+            return false;
+        }
+    };
 
-    fn expected_message(code: &Code, name: &str, occ1: usize, occ2: usize) -> Message {
-        Message::error(
-            code.s(&name, occ2),
-            format!("Duplicate declaration of '{}'", &name),
-        )
-        .related(code.s(&name, occ1), "Previously defined here")
+    class == entity_class
+}
+
+/// True if the entity class denotes design units or statement labels
+fn is_unit_or_label_class(entity_class: EntityClass) -> bool {
+    matches!(
+        entity_class,
+        EntityClass::Entity
+            | EntityClass::Architecture
+            | EntityClass::Configuration
+            | EntityClass::Package
+            | EntityClass::Label
+    )
+}
+
+/// The source position of a range
+fn range_pos(range: &Range) -> SrcPos {
+    match range {
+        Range::Range(ref constraint) => {
+            constraint.left_expr.pos.combine(&constraint.right_expr.pos)
+        }
+        Range::Attribute(ref attr) => attr.name.pos.combine(&attr.attr),
+    }
+}
+
+/// Report a range which contains no values, returns true if the range is null
+fn check_null_range(
+    evaluator: &StaticEvaluator<'_, '_>,
+    range: &Range,
+    messages: &mut dyn MessageHandler,
+) -> bool {
+    let evaluated = match evaluator.evaluate_range(range) {
+        Some(evaluated) => evaluated,
+        None => {
+            return false;
+        }
+    };
+
+    if evaluated.is_null() == Some(true) {
+        messages.push(Message::warning(
+            range_pos(range),
+            format!("Range {} is a null range", evaluated),
+            MessageCode::NullRange,
+        ));
+        true
+    } else {
+        false
+    }
+}
+
+/// LRM 5.2.3 and 5.2.5 The bounds of a range type definition decide whether it defines
+/// an integer or a floating-point type, so both shall be integer or both shall be real
+fn check_range_type_definition(
+    evaluator: &StaticEvaluator<'_, '_>,
+    range: &Range,
+    messages: &mut dyn MessageHandler,
+) {
+    if let Some(evaluated) = evaluator.evaluate_range(range) {
+        if evaluated.range_type_kind().is_none() {
+            messages.push(Message::error(
+                range_pos(range),
+                format!(
+                    "Range {} of a type definition shall have both integer or both real bounds",
+                    evaluated
+                ),
+                MessageCode::RangeTypeMismatch,
+            ));
+        }
+    }
+}
+
+fn check_null_discrete_range(
+    evaluator: &StaticEvaluator<'_, '_>,
+    discrete_range: &DiscreteRange,
+    messages: &mut dyn MessageHandler,
+) {
+    match discrete_range {
+        DiscreteRange::Discrete(_, Some(ref range)) | DiscreteRange::Range(ref range) => {
+            check_null_range(evaluator, range, messages);
+        }
+        DiscreteRange::Discrete(_, None) => {}
+    }
+}
+
+/// The number of elements of a string literal, bit string literal or positional aggregate
+fn element_count(expr: &Expression) -> Option<i64> {
+    match expr {
+        Expression::Literal(Literal::String(ref value)) => Some(value.len() as i64),
+        Expression::Literal(Literal::BitString(ref bit_string)) => bit_string_length(bit_string),
+        Expression::Aggregate(ref associations) => {
+            // A single parenthesized element is not an aggregate
+            if associations.len() < 2 {
+                return None;
+            }
+            for association in associations.iter() {
+                if let ElementAssociation::Named(..) = association {
+                    return None;
+                }
+            }
+            Some(associations.len() as i64)
+        }
+        _ => None,
+    }
+}
+
+/// LRM 15.8 Bit string literals
+fn bit_string_length(bit_string: &BitString) -> Option<i64> {
+    if let Some(length) = bit_string.length {
+        return Some(i64::from(length));
+    }
+
+    let digits = bit_string
+        .value
+        .bytes
+        .iter()
+        .filter(|byte| **byte != b'_')
+        .count() as i64;
+
+    match bit_string.base {
+        BaseSpecifier::B | BaseSpecifier::UB | BaseSpecifier::SB => Some(digits),
+        BaseSpecifier::O | BaseSpecifier::UO | BaseSpecifier::SO => Some(3 * digits),
+        BaseSpecifier::X | BaseSpecifier::UX | BaseSpecifier::SX => Some(4 * digits),
+        // The length of a decimal bit string depends on its value
+        BaseSpecifier::D => None,
+    }
+}
+
+/// The value of an object of a constrained array subtype shall have a matching length
+fn check_array_length(
+    evaluator: &StaticEvaluator<'_, '_>,
+    subtype_indication: &SubtypeIndication,
+    expr: &WithPos<Expression>,
+    messages: &mut dyn MessageHandler,
+) {
+    let actual = match element_count(&expr.item) {
+        Some(actual) => actual,
+        None => {
+            return;
+        }
+    };
+
+    if let Some(expected) = evaluator.array_length(subtype_indication) {
+        if actual != expected {
+            messages.push(Message::error(
+                &expr.pos,
+                format!(
+                    "Expected value of length {} for subtype '{}', got length {}",
+                    expected, subtype_indication.type_mark.item, actual
+                ),
+                MessageCode::LengthMismatch,
+            ));
+        }
+    }
+}
+
+/// The bounds of a non-null range constraint shall belong to the subtype denoted by the type mark
+fn check_range_constraint(
+    evaluator: &StaticEvaluator<'_, '_>,
+    type_mark: &WithPos<SelectedName>,
+    range: &Range,
+    messages: &mut dyn MessageHandler,
+) {
+    if check_null_range(evaluator, range, messages) {
+        return;
+    }
+
+    let bounds = match evaluator.type_mark_range(&type_mark.item) {
+        Some(bounds) => bounds,
+        None => {
+            return;
+        }
+    };
+    let constraint = match evaluator.evaluate_range(range) {
+        Some(constraint) => constraint,
+        None => {
+            return;
+        }
+    };
+
+    if let (Some(kind), Some(constraint_kind)) =
+        (bounds.range_type_kind(), constraint.range_type_kind())
+    {
+        if kind != constraint_kind {
+            messages.push(Message::error(
+                range_pos(range),
+                format!(
+                    "Range {} is not of the {} type '{}'",
+                    constraint, kind, type_mark.item
+                ),
+                MessageCode::RangeTypeMismatch,
+            ));
+            return;
+        }
+    }
+
+    let bound_positions = match range {
+        Range::Range(ref constraint) => (
+            constraint.left_expr.pos.clone(),
+            constraint.right_expr.pos.clone(),
+        ),
+        Range::Attribute(..) => (range_pos(range), range_pos(range)),
+    };
+
+    for (value, pos) in [
+        (&constraint.left, bound_positions.0),
+        (&constraint.right, bound_positions.1),
+    ]
+    .iter()
+    {
+        if bounds.contains(value) == Some(false) {
+            messages.push(Message::error(
+                pos,
+                format!(
+                    "{} is out of the range {} of '{}'",
+                    value, bounds, type_mark.item
+                ),
+                MessageCode::RangeOutOfBounds,
+            ));
+        }
+    }
+}
+
+/// The designator of a name without any index, slice or selected suffix
+fn base_designator(name: &Name) -> Option<&Designator> {
+    match name {
+        Name::Designator(ref designator) => Some(designator),
+        Name::Selected(ref prefix, ..)
+        | Name::Indexed(ref prefix, ..)
+        | Name::Slice(ref prefix, ..) => base_designator(&prefix.item),
+        Name::FunctionCall(ref call) => base_designator(&call.name.item),
+        _ => None,
+    }
+}
+
+fn interface_designator(decl: &InterfaceDeclaration) -> Designator {
+    match decl {
+        InterfaceDeclaration::Object(ref object_decl) => {
+            Designator::Identifier(object_decl.ident.item.clone())
+        }
+        InterfaceDeclaration::File(ref file_decl) => {
+            Designator::Identifier(file_decl.ident.item.clone())
+        }
+        InterfaceDeclaration::Type(ref ident) => Designator::Identifier(ident.item.clone()),
+        InterfaceDeclaration::Subprogram(ref subpgm, ..) => subpgm.designator().item,
+        InterfaceDeclaration::Package(ref package_decl) => {
+            Designator::Identifier(package_decl.ident.item.clone())
+        }
+        InterfaceDeclaration::View(ref view_decl) => {
+            Designator::Identifier(view_decl.ident.item.clone())
+        }
+    }
+}
+
+/// The alternative of an if or case generate statement denoted by the block specification label(alternative_label)
+/// The first alternative is used when there is no alternative label
+fn generate_alternative<'g>(
+    block_spec: &WithPos<Name>,
+    bodies: &[&'g GenerateBody],
+) -> &'g GenerateBody {
+    let alternative_label = match block_spec.item {
+        Name::FunctionCall(ref call) => match call.parameters.as_slice() {
+            [AssociationElement {
+                formal: None,
+                actual:
+                    WithPos {
+                        item: ActualPart::Expression(Expression::Name(ref name)),
+                        ..
+                    },
+            }] => base_designator(name),
+            _ => None,
+        },
+        Name::Indexed(_, ref indexes) => match indexes.as_slice() {
+            [WithPos {
+                item: Expression::Name(ref name),
+                ..
+            }] => base_designator(name),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    if let Some(Designator::Identifier(ref label)) = alternative_label {
+        for body in bodies.iter() {
+            if body.alternative_label.as_ref().map(|ident| &ident.item) == Some(label) {
+                return body;
+            }
+        }
+    }
+    bodies[0]
+}
+
+/// True if the formal or the argument of a conversion function applied to it is within the interface
+fn is_interface_formal(formal: &Name, designators: &[Designator]) -> bool {
+    if let Some(designator) = base_designator(formal) {
+        if designators.contains(designator) {
+            return true;
+        }
+    }
+
+    if let Name::FunctionCall(ref call) = formal {
+        call.parameters.iter().any(|param| match param.actual.item {
+            ActualPart::Expression(Expression::Name(ref name)) => {
+                is_interface_formal(name, designators)
+            }
+            _ => false,
+        })
+    } else {
+        false
+    }
+}
+
+/// Check the formals and the number of positional associations of a binding map
+fn check_binding_map(
+    entity: &EntityDeclaration,
+    interface_list: Option<&Vec<InterfaceDeclaration>>,
+    map: &[AssociationElement],
+    kind: &str,
+    messages: &mut dyn MessageHandler,
+) {
+    let designators: Vec<Designator> = interface_list
+        .map(|list| list.iter().map(interface_designator).collect())
+        .unwrap_or_default();
+
+    let mut num_positional = 0;
+    for element in map.iter() {
+        if let Some(ref formal) = element.formal {
+            if is_interface_formal(&formal.item, &designators) {
+                continue;
+            }
+            if let Some(designator) = base_designator(&formal.item) {
+                messages.push(Message::error(
+                    formal,
+                    format!(
+                        "No {} '{}' in entity '{}'",
+                        kind, designator, entity.ident.item
+                    ),
+                    MessageCode::BindingMismatch,
+                ));
+            }
+        } else {
+            num_positional += 1;
+            if num_positional == designators.len() + 1 {
+                messages.push(Message::error(
+                    &element.actual,
+                    format!(
+                        "Too many {} associations for entity '{}'",
+                        kind, entity.ident.item
+                    ),
+                    MessageCode::BindingMismatch,
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::Message;
+    use crate::test_util::{check_messages, check_no_messages, Code, CodeBuilder};
+
+    fn expected_message(code: &Code, name: &str, occ1: usize, occ2: usize) -> Message {
+        Message::error(
+            code.s(&name, occ2),
+            format!("Duplicate declaration of '{}'", &name),
+            MessageCode::DuplicateDeclaration,
+        )
+        .related(code.s(&name, occ1), "Previously defined here")
     }
 
     fn expected_messages(code: &Code, names: &[&str]) -> Vec<Message> {
@@ -1321,6 +3123,7 @@ mod tests {
                 Message::error(
                     code2.s1(&name),
                     format!("Duplicate declaration of '{}'", &name),
+                    MessageCode::DuplicateDeclaration,
                 )
                 .related(code1.s1(&name), "Previously defined here"),
             )
@@ -1405,6 +3208,7 @@ end package body;
             vec![Message::error(
                 &code.s1("a1"),
                 "Deferred constants are only allowed in package declarations (not body)",
+                MessageCode::IllegalDeferredConstant,
             )],
         );
     }
@@ -1428,6 +3232,7 @@ end package;
             vec![Message::error(
                 &code.s("a1", 2),
                 "Full declaration of deferred constant is only allowed in a package body",
+                MessageCode::IllegalDeferredConstant,
             )],
         );
     }
@@ -1458,10 +3263,12 @@ end package body;
                 Message::error(
                     &code.s1("a1"),
                     "Deferred constant 'a1' lacks corresponding full constant declaration in package body",
+                    MessageCode::MissingFullDeclaration,
                 ),
                 Message::error(
                     &code.s1("b1"),
                     "Deferred constant 'b1' lacks corresponding full constant declaration in package body",
+                    MessageCode::MissingFullDeclaration,
                 ),
             ],
         );
@@ -1492,8 +3299,16 @@ end package body;
         check_messages(
             messages,
             vec![
-                Message::error(&code.s1("a1"), "Missing body for protected type 'a1'"),
-                Message::error(&code.s1("b1"), "Missing body for protected type 'b1'"),
+                Message::error(
+                    &code.s1("a1"),
+                    "Missing body for protected type 'a1'",
+                    MessageCode::MissingFullDeclaration,
+                ),
+                Message::error(
+                    &code.s1("b1"),
+                    "Missing body for protected type 'b1'",
+                    MessageCode::MissingFullDeclaration,
+                ),
             ],
         );
     }
@@ -1526,9 +3341,21 @@ end package body;
         check_messages(
             messages,
             vec![
-                Message::error(&code.s1("a1"), "No declaration of protected type 'a1'"),
-                Message::error(&code.s1("b1"), "No declaration of protected type 'b1'"),
-                Message::error(&code.s("b1", 2), "Missing body for protected type 'b1'"),
+                Message::error(
+                    &code.s1("a1"),
+                    "No declaration of protected type 'a1'",
+                    MessageCode::UnresolvedName,
+                ),
+                Message::error(
+                    &code.s1("b1"),
+                    "No declaration of protected type 'b1'",
+                    MessageCode::UnresolvedName,
+                ),
+                Message::error(
+                    &code.s("b1", 2),
+                    "Missing body for protected type 'b1'",
+                    MessageCode::MissingFullDeclaration,
+                ),
             ],
         );
     }
@@ -1681,6 +3508,7 @@ end package body;
 package pkg is
   type rec_t;
   type rec_t is record
+    field : natural;
   end record;
 end package;
 ",
@@ -1700,6 +3528,7 @@ package pkg is
   type rec_t;
   type rec_t;
   type rec_t is record
+    field : natural;
   end record;
 end package;
 ",
@@ -1722,6 +3551,7 @@ end package;
 package body pkg is
   -- Must appear in the same immediate declarative region
   type rec_t is record
+    field : natural;
   end record;
 end package body;
 ",
@@ -1739,6 +3569,7 @@ begin
   blk : block
     -- Must appear in the same immediate declarative region
     type rec_t is record
+      field : natural;
     end record;
   begin
   end block;
@@ -1764,11 +3595,13 @@ end package body;
             expected_messages.push(Message::error(
                 code.s1("rec_t"),
                 "Missing full type declaration of incomplete type 'rec_t'",
+                MessageCode::MissingFullDeclaration,
             ));
             expected_messages.push(
                 Message::hint(
                     code.s1("rec_t"),
                     "The full type declaration shall occur immediately within the same declarative part",
+                    MessageCode::MissingFullDeclaration,
                 ));
         }
 
@@ -2459,6 +4292,7 @@ end entity;
             vec![Message::error(
                 code.s1("missing_lib"),
                 "No such library 'missing_lib'",
+                MessageCode::MissingLibrary,
             )],
         )
     }
@@ -2571,6 +4405,7 @@ end package body;
             vec![Message::error(
                 code.s("pkg2", 3),
                 "No declaration of 'pkg2'",
+                MessageCode::UnresolvedName,
             )],
         )
     }
@@ -2594,6 +4429,7 @@ end context;
             vec![Message::error(
                 code.s1("missing_lib"),
                 "No such library 'missing_lib'",
+                MessageCode::MissingLibrary,
             )],
         )
     }
@@ -2665,16 +4501,19 @@ end entity;
             vec![Message::hint(
                 code.s1("work"),
                 "Library clause not necessary for current working library",
+                MessageCode::UnnecessaryLibraryClause,
             )],
         )
     }
 
+    use crate::builtin_libraries::BUILTIN_FILES;
     use crate::source::Source;
     use std::collections::{hash_map::Entry, HashMap};
 
     struct LibraryBuilder {
         code_builder: CodeBuilder,
         libraries: HashMap<Symbol, Vec<Code>>,
+        standards: HashMap<Symbol, VHDLStandard>,
     }
 
     impl LibraryBuilder {
@@ -2682,40 +4521,17 @@ end entity;
             LibraryBuilder {
                 code_builder: CodeBuilder::new(),
                 libraries: HashMap::default(),
+                standards: HashMap::default(),
             }
         }
 
         fn new() -> LibraryBuilder {
-            use crate::latin_1::Latin1String;
-
             let mut library = LibraryBuilder::new_no_std();
-            library.code_from_source(
-                "std",
-                Source::inline(
-                    "standard.vhd",
-                    Arc::new(Latin1String::new(include_bytes!(
-                        "../../../example_project/vhdl_libraries/2008/std/standard.vhd"
-                    ))),
-                ),
-            );
-            library.code_from_source(
-                "std",
-                Source::inline(
-                    "textio.vhd",
-                    Arc::new(Latin1String::new(include_bytes!(
-                        "../../../example_project/vhdl_libraries/2008/std/textio.vhd"
-                    ))),
-                ),
-            );
-            library.code_from_source(
-                "std",
-                Source::inline(
-                    "env.vhd",
-                    Arc::new(Latin1String::new(include_bytes!(
-                        "../../../example_project/vhdl_libraries/2008/std/env.vhd"
-                    ))),
-                ),
-            );
+            for builtin_file in BUILTIN_FILES.iter() {
+                if builtin_file.library_name == "std" {
+                    library.code_from_source("std", builtin_file.source());
+                }
+            }
             library
         }
 
@@ -2737,6 +4553,20 @@ end entity;
             code
         }
 
+        /// Add code to a library which is parsed and analyzed according to the standard
+        fn code_with_standard(
+            &mut self,
+            library_name: &str,
+            code: &str,
+            standard: VHDLStandard,
+        ) -> Code {
+            let code = self.code_builder.code_with_standard(code, standard);
+            self.add_code(library_name, code.clone());
+            self.standards
+                .insert(self.code_builder.symbol(library_name), standard);
+            code
+        }
+
         fn code_from_source(&mut self, library_name: &str, source: Source) -> Code {
             let code = self.code_builder.code_from_source(source);
             self.add_code(library_name, code.clone());
@@ -2744,26 +4574,64 @@ end entity;
         }
 
         fn analyze(&self) -> Vec<Message> {
+            let (messages, _) = self.analyze_with_static_values();
+            messages
+        }
+
+        fn analyze_with_static_values(&self) -> (Vec<Message>, Vec<(SrcPos, Value)>) {
             let mut root = DesignRoot::new();
             let mut messages = Vec::new();
 
             for (library_name, codes) in self.libraries.iter() {
                 let design_files = codes.iter().map(|code| code.design_file()).collect();
-                let library = Library::new(
+                let mut library = Library::new(
                     library_name.clone(),
                     &self.code_builder.symbol("work"),
                     design_files,
                     &mut messages,
                 );
+                if let Some(standard) = self.standards.get(library_name) {
+                    library.set_standard(*standard);
+                }
                 root.add_library(library);
             }
 
-            Analyzer::new(&root, &self.code_builder.symtab.clone()).analyze(&mut messages);
+            let analyzer = Analyzer::new(&root, &self.code_builder.symtab.clone());
+            analyzer.analyze(&mut messages);
+            let static_values = analyzer.take_static_values();
 
-            messages
+            (messages, static_values)
         }
     }
 
+    #[test]
+    fn std_library_without_standard_package() {
+        let mut builder = LibraryBuilder::new_no_std();
+        builder.code(
+            "std",
+            "
+package textio is
+end package;",
+        );
+        let code = builder.code(
+            "libname",
+            "
+package pkg is
+  constant c : natural := 0;
+end package;",
+        );
+
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s1("natural"),
+                "No declaration of 'natural'",
+                MessageCode::UnresolvedName,
+            )],
+        );
+    }
+
     #[test]
     fn check_use_clause_for_missing_design_unit() {
         let mut builder = LibraryBuilder::new();
@@ -2820,10 +4688,12 @@ end entity;
                 Message::error(
                     code.s("missing_pkg", 1),
                     "No primary unit 'missing_pkg' within 'libname'",
+                    MessageCode::UnresolvedName,
                 ),
                 Message::error(
                     code.s("missing_pkg", 2),
                     "No primary unit 'missing_pkg' within 'libname'",
+                    MessageCode::UnresolvedName,
                 ),
             ],
         )
@@ -2852,6 +4722,7 @@ end entity;
             vec![Message::error(
                 code.s("libname", 1),
                 "No declaration of 'libname'",
+                MessageCode::UnresolvedName,
             )],
         )
     }
@@ -2881,806 +4752,2032 @@ begin
   begin
   end process;
 
-  blk : block
-    use pkg.const; -- Works
-    use libname.pkg1; -- Error
-  begin
-  end block;
+  blk : block
+    use pkg.const; -- Works
+    use libname.pkg1; -- Error
+  begin
+  end block;
+
+end architecture;
+            ",
+        );
+
+        let messages = builder.analyze();
+
+        check_messages(
+            messages,
+            vec![
+                Message::error(
+                    code.s("pkg1", 1),
+                    "No primary unit 'pkg1' within 'libname'",
+                    MessageCode::UnresolvedName,
+                ),
+                Message::error(
+                    code.s("pkg1", 2),
+                    "No primary unit 'pkg1' within 'libname'",
+                    MessageCode::UnresolvedName,
+                ),
+                Message::error(
+                    code.s("pkg1", 3),
+                    "No primary unit 'pkg1' within 'libname'",
+                    MessageCode::UnresolvedName,
+                ),
+            ],
+        )
+    }
+
+    #[test]
+    fn check_context_reference_for_missing_context() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+context ctx is
+end context;
+
+context work.ctx;
+context work.missing_ctx;
+
+entity dummy is
+end entity;
+            ",
+        );
+
+        let messages = builder.analyze();
+
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s1("missing_ctx"),
+                "No primary unit 'missing_ctx' within 'libname'",
+                MessageCode::UnresolvedName,
+            )],
+        )
+    }
+
+    #[test]
+    fn check_context_reference_for_non_context() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package pkg is
+end package;
+
+context work.pkg;
+
+entity dummy is
+end entity;
+            ",
+        );
+
+        let messages = builder.analyze();
+
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s("pkg", 2),
+                "'pkg' does not denote a context declaration",
+                MessageCode::InvalidSelectedName,
+            )],
+        )
+    }
+
+    #[test]
+    fn check_use_clause_and_context_clause_must_be_selected_name() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+library libname;
+
+context libname;
+use work;
+use libname;
+
+use work.pkg(0);
+context work.ctx'range;
+
+entity dummy is
+end entity;
+            ",
+        );
+
+        let messages = builder.analyze();
+
+        check_messages(
+            messages,
+            vec![
+                Message::error(
+                    code.s1("context libname;"),
+                    "Context reference must be a selected name",
+                    MessageCode::InvalidSelectedName,
+                ),
+                Message::error(
+                    code.s1("use work;"),
+                    "Use clause must be a selected name",
+                    MessageCode::InvalidSelectedName,
+                ),
+                Message::error(
+                    code.s1("use libname;"),
+                    "Use clause must be a selected name",
+                    MessageCode::InvalidSelectedName,
+                ),
+                Message::error(
+                    code.s1("use work.pkg(0);"),
+                    "Use clause must be a selected name",
+                    MessageCode::InvalidSelectedName,
+                ),
+                Message::error(
+                    code.s1("context work.ctx'range;"),
+                    "Context reference must be a selected name",
+                    MessageCode::InvalidSelectedName,
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn check_two_stage_use_clause_for_missing_name() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package pkg is
+  type enum_t is (alpha, beta);
+  constant const : enum_t := alpha;
+end package;
+
+use work.pkg;
+use pkg.const;
+use pkg.const2;
+
+package pkg2 is
+end package;
+            ",
+        );
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s1("const2"),
+                "No declaration of 'const2' within package 'libname.pkg'",
+                MessageCode::UnresolvedName,
+            )],
+        );
+    }
+    #[test]
+    fn check_use_clause_for_missing_name_in_package() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package pkg is
+  type enum_t is (alpha, beta);
+  constant const : enum_t := alpha;
+end package;
+
+use work.pkg.const;
+use work.pkg.const2;
+
+package pkg2 is
+end package;
+            ",
+        );
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s1("const2"),
+                "No declaration of 'const2' within package 'libname.pkg'",
+                MessageCode::UnresolvedName,
+            )],
+        );
+    }
+
+    #[test]
+    fn check_use_clause_for_missing_name_in_package_instance() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package gpkg is
+  generic (constant gconst : natural);
+  constant const : natural := 0;
+end package;
+
+package ipkg is new work.gpkg generic map (gconst => 0);
+
+use work.ipkg.const;
+use work.ipkg.const2;
+
+-- @TODO should probably not be visible #19
+-- use work.ipkg.gconst;
+
+package pkg is
+end package;
+            ",
+        );
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![
+                // @TODO add use instance path in error message
+                Message::error(
+                    code.s1("const2"),
+                    "No declaration of 'const2' within package instance 'libname.ipkg'",
+                    MessageCode::UnresolvedName,
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn use_clause_cannot_reference_potentially_visible_name() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package pkg2 is
+  constant const1 : natural := 0;
+  constant const2 : natural := 0;
+end package;
+
+
+use work.pkg2.const1;
+
+package pkg is
+  use work.pkg2.const2;
+  constant const3 : natural := 0;
+end package;
+
+use work.pkg.const1;
+use work.pkg.const2;
+use work.pkg.const3;
+
+entity ent is
+end entity;
+            ",
+        );
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![
+                Message::error(
+                    code.s("const1", 3),
+                    "No declaration of 'const1' within package 'libname.pkg'",
+                    MessageCode::UnresolvedName,
+                ),
+                Message::error(
+                    code.s("const2", 3),
+                    "No declaration of 'const2' within package 'libname.pkg'",
+                    MessageCode::UnresolvedName,
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn error_on_use_clause_with_double_all() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package pkg1 is
+  constant const1 : natural := 0;
+end package;
+
+use work.all.all;
+use work.all.foo;
+
+entity ent is
+end entity;
+            ",
+        );
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![
+                Message::error(
+                    code.s("work.all", 1),
+                    "'.all' may not be the prefix of a selected name",
+                    MessageCode::InvalidSelectedName,
+                ),
+                Message::error(
+                    code.s("work.all", 2),
+                    "'.all' may not be the prefix of a selected name",
+                    MessageCode::InvalidSelectedName,
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn an_uninstantiated_package_may_not_be_prefix_of_selected_name() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package gpkg is
+  generic (const : natural);
+end package;
+
+use work.gpkg.all;
+
+package pkg is
+  use work.gpkg.const;
+end package;
+            ",
+        );
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![
+                Message::error(
+                    code.s("work.gpkg", 1),
+                    "Uninstantiated generic package 'libname.gpkg' may not be the prefix of a selected name",
+                    MessageCode::InvalidSelectedName,
+                ),
+                Message::error(
+                    code.s("work.gpkg", 2),
+                    "Uninstantiated generic package 'libname.gpkg' may not be the prefix of a selected name",
+                    MessageCode::InvalidSelectedName,
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn package_name_must_be_visible_in_package_instance() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package gpkg is
+  generic (const : natural);
+end package;
+
+package ipkg_err is new gpkg generic map (const => 0);
+package ipkg_ok is new work.gpkg generic map (const => 0);
+
+package nested is
+  package ipkg_err is new gpkg generic map (const => 0);
+  package ipkg_ok is new work.gpkg generic map (const => 0);
+end package;
+            ",
+        );
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![
+                Message::error(
+                    code.s("gpkg", 2),
+                    "No declaration of 'gpkg'",
+                    MessageCode::UnresolvedName,
+                ),
+                Message::error(
+                    code.s("gpkg", 4),
+                    "No declaration of 'gpkg'",
+                    MessageCode::UnresolvedName,
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn package_name_must_be_an_uninstantiated_package_in_package_instance() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package pkg is
+  constant const : natural := 0;
+end package;
+
+package ipkg is new work.pkg generic map (const => 0);
+
+package nested is
+  package ipkg2 is new work.pkg.const generic map (const => 0);
+end package;
+            ",
+        );
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![
+                Message::error(
+                    code.s1("work.pkg"),
+                    "'pkg' is not an uninstantiated generic package",
+                    MessageCode::InvalidSelectedName,
+                ),
+                Message::error(
+                    code.s1("work.pkg.const"),
+                    "'const' is not an uninstantiated generic package",
+                    MessageCode::InvalidSelectedName,
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn use_clause_with_selected_all_design_units() {
+        let mut builder = LibraryBuilder::new();
+        builder.code(
+            "libname",
+            "
+package pkg1 is
+  constant const1 : natural := 0;
+end package;
+
+package pkg2 is
+  constant const2 : natural := 0;
+end package;
+
+use work.all;
+use pkg1.const1;
+use pkg2.const2;
+
+entity ent is
+end entity;
+            ",
+        );
+        let messages = builder.analyze();
+        check_no_messages(&messages);
+    }
+
+    #[test]
+    fn use_clause_with_selected_all_names() {
+        let mut builder = LibraryBuilder::new();
+        builder.code(
+            "libname",
+            "
+package pkg1 is
+  type enum_t is (alpha, beta);
+end package;
+
+use work.pkg1.all;
+
+entity ent is
+end entity;
+
+architecture rtl of ent is
+  signal foo : enum_t;
+begin
+end architecture;
+            ",
+        );
+        let messages = builder.analyze();
+        check_no_messages(&messages);
+    }
+
+    #[test]
+    fn detects_circular_dependencies() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+use work.pkg2.const;
+
+package pkg1 is
+  constant const : natural := 0;
+end package;
+
+use work.pkg1.const;
+
+package pkg2 is
+  constant const : natural := 0;
+end package;",
+        );
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![
+                Message::error(
+                    code.s1("work.pkg1"),
+                    "Found circular dependency when referencing 'libname.pkg1'",
+                    MessageCode::CircularDependency,
+                ),
+                Message::error(
+                    code.s1("work.pkg2"),
+                    "Found circular dependency when referencing 'libname.pkg2'",
+                    MessageCode::CircularDependency,
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn detects_circular_dependencies_all() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+use work.pkg2.all;
+
+package pkg1 is
+  constant const : natural := 0;
+end package;
+
+use work.pkg1.all;
+
+package pkg2 is
+  constant const : natural := 0;
+end package;",
+        );
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![
+                Message::error(
+                    code.s1("work.pkg1"),
+                    "Found circular dependency when referencing 'libname.pkg1'",
+                    MessageCode::CircularDependency,
+                ),
+                Message::error(
+                    code.s1("work.pkg2"),
+                    "Found circular dependency when referencing 'libname.pkg2'",
+                    MessageCode::CircularDependency,
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn detects_circular_dependencies_only_when_used() {
+        let mut builder = LibraryBuilder::new();
+        builder.code(
+            "libname",
+            "
+use work.all;
+
+package pkg1 is
+  constant const : natural := 0;
+end package;
+
+use work.pkg1.const;
+
+package pkg2 is
+  constant const : natural := 0;
+end package;",
+        );
+        let messages = builder.analyze();
+        check_no_messages(&messages);
+    }
+
+    #[test]
+    fn resolves_type_mark_in_subtype_indications() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package pkg1 is
+  -- Object declaration
+  constant const : natural := 0;
+  constant const2 : missing := 0;
+
+  -- File declaration
+  file fil : std.textio.text;
+  file fil2 : missing;
+
+  -- Alias declaration
+  alias foo : natural is const;
+  alias foo2 : missing is const;
+
+  -- Array type definiton
+  type arr_t is array (natural range <>) of natural;
+  type arr_t2 is array (natural range <>) of missing;
+
+  -- Access type definiton
+  type acc_t is access natural;
+  type acc_t2 is access missing;
+
+  -- Subtype definiton
+  subtype sub_t is natural range 0 to 1;
+  subtype sub_t2 is missing range 0 to 1;
+
+  -- Record definition
+  type rec_t is record
+     f1 : natural;
+     f2 : missing;
+  end record;
+
+  -- Interface file
+  procedure p1 (fil : std.textio.text);
+  procedure p2 (fil : missing);
+
+  -- Interface object
+  function f1 (const : natural) return natural;
+  function f2 (const : missing) return natural;
+end package;",
+        );
+
+        let expected = (0..9)
+            .map(|idx| {
+                Message::error(
+                    code.s("missing", 1 + idx),
+                    "No declaration of 'missing'",
+                    MessageCode::UnresolvedName,
+                )
+            })
+            .collect();
+
+        let messages = builder.analyze();
+        check_messages(messages, expected);
+    }
+
+    #[test]
+    fn resolves_return_type() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package pkg is
+  function f1 (const : natural) return natural;
+  function f2 (const : natural) return missing;
+end package;",
+        );
+
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s1("missing"),
+                "No declaration of 'missing'",
+                MessageCode::UnresolvedName,
+            )],
+        );
+    }
+
+    #[test]
+    fn resolves_attribute_declaration_type_mark() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package pkg is
+  attribute attr : string;
+  attribute attr2 : missing;
+end package;",
+        );
+
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s1("missing"),
+                "No declaration of 'missing'",
+                MessageCode::UnresolvedName,
+            )],
+        );
+    }
+
+    #[test]
+    fn protected_type_is_visible_in_declaration() {
+        let mut builder = LibraryBuilder::new();
+        builder.code(
+            "libname",
+            "
+package pkg1 is
+  type prot_t is protected
+     procedure proc(val : inout prot_t);
+  end protected;
+
+  type prot_t is protected body
+     procedure proc(val : inout prot_t) is
+     begin
+     end;
+  end protected body;
+end package;",
+        );
+
+        let messages = builder.analyze();
+        check_no_messages(&messages);
+    }
+
+    #[test]
+    fn use_all_in_package() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package pkg1 is
+  subtype typ is natural range 0 to 1;
+end package;
+
+use work.pkg1.all;
+
+package pkg2 is
+  constant const : typ := 0;
+  constant const2 : missing := 0;
+end package;
+
+",
+        );
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s1("missing"),
+                "No declaration of 'missing'",
+                MessageCode::UnresolvedName,
+            )],
+        );
+    }
+
+    #[test]
+    fn use_all_in_primary_package_instance() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package gpkg is
+  generic (const : natural);
+  subtype typ is natural range 0 to 1;
+end package;
+
+package ipkg is new work.gpkg generic map (const => 0);
+
+use work.ipkg.all;
+
+package pkg is
+  constant const : typ := 0;
+  constant const2 : missing := 0;
+end package;
+
+",
+        );
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s1("missing"),
+                "No declaration of 'missing'",
+                MessageCode::UnresolvedName,
+            )],
+        );
+    }
+
+    #[test]
+    fn use_of_interface_package_declaration() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package gpkg1 is
+  generic (const : natural);
+  subtype typ is natural range 0 to 1;
+end package;
+
+package gpkg2 is
+  generic (package ipkg is new work.gpkg1 generic map (const => 1));
+  use ipkg.typ;
+  use ipkg.missing;
+end package;
+",
+        );
+
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s1("missing"),
+                "No declaration of 'missing' within package instance 'ipkg'",
+                MessageCode::UnresolvedName,
+            )],
+        );
+    }
+    #[test]
+    fn use_in_local_package_instance() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package gpkg is
+  generic (const : natural);
+  subtype typ is natural range 0 to 1;
+end package;
+
+
+package pkg is
+  package ipkg is new work.gpkg generic map (const => 0);
+  use ipkg.typ;
+
+  constant const : typ := 0;
+  constant const2 : missing := 0;
+end package;
+
+",
+        );
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s1("missing"),
+                "No declaration of 'missing'",
+                MessageCode::UnresolvedName,
+            )],
+        );
+    }
+
+    #[test]
+    fn use_all_in_local_package_instance() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package gpkg is
+  generic (const : natural);
+  subtype typ is natural range 0 to 1;
+end package;
+
+
+package pkg is
+  package ipkg is new work.gpkg generic map (const => 0);
+  use ipkg.all;
+
+  constant const : typ := 0;
+  constant const2 : missing := 0;
+end package;
 
-end architecture;
-            ",
+",
         );
-
         let messages = builder.analyze();
-
         check_messages(
             messages,
-            vec![
-                Message::error(code.s("pkg1", 1), "No primary unit 'pkg1' within 'libname'"),
-                Message::error(code.s("pkg1", 2), "No primary unit 'pkg1' within 'libname'"),
-                Message::error(code.s("pkg1", 3), "No primary unit 'pkg1' within 'libname'"),
-            ],
-        )
+            vec![Message::error(
+                code.s1("missing"),
+                "No declaration of 'missing'",
+                MessageCode::UnresolvedName,
+            )],
+        );
     }
 
     #[test]
-    fn check_context_reference_for_missing_context() {
+    fn resolves_generic_types_of_subprograms() {
         let mut builder = LibraryBuilder::new();
         let code = builder.code(
             "libname",
             "
-context ctx is
-end context;
-
-context work.ctx;
-context work.missing_ctx;
-
-entity dummy is
-end entity;
-            ",
+package pkg is
+  function swap generic (type t) parameter (value : t) return t;
+  procedure proc generic (type t) parameter (value : missing);
+end package;",
         );
 
         let messages = builder.analyze();
-
         check_messages(
             messages,
             vec![Message::error(
-                code.s1("missing_ctx"),
-                "No primary unit 'missing_ctx' within 'libname'",
+                code.s1("missing"),
+                "No declaration of 'missing'",
+                MessageCode::UnresolvedName,
             )],
-        )
+        );
     }
 
     #[test]
-    fn check_context_reference_for_non_context() {
+    fn resolves_vhdl_2019_declarations() {
         let mut builder = LibraryBuilder::new();
-        let code = builder.code(
+        let code = builder.code_with_standard(
             "libname",
             "
 package pkg is
+  type bus_t is record
+    data : natural;
+    ack : boolean;
+  end record;
+
+  type empty_t is record
+  end record;
+
+  view master of bus_t is
+    data : out;
+    ack : in;
+  end view;
+
+  view slave of missing is
+    data : in;
+  end view;
+
+  type counter_t is protected
+    private variable count : natural;
+    private variable other : missing;
+  end protected;
 end package;
 
-context work.pkg;
+package body pkg is
+  type counter_t is protected body
+  end protected body;
+end package body;
 
-entity dummy is
-end entity;
-            ",
+use work.pkg.all;
+
+entity ent is
+  port (
+    m : view master;
+    s : view master'converse;
+    u : view undeclared;);
+end entity;",
+            VHDLStandard::VHDL2019,
         );
 
-        let messages = builder.analyze();
+        let missing = |occurence| {
+            Message::error(
+                code.s("missing", occurence),
+                "No declaration of 'missing'",
+                MessageCode::UnresolvedName,
+            )
+        };
 
+        let messages = builder.analyze();
         check_messages(
             messages,
-            vec![Message::error(
-                code.s("pkg", 2),
-                "'pkg' does not denote a context declaration",
-            )],
-        )
+            vec![
+                missing(1),
+                missing(2),
+                Message::error(
+                    code.s1("undeclared"),
+                    "No declaration of 'undeclared'",
+                    MessageCode::UnresolvedName,
+                ),
+            ],
+        );
     }
 
     #[test]
-    fn check_use_clause_and_context_clause_must_be_selected_name() {
+    fn generic_package_requires_vhdl_2008() {
         let mut builder = LibraryBuilder::new();
-        let code = builder.code(
+        let code = builder.code_with_standard(
             "libname",
             "
-library libname;
-
-context libname;
-use work;
-use libname;
-
-use work.pkg(0);
-context work.ctx'range;
+package gpkg is
+  generic (const : natural);
+end package;
 
-entity dummy is
-end entity;
-            ",
+package ipkg is new work.gpkg generic map (const => 0);
+",
+            VHDLStandard::VHDL1993,
         );
 
         let messages = builder.analyze();
-
         check_messages(
             messages,
             vec![
                 Message::error(
-                    code.s1("context libname;"),
-                    "Context reference must be a selected name",
-                ),
-                Message::error(code.s1("use work;"), "Use clause must be a selected name"),
-                Message::error(
-                    code.s1("use libname;"),
-                    "Use clause must be a selected name",
-                ),
-                Message::error(
-                    code.s1("use work.pkg(0);"),
-                    "Use clause must be a selected name",
+                    code.s1("gpkg"),
+                    "Generic package requires VHDL-2008 or later, the selected standard is VHDL-93",
+                    MessageCode::UnsupportedInStandard,
                 ),
                 Message::error(
-                    code.s1("context work.ctx'range;"),
-                    "Context reference must be a selected name",
+                    code.s1("ipkg"),
+                    "Package instantiation requires VHDL-2008 or later, the selected standard is VHDL-93",
+                    MessageCode::UnsupportedInStandard,
                 ),
             ],
         );
     }
 
     #[test]
-    fn check_two_stage_use_clause_for_missing_name() {
+    fn psl_directive_resolves_signal_names() {
         let mut builder = LibraryBuilder::new();
         let code = builder.code(
             "libname",
             "
-package pkg is
-  type enum_t is (alpha, beta);
-  constant const : enum_t := alpha;
-end package;
-
-use work.pkg;
-use pkg.const;
-use pkg.const2;
+entity ent is
+  port (clk, req, ack : in bit);
+end entity;
 
-package pkg2 is
-end package;
-            ",
+architecture rtl of ent is
+  -- psl default clock is (clk'event and clk = '1');
+  -- psl property handshake is always (req -> next ack);
+begin
+  -- psl assert handshake;
+  -- psl cover {req; ack; missing};
+end architecture;
+",
         );
+
         let messages = builder.analyze();
         check_messages(
             messages,
             vec![Message::error(
-                code.s1("const2"),
-                "No declaration of 'const2' within package 'libname.pkg'",
+                code.s1("missing"),
+                "No declaration of 'missing'",
+                MessageCode::UnresolvedName,
             )],
         );
     }
+
     #[test]
-    fn check_use_clause_for_missing_name_in_package() {
+    fn verification_unit_resolves_names_in_bound_architecture() {
         let mut builder = LibraryBuilder::new();
-        let code = builder.code(
+        builder.code(
             "libname",
             "
-package pkg is
-  type enum_t is (alpha, beta);
-  constant const : enum_t := alpha;
-end package;
+entity ent is
+  port (clk, req : in bit);
+end entity;
 
-use work.pkg.const;
-use work.pkg.const2;
+architecture rtl of ent is
+  signal ack : bit;
+begin
+end architecture;
 
-package pkg2 is
-end package;
-            ",
+vunit checks(ent(rtl)) {
+  default clock is (clk'event and clk = '1');
+  sequence handshake is {req; [*]; ack};
+  assert always (handshake |=> not req);
+}
+",
+        );
+
+        let messages = builder.analyze();
+        check_no_messages(&messages);
+    }
+
+    #[test]
+    fn verification_unit_bound_to_missing_entity() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+vunit checks(missing) {
+}
+",
         );
+
         let messages = builder.analyze();
         check_messages(
             messages,
             vec![Message::error(
-                code.s1("const2"),
-                "No declaration of 'const2' within package 'libname.pkg'",
+                code.s1("missing"),
+                "No entity 'missing' within library 'libname'",
+                MessageCode::MissingPrimaryUnit,
             )],
         );
     }
 
     #[test]
-    fn check_use_clause_for_missing_name_in_package_instance() {
+    fn configuration_binds_existing_entities_and_instances() {
         let mut builder = LibraryBuilder::new();
-        let code = builder.code(
+        builder.code(
             "libname",
             "
-package gpkg is
-  generic (constant gconst : natural);
-  constant const : natural := 0;
-end package;
+entity sub is
+  generic (width : natural);
+  port (clk : in bit; data : out bit_vector(width-1 downto 0));
+end entity;
 
-package ipkg is new work.gpkg generic map (gconst => 0);
+architecture rtl of sub is
+begin
+end architecture;
 
-use work.ipkg.const;
-use work.ipkg.const2;
+entity ent is
+end entity;
 
--- @TODO should probably not be visible #19
--- use work.ipkg.gconst;
+architecture a of ent is
+  component comp is
+    generic (width : natural);
+    port (clk : in bit; data : out bit_vector(width-1 downto 0));
+  end component;
+  signal clk : bit;
+  signal data : bit_vector(7 downto 0);
+begin
+  inst : comp
+    generic map (width => 8)
+    port map (clk => clk, data => data);
 
-package pkg is
-end package;
-            ",
+  gen : for i in 0 to 1 generate
+    signal gen_data : bit_vector(7 downto 0);
+  begin
+    gen_inst : comp
+      generic map (8)
+      port map (clk, gen_data);
+  end generate;
+end architecture;
+
+configuration cfg of ent is
+  use work.all;
+  for a
+    for inst : comp
+      use entity work.sub(rtl)
+        generic map (width => width)
+        port map (clk => clk, data(7 downto 0) => data);
+    end for;
+    for gen(0)
+      for gen_inst : comp
+        use entity work.sub generic map (8) port map (clk, data);
+      end for;
+    end for;
+  end for;
+end configuration;
+",
         );
+
         let messages = builder.analyze();
-        check_messages(
-            messages,
-            vec![
-                // @TODO add use instance path in error message
-                Message::error(
-                    code.s1("const2"),
-                    "No declaration of 'const2' within package instance 'libname.ipkg'",
-                ),
-            ],
-        );
+        check_no_messages(&messages);
     }
 
     #[test]
-    fn use_clause_cannot_reference_potentially_visible_name() {
+    fn configuration_of_missing_architecture() {
         let mut builder = LibraryBuilder::new();
         let code = builder.code(
             "libname",
             "
-package pkg2 is
-  constant const1 : natural := 0;
-  constant const2 : natural := 0;
-end package;
-
-
-use work.pkg2.const1;
+entity ent is
+end entity;
 
-package pkg is
-  use work.pkg2.const2;
-  constant const3 : natural := 0;
-end package;
+configuration cfg of ent is
+  for missing
+  end for;
+end configuration;
+",
+        );
 
-use work.pkg.const1;
-use work.pkg.const2;
-use work.pkg.const3;
+        let messages = builder.analyze();
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s1("missing"),
+                "No architecture 'missing' of entity 'ent'",
+                MessageCode::UnresolvedName,
+            )],
+        );
+    }
 
+    #[test]
+    fn configuration_of_missing_instance_and_block() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
 entity ent is
 end entity;
-            ",
+
+architecture a of ent is
+  component comp is
+  end component;
+begin
+  inst : component comp;
+end architecture;
+
+configuration cfg of ent is
+  for a
+    for missing_inst : comp
+    end for;
+    for missing_block
+    end for;
+  end for;
+end configuration;
+",
         );
+
         let messages = builder.analyze();
         check_messages(
             messages,
             vec![
                 Message::error(
-                    code.s("const1", 3),
-                    "No declaration of 'const1' within package 'libname.pkg'",
+                    code.s1("missing_inst"),
+                    "No component instance 'missing_inst'",
+                    MessageCode::UnresolvedName,
                 ),
                 Message::error(
-                    code.s("const2", 3),
-                    "No declaration of 'const2' within package 'libname.pkg'",
+                    code.s1("missing_block"),
+                    "No block or generate statement 'missing_block'",
+                    MessageCode::UnresolvedName,
                 ),
             ],
         );
     }
 
     #[test]
-    fn error_on_use_clause_with_double_all() {
+    fn binding_maps_must_match_entity_interface() {
         let mut builder = LibraryBuilder::new();
         let code = builder.code(
             "libname",
             "
-package pkg1 is
-  constant const1 : natural := 0;
-end package;
+entity sub is
+  generic (width : natural);
+  port (clk : in bit);
+end entity;
 
-use work.all.all;
-use work.all.foo;
+architecture rtl of sub is
+begin
+end architecture;
 
 entity ent is
 end entity;
-            ",
+
+architecture a of ent is
+  component comp is
+  end component;
+  signal clk : bit;
+  for all : comp use entity work.sub(missing_arch) port map (missing_port => clk);
+begin
+  inst : component comp;
+end architecture;
+
+configuration cfg of ent is
+  for a
+    for inst : comp
+      use entity work.sub(rtl)
+        generic map (missing_generic => 8)
+        port map (clk, clk);
+    end for;
+  end for;
+end configuration;
+
+configuration cfg2 of ent is
+  for a
+    for inst : comp
+      use entity work.cfg;
+    end for;
+  end for;
+end configuration;
+",
         );
+
         let messages = builder.analyze();
         check_messages(
             messages,
             vec![
                 Message::error(
-                    code.s("work.all", 1),
-                    "'.all' may not be the prefix of a selected name",
+                    code.s1("missing_arch"),
+                    "No architecture 'missing_arch' of entity 'sub'",
+                    MessageCode::UnresolvedName,
                 ),
                 Message::error(
-                    code.s("work.all", 2),
-                    "'.all' may not be the prefix of a selected name",
+                    code.s1("missing_port"),
+                    "No port 'missing_port' in entity 'sub'",
+                    MessageCode::BindingMismatch,
+                ),
+                Message::error(
+                    code.s1("missing_generic"),
+                    "No generic 'missing_generic' in entity 'sub'",
+                    MessageCode::BindingMismatch,
+                ),
+                Message::error(
+                    code.s("clk", 5),
+                    "Too many port associations for entity 'sub'",
+                    MessageCode::BindingMismatch,
+                ),
+                Message::error(
+                    code.s1("work.cfg"),
+                    "'work.cfg' does not denote an entity",
+                    MessageCode::BindingMismatch,
                 ),
             ],
         );
     }
 
     #[test]
-    fn an_uninstantiated_package_may_not_be_prefix_of_selected_name() {
+    fn attribute_specifications_name_entities_of_the_class() {
         let mut builder = LibraryBuilder::new();
         let code = builder.code(
             "libname",
             "
-package gpkg is
-  generic (const : natural);
-end package;
-
-use work.gpkg.all;
-
 package pkg is
-  use work.gpkg.const;
+  attribute attr : natural;
+
+  type enum_t is (alpha, 'b');
+  type distance is range 0 to 1000
+    units
+      mm;
+      cm = 10 mm;
+    end units;
+  subtype sub_t is natural;
+  constant const : natural := 0;
+  signal sig : bit;
+  shared variable var : natural;
+  file fh : std.textio.text;
+  component comp is
+  end component;
+  procedure proc;
+  function fun return natural;
+
+  group pin2pin is (signal, signal);
+  group path : pin2pin (sig, sig);
+
+  attribute attr of enum_t : type is 0;
+  attribute attr of alpha, 'b' : literal is 0;
+  attribute attr of cm : units is 0;
+  attribute attr of sub_t : subtype is 0;
+  attribute attr of const : constant is 0;
+  attribute attr of sig : signal is 0;
+  attribute attr of var : variable is 0;
+  attribute attr of fh : file is 0;
+  attribute attr of comp : component is 0;
+  attribute attr of proc : procedure is 0;
+  attribute attr of fun : function is 0;
+  attribute attr of path : group is 0;
+  attribute attr of pkg : package is 0;
+  attribute attr of all : signal is 0;
+
+  attribute attr of const : signal is 0;
+  attribute attr of missing : signal is 0;
 end package;
-            ",
+",
         );
+
         let messages = builder.analyze();
         check_messages(
             messages,
             vec![
                 Message::error(
-                    code.s("work.gpkg", 1),
-                    "Uninstantiated generic package 'libname.gpkg' may not be the prefix of a selected name",
+                    code.s1("const : signal").s1("const"),
+                    "'const' is not of entity class signal",
+                    MessageCode::EntityClassMismatch,
                 ),
                 Message::error(
-                    code.s("work.gpkg", 2),
-                    "Uninstantiated generic package 'libname.gpkg' may not be the prefix of a selected name",
+                    code.s1("missing"),
+                    "No declaration of 'missing'",
+                    MessageCode::UnresolvedName,
                 ),
             ],
         );
     }
 
     #[test]
-    fn package_name_must_be_visible_in_package_instance() {
+    fn group_declaration_denotes_group_template() {
         let mut builder = LibraryBuilder::new();
         let code = builder.code(
             "libname",
             "
-package gpkg is
-  generic (const : natural);
-end package;
-
-package ipkg_err is new gpkg generic map (const => 0);
-package ipkg_ok is new work.gpkg generic map (const => 0);
-
-package nested is
-  package ipkg_err is new gpkg generic map (const => 0);
-  package ipkg_ok is new work.gpkg generic map (const => 0);
-end package;
-            ",
+entity ent is
+  port (clk : in bit; q : out bit);
+  group pin2pin is (signal, signal);
+  group path : pin2pin (clk, q);
+  group bad_path : clk (clk, q);
+  group missing_path : missing (clk, q);
+end entity;
+",
         );
+
         let messages = builder.analyze();
         check_messages(
             messages,
             vec![
-                Message::error(code.s("gpkg", 2), "No declaration of 'gpkg'"),
-                Message::error(code.s("gpkg", 4), "No declaration of 'gpkg'"),
+                Message::error(
+                    code.s1("clk (clk").s1("clk"),
+                    "'clk' does not denote a group template",
+                    MessageCode::EntityClassMismatch,
+                ),
+                Message::error(
+                    code.s1(": missing").s1("missing"),
+                    "No declaration of 'missing'",
+                    MessageCode::UnresolvedName,
+                ),
             ],
         );
     }
 
     #[test]
-    fn package_name_must_be_an_uninstantiated_package_in_package_instance() {
+    fn attribute_specification_checks_every_overload() {
         let mut builder = LibraryBuilder::new();
         let code = builder.code(
             "libname",
             "
 package pkg is
-  constant const : natural := 0;
-end package;
-
-package ipkg is new work.pkg generic map (const => 0);
+  attribute attr : natural;
 
-package nested is
-  package ipkg2 is new work.pkg.const generic map (const => 0);
+  type enum_t is (alpha, beta);
+  function alpha return natural;
+  procedure proc;
+  procedure overloaded;
+  function overloaded return natural;
+
+  attribute attr of alpha : literal is 0;
+  attribute attr of alpha : function is 0;
+  attribute attr of overloaded : procedure is 0;
+  attribute attr of overloaded : function is 0;
+
+  attribute attr of proc : function is 0;
+  attribute attr of beta : function is 0;
 end package;
-            ",
+",
         );
+
         let messages = builder.analyze();
         check_messages(
             messages,
             vec![
                 Message::error(
-                    code.s1("work.pkg"),
-                    "'pkg' is not an uninstantiated generic package",
+                    code.s1("proc : function").s1("proc"),
+                    "'proc' is not of entity class function",
+                    MessageCode::EntityClassMismatch,
                 ),
                 Message::error(
-                    code.s1("work.pkg.const"),
-                    "'const' is not an uninstantiated generic package",
+                    code.s1("beta : function").s1("beta"),
+                    "'beta' is not of entity class function",
+                    MessageCode::EntityClassMismatch,
                 ),
             ],
         );
     }
 
     #[test]
-    fn use_clause_with_selected_all_design_units() {
+    fn group_template_is_not_of_entity_class_group() {
         let mut builder = LibraryBuilder::new();
-        builder.code(
+        let code = builder.code(
             "libname",
             "
-package pkg1 is
-  constant const1 : natural := 0;
-end package;
-
-package pkg2 is
-  constant const2 : natural := 0;
-end package;
-
-use work.all;
-use pkg1.const1;
-use pkg2.const2;
-
 entity ent is
+  port (clk : in bit; q : out bit);
+  attribute attr : natural;
+  group pin2pin is (signal, signal);
+  group path : pin2pin (clk, q);
+  attribute attr of path : group is 0;
+  attribute attr of pin2pin : group is 0;
 end entity;
-            ",
+",
         );
-        let messages = builder.analyze();
-        check_no_messages(&messages);
-    }
-
-    #[test]
-    fn use_clause_with_selected_all_names() {
-        let mut builder = LibraryBuilder::new();
-        builder.code(
-            "libname",
-            "
-package pkg1 is
-  type enum_t is (alpha, beta);
-end package;
-
-use work.pkg1.all;
-
-entity ent is
-end entity;
 
-architecture rtl of ent is
-  signal foo : enum_t;
-begin
-end architecture;
-            ",
-        );
         let messages = builder.analyze();
-        check_no_messages(&messages);
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s1("pin2pin : group").s1("pin2pin"),
+                "'pin2pin' is not of entity class group",
+                MessageCode::EntityClassMismatch,
+            )],
+        );
     }
 
     #[test]
-    fn detects_circular_dependencies() {
+    fn resolves_group_constituents() {
         let mut builder = LibraryBuilder::new();
         let code = builder.code(
             "libname",
             "
-use work.pkg2.const;
-
-package pkg1 is
-  constant const : natural := 0;
-end package;
-
-use work.pkg1.const;
-
-package pkg2 is
+entity ent is
+  port (clk : in bit; q : out bit);
   constant const : natural := 0;
-end package;",
+  group pin2pin is (signal, signal);
+  group signals is (signal <>);
+  group path : pin2pin (nosuch_sig, q);
+  group bad_path : pin2pin (clk, const);
+  group all_signals : signals (clk, q, const);
+  group labels is (label <>);
+  group labeled : labels (lbl, other_lbl);
+end entity;
+",
         );
+
         let messages = builder.analyze();
         check_messages(
             messages,
             vec![
                 Message::error(
-                    code.s1("work.pkg1"),
-                    "Found circular dependency when referencing 'libname.pkg1'",
+                    code.s1("nosuch_sig"),
+                    "No declaration of 'nosuch_sig'",
+                    MessageCode::UnresolvedName,
                 ),
                 Message::error(
-                    code.s1("work.pkg2"),
-                    "Found circular dependency when referencing 'libname.pkg2'",
+                    code.s1("(clk, const)").s1("const"),
+                    "'const' is not of entity class signal",
+                    MessageCode::EntityClassMismatch,
+                ),
+                Message::error(
+                    code.s1("q, const)").s1("const"),
+                    "'const' is not of entity class signal",
+                    MessageCode::EntityClassMismatch,
                 ),
             ],
         );
     }
 
     #[test]
-    fn detects_circular_dependencies_all() {
+    fn attribute_specification_of_design_units_and_labels() {
         let mut builder = LibraryBuilder::new();
         let code = builder.code(
             "libname",
             "
-use work.pkg2.all;
-
-package pkg1 is
-  constant const : natural := 0;
+package pkg is
+  attribute attr : natural;
+  attribute attr of pkg : package is 0;
+  attribute attr of other_pkg : package is 0;
 end package;
 
-use work.pkg1.all;
+use work.pkg.attr;
 
-package pkg2 is
-  constant const : natural := 0;
-end package;",
+entity ent is
+  attribute attr of ent : entity is 0;
+  attribute attr of a : architecture is 0;
+end entity;
+
+architecture a of ent is
+  attribute attr of a : architecture is 0;
+  attribute attr of ent : entity is 0;
+  attribute attr of lbl : label is 0;
+  attribute attr of missing_lbl : label is 0;
+  signal sig : bit;
+begin
+  lbl: sig <= '1';
+
+  blk: block
+    attribute attr of inner_lbl : label is 0;
+    attribute attr of lbl : label is 0;
+  begin
+    inner_lbl: sig <= '0';
+  end block;
+end architecture;
+",
         );
+
         let messages = builder.analyze();
         check_messages(
             messages,
             vec![
                 Message::error(
-                    code.s1("work.pkg1"),
-                    "Found circular dependency when referencing 'libname.pkg1'",
+                    code.s1("other_pkg"),
+                    "'other_pkg' does not denote the enclosing package",
+                    MessageCode::EntityClassMismatch,
                 ),
                 Message::error(
-                    code.s1("work.pkg2"),
-                    "Found circular dependency when referencing 'libname.pkg2'",
+                    code.s1("a : architecture").s1("a"),
+                    "'a' does not denote the enclosing architecture",
+                    MessageCode::EntityClassMismatch,
+                ),
+                Message::error(
+                    code.s("ent : entity", 2).s1("ent"),
+                    "'ent' does not denote the enclosing entity",
+                    MessageCode::EntityClassMismatch,
+                ),
+                Message::error(
+                    code.s1("missing_lbl"),
+                    "No statement labeled 'missing_lbl'",
+                    MessageCode::UnresolvedName,
+                ),
+                Message::error(
+                    code.s("of lbl : label", 2).s1("lbl"),
+                    "No statement labeled 'lbl'",
+                    MessageCode::UnresolvedName,
                 ),
             ],
         );
     }
 
-    #[test]
-    fn detects_circular_dependencies_only_when_used() {
-        let mut builder = LibraryBuilder::new();
-        builder.code(
-            "libname",
-            "
-use work.all;
-
-package pkg1 is
-  constant const : natural := 0;
-end package;
-
-use work.pkg1.const;
-
-package pkg2 is
-  constant const : natural := 0;
-end package;",
-        );
-        let messages = builder.analyze();
-        check_no_messages(&messages);
-    }
-
-    #[test]
-    fn resolves_type_mark_in_subtype_indications() {
-        let mut builder = LibraryBuilder::new();
-        let code = builder.code(
-            "libname",
-            "
-package pkg1 is
-  -- Object declaration
-  constant const : natural := 0;
-  constant const2 : missing := 0;
-
-  -- File declaration
-  file fil : std.textio.text;
-  file fil2 : missing;
-
-  -- Alias declaration
-  alias foo : natural is const;
-  alias foo2 : missing is const;
-
-  -- Array type definiton
-  type arr_t is array (natural range <>) of natural;
-  type arr_t2 is array (natural range <>) of missing;
-
-  -- Access type definiton
-  type acc_t is access natural;
-  type acc_t2 is access missing;
-
-  -- Subtype definiton
-  subtype sub_t is natural range 0 to 1;
-  subtype sub_t2 is missing range 0 to 1;
-
-  -- Record definition
-  type rec_t is record
-     f1 : natural;
-     f2 : missing;
-  end record;
-
-  -- Interface file
-  procedure p1 (fil : std.textio.text);
-  procedure p2 (fil : missing);
-
-  -- Interface object
-  function f1 (const : natural) return natural;
-  function f2 (const : missing) return natural;
-end package;",
+    #[test]
+    fn static_ranges_within_bounds_are_not_reported() {
+        let mut builder = LibraryBuilder::new();
+        builder.code(
+            "libname",
+            "
+package pkg is
+  constant width : natural := 8;
+  type word_t is range 0 to 2**width - 1;
+  type ratio_t is range -1.0 to 1.0;
+  type distance_t is range 0 to 1000 units mm; m = 1000 mm; end units;
+  subtype byte_t is natural range 0 to width - 1;
+  subtype short_delay_t is delay_length range 1 ns to 10 ns;
+  subtype half_t is ratio_t range 0.0 to 0.5;
+  subtype word_range_t is word_t range word_t'low to word_t'high;
+end package;
+",
         );
 
-        let expected = (0..9)
-            .map(|idx| Message::error(code.s("missing", 1 + idx), "No declaration of 'missing'"))
-            .collect();
+        let messages = builder.analyze();
+        check_messages(messages, vec![]);
+    }
+
+    #[test]
+    fn null_ranges_are_reported() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package pkg is
+  type int_t is range 10 to 0;
+  type real_t is range 1.0 to -1.0;
+  subtype sub_t is natural range 5 to 4;
+end package;
+",
+        );
 
         let messages = builder.analyze();
-        check_messages(messages, expected);
+        check_messages(
+            messages,
+            vec![
+                Message::warning(
+                    code.s1("10 to 0"),
+                    "Range 10 to 0 is a null range",
+                    MessageCode::NullRange,
+                ),
+                Message::warning(
+                    code.s1("1.0 to -1.0"),
+                    "Range 1.0 to -1.0 is a null range",
+                    MessageCode::NullRange,
+                ),
+                Message::warning(
+                    code.s1("5 to 4"),
+                    "Range 5 to 4 is a null range",
+                    MessageCode::NullRange,
+                ),
+            ],
+        );
     }
 
     #[test]
-    fn resolves_return_type() {
+    fn range_constraint_out_of_bounds() {
         let mut builder = LibraryBuilder::new();
         let code = builder.code(
             "libname",
             "
 package pkg is
-  function f1 (const : natural) return natural;
-  function f2 (const : natural) return missing;
-end package;",
+  type ratio_t is range -1.0 to 1.0;
+  subtype neg_t is natural range -1 to 10;
+  subtype big_t is ratio_t range 0.0 to 2.0;
+  subtype delay_t is delay_length range -1 ns to 1 ns;
+end package;
+",
         );
 
         let messages = builder.analyze();
         check_messages(
             messages,
-            vec![Message::error(
-                code.s1("missing"),
-                "No declaration of 'missing'",
-            )],
+            vec![
+                Message::error(
+                    code.s1("-1 to 10").s1("-1"),
+                    "-1 is out of the range 0 to 2147483647 of 'natural'",
+                    MessageCode::RangeOutOfBounds,
+                ),
+                Message::error(
+                    code.s1("2.0"),
+                    "2.0 is out of the range -1.0 to 1.0 of 'ratio_t'",
+                    MessageCode::RangeOutOfBounds,
+                ),
+                Message::error(
+                    code.s1("-1 ns"),
+                    "-1000000 fs is out of the range 0 fs to 9223372036854775807 fs of 'delay_length'",
+                    MessageCode::RangeOutOfBounds,
+                ),
+            ],
         );
     }
 
     #[test]
-    fn resolves_attribute_declaration_type_mark() {
+    fn range_type_kind_is_decided_by_the_type_of_the_bounds() {
         let mut builder = LibraryBuilder::new();
         let code = builder.code(
             "libname",
             "
 package pkg is
-  attribute attr : string;
-  attribute attr2 : missing;
-end package;",
+  constant lo : real := 0.0;
+  constant hi : real := 1.0;
+  constant int_hi : natural := 10;
+  type lo_hi_t is range lo to hi;
+  type full_real_t is range real'low to real'high;
+  type int_t is range 0 to int_hi;
+  subtype half_t is lo_hi_t range 0.0 to 0.5;
+  subtype big_t is lo_hi_t range lo to 2.0;
+  subtype int_sub_t is lo_hi_t range 0 to 1;
+  subtype real_sub_t is int_t range lo to hi;
+  subtype positive_real_t is full_real_t range 0.0 to real'high;
+  type mixed_t is range 0 to hi;
+end package;
+",
         );
 
         let messages = builder.analyze();
         check_messages(
             messages,
-            vec![Message::error(
-                code.s1("missing"),
-                "No declaration of 'missing'",
-            )],
+            vec![
+                Message::error(
+                    code.s1("2.0"),
+                    "2.0 is out of the range 0.0 to 1.0 of 'lo_hi_t'",
+                    MessageCode::RangeOutOfBounds,
+                ),
+                Message::error(
+                    code.s1("range 0 to 1").s1("0 to 1"),
+                    "Range 0 to 1 is not of the floating-point type 'lo_hi_t'",
+                    MessageCode::RangeTypeMismatch,
+                ),
+                Message::error(
+                    code.s1("int_t range lo to hi").s1("lo to hi"),
+                    "Range 0.0 to 1.0 is not of the integer type 'int_t'",
+                    MessageCode::RangeTypeMismatch,
+                ),
+                Message::error(
+                    code.s1("0 to hi"),
+                    "Range 0 to 1.0 of a type definition shall have both integer or both real bounds",
+                    MessageCode::RangeTypeMismatch,
+                ),
+            ],
         );
     }
 
     #[test]
-    fn protected_type_is_visible_in_declaration() {
+    fn evaluates_static_values_of_constants() {
         let mut builder = LibraryBuilder::new();
-        builder.code(
+        let code = builder.code(
             "libname",
             "
-package pkg1 is
-  type prot_t is protected
-     procedure proc(val : inout prot_t);
-  end protected;
+entity ent is
+  generic (addr_w : natural := 10; data_w : natural := 2 * addr_w; n : natural);
+end entity;
 
-  type prot_t is protected body
-     procedure proc(val : inout prot_t) is
-     begin
-     end;
-  end protected body;
-end package;",
+architecture a of ent is
+  type state_t is (idle, busy, done);
+  type word_t is array (0 to data_w - 1) of bit;
+  constant depth : natural := 2**addr_w;
+  constant last_state : state_t := state_t'val(state_t'pos(idle) + 2);
+  constant next_state : state_t := state_t'succ(idle);
+  constant word_len : natural := word_t'length;
+  constant is_wide : boolean := data_w > 16 and not (depth = 0);
+  constant rounded : integer := integer(2.5) + integer(-2.5);
+  constant half : real := real(depth) / 2.0;
+  constant not_static : natural := depth + n;
+begin
+end architecture;
+",
         );
 
-        let messages = builder.analyze();
+        let (messages, static_values) = builder.analyze_with_static_values();
         check_no_messages(&messages);
+
+        let value_of = |name: &str| {
+            let pos = code.s1(&format!("{} :", name)).s1(name).pos();
+            static_values
+                .iter()
+                .find(|(ident_pos, _)| *ident_pos == pos)
+                .map(|(_, value)| value.to_string())
+        };
+
+        assert_eq!(value_of("depth"), Some("1024".to_owned()));
+        assert_eq!(value_of("last_state"), Some("done".to_owned()));
+        assert_eq!(value_of("next_state"), Some("busy".to_owned()));
+        assert_eq!(value_of("word_len"), Some("20".to_owned()));
+        assert_eq!(value_of("is_wide"), Some("true".to_owned()));
+        assert_eq!(value_of("rounded"), Some("0".to_owned()));
+        assert_eq!(value_of("half"), Some("512.0".to_owned()));
+        assert_eq!(value_of("not_static"), None);
     }
 
     #[test]
-    fn use_all_in_package() {
+    fn evaluates_functions_of_ieee_math_real() {
         let mut builder = LibraryBuilder::new();
+        for builtin_file in BUILTIN_FILES.iter() {
+            if builtin_file.file_name().ends_with("/math_real.vhdl") {
+                builder.code_from_source("ieee", builtin_file.source());
+            }
+        }
+        builder.code(
+            "libname",
+            "
+package math_real is
+  function ceil (x : real) return real;
+end package;
+",
+        );
         let code = builder.code(
             "libname",
             "
-package pkg1 is
-  subtype typ is natural range 0 to 1;
+library ieee;
+use ieee.math_real.all;
+
+package pkg is
+  constant width : natural := integer(ceil(log2(real(1000))));
+  constant named : real := realmax(y => 2.0, x => 1.0);
+  constant mixed : real := realmin(3.0, y => 1.5);
+  constant rounded : real := round(x => 2.5);
 end package;
 
-use work.pkg1.all;
+use work.math_real.all;
 
 package pkg2 is
-  constant const : typ := 0;
-  constant const2 : missing := 0;
+  constant own : real := ceil(2.5);
 end package;
+",
+        );
+
+        let (messages, static_values) = builder.analyze_with_static_values();
+        check_no_messages(&messages);
+
+        let value_of = |name: &str| {
+            let pos = code.s1(&format!("{} :", name)).s1(name).pos();
+            static_values
+                .iter()
+                .find(|(ident_pos, _)| *ident_pos == pos)
+                .map(|(_, value)| value.to_string())
+        };
+
+        assert_eq!(value_of("width"), Some("10".to_owned()));
+        assert_eq!(value_of("named"), Some("2.0".to_owned()));
+        assert_eq!(value_of("mixed"), Some("1.5".to_owned()));
+        assert_eq!(value_of("rounded"), Some("3.0".to_owned()));
+        assert_eq!(value_of("own"), None);
+    }
 
+    #[test]
+    fn array_length_mismatch() {
+        let mut builder = LibraryBuilder::new();
+        let code = builder.code(
+            "libname",
+            "
+package pkg is
+  subtype byte_t is bit_vector(7 downto 0);
+  constant good_string : string(1 to 3) := \"abc\";
+  constant good_hex : byte_t := x\"ff\";
+  constant good_aggregate : bit_vector(0 to 1) := ('0', '1');
+  constant good_others : byte_t := (others => '0');
+  constant bad_string : string(1 to 3) := \"abcd\";
+  constant bad_octal : byte_t := o\"17\";
+  constant bad_aggregate : bit_vector(0 to 2) := ('0', '1');
+end package;
 ",
         );
+
         let messages = builder.analyze();
         check_messages(
             messages,
-            vec![Message::error(
-                code.s1("missing"),
-                "No declaration of 'missing'",
-            )],
+            vec![
+                Message::error(
+                    code.s1("\"abcd\""),
+                    "Expected value of length 3 for subtype 'string', got length 4",
+                    MessageCode::LengthMismatch,
+                ),
+                Message::error(
+                    code.s1("o\"17\""),
+                    "Expected value of length 8 for subtype 'byte_t', got length 6",
+                    MessageCode::LengthMismatch,
+                ),
+                Message::error(
+                    code.s("('0', '1')", 2),
+                    "Expected value of length 3 for subtype 'bit_vector', got length 2",
+                    MessageCode::LengthMismatch,
+                ),
+            ],
         );
     }
 
     #[test]
-    fn use_all_in_primary_package_instance() {
+    fn evaluates_selected_type_marks() {
         let mut builder = LibraryBuilder::new();
         let code = builder.code(
             "libname",
             "
-package gpkg is
-  generic (const : natural);
-  subtype typ is natural range 0 to 1;
+package pkg is
+  constant width : natural := 8;
+  subtype byte_t is bit_vector(width - 1 downto 0);
+  subtype small_t is integer range 0 to 3;
 end package;
 
-package ipkg is new work.gpkg generic map (const => 0);
-
-use work.ipkg.all;
+use work.pkg;
 
-package pkg is
-  constant const : typ := 0;
-  constant const2 : missing := 0;
-end package;
+entity ent is
+end entity;
 
+architecture a of ent is
+  constant bad_hex : work.pkg.byte_t := x\"fff\";
+  signal bad_range : pkg.small_t range 0 to 7;
+  signal bad_natural : std.standard.natural range -1 to 0;
+begin
+end architecture;
 ",
         );
+
         let messages = builder.analyze();
         check_messages(
             messages,
-            vec![Message::error(
-                code.s1("missing"),
-                "No declaration of 'missing'",
-            )],
+            vec![
+                Message::error(
+                    code.s1("x\"fff\""),
+                    "Expected value of length 8 for subtype 'work.pkg.byte_t', got length 12",
+                    MessageCode::LengthMismatch,
+                ),
+                Message::error(
+                    code.s1("0 to 7").s1("7"),
+                    "7 is out of the range 0 to 3 of 'pkg.small_t'",
+                    MessageCode::RangeOutOfBounds,
+                ),
+                Message::error(
+                    code.s1("-1"),
+                    "-1 is out of the range 0 to 2147483647 of 'std.standard.natural'",
+                    MessageCode::RangeOutOfBounds,
+                ),
+            ],
         );
     }
 
     #[test]
-    fn use_of_interface_package_declaration() {
+    fn for_generate_over_null_range() {
         let mut builder = LibraryBuilder::new();
         let code = builder.code(
             "libname",
             "
-package gpkg1 is
-  generic (const : natural);
-  subtype typ is natural range 0 to 1;
-end package;
+entity ent is
+  generic (width : natural := 0);
+end entity;
 
-package gpkg2 is
-  generic (package ipkg is new work.gpkg1 generic map (const => 1));
-  use ipkg.typ;
-  use ipkg.missing;
-end package;
+architecture a of ent is
+  constant count : natural := 8;
+begin
+  gen_empty: for i in count - 1 downto count generate
+  end generate;
+
+  gen_generic: for i in 0 to width - 1 generate
+  end generate;
+end architecture;
 ",
         );
 
         let messages = builder.analyze();
         check_messages(
             messages,
-            vec![Message::error(
-                code.s1("missing"),
-                "No declaration of 'missing' within package instance 'ipkg'",
+            vec![Message::warning(
+                code.s1("count - 1 downto count"),
+                "Range 7 downto 8 is a null range",
+                MessageCode::NullRange,
             )],
         );
     }
+
     #[test]
-    fn use_in_local_package_instance() {
+    fn resolves_targets_of_force_and_release() {
         let mut builder = LibraryBuilder::new();
         let code = builder.code(
             "libname",
             "
-package gpkg is
-  generic (const : natural);
-  subtype typ is natural range 0 to 1;
-end package;
-
-
-package pkg is
-  package ipkg is new work.gpkg generic map (const => 0);
-  use ipkg.typ;
-
-  constant const : typ := 0;
-  constant const2 : missing := 0;
-end package;
+entity ent is
+end entity;
 
+architecture a of ent is
+  type rec_t is record
+    field : bit;
+  end record;
+  signal s : bit_vector(0 to 1);
+  signal rec : rec_t;
+begin
+  process
+  begin
+    for i in s'range loop
+      s(i) <= force '1';
+    end loop;
+    rec.field <= force out '0';
+    if true then
+      missing <= force '1';
+    else
+      missing2(0) <= release;
+    end if;
+    s <= release;
+    wait;
+  end process;
+end architecture;
 ",
         );
+
         let messages = builder.analyze();
         check_messages(
             messages,
-            vec![Message::error(
-                code.s1("missing"),
-                "No declaration of 'missing'",
-            )],
+            vec![
+                Message::error(
+                    code.s1("missing <=").s1("missing"),
+                    "No declaration of 'missing'",
+                    MessageCode::UnresolvedName,
+                ),
+                Message::error(
+                    code.s1("missing2"),
+                    "No declaration of 'missing2'",
+                    MessageCode::UnresolvedName,
+                ),
+            ],
         );
     }
 
     #[test]
-    fn use_all_in_local_package_instance() {
+    fn resolves_names_in_disconnection_specification() {
         let mut builder = LibraryBuilder::new();
         let code = builder.code(
             "libname",
             "
-package gpkg is
-  generic (const : natural);
-  subtype typ is natural range 0 to 1;
-end package;
-
-
-package pkg is
-  package ipkg is new work.gpkg generic map (const => 0);
-  use ipkg.all;
-
-  constant const : typ := 0;
-  constant const2 : missing := 0;
-end package;
+entity ent is
+end entity;
 
+architecture a of ent is
+  signal s : bit register;
+  disconnect s : bit after 1 ns;
+  disconnect missing : bit after 1 ns;
+  disconnect others : missing_t after 1 ns;
+begin
+end architecture;
 ",
         );
+
         let messages = builder.analyze();
         check_messages(
             messages,
-            vec![Message::error(
-                code.s1("missing"),
-                "No declaration of 'missing'",
-            )],
+            vec![
+                Message::error(
+                    code.s1("missing"),
+                    "No declaration of 'missing'",
+                    MessageCode::UnresolvedName,
+                ),
+                Message::error(
+                    code.s1("missing_t"),
+                    "No declaration of 'missing_t'",
+                    MessageCode::UnresolvedName,
+                ),
+            ],
         );
     }
-
 }
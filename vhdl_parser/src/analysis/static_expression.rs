@@ -0,0 +1,967 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
+
+//! LRM 9.4 Static expressions
+//! Evaluation of locally and globally static expressions such as the bounds of scalar types,
+//! the length of array subtypes and the value of constants
+
+use self::fnv::FnvHashMap;
+use super::declarative_region::{AnyDeclaration, DeclarativeRegion, PrimaryUnitData};
+use super::library::PackageDesignUnit;
+use crate::ast::*;
+use crate::source::WithPos;
+use crate::symbol_table::Symbol;
+use fnv;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+/// Guards against circular constant and type definitions
+const MAX_DEPTH: usize = 32;
+
+/// The value of a static expression
+#[derive(PartialEq, Debug, Clone)]
+pub enum Value {
+    Integer(i64),
+    Real(f64),
+    /// A physical value as a number of primary units
+    Physical(i64, Symbol),
+    /// The position number and literal of an enumeration value
+    Enumeration(i64, Designator),
+    Boolean(bool),
+}
+
+impl Value {
+    fn partial_cmp(&self, other: &Value) -> Option<Ordering> {
+        match (self, other) {
+            (Value::Integer(left), Value::Integer(right)) => Some(left.cmp(right)),
+            (Value::Real(left), Value::Real(right)) => left.partial_cmp(right),
+            (Value::Physical(left, _), Value::Physical(right, _)) => Some(left.cmp(right)),
+            (Value::Enumeration(left, _), Value::Enumeration(right, _)) => Some(left.cmp(right)),
+            (Value::Boolean(left), Value::Boolean(right)) => Some(left.cmp(right)),
+            _ => None,
+        }
+    }
+
+    /// LRM 16.2.2 The position number of a value of a discrete or physical type
+    pub fn position(&self) -> Option<i64> {
+        match self {
+            Value::Integer(value) | Value::Physical(value, _) | Value::Enumeration(value, _) => {
+                Some(*value)
+            }
+            Value::Boolean(value) => Some(i64::from(*value)),
+            Value::Real(..) => None,
+        }
+    }
+
+    /// The value of the same type with another position number
+    fn with_position(&self, position: i64) -> Value {
+        match self {
+            Value::Physical(_, unit) => Value::Physical(position, unit.clone()),
+            _ => Value::Integer(position),
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Integer(value) => write!(f, "{}", value),
+            Value::Real(value) => write!(f, "{:?}", value),
+            Value::Physical(value, unit) => write!(f, "{} {}", value, unit),
+            Value::Enumeration(_, literal) => write!(f, "{}", literal),
+            Value::Boolean(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// The evaluated bounds of a scalar range
+#[derive(PartialEq, Debug, Clone)]
+pub struct ScalarRange {
+    pub left: Value,
+    pub direction: Direction,
+    pub right: Value,
+}
+
+impl ScalarRange {
+    fn low_high(&self) -> (&Value, &Value) {
+        match self.direction {
+            Direction::Ascending => (&self.left, &self.right),
+            Direction::Descending => (&self.right, &self.left),
+        }
+    }
+
+    /// True if the range contains no values, None if the bounds cannot be compared
+    pub fn is_null(&self) -> Option<bool> {
+        let (low, high) = self.low_high();
+        low.partial_cmp(high)
+            .map(|ordering| ordering == Ordering::Greater)
+    }
+
+    /// True if the value is within the range, None if the value cannot be compared
+    pub fn contains(&self, value: &Value) -> Option<bool> {
+        let (low, high) = self.low_high();
+        let above_low = value.partial_cmp(low)? != Ordering::Less;
+        let below_high = value.partial_cmp(high)? != Ordering::Greater;
+        Some(above_low && below_high)
+    }
+
+    /// The number of values of a discrete range
+    pub fn length(&self) -> Option<i64> {
+        let (low, high) = self.low_high();
+        let length = high
+            .position()?
+            .checked_sub(low.position()?)?
+            .checked_add(1)?;
+        Some(length.max(0))
+    }
+
+    /// LRM 5.2.3 and 5.2.5 The kind of type defined by a range type definition with these bounds
+    /// None if the bounds are not both integer or both real
+    pub fn range_type_kind(&self) -> Option<RangeTypeKind> {
+        match (&self.left, &self.right) {
+            (Value::Integer(..), Value::Integer(..)) => Some(RangeTypeKind::Integer),
+            (Value::Real(..), Value::Real(..)) => Some(RangeTypeKind::Floating),
+            _ => None,
+        }
+    }
+
+    fn reversed(self) -> ScalarRange {
+        ScalarRange {
+            left: self.right,
+            direction: match self.direction {
+                Direction::Ascending => Direction::Descending,
+                Direction::Descending => Direction::Ascending,
+            },
+            right: self.left,
+        }
+    }
+}
+
+impl Display for ScalarRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let direction = match self.direction {
+            Direction::Ascending => "to",
+            Direction::Descending => "downto",
+        };
+        write!(f, "{} {} {}", self.left, direction, self.right)
+    }
+}
+
+/// A range type definition defines either an integer or a floating-point type
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum RangeTypeKind {
+    Integer,
+    Floating,
+}
+
+impl Display for RangeTypeKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RangeTypeKind::Integer => write!(f, "integer"),
+            RangeTypeKind::Floating => write!(f, "floating-point"),
+        }
+    }
+}
+
+/// The design units outside of the declarative region which static expressions may refer to
+pub trait StaticContext<'a> {
+    /// The analysis result of the package denoted by the prefix of a selected name
+    fn package_data(
+        &self,
+        region: &DeclarativeRegion<'_, 'a>,
+        prefix: &SelectedName,
+    ) -> Option<Arc<PrimaryUnitData<'a>>>;
+
+    /// The ieee.math_real package whose functions are evaluated
+    fn math_real_package(&self) -> Option<&'a PackageDesignUnit>;
+}
+
+/// Evaluates static expressions using the constants and types visible within a region
+/// Expressions which are not static or not yet supported evaluate to None
+pub struct StaticEvaluator<'r, 'a> {
+    region: &'r DeclarativeRegion<'r, 'a>,
+    /// The values of generic constants supplied at elaboration
+    generics: Option<&'r FnvHashMap<Symbol, Value>>,
+    /// Selected names and ieee.math_real functions are only evaluated with a context
+    context: Option<&'r dyn StaticContext<'a>>,
+}
+
+impl<'r, 'a> StaticEvaluator<'r, 'a> {
+    pub fn new(region: &'r DeclarativeRegion<'r, 'a>) -> StaticEvaluator<'r, 'a> {
+        StaticEvaluator {
+            region,
+            generics: None,
+            context: None,
+        }
+    }
+
+    /// Globally static expressions may also use the values of generic constants
+    pub fn with_generics(self, generics: &'r FnvHashMap<Symbol, Value>) -> StaticEvaluator<'r, 'a> {
+        StaticEvaluator {
+            generics: Some(generics),
+            ..self
+        }
+    }
+
+    /// Selected type marks are evaluated within the package which the context provides
+    pub fn with_context(self, context: &'r dyn StaticContext<'a>) -> StaticEvaluator<'r, 'a> {
+        StaticEvaluator {
+            context: Some(context),
+            ..self
+        }
+    }
+
+    pub fn evaluate(&self, expr: &Expression) -> Option<Value> {
+        self.evaluate_depth(expr, 0)
+    }
+
+    /// The values of the generic constants which are supplied or have a static default value
+    /// A default value may depend on the generic constants declared before it
+    pub fn generic_values(
+        &self,
+        generics: &[InterfaceDeclaration],
+        supplied: FnvHashMap<Symbol, Value>,
+    ) -> FnvHashMap<Symbol, Value> {
+        let mut values = supplied;
+        for generic in generics.iter() {
+            if let InterfaceDeclaration::Object(InterfaceObjectDeclaration {
+                class: ObjectClass::Constant,
+                ref ident,
+                expression: Some(ref expr),
+                ..
+            }) = generic
+            {
+                if values.contains_key(&ident.item) {
+                    continue;
+                }
+                let evaluator = StaticEvaluator {
+                    region: self.region,
+                    generics: Some(&values),
+                    context: self.context,
+                };
+                if let Some(value) = evaluator.evaluate(&expr.item) {
+                    values.insert(ident.item.clone(), value);
+                }
+            }
+        }
+        values
+    }
+
+    /// The number of elements of the first index range of a constrained array subtype
+    pub fn array_length(&self, subtype_indication: &SubtypeIndication) -> Option<i64> {
+        match subtype_indication.constraint {
+            Some(WithPos {
+                item: SubtypeConstraint::Array(ref ranges, _),
+                ..
+            }) => self.evaluate_discrete_range(ranges.first()?)?.length(),
+            Some(_) => None,
+            None => self.array_type_mark_length(&subtype_indication.type_mark.item, 0),
+        }
+    }
+
+    /// The range of a scalar type or subtype
+    pub fn type_mark_range(&self, type_mark: &SelectedName) -> Option<ScalarRange> {
+        self.type_mark_range_depth(type_mark, 0)
+    }
+
+    pub fn evaluate_range(&self, range: &Range) -> Option<ScalarRange> {
+        self.evaluate_range_depth(range, 0)
+    }
+
+    pub fn evaluate_discrete_range(&self, discrete_range: &DiscreteRange) -> Option<ScalarRange> {
+        self.evaluate_discrete_range_depth(discrete_range, 0)
+    }
+
+    fn evaluate_depth(&self, expr: &Expression, depth: usize) -> Option<Value> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+
+        match expr {
+            Expression::Literal(ref literal) => self.evaluate_literal(literal),
+            Expression::Name(ref name) => self.evaluate_name(name, depth),
+            Expression::Qualified(ref qexpr) => self.evaluate_depth(&qexpr.expr.item, depth + 1),
+            Expression::Unary(op, ref operand) => {
+                evaluate_unary(*op, self.evaluate_depth(&operand.item, depth + 1)?)
+            }
+            Expression::Binary(op, ref left, ref right) => evaluate_binary(
+                *op,
+                self.evaluate_depth(&left.item, depth + 1)?,
+                self.evaluate_depth(&right.item, depth + 1)?,
+            ),
+            _ => None,
+        }
+    }
+
+    fn evaluate_literal(&self, literal: &Literal) -> Option<Value> {
+        match literal {
+            Literal::AbstractLiteral(AbstractLiteral::Integer(value)) => {
+                Some(Value::Integer(*value))
+            }
+            Literal::AbstractLiteral(AbstractLiteral::Real(value)) => Some(Value::Real(*value)),
+            Literal::Physical(value, ref unit) => {
+                let (physical, _) = self.lookup_physical_unit(unit)?;
+                let scale = unit_scale(physical, unit, 0)?;
+                Some(Value::Physical(
+                    scale_abstract_literal(value, scale)?,
+                    physical.primary_unit.item.clone(),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    fn evaluate_name(&self, name: &Name, depth: usize) -> Option<Value> {
+        match name {
+            Name::Designator(ref designator) => {
+                let visible_decl = self.region.lookup(designator, true)?;
+                match visible_decl.decl {
+                    AnyDeclaration::Declaration(Declaration::Object(ObjectDeclaration {
+                        class: ObjectClass::Constant,
+                        expression: Some(ref expr),
+                        ..
+                    })) => self.evaluate_depth(&expr.item, depth + 1),
+                    AnyDeclaration::Interface(InterfaceDeclaration::Object(
+                        InterfaceObjectDeclaration {
+                            class: ObjectClass::Constant,
+                            ref ident,
+                            ..
+                        },
+                    )) => self.generics?.get(&ident.item).cloned(),
+                    AnyDeclaration::Enum(type_decl, literal) => {
+                        if let TypeDefinition::Enumeration(ref literals) = type_decl.def {
+                            let position =
+                                literals.iter().position(|lit| lit.item == literal.item)?;
+                            Some(enumeration_value(type_decl, literals, position as i64)?)
+                        } else {
+                            None
+                        }
+                    }
+                    AnyDeclaration::PhysicalUnit(physical, unit) => Some(Value::Physical(
+                        unit_scale(physical, &unit.item, 0)?,
+                        physical.primary_unit.item.clone(),
+                    )),
+                    _ => None,
+                }
+            }
+            Name::Attribute(ref attr) => {
+                if attr.signature.is_some() {
+                    return None;
+                }
+                let range = self.prefix_range(&attr.name.item, depth)?;
+                let attr_name = attr.attr.item.name_utf8().to_lowercase();
+
+                if let Some(ref expr) = attr.expr {
+                    let argument = self.evaluate_depth(&expr.item, depth + 1)?;
+                    return match attr_name.as_str() {
+                        "pos" => argument.position().map(Value::Integer),
+                        "val" => self.value_of_position(&range, argument.position()?, depth),
+                        "succ" => self.value_of_position(
+                            &range,
+                            argument.position()?.checked_add(1)?,
+                            depth,
+                        ),
+                        "pred" => self.value_of_position(
+                            &range,
+                            argument.position()?.checked_sub(1)?,
+                            depth,
+                        ),
+                        _ => None,
+                    };
+                }
+
+                let (low, high) = range.low_high();
+                match attr_name.as_str() {
+                    "left" => Some(range.left.clone()),
+                    "right" => Some(range.right.clone()),
+                    "low" => Some(low.clone()),
+                    "high" => Some(high.clone()),
+                    "length" => range.length().map(Value::Integer),
+                    "ascending" => Some(Value::Boolean(range.direction == Direction::Ascending)),
+                    _ => None,
+                }
+            }
+            Name::FunctionCall(ref call) => self.evaluate_function_call(call, depth),
+            _ => None,
+        }
+    }
+
+    /// The value of a scalar type with the given position number
+    fn value_of_position(&self, range: &ScalarRange, position: i64, depth: usize) -> Option<Value> {
+        let (low, _) = range.low_high();
+        match low {
+            Value::Boolean(..) => match position {
+                0 => Some(Value::Boolean(false)),
+                1 => Some(Value::Boolean(true)),
+                _ => None,
+            },
+            Value::Enumeration(..) => {
+                // The range of an enumeration subtype may not start at the first literal
+                let type_decl = self.enumeration_type_of(low, depth)?;
+                if let TypeDefinition::Enumeration(ref literals) = type_decl.def {
+                    enumeration_value(type_decl, literals, position)
+                } else {
+                    None
+                }
+            }
+            Value::Real(..) => None,
+            _ => Some(low.with_position(position)),
+        }
+    }
+
+    fn enumeration_type_of(&self, value: &Value, depth: usize) -> Option<&'a TypeDeclaration> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+
+        let literal = if let Value::Enumeration(_, ref literal) = value {
+            literal
+        } else {
+            return None;
+        };
+
+        match self.region.lookup(literal, true)?.decl {
+            AnyDeclaration::Enum(type_decl, _) => Some(type_decl),
+            _ => None,
+        }
+    }
+
+    /// LRM 9.3.6 Type conversions and calls to pure functions of ieee.math_real
+    fn evaluate_function_call(&self, call: &FunctionCall, depth: usize) -> Option<Value> {
+        let designator = if let Name::Designator(ref designator) = call.name.item {
+            designator
+        } else {
+            return None;
+        };
+
+        let mut positional = Vec::with_capacity(call.parameters.len());
+        let mut named = Vec::new();
+        for parameter in call.parameters.iter() {
+            let value = if let ActualPart::Expression(ref expr) = parameter.actual.item {
+                self.evaluate_depth(expr, depth + 1)?
+            } else {
+                return None;
+            };
+            match parameter.formal {
+                Some(WithPos {
+                    item: Name::Designator(ref formal),
+                    ..
+                }) => named.push((formal, value)),
+                Some(_) => {
+                    return None;
+                }
+                None if named.is_empty() => positional.push(value),
+                // Positional association may not follow named association
+                None => {
+                    return None;
+                }
+            }
+        }
+
+        let visible_decl = self.region.lookup(designator, true)?;
+        match visible_decl.decl {
+            AnyDeclaration::Declaration(Declaration::Type(..)) => {
+                if positional.len() != 1 || !named.is_empty() {
+                    return None;
+                }
+                let target = self.designator_range(designator, depth + 1)?;
+                convert_type(&target.left, positional.pop()?)
+            }
+            AnyDeclaration::Declaration(Declaration::SubprogramDeclaration(
+                SubprogramDeclaration::Function(ref function),
+            )) => {
+                if !function.pure || !self.is_math_real_function(function) {
+                    return None;
+                }
+                let arguments = associate_parameters(&function.parameter_list, positional, named)?;
+                evaluate_math_real_function(designator, &arguments)
+            }
+            _ => None,
+        }
+    }
+
+    fn is_math_real_function(&self, function: &FunctionSpecification) -> bool {
+        let package = match self.context.and_then(|context| context.math_real_package()) {
+            Some(package) => package,
+            None => {
+                return false;
+            }
+        };
+
+        package.package.unit.decl.iter().any(|decl| match decl {
+            Declaration::SubprogramDeclaration(SubprogramDeclaration::Function(ref decl)) => {
+                std::ptr::eq(decl, function)
+            }
+            _ => false,
+        })
+    }
+
+    /// Evaluate a selected name within the declarative region of the package denoted by its prefix
+    fn within_package<T>(
+        &self,
+        prefix: &SelectedName,
+        evaluate: impl FnOnce(&StaticEvaluator<'_, 'a>) -> Option<T>,
+    ) -> Option<T> {
+        let context = self.context?;
+        let data = context.package_data(self.region, prefix)?;
+        evaluate(&StaticEvaluator {
+            region: &data.region,
+            generics: None,
+            context: Some(context),
+        })
+    }
+
+    /// The range of the prefix of an attribute such as integer'high or vector'length
+    fn prefix_range(&self, prefix: &Name, depth: usize) -> Option<ScalarRange> {
+        match prefix {
+            Name::Designator(ref designator) => self.designator_range(designator, depth + 1),
+            _ => None,
+        }
+    }
+
+    fn lookup_physical_unit(
+        &self,
+        unit: &Symbol,
+    ) -> Option<(&'a PhysicalTypeDeclaration, &'a Ident)> {
+        let visible_decl = self
+            .region
+            .lookup(&Designator::Identifier(unit.clone()), true)?;
+        match visible_decl.decl {
+            AnyDeclaration::PhysicalUnit(physical, ident) => Some((physical, ident)),
+            _ => None,
+        }
+    }
+
+    fn type_mark_range_depth(&self, type_mark: &SelectedName, depth: usize) -> Option<ScalarRange> {
+        match type_mark {
+            SelectedName::Designator(ref designator) => self.designator_range(designator, depth),
+            SelectedName::Selected(ref prefix, ref suffix) => self
+                .within_package(&prefix.item, |evaluator| {
+                    evaluator.designator_range(&suffix.item, depth + 1)
+                }),
+        }
+    }
+
+    /// The range of a scalar type or the first index range of an array type or array object
+    fn designator_range(&self, designator: &Designator, depth: usize) -> Option<ScalarRange> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+
+        let visible_decl = self.region.lookup(designator, true)?;
+        let type_decl = match visible_decl.decl {
+            AnyDeclaration::Declaration(Declaration::Type(ref type_decl)) => type_decl,
+            AnyDeclaration::Declaration(Declaration::Object(ref object_decl)) => {
+                return self.subtype_indication_range(&object_decl.subtype_indication, depth + 1);
+            }
+            AnyDeclaration::Interface(InterfaceDeclaration::Object(ref object_decl)) => {
+                return self.subtype_indication_range(&object_decl.subtype_indication, depth + 1);
+            }
+            _ => {
+                return None;
+            }
+        };
+
+        match type_decl.def {
+            TypeDefinition::Enumeration(ref literals) => Some(ScalarRange {
+                left: enumeration_value(type_decl, literals, 0)?,
+                direction: Direction::Ascending,
+                right: enumeration_value(type_decl, literals, literals.len() as i64 - 1)?,
+            }),
+            TypeDefinition::Range(ref range) => self.evaluate_range_depth(range, depth + 1),
+            TypeDefinition::Physical(ref physical) => {
+                let range = self.evaluate_range_depth(&physical.range, depth + 1)?;
+                let to_physical = |value| match value {
+                    Value::Integer(value) => {
+                        Some(Value::Physical(value, physical.primary_unit.item.clone()))
+                    }
+                    _ => None,
+                };
+                Some(ScalarRange {
+                    left: to_physical(range.left)?,
+                    direction: range.direction,
+                    right: to_physical(range.right)?,
+                })
+            }
+            TypeDefinition::Array(ref indexes, _) => match indexes.first()? {
+                ArrayIndex::Discrete(ref discrete_range) => {
+                    self.evaluate_discrete_range_depth(discrete_range, depth + 1)
+                }
+                ArrayIndex::IndexSubtypeDefintion(..) => None,
+            },
+            TypeDefinition::Subtype(ref subtype_indication) => {
+                self.subtype_indication_range(subtype_indication, depth + 1)
+            }
+            _ => None,
+        }
+    }
+
+    fn subtype_indication_range(
+        &self,
+        subtype_indication: &SubtypeIndication,
+        depth: usize,
+    ) -> Option<ScalarRange> {
+        match subtype_indication.constraint {
+            Some(WithPos {
+                item: SubtypeConstraint::Range(ref range),
+                ..
+            }) => self.evaluate_range_depth(range, depth + 1),
+            Some(WithPos {
+                item: SubtypeConstraint::Array(ref ranges, _),
+                ..
+            }) => self.evaluate_discrete_range_depth(ranges.first()?, depth + 1),
+            Some(_) => None,
+            None => self.type_mark_range_depth(&subtype_indication.type_mark.item, depth + 1),
+        }
+    }
+
+    /// The length of an array type mark, scalar type marks have no length
+    fn array_type_mark_length(&self, type_mark: &SelectedName, depth: usize) -> Option<i64> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+
+        let designator = match type_mark {
+            SelectedName::Designator(ref designator) => designator,
+            SelectedName::Selected(ref prefix, ref suffix) => {
+                let type_mark = SelectedName::Designator(suffix.item.clone());
+                return self.within_package(&prefix.item, |evaluator| {
+                    evaluator.array_type_mark_length(&type_mark, depth + 1)
+                });
+            }
+        };
+
+        let type_decl = match self.region.lookup(designator, true)?.decl {
+            AnyDeclaration::Declaration(Declaration::Type(ref type_decl)) => type_decl,
+            _ => {
+                return None;
+            }
+        };
+
+        match type_decl.def {
+            TypeDefinition::Array(..) => self.designator_range(designator, depth + 1)?.length(),
+            TypeDefinition::Subtype(ref subtype_indication) => {
+                match subtype_indication.constraint {
+                    Some(WithPos {
+                        item: SubtypeConstraint::Array(ref ranges, _),
+                        ..
+                    }) => self
+                        .evaluate_discrete_range_depth(ranges.first()?, depth + 1)?
+                        .length(),
+                    Some(_) => None,
+                    None => {
+                        self.array_type_mark_length(&subtype_indication.type_mark.item, depth + 1)
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn evaluate_discrete_range_depth(
+        &self,
+        discrete_range: &DiscreteRange,
+        depth: usize,
+    ) -> Option<ScalarRange> {
+        match discrete_range {
+            DiscreteRange::Discrete(_, Some(ref range)) | DiscreteRange::Range(ref range) => {
+                self.evaluate_range_depth(range, depth + 1)
+            }
+            DiscreteRange::Discrete(ref type_mark, None) => {
+                self.type_mark_range_depth(&type_mark.item, depth + 1)
+            }
+        }
+    }
+
+    fn evaluate_range_depth(&self, range: &Range, depth: usize) -> Option<ScalarRange> {
+        match range {
+            Range::Range(ref constraint) => Some(ScalarRange {
+                left: self.evaluate_depth(&constraint.left_expr.item, depth + 1)?,
+                direction: constraint.direction,
+                right: self.evaluate_depth(&constraint.right_expr.item, depth + 1)?,
+            }),
+            Range::Attribute(ref attr) => {
+                if attr.signature.is_some() || attr.expr.is_some() {
+                    return None;
+                }
+                let range = self.prefix_range(&attr.name.item, depth)?;
+                match attr.attr.item.name_utf8().to_lowercase().as_str() {
+                    "range" => Some(range),
+                    "reverse_range" => Some(range.reversed()),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+fn is_boolean_type(type_decl: &TypeDeclaration) -> bool {
+    type_decl.ident.item.name_utf8().to_lowercase() == "boolean"
+}
+
+/// The value of the enumeration literal at a position, literals of boolean are boolean values
+fn enumeration_value(
+    type_decl: &TypeDeclaration,
+    literals: &[WithPos<EnumerationLiteral>],
+    position: i64,
+) -> Option<Value> {
+    if position < 0 {
+        return None;
+    }
+    let literal = literals.get(position as usize)?;
+    if is_boolean_type(type_decl) {
+        Some(Value::Boolean(position == 1))
+    } else {
+        Some(Value::Enumeration(
+            position,
+            literal.item.clone().into_designator(),
+        ))
+    }
+}
+
+/// LRM 9.3.6 Type conversions between integer and floating point types
+fn convert_type(target: &Value, value: Value) -> Option<Value> {
+    match (target, value) {
+        (Value::Integer(..), Value::Integer(value)) => Some(Value::Integer(value)),
+        // Rounding is away from zero when the value is halfway between two integers
+        (Value::Integer(..), Value::Real(value)) => {
+            let value = value.round();
+            if value >= i64::MIN as f64 && value <= i64::MAX as f64 {
+                Some(Value::Integer(value as i64))
+            } else {
+                None
+            }
+        }
+        (Value::Real(..), Value::Integer(value)) => Some(Value::Real(value as f64)),
+        (Value::Real(..), Value::Real(value)) => Some(Value::Real(value)),
+        _ => None,
+    }
+}
+
+/// LRM 6.5.7.1 The actual values in the order of the formal parameters
+fn associate_parameters(
+    formals: &[InterfaceDeclaration],
+    positional: Vec<Value>,
+    named: Vec<(&Designator, Value)>,
+) -> Option<Vec<Value>> {
+    if positional.len() + named.len() != formals.len() {
+        return None;
+    }
+
+    let mut arguments = positional;
+    for formal in formals.iter().skip(arguments.len()) {
+        let ident = match formal {
+            InterfaceDeclaration::Object(ref object_decl) => &object_decl.ident,
+            _ => {
+                return None;
+            }
+        };
+        let (_, value) = named.iter().find(|(designator, _)| match designator {
+            Designator::Identifier(ref name) => *name == ident.item,
+            _ => false,
+        })?;
+        arguments.push(value.clone());
+    }
+    Some(arguments)
+}
+
+/// The functions of ieee.math_real which have a simple implementation
+fn evaluate_math_real_function(designator: &Designator, arguments: &[Value]) -> Option<Value> {
+    let name = if let Designator::Identifier(ref sym) = designator {
+        sym.name_utf8().to_lowercase()
+    } else {
+        return None;
+    };
+
+    let real = |index: usize| match arguments.get(index) {
+        Some(Value::Real(value)) => Some(*value),
+        _ => None,
+    };
+
+    let value = match (name.as_str(), arguments.len()) {
+        ("ceil", 1) => real(0)?.ceil(),
+        ("floor", 1) => real(0)?.floor(),
+        ("round", 1) => real(0)?.round(),
+        ("trunc", 1) => real(0)?.trunc(),
+        ("sign", 1) => {
+            let value = real(0)?;
+            if value > 0.0 {
+                1.0
+            } else if value < 0.0 {
+                -1.0
+            } else {
+                0.0
+            }
+        }
+        ("sqrt", 1) if real(0)? >= 0.0 => real(0)?.sqrt(),
+        ("log2", 1) if real(0)? > 0.0 => real(0)?.log2(),
+        ("log10", 1) if real(0)? > 0.0 => real(0)?.log10(),
+        ("exp", 1) => real(0)?.exp(),
+        ("realmax", 2) => real(0)?.max(real(1)?),
+        ("realmin", 2) => real(0)?.min(real(1)?),
+        _ => {
+            return None;
+        }
+    };
+    Some(Value::Real(value))
+}
+
+/// The number of primary units of a unit of a physical type
+fn unit_scale(physical: &PhysicalTypeDeclaration, unit: &Symbol, depth: usize) -> Option<i64> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+
+    if physical.primary_unit.item == *unit {
+        return Some(1);
+    }
+
+    let (_, literal) = physical
+        .secondary_units
+        .iter()
+        .find(|(ident, _)| ident.item == *unit)?;
+
+    match literal {
+        Literal::Physical(value, ref base_unit) => {
+            let scale = unit_scale(physical, base_unit, depth + 1)?;
+            scale_abstract_literal(value, scale)
+        }
+        _ => None,
+    }
+}
+
+fn scale_abstract_literal(value: &AbstractLiteral, scale: i64) -> Option<i64> {
+    match value {
+        AbstractLiteral::Integer(value) => value.checked_mul(scale),
+        AbstractLiteral::Real(value) => Some((value * scale as f64).round() as i64),
+    }
+}
+
+fn evaluate_unary(op: Unary, operand: Value) -> Option<Value> {
+    match (op, operand) {
+        (Unary::Plus, operand) => Some(operand),
+        (Unary::Minus, Value::Integer(value)) => value.checked_neg().map(Value::Integer),
+        (Unary::Minus, Value::Real(value)) => Some(Value::Real(-value)),
+        (Unary::Minus, Value::Physical(value, unit)) => {
+            Some(Value::Physical(value.checked_neg()?, unit))
+        }
+        (Unary::Abs, Value::Integer(value)) => value.checked_abs().map(Value::Integer),
+        (Unary::Abs, Value::Real(value)) => Some(Value::Real(value.abs())),
+        (Unary::Abs, Value::Physical(value, unit)) => {
+            Some(Value::Physical(value.checked_abs()?, unit))
+        }
+        (Unary::Not, Value::Boolean(value)) => Some(Value::Boolean(!value)),
+        _ => None,
+    }
+}
+
+/// LRM 9.2.7 The mod operator gives a result with the sign of the right operand
+fn integer_mod(left: i64, right: i64) -> Option<i64> {
+    let rem = left.checked_rem(right)?;
+    if rem != 0 && (rem < 0) != (right < 0) {
+        rem.checked_add(right)
+    } else {
+        Some(rem)
+    }
+}
+
+fn evaluate_integer_binary(op: Binary, left: i64, right: i64) -> Option<i64> {
+    match op {
+        Binary::Plus => left.checked_add(right),
+        Binary::Minus => left.checked_sub(right),
+        Binary::Times => left.checked_mul(right),
+        Binary::Div => left.checked_div(right),
+        Binary::Mod => integer_mod(left, right),
+        Binary::Rem => left.checked_rem(right),
+        Binary::Pow => {
+            if right < 0 || right > i64::from(u32::MAX) {
+                None
+            } else {
+                left.checked_pow(right as u32)
+            }
+        }
+        _ => None,
+    }
+}
+
+/// LRM 9.2.3 Relational operators
+fn evaluate_relation(op: Binary, left: &Value, right: &Value) -> Option<Value> {
+    let ordering = left.partial_cmp(right)?;
+    let result = match op {
+        Binary::EQ => ordering == Ordering::Equal,
+        Binary::NE => ordering != Ordering::Equal,
+        Binary::LT => ordering == Ordering::Less,
+        Binary::LTE => ordering != Ordering::Greater,
+        Binary::GT => ordering == Ordering::Greater,
+        Binary::GTE => ordering != Ordering::Less,
+        _ => {
+            return None;
+        }
+    };
+    Some(Value::Boolean(result))
+}
+
+/// LRM 9.2.2 Logical operators
+fn evaluate_logical(op: Binary, left: bool, right: bool) -> Option<bool> {
+    match op {
+        Binary::And => Some(left && right),
+        Binary::Or => Some(left || right),
+        Binary::Nand => Some(!(left && right)),
+        Binary::Nor => Some(!(left || right)),
+        Binary::Xor => Some(left != right),
+        Binary::Xnor => Some(left == right),
+        _ => None,
+    }
+}
+
+fn evaluate_binary(op: Binary, left: Value, right: Value) -> Option<Value> {
+    if let Some(value) = evaluate_relation(op, &left, &right) {
+        return Some(value);
+    }
+
+    match (left, right) {
+        (Value::Boolean(left), Value::Boolean(right)) => {
+            evaluate_logical(op, left, right).map(Value::Boolean)
+        }
+        (Value::Integer(left), Value::Integer(right)) => {
+            evaluate_integer_binary(op, left, right).map(Value::Integer)
+        }
+        (Value::Real(left), Value::Real(right)) => match op {
+            Binary::Plus => Some(Value::Real(left + right)),
+            Binary::Minus => Some(Value::Real(left - right)),
+            Binary::Times => Some(Value::Real(left * right)),
+            Binary::Div if right != 0.0 => Some(Value::Real(left / right)),
+            _ => None,
+        },
+        (Value::Real(left), Value::Integer(right)) => match op {
+            Binary::Pow if right.abs() <= i64::from(i32::MAX) => {
+                Some(Value::Real(left.powi(right as i32)))
+            }
+            _ => None,
+        },
+        (Value::Physical(left, unit), Value::Physical(right, _)) => match op {
+            Binary::Plus | Binary::Minus | Binary::Mod | Binary::Rem => {
+                evaluate_integer_binary(op, left, right).map(|value| Value::Physical(value, unit))
+            }
+            Binary::Div => left.checked_div(right).map(Value::Integer),
+            _ => None,
+        },
+        (Value::Physical(left, unit), Value::Integer(right)) => match op {
+            Binary::Times | Binary::Div => {
+                evaluate_integer_binary(op, left, right).map(|value| Value::Physical(value, unit))
+            }
+            _ => None,
+        },
+        (Value::Integer(left), Value::Physical(right, unit)) => match op {
+            Binary::Times => left
+                .checked_mul(right)
+                .map(|value| Value::Physical(value, unit)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
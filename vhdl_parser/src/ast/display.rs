@@ -33,3 +33,78 @@ impl<T: Display> Display for WithPos<T> {
         write!(f, "{}", &self.item)
     }
 }
+
+impl Display for VerificationUnitKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            VerificationUnitKind::VUnit => write!(f, "vunit"),
+            VerificationUnitKind::VMode => write!(f, "vmode"),
+            VerificationUnitKind::VProp => write!(f, "vprop"),
+        }
+    }
+}
+
+impl Display for EntityClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let keyword = match self {
+            EntityClass::Entity => "entity",
+            EntityClass::Architecture => "architecture",
+            EntityClass::Configuration => "configuration",
+            EntityClass::Procedure => "procedure",
+            EntityClass::Function => "function",
+            EntityClass::Package => "package",
+            EntityClass::Type => "type",
+            EntityClass::Subtype => "subtype",
+            EntityClass::Constant => "constant",
+            EntityClass::Signal => "signal",
+            EntityClass::Variable => "variable",
+            EntityClass::Component => "component",
+            EntityClass::Label => "label",
+            EntityClass::Literal => "literal",
+            EntityClass::Units => "units",
+            EntityClass::Group => "group",
+            EntityClass::File => "file",
+            EntityClass::Property => "property",
+            EntityClass::Sequence => "sequence",
+        };
+        write!(f, "{}", keyword)
+    }
+}
+
+impl Display for AnyDesignUnit {
+    /// Show the kind and name of the design unit, such as 'architecture rtl of ent'
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            AnyDesignUnit::Primary(ref unit) => match unit {
+                PrimaryUnit::EntityDeclaration(ref unit) => {
+                    write!(f, "entity {}", unit.unit.ident.item)
+                }
+                PrimaryUnit::Configuration(ref unit) => {
+                    write!(f, "configuration {}", unit.unit.ident.item)
+                }
+                PrimaryUnit::PackageDeclaration(ref unit) => {
+                    write!(f, "package {}", unit.unit.ident.item)
+                }
+                PrimaryUnit::PackageInstance(ref unit) => {
+                    write!(f, "package {}", unit.unit.ident.item)
+                }
+                PrimaryUnit::ContextDeclaration(ref unit) => {
+                    write!(f, "context {}", unit.ident.item)
+                }
+                PrimaryUnit::VerificationUnit(ref unit) => {
+                    write!(f, "{} {}", unit.unit.kind, unit.unit.ident.item)
+                }
+            },
+            AnyDesignUnit::Secondary(ref unit) => match unit {
+                SecondaryUnit::Architecture(ref unit) => write!(
+                    f,
+                    "architecture {} of {}",
+                    unit.unit.ident.item, unit.unit.entity_name.item
+                ),
+                SecondaryUnit::PackageBody(ref unit) => {
+                    write!(f, "package body {}", unit.unit.ident.item)
+                }
+            },
+        }
+    }
+}
@@ -68,6 +68,12 @@ impl HasIdent for ConfigurationDeclaration {
     }
 }
 
+impl HasIdent for VerificationUnit {
+    fn ident(&self) -> &Ident {
+        &self.ident
+    }
+}
+
 impl HasIdent for PrimaryUnit {
     fn ident(&self) -> &Ident {
         match self {
@@ -76,6 +82,25 @@ impl HasIdent for PrimaryUnit {
             PrimaryUnit::PackageDeclaration(ref unit) => &unit.unit.ident,
             PrimaryUnit::PackageInstance(ref unit) => &unit.unit.ident,
             PrimaryUnit::ContextDeclaration(ref unit) => &unit.ident,
+            PrimaryUnit::VerificationUnit(ref unit) => &unit.unit.ident,
+        }
+    }
+}
+
+impl HasIdent for SecondaryUnit {
+    fn ident(&self) -> &Ident {
+        match self {
+            SecondaryUnit::Architecture(ref unit) => &unit.unit.ident,
+            SecondaryUnit::PackageBody(ref unit) => &unit.unit.ident,
+        }
+    }
+}
+
+impl HasIdent for AnyDesignUnit {
+    fn ident(&self) -> &Ident {
+        match self {
+            AnyDesignUnit::Primary(ref unit) => unit.ident(),
+            AnyDesignUnit::Secondary(ref unit) => unit.ident(),
         }
     }
 }
@@ -11,13 +11,15 @@
 mod display;
 mod has_ident;
 mod name;
+mod psl;
 
 pub use self::display::*;
 pub use self::has_ident::*;
 pub use self::name::*;
+pub use self::psl::*;
 
 use crate::latin_1::Latin1String;
-use crate::source::WithPos;
+use crate::source::{SrcPos, WithPos};
 use crate::symbol_table::Symbol;
 
 /// LRM 15.8 Bit string literals
@@ -332,10 +334,42 @@ pub struct ElementDeclaration {
     pub subtype: SubtypeIndication,
 }
 
+/// LRM 6.9 Group template declarations
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct EntityClassEntry {
+    pub entity_class: EntityClass,
+    /// The entry is followed by <> and may be repeated
+    pub is_box: bool,
+}
+
+/// LRM 6.9 Group template declarations
+#[derive(PartialEq, Debug, Clone)]
+pub struct GroupTemplateDeclaration {
+    pub ident: Ident,
+    pub entries: Vec<EntityClassEntry>,
+}
+
+/// LRM 6.10 Group declarations
+#[derive(PartialEq, Debug, Clone)]
+pub struct GroupDeclaration {
+    pub ident: Ident,
+    pub template_name: WithPos<SelectedName>,
+    pub constituents: Vec<WithPos<Name>>,
+}
+
+/// LRM 6.9 and 6.10 Groups
+#[derive(PartialEq, Debug, Clone)]
+pub enum Group {
+    Template(GroupTemplateDeclaration),
+    Declaration(GroupDeclaration),
+}
+
 /// LRM 5.6.2 Protected type declarations
 #[derive(PartialEq, Debug, Clone)]
 pub enum ProtectedTypeDeclarativeItem {
     Subprogram(SubprogramDeclaration),
+    /// VHDL-2019 private variable declaration
+    PrivateVariable(ObjectDeclaration),
 }
 
 #[derive(PartialEq, Eq, Hash, Debug, Clone)]
@@ -377,17 +411,27 @@ pub enum EntityName {
 }
 
 /// LRM 7.2 Attribute specification
-// @TODO there are more classes
 #[derive(PartialEq, Debug, Clone, Copy)]
 pub enum EntityClass {
     Entity,
     Architecture,
     Configuration,
+    Procedure,
+    Function,
     Package,
+    Type,
+    Subtype,
+    Constant,
     Signal,
     Variable,
-    Procedure,
-    Function,
+    Component,
+    Label,
+    Literal,
+    Units,
+    Group,
+    File,
+    Property,
+    Sequence,
 }
 
 /// LRM 7.2 Attribute specification
@@ -439,11 +483,11 @@ pub enum TypeDefinition {
     /// LRM 5.2 Scalar Types
     /// LRM 5.2.2 Enumeration types
     Enumeration(Vec<WithPos<EnumerationLiteral>>),
-    /// LRM 5.2.3 Integer types
-    Integer(Range),
+    /// LRM 5.2.3 Integer types and LRM 5.2.5 Floating-point types
+    /// The analysis tells them apart by the type of the bounds
+    Range(Range),
     /// LRM 5.2.4 Physical types
     Physical(PhysicalTypeDeclaration),
-    // @TODO floating
     /// LRM 5.3 Composite Types
     /// LRM 5.3.2 Array types
     Array(Vec<ArrayIndex>, SubtypeIndication),
@@ -473,17 +517,24 @@ pub struct TypeDeclaration {
 #[derive(PartialEq, Debug, Clone, Copy)]
 pub enum ObjectClass {
     Signal,
-    // @TODO signal_kind
     Constant,
     Variable,
     SharedVariable,
 }
 
+/// LRM 6.4.2.3 Signal kind of a guarded signal
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum SignalKind {
+    Register,
+    Bus,
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub struct ObjectDeclaration {
     pub class: ObjectClass,
     pub ident: Ident,
     pub subtype_indication: SubtypeIndication,
+    pub signal_kind: Option<SignalKind>,
     pub expression: Option<WithPos<Expression>>,
 }
 
@@ -505,6 +556,7 @@ pub enum SubprogramDesignator {
 #[derive(PartialEq, Debug, Clone)]
 pub struct ProcedureSpecification {
     pub designator: WithPos<SubprogramDesignator>,
+    pub generic_list: Option<Vec<InterfaceDeclaration>>,
     pub parameter_list: Vec<InterfaceDeclaration>,
 }
 
@@ -513,6 +565,7 @@ pub struct ProcedureSpecification {
 pub struct FunctionSpecification {
     pub pure: bool,
     pub designator: WithPos<SubprogramDesignator>,
+    pub generic_list: Option<Vec<InterfaceDeclaration>>,
     pub parameter_list: Vec<InterfaceDeclaration>,
     pub return_type: WithPos<SelectedName>,
 }
@@ -551,6 +604,7 @@ pub struct InterfaceObjectDeclaration {
     pub ident: Ident,
     pub mode: Mode,
     pub subtype_indication: SubtypeIndication,
+    pub signal_kind: Option<SignalKind>,
     pub expression: Option<WithPos<Expression>>,
 }
 
@@ -575,6 +629,13 @@ pub struct InterfacePackageDeclaration {
     pub generic_map: InterfacePackageGenericMapAspect,
 }
 
+/// VHDL-2019 interface signal declaration with a mode view
+#[derive(PartialEq, Debug, Clone)]
+pub struct InterfaceViewDeclaration {
+    pub ident: Ident,
+    pub view: ModeViewIndication,
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub enum InterfaceDeclaration {
     Object(InterfaceObjectDeclaration),
@@ -584,6 +645,7 @@ pub enum InterfaceDeclaration {
     Subprogram(SubprogramDeclaration, Option<SubprogramDefault>),
     /// LRM 6.5.5 Interface package declaration
     Package(InterfacePackageDeclaration),
+    View(InterfaceViewDeclaration),
 }
 
 #[derive(PartialEq, Debug, Clone, Copy)]
@@ -595,6 +657,35 @@ pub enum Mode {
     Linkage,
 }
 
+/// VHDL-2019 mode view indication
+/// view name [of subtype] for records and view (name) of subtype for arrays
+#[derive(PartialEq, Debug, Clone)]
+pub struct ModeViewIndication {
+    pub is_array: bool,
+    pub name: WithPos<Name>,
+    pub subtype_indication: Option<SubtypeIndication>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum ElementMode {
+    Simple(Mode),
+    View(ModeViewIndication),
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct ModeViewElement {
+    pub names: Vec<Ident>,
+    pub mode: ElementMode,
+}
+
+/// VHDL-2019 mode view declaration
+#[derive(PartialEq, Debug, Clone)]
+pub struct ModeViewDeclaration {
+    pub ident: Ident,
+    pub subtype_indication: SubtypeIndication,
+    pub elements: Vec<ModeViewElement>,
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub struct PortClause {
     pub port_list: Vec<InterfaceDeclaration>,
@@ -615,12 +706,16 @@ pub enum Declaration {
     Type(TypeDeclaration),
     Component(ComponentDeclaration),
     Attribute(Attribute),
+    Group(Group),
     Alias(AliasDeclaration),
     SubprogramDeclaration(SubprogramDeclaration),
     SubprogramBody(SubprogramBody),
     Use(WithPos<UseClause>),
     Package(PackageInstantiation),
     Configuration(ConfigurationSpecification),
+    Disconnection(DisconnectionSpecification),
+    View(ModeViewDeclaration),
+    Psl(PslDeclaration),
 }
 
 /// LRM 10.2 Wait statement
@@ -682,6 +777,28 @@ pub struct SignalAssignment {
     pub rhs: AssignmentRightHand<Waveform>,
 }
 
+/// LRM 10.5 Signal assignment statement
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum ForceMode {
+    In,
+    Out,
+}
+
+/// LRM 10.5 Signal assignment statement
+#[derive(PartialEq, Debug, Clone)]
+pub struct SignalForceAssignment {
+    pub target: WithPos<Target>,
+    pub force_mode: Option<ForceMode>,
+    pub rhs: AssignmentRightHand<WithPos<Expression>>,
+}
+
+/// LRM 10.5 Signal assignment statement
+#[derive(PartialEq, Debug, Clone)]
+pub struct SignalReleaseAssignment {
+    pub target: WithPos<Target>,
+    pub force_mode: Option<ForceMode>,
+}
+
 /// LRM 10.6 Variable assignment statement
 #[derive(PartialEq, Debug, Clone)]
 pub struct VariableAssignment {
@@ -773,6 +890,8 @@ pub enum SequentialStatement {
     Report(ReportStatement),
     VariableAssignment(VariableAssignment),
     SignalAssignment(SignalAssignment),
+    SignalForceAssignment(SignalForceAssignment),
+    SignalReleaseAssignment(SignalReleaseAssignment),
     ProcedureCall(FunctionCall),
     If(IfStatement),
     Case(CaseStatement),
@@ -781,6 +900,8 @@ pub enum SequentialStatement {
     Exit(ExitStatement),
     Return(ReturnStatement),
     Null,
+    /// A statement with a syntax error, the valid statements nested within it are kept
+    Error(Vec<LabeledSequentialStatement>),
 }
 
 /// LRM 10. Sequential statements
@@ -885,6 +1006,7 @@ pub enum ConcurrentStatement {
     ForGenerate(ForGenerateStatement),
     IfGenerate(IfGenerateStatement),
     CaseGenerate(CaseGenerateStatement),
+    PslDirective(PslDirective),
 }
 
 /// LRM 11. Concurrent statements
@@ -943,6 +1065,22 @@ pub enum InstantiationList {
     All,
 }
 
+/// LRM 7.4 Disconnection specification
+#[derive(PartialEq, Debug, Clone)]
+pub enum GuardedSignalList {
+    Names(Vec<WithPos<Name>>),
+    Others,
+    All,
+}
+
+/// LRM 7.4 Disconnection specification
+#[derive(PartialEq, Debug, Clone)]
+pub struct DisconnectionSpecification {
+    pub signals: GuardedSignalList,
+    pub type_mark: WithPos<SelectedName>,
+    pub after: WithPos<Expression>,
+}
+
 /// LRM 7.3.2 Binding indication
 #[derive(PartialEq, Debug, Clone)]
 pub enum EntityAspect {
@@ -984,8 +1122,8 @@ pub struct ConfigurationSpecification {
 #[derive(PartialEq, Debug, Clone)]
 pub enum ConfigurationDeclarativeItem {
     Use(WithPos<UseClause>),
-    // @TODO attribute
-    // @TODO group
+    Attribute(AttributeSpecification),
+    Group(GroupDeclaration),
 }
 /// LRM 3.4 Configuration declarations
 #[derive(PartialEq, Debug, Clone)]
@@ -1007,7 +1145,7 @@ pub enum ConfigurationItem {
 #[derive(PartialEq, Debug, Clone)]
 pub struct BlockConfiguration {
     pub block_spec: WithPos<Name>,
-    pub use_clauses: Vec<UseClause>,
+    pub use_clauses: Vec<WithPos<UseClause>>,
     pub items: Vec<ConfigurationItem>,
 }
 
@@ -1071,6 +1209,9 @@ pub enum PrimaryUnit {
 
     /// LRM 13.4 Context clauses
     ContextDeclaration(ContextDeclaration),
+
+    /// PSL 1.1 7.2 Verification units
+    VerificationUnit(DesignUnit<VerificationUnit>),
 }
 
 /// LRM 13.1 Design units
@@ -1100,4 +1241,8 @@ pub enum AnyDesignUnit {
 #[derive(PartialEq, Debug, Clone)]
 pub struct DesignFile {
     pub design_units: Vec<AnyDesignUnit>,
+    /// Code disabled by conditional analysis
+    pub inactive_regions: Vec<SrcPos>,
+    /// Code between translate_off and translate_on synthesis pragmas
+    pub translate_off_regions: Vec<SrcPos>,
 }
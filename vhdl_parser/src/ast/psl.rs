@@ -0,0 +1,229 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
+
+//! PSL embedded in VHDL-2008, LRM 1.1 and IEEE 1850-2005 (PSL 1.1)
+
+use super::{Declaration, Expression, Ident, LabeledConcurrentStatement, Name, SelectedName};
+use crate::source::WithPos;
+
+/// PSL 1.1 6.1.1.1 SERE repetition operators
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum PslRepetitionKind {
+    /// [*]
+    Consecutive,
+    /// [+]
+    OneOrMore,
+    /// [=]
+    NonConsecutive,
+    /// [->]
+    Goto,
+}
+
+/// PSL 1.1 6.1.1.1 The count of a repetition, None as the upper bound means inf
+#[derive(PartialEq, Debug, Clone)]
+pub enum PslCount {
+    Number(WithPos<Expression>),
+    Range(WithPos<Expression>, Option<WithPos<Expression>>),
+}
+
+/// PSL 1.1 6.1.1.1 SERE repetition operators
+#[derive(PartialEq, Debug, Clone)]
+pub struct PslRepetition {
+    pub kind: PslRepetitionKind,
+    pub count: Option<PslCount>,
+}
+
+/// PSL 1.1 6.1.1 and 6.1.2 SERE and sequence composition operators
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum PslSereOperator {
+    /// ;
+    Concatenation,
+    /// :
+    Fusion,
+    /// |
+    Or,
+    /// &&
+    LengthMatchingAnd,
+    /// &
+    NonLengthMatchingAnd,
+    Within,
+}
+
+/// PSL 1.1 6.1 Sequential expressions
+#[derive(PartialEq, Debug, Clone)]
+pub enum PslSere {
+    Boolean(WithPos<Expression>),
+    Binary(
+        PslSereOperator,
+        Box<WithPos<PslSere>>,
+        Box<WithPos<PslSere>>,
+    ),
+    Repetition(Option<Box<WithPos<PslSere>>>, PslRepetition),
+}
+
+/// PSL 1.1 6.2.1.3 The quantifier of next_a, next_e, next_event_a and next_event_e
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum PslQuantifier {
+    /// The property holds in all cycles of the range
+    All,
+    /// The property holds in some cycle of the range
+    Exists,
+}
+
+/// PSL 1.1 6.2.1 Foundation language properties with a single operand
+#[derive(PartialEq, Debug, Clone)]
+pub enum PslUnaryOperator {
+    Always,
+    Never,
+    /// eventually!
+    Eventually,
+    /// next, next! and next[n]
+    Next {
+        strong: bool,
+        count: Option<WithPos<Expression>>,
+    },
+    /// next_a[k to l] and next_e[k to l], strong when followed by !
+    NextRange {
+        quantifier: PslQuantifier,
+        strong: bool,
+        count: PslCount,
+    },
+    /// next_event(b), next_event(b)[n], next_event_a(b)[k to l] and next_event_e(b)[k to l]
+    NextEvent {
+        quantifier: Option<PslQuantifier>,
+        strong: bool,
+        condition: WithPos<Expression>,
+        count: Option<PslCount>,
+    },
+}
+
+/// PSL 1.1 6.2.1 Foundation language properties with two operands
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum PslBinaryOperator {
+    /// ->
+    Implication,
+    /// <->
+    Equivalence,
+    Until {
+        strong: bool,
+        inclusive: bool,
+    },
+    Before {
+        strong: bool,
+        inclusive: bool,
+    },
+    Abort,
+}
+
+/// PSL 1.1 6.2 Properties
+#[derive(PartialEq, Debug, Clone)]
+pub enum PslProperty {
+    /// A boolean or a sequence, strong when followed by !
+    Sequence {
+        sere: WithPos<PslSere>,
+        strong: bool,
+    },
+    Unary(PslUnaryOperator, Box<WithPos<PslProperty>>),
+    Binary(
+        PslBinaryOperator,
+        Box<WithPos<PslProperty>>,
+        Box<WithPos<PslProperty>>,
+    ),
+    /// |-> when overlapping else |=>
+    SuffixImplication {
+        sequence: WithPos<PslSere>,
+        overlapping: bool,
+        property: Box<WithPos<PslProperty>>,
+    },
+    /// property @ clock
+    Clocked(Box<WithPos<PslProperty>>, WithPos<Expression>),
+}
+
+/// PSL 1.1 7.1 Verification directives
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum PslDirectiveKind {
+    Assert,
+    Assume,
+    AssumeGuarantee,
+    Restrict,
+    RestrictGuarantee,
+    Cover,
+}
+
+/// PSL 1.1 7.1 Verification directives
+#[derive(PartialEq, Debug, Clone)]
+pub struct PslDirective {
+    pub kind: PslDirectiveKind,
+    pub property: WithPos<PslProperty>,
+    pub report: Option<WithPos<Expression>>,
+    pub severity: Option<WithPos<Expression>>,
+}
+
+/// PSL 1.1 6.2.3.1 Kind of a formal parameter
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum PslParameterKind {
+    Const,
+    Boolean,
+    Property,
+    Sequence,
+}
+
+/// PSL 1.1 6.2.3.1 Formal parameters of properties and sequences
+#[derive(PartialEq, Debug, Clone)]
+pub struct PslParameter {
+    pub kind: PslParameterKind,
+    pub idents: Vec<Ident>,
+}
+
+/// PSL 1.1 6.2.3.1 Property declaration
+#[derive(PartialEq, Debug, Clone)]
+pub struct PslPropertyDeclaration {
+    pub ident: Ident,
+    pub parameters: Vec<PslParameter>,
+    pub property: WithPos<PslProperty>,
+}
+
+/// PSL 1.1 6.1.3.1 Sequence declaration
+#[derive(PartialEq, Debug, Clone)]
+pub struct PslSequenceDeclaration {
+    pub ident: Ident,
+    pub parameters: Vec<PslParameter>,
+    pub sequence: WithPos<PslSere>,
+}
+
+/// PSL 1.1 6 and 5.3 Declarations
+#[derive(PartialEq, Debug, Clone)]
+pub enum PslDeclaration {
+    Property(PslPropertyDeclaration),
+    Sequence(PslSequenceDeclaration),
+    DefaultClock(WithPos<Expression>),
+}
+
+/// PSL 1.1 7.2 Verification units
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum VerificationUnitKind {
+    VUnit,
+    VMode,
+    VProp,
+}
+
+/// PSL 1.1 7.2 The entity and optional architecture a verification unit is bound to
+#[derive(PartialEq, Debug, Clone)]
+pub struct VerificationUnitBinding {
+    pub entity_name: WithPos<SelectedName>,
+    pub architecture_name: Option<Ident>,
+}
+
+/// PSL 1.1 7.2 Verification units
+#[derive(PartialEq, Debug, Clone)]
+pub struct VerificationUnit {
+    pub kind: VerificationUnitKind,
+    pub ident: Ident,
+    pub binding: Option<VerificationUnitBinding>,
+    pub inherits: Vec<WithPos<Name>>,
+    pub decl: Vec<Declaration>,
+    pub statements: Vec<LabeledConcurrentStatement>,
+}
@@ -9,43 +9,58 @@ use crate::ast::{
     EntityTag,
 };
 use crate::expression::parse_expression;
-use crate::message::ParseResult;
+use crate::message::{MessageHandler, ParseResult};
 use crate::names::parse_selected_name;
 use crate::subprogram::parse_signature;
 use crate::tokenizer::Kind::*;
 use crate::tokenstream::TokenStream;
 
-fn parse_entity_class(stream: &mut TokenStream) -> ParseResult<EntityClass> {
+pub fn parse_entity_class(stream: &mut TokenStream) -> ParseResult<EntityClass> {
     let token = stream.expect()?;
     Ok(try_token_kind!(
         token,
         Entity => EntityClass::Entity,
         Architecture => EntityClass::Architecture,
         Configuration => EntityClass::Configuration,
+        Procedure => EntityClass::Procedure,
+        Function => EntityClass::Function,
         Package => EntityClass::Package,
+        Type => EntityClass::Type,
+        Subtype => EntityClass::Subtype,
+        Constant => EntityClass::Constant,
         Signal => EntityClass::Signal,
         Variable => EntityClass::Variable,
-        Procedure => EntityClass::Procedure,
-        Function => EntityClass::Function
+        Component => EntityClass::Component,
+        Label => EntityClass::Label,
+        Literal => EntityClass::Literal,
+        Units => EntityClass::Units,
+        Group => EntityClass::Group,
+        File => EntityClass::File,
+        Property => EntityClass::Property,
+        Sequence => EntityClass::Sequence
     ))
 }
 
-pub fn parse_entity_name_list(stream: &mut TokenStream) -> ParseResult<Vec<EntityName>> {
+pub fn parse_entity_name_list(
+    stream: &mut TokenStream,
+    messages: &mut dyn MessageHandler,
+) -> ParseResult<Vec<EntityName>> {
     let token = stream.peek_expect()?;
     Ok(try_token_kind!(
         token,
-        Identifier | StringLiteral => {
+        Identifier | StringLiteral | Character => {
             let mut entity_name_list = Vec::new();
             loop {
                 let designator_token = stream.expect()?;
                 let designator = try_token_kind!(
                     designator_token,
                     Identifier => designator_token.expect_ident()?.map_into(Designator::Identifier),
-                    StringLiteral => designator_token.expect_string()?.map_into(Designator::OperatorSymbol));
+                    StringLiteral => designator_token.expect_string()?.map_into(Designator::OperatorSymbol),
+                    Character => designator_token.expect_character()?.map_into(Designator::Character));
 
                 let signature = {
                     if stream.peek_kind()? == Some(LeftSquare) {
-                        Some(parse_signature(stream)?)
+                        Some(parse_signature(stream, messages)?)
                     } else {
                         None
                     }
@@ -81,7 +96,10 @@ pub fn parse_entity_name_list(stream: &mut TokenStream) -> ParseResult<Vec<Entit
     ))
 }
 
-pub fn parse_attribute(stream: &mut TokenStream) -> ParseResult<Vec<Attribute>> {
+pub fn parse_attribute(
+    stream: &mut TokenStream,
+    messages: &mut dyn MessageHandler,
+) -> ParseResult<Vec<Attribute>> {
     stream.expect_kind(Attribute)?;
     let ident = stream.expect_ident()?;
     let token = stream.expect()?;
@@ -97,7 +115,7 @@ pub fn parse_attribute(stream: &mut TokenStream) -> ParseResult<Vec<Attribute>>
             })]
         },
         Of => {
-            let entity_names = parse_entity_name_list(stream)?;
+            let entity_names = parse_entity_name_list(stream, messages)?;
             stream.expect_kind(Colon)?;
             let entity_class = parse_entity_class(stream)?;
             stream.expect_kind(Is)?;
@@ -127,7 +145,7 @@ mod tests {
     fn parse_simple_attribute_declaration() {
         let code = Code::new("attribute foo : lib.name;");
         assert_eq!(
-            code.with_stream(parse_attribute),
+            code.with_stream_no_messages(parse_attribute),
             vec![Attribute::Declaration(AttributeDeclaration {
                 ident: code.s1("foo").ident(),
                 type_mark: code.s1("lib.name").selected_name()
@@ -139,7 +157,7 @@ mod tests {
     fn parse_simple_attribute_specification() {
         let code = Code::new("attribute attr_name of foo : signal is 0+1;");
         assert_eq!(
-            code.with_stream(parse_attribute),
+            code.with_stream_no_messages(parse_attribute),
             vec![Attribute::Specification(AttributeSpecification {
                 ident: code.s1("attr_name").ident(),
                 entity_name: EntityName::Name(EntityTag {
@@ -156,7 +174,7 @@ mod tests {
     fn parse_simple_attribute_specification_operator_symbol() {
         let code = Code::new("attribute attr_name of \"**\" : function is 0+1;");
         assert_eq!(
-            code.with_stream(parse_attribute),
+            code.with_stream_no_messages(parse_attribute),
             vec![Attribute::Specification(AttributeSpecification {
                 ident: code.s1("attr_name").ident(),
                 entity_name: EntityName::Name(EntityTag {
@@ -173,7 +191,7 @@ mod tests {
     fn parse_attribute_specification_list() {
         let code = Code::new("attribute attr_name of foo, bar : signal is 0+1;");
         assert_eq!(
-            code.with_stream(parse_attribute),
+            code.with_stream_no_messages(parse_attribute),
             vec![
                 Attribute::Specification(AttributeSpecification {
                     ident: code.s1("attr_name").ident(),
@@ -201,7 +219,7 @@ mod tests {
     fn parse_attribute_specification_all() {
         let code = Code::new("attribute attr_name of all : signal is 0+1;");
         assert_eq!(
-            code.with_stream(parse_attribute),
+            code.with_stream_no_messages(parse_attribute),
             vec![Attribute::Specification(AttributeSpecification {
                 ident: code.s1("attr_name").ident(),
                 entity_name: EntityName::All,
@@ -215,7 +233,7 @@ mod tests {
     fn parse_attribute_specification_others() {
         let code = Code::new("attribute attr_name of others : signal is 0+1;");
         assert_eq!(
-            code.with_stream(parse_attribute),
+            code.with_stream_no_messages(parse_attribute),
             vec![Attribute::Specification(AttributeSpecification {
                 ident: code.s1("attr_name").ident(),
                 entity_name: EntityName::Others,
@@ -229,7 +247,7 @@ mod tests {
     fn parse_attribute_specification_with_signature() {
         let code = Code::new("attribute attr_name of foo[return natural] : function is 0+1;");
         assert_eq!(
-            code.with_stream(parse_attribute),
+            code.with_stream_no_messages(parse_attribute),
             vec![Attribute::Specification(AttributeSpecification {
                 ident: code.s1("attr_name").ident(),
                 entity_name: EntityName::Name(EntityTag {
@@ -242,4 +260,53 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parse_attribute_specification_character_literal() {
+        let code = Code::new("attribute attr_name of 'a' : literal is 0+1;");
+        assert_eq!(
+            code.with_stream_no_messages(parse_attribute),
+            vec![Attribute::Specification(AttributeSpecification {
+                ident: code.s1("attr_name").ident(),
+                entity_name: EntityName::Name(EntityTag {
+                    designator: code.s1("'a'").designator(),
+                    signature: None
+                }),
+                entity_class: EntityClass::Literal,
+                expr: code.s1("0+1").expr()
+            })]
+        )
+    }
+
+    #[test]
+    fn parse_attribute_specification_entity_classes() {
+        let classes = [
+            ("type", EntityClass::Type),
+            ("subtype", EntityClass::Subtype),
+            ("constant", EntityClass::Constant),
+            ("component", EntityClass::Component),
+            ("label", EntityClass::Label),
+            ("literal", EntityClass::Literal),
+            ("units", EntityClass::Units),
+            ("group", EntityClass::Group),
+            ("file", EntityClass::File),
+            ("property", EntityClass::Property),
+            ("sequence", EntityClass::Sequence),
+        ];
+
+        for (keyword, entity_class) in classes.iter() {
+            let code = Code::new(&format!("attribute attr_name of foo : {} is 0;", keyword));
+            assert_eq!(
+                code.with_stream_no_messages(parse_attribute),
+                vec![Attribute::Specification(AttributeSpecification {
+                    ident: code.s1("attr_name").ident(),
+                    entity_name: EntityName::Name(EntityTag {
+                        designator: code.s1("foo").designator(),
+                        signature: None
+                    }),
+                    entity_class: *entity_class,
+                    expr: code.s1("0").expr()
+                })]
+            )
+        }
+    }
 }
@@ -0,0 +1,400 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
+
+//! A baseline of known messages such that only new messages are reported
+//!
+//! Entries are keyed by file, enclosing design unit and message text but not by line number
+//! such that unrelated edits do not invalidate the baseline.
+//! File names are stored as absolute paths, in the baseline file they are relative to its folder
+//! when the file is within it.
+
+use crate::message::Message;
+use crate::project::Project;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io;
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+use toml::value::{Table, Value};
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug)]
+struct BaselineEntry {
+    file_name: String,
+    design_unit: Option<String>,
+    message: String,
+}
+
+impl BaselineEntry {
+    fn new(project: &Project, message: &Message) -> BaselineEntry {
+        BaselineEntry {
+            file_name: absolute_file_name(Path::new(message.pos.source.file_name())),
+            design_unit: project
+                .enclosing_design_unit(&message.pos)
+                .map(|design_unit| design_unit.to_string()),
+            message: message.message.clone(),
+        }
+    }
+}
+
+/// The canonical path, the folders of a file which does not exist are canonicalized as far as they exist
+fn absolute_path(path: &Path) -> PathBuf {
+    if let Ok(path) = std::fs::canonicalize(path) {
+        return path;
+    }
+
+    let absolute = match std::env::current_dir() {
+        Ok(current_dir) => current_dir.join(path),
+        Err(_) => path.to_owned(),
+    };
+    match (absolute.parent(), absolute.file_name()) {
+        (Some(parent), Some(name)) => absolute_path(parent).join(name),
+        _ => absolute,
+    }
+}
+
+fn absolute_file_name(path: &Path) -> String {
+    absolute_path(path).to_string_lossy().into_owned()
+}
+
+#[derive(Default, PartialEq, Debug)]
+pub struct Baseline {
+    // The number of times each entry occurs
+    entries: BTreeMap<BaselineEntry, usize>,
+}
+
+impl Baseline {
+    pub fn from_messages(project: &Project, messages: &[Message]) -> Baseline {
+        let mut baseline = Baseline::default();
+        for message in messages {
+            *baseline
+                .entries
+                .entry(BaselineEntry::new(project, message))
+                .or_insert(0) += 1;
+        }
+        baseline
+    }
+
+    /// The number of messages in the baseline
+    pub fn len(&self) -> usize {
+        self.entries.values().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove messages that are in the baseline
+    /// When an entry occurs more times than in the baseline the excess messages are kept
+    pub fn filter_new(&self, project: &Project, messages: Vec<Message>) -> Vec<Message> {
+        let mut remaining = self.entries.clone();
+        let mut new_messages = Vec::new();
+
+        for message in messages {
+            let entry = BaselineEntry::new(project, &message);
+            match remaining.get_mut(&entry) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                }
+                _ => {
+                    new_messages.push(message);
+                }
+            }
+        }
+
+        new_messages
+    }
+
+    /// Relative file names are relative to the parent folder of the baseline file
+    pub fn from_str(string: &str, parent: &Path) -> Result<Baseline, String> {
+        let parent = absolute_path(parent);
+        let baseline: Value = toml::from_str(string).map_err(|err| err.to_string())?;
+        let mut entries = BTreeMap::new();
+
+        let messages = match baseline.get("messages") {
+            Some(messages) => messages
+                .as_array()
+                .ok_or("messages must be an array of tables")?,
+            None => return Ok(Baseline { entries }),
+        };
+
+        for message in messages.iter() {
+            let get_str = |key: &str| -> Result<Option<String>, String> {
+                match message.get(key) {
+                    Some(value) => value
+                        .as_str()
+                        .map(|value| Some(value.to_owned()))
+                        .ok_or_else(|| format!("{} is not a string", key)),
+                    None => Ok(None),
+                }
+            };
+
+            let file_name = get_str("file")?.ok_or("missing field file")?;
+            let path = Path::new(&file_name);
+            let file_name = if path.is_absolute() {
+                file_name
+            } else {
+                parent.join(path).to_string_lossy().into_owned()
+            };
+
+            let entry = BaselineEntry {
+                file_name,
+                design_unit: get_str("unit")?,
+                message: get_str("message")?.ok_or("missing field message")?,
+            };
+
+            let count = match message.get("count") {
+                Some(count) => count
+                    .as_integer()
+                    .filter(|count| *count > 0)
+                    .ok_or("count must be a positive integer")?
+                    as usize,
+                None => 1,
+            };
+
+            *entries.entry(entry).or_insert(0) += count;
+        }
+
+        Ok(Baseline { entries })
+    }
+
+    /// File names within the parent folder of the baseline file are written relative to it
+    pub fn to_string(&self, parent: &Path) -> String {
+        let parent = absolute_path(parent);
+        let mut messages = Vec::new();
+        for (entry, count) in self.entries.iter() {
+            let file_name = Path::new(&entry.file_name)
+                .strip_prefix(&parent)
+                .ok()
+                .and_then(|path| path.to_str())
+                .filter(|path| !path.is_empty())
+                .unwrap_or(&entry.file_name);
+
+            let mut message = Table::new();
+            message.insert("file".to_owned(), Value::String(file_name.to_owned()));
+            if let Some(ref design_unit) = entry.design_unit {
+                message.insert("unit".to_owned(), Value::String(design_unit.clone()));
+            }
+            message.insert("message".to_owned(), Value::String(entry.message.clone()));
+            if *count > 1 {
+                message.insert("count".to_owned(), Value::Integer(*count as i64));
+            }
+            messages.push(Value::Table(message));
+        }
+
+        let mut baseline = Table::new();
+        baseline.insert("messages".to_owned(), Value::Array(messages));
+        toml::to_string(&Value::Table(baseline)).expect("Baseline is valid toml")
+    }
+
+    pub fn read_file_path(file_name: &Path) -> io::Result<Baseline> {
+        let mut file = File::open(file_name)?;
+        let mut contents = String::new();
+        file.read_to_string(&mut contents)?;
+
+        Baseline::from_str(&contents, parent_folder(file_name)?).map_err(io::Error::other)
+    }
+
+    pub fn write_file_path(&self, file_name: &Path) -> io::Result<()> {
+        std::fs::write(file_name, self.to_string(parent_folder(file_name)?))
+    }
+}
+
+fn parent_folder(file_name: &Path) -> io::Result<&Path> {
+    file_name.parent().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "Baseline file name {} has no parent folder",
+                file_name.display()
+            ),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::source::Source;
+
+    fn analyse(project: &mut Project, file_name: &str, code: &str) -> Vec<Message> {
+        let source = Source::inline_utf8(file_name, code).unwrap();
+        project.update_source(file_name, &source).unwrap();
+        project.analyse()
+    }
+
+    #[test]
+    fn filters_known_messages_independent_of_line() {
+        let mut project = Project::new();
+        let messages = analyse(
+            &mut project,
+            "parent/file.vhd",
+            "\
+entity ent is
+end entity ent2;
+",
+        );
+        assert_eq!(messages.len(), 1);
+        let baseline = Baseline::from_messages(&project, &messages);
+        assert_eq!(baseline.len(), 1);
+
+        let messages = analyse(
+            &mut project,
+            "parent/file.vhd",
+            "\
+-- Unrelated edit
+
+entity ent is
+end entity ent2;
+",
+        );
+        assert_eq!(baseline.filter_new(&project, messages), vec![]);
+    }
+
+    #[test]
+    fn keeps_new_messages() {
+        let mut project = Project::new();
+        let messages = analyse(
+            &mut project,
+            "parent/file.vhd",
+            "\
+entity ent is
+end entity ent2;
+",
+        );
+        let baseline = Baseline::from_messages(&project, &messages);
+
+        let messages = analyse(
+            &mut project,
+            "parent/file.vhd",
+            "\
+entity ent is
+end entity ent2;
+
+entity ent3 is
+end entity ent4;
+",
+        );
+        let new_messages = baseline.filter_new(&project, messages.clone());
+        assert_eq!(new_messages, vec![messages[1].clone()]);
+    }
+
+    #[test]
+    fn keeps_excess_duplicate_messages() {
+        let mut project = Project::new();
+        let messages = analyse(
+            &mut project,
+            "parent/file.vhd",
+            "\
+entity ent is
+end entity ent2;
+",
+        );
+        let baseline = Baseline::from_messages(&project, &messages);
+
+        let mut duplicated = messages.clone();
+        duplicated.extend(messages.iter().cloned());
+        assert_eq!(baseline.filter_new(&project, duplicated).len(), 1);
+    }
+
+    #[test]
+    fn to_string_and_from_str() {
+        let mut project = Project::new();
+        let messages = analyse(
+            &mut project,
+            "parent/file.vhd",
+            "\
+entity ent is
+end entity ent2;
+",
+        );
+        let parent = Path::new("parent");
+        let mut messages = messages;
+        messages.push(messages[0].clone());
+        let baseline = Baseline::from_messages(&project, &messages);
+        let string = baseline.to_string(parent);
+
+        assert_eq!(
+            string,
+            "\
+[[messages]]
+count = 2
+file = \"file.vhd\"
+message = \"End identifier mismatch, expected ent\"
+unit = \"entity ent\"
+"
+        );
+        assert_eq!(Baseline::from_str(&string, parent), Ok(baseline));
+    }
+
+    #[test]
+    fn from_str_errors() {
+        let parent = Path::new("parent");
+        assert_eq!(
+            Baseline::from_str("[[messages]]\nmessage = 'foo'\n", parent),
+            Err("missing field file".to_owned())
+        );
+        assert_eq!(
+            Baseline::from_str("[[messages]]\nfile = 'foo.vhd'\n", parent),
+            Err("missing field message".to_owned())
+        );
+    }
+
+    #[test]
+    fn file_outside_of_parent_round_trip() {
+        let mut project = Project::new();
+        let messages = analyse(
+            &mut project,
+            "proj/src/a.vhd",
+            "\
+entity ent is
+end entity ent2;
+",
+        );
+        let baseline = Baseline::from_messages(&project, &messages);
+        let parent = std::env::temp_dir().join("baseline_folder");
+        let string = baseline.to_string(&parent);
+
+        // The file name is written as an absolute path
+        let expected = absolute_file_name(Path::new("proj/src/a.vhd"));
+        assert!(string.contains(&format!("file = {:?}", expected)));
+        let baseline = Baseline::from_str(&string, &parent).unwrap();
+        assert_eq!(baseline.filter_new(&project, messages), vec![]);
+    }
+
+    #[test]
+    fn write_and_read_file_path() {
+        let mut project = Project::new();
+        let tempdir = tempfile::tempdir().unwrap();
+        let file_name = tempdir.path().join("src").join("file.vhd");
+        let messages = analyse(
+            &mut project,
+            file_name.to_str().unwrap(),
+            "\
+entity ent is
+end entity ent2;
+",
+        );
+        let baseline = Baseline::from_messages(&project, &messages);
+
+        let baseline_file_name = tempdir.path().join("baseline.toml");
+        baseline.write_file_path(&baseline_file_name).unwrap();
+        let contents = std::fs::read_to_string(&baseline_file_name).unwrap();
+        assert!(contents.contains("file = \"src/file.vhd\""));
+        assert_eq!(
+            Baseline::read_file_path(&baseline_file_name).unwrap(),
+            baseline
+        );
+    }
+
+    #[test]
+    fn baseline_file_without_parent() {
+        let baseline = Baseline::default();
+        assert_eq!(
+            baseline.write_file_path(Path::new("/")).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+}
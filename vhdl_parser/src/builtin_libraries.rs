@@ -0,0 +1,61 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
+
+//! The VHDL-2008 std and ieee libraries which are bundled with the parser
+//! such that projects do not need to provide their own copy
+
+use crate::latin_1::Latin1String;
+use crate::source::Source;
+use std::sync::Arc;
+
+pub struct BuiltinFile {
+    pub library_name: &'static str,
+    file_name: &'static str,
+    // The files are latin-1 encoded
+    contents: &'static [u8],
+}
+
+macro_rules! builtin_file {
+    ($library_name:expr, $folder:expr, $file_name:expr) => {
+        BuiltinFile {
+            library_name: $library_name,
+            file_name: $file_name,
+            contents: include_bytes!(concat!("../libraries/", $folder, "/", $file_name)),
+        }
+    };
+}
+
+pub const BUILTIN_FILES: &[BuiltinFile] = &[
+    builtin_file!("std", "std", "standard.vhd"),
+    builtin_file!("std", "std", "textio.vhd"),
+    builtin_file!("std", "std", "env.vhd"),
+    builtin_file!("ieee", "ieee2008", "std_logic_1164.vhdl"),
+    builtin_file!("ieee", "ieee2008", "std_logic_textio.vhdl"),
+    builtin_file!("ieee", "ieee2008", "numeric_bit.vhdl"),
+    builtin_file!("ieee", "ieee2008", "numeric_bit_unsigned.vhdl"),
+    builtin_file!("ieee", "ieee2008", "numeric_std.vhdl"),
+    builtin_file!("ieee", "ieee2008", "numeric_std_unsigned.vhdl"),
+    builtin_file!("ieee", "ieee2008", "math_real.vhdl"),
+    builtin_file!("ieee", "ieee2008", "math_complex.vhdl"),
+    builtin_file!("ieee", "ieee2008", "fixed_float_types.vhdl"),
+    builtin_file!("ieee", "ieee2008", "fixed_generic_pkg.vhdl"),
+    builtin_file!("ieee", "ieee2008", "fixed_pkg.vhdl"),
+    builtin_file!("ieee", "ieee2008", "float_generic_pkg.vhdl"),
+    builtin_file!("ieee", "ieee2008", "float_pkg.vhdl"),
+    builtin_file!("ieee", "ieee2008", "ieee_bit_context.vhdl"),
+    builtin_file!("ieee", "ieee2008", "ieee_std_context.vhdl"),
+];
+
+impl BuiltinFile {
+    /// A file name which cannot be confused with a file on disk
+    pub fn file_name(&self) -> String {
+        format!("{{builtin}}/{}/{}", self.library_name, self.file_name)
+    }
+
+    pub fn source(&self) -> Source {
+        Source::inline(self.file_name(), Arc::new(Latin1String::new(self.contents)))
+    }
+}
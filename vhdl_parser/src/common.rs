@@ -5,9 +5,11 @@
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
 use crate::ast::Ident;
-use crate::message::{Message, ParseResult};
+use crate::message::{Message, MessageCode, MessageHandler, ParseResult};
+use crate::source::SrcPos;
 use crate::tokenizer::Kind;
 use crate::tokenstream::TokenStream;
+use crate::vhdl_standard::VHDLStandard;
 
 /// Parse optional part followed by optional keyword
 pub fn parse_optional<F, R>(
@@ -29,17 +31,66 @@ where
     Ok(optional)
 }
 
+/// The end identifier shall repeat the opening identifier
 pub fn error_on_end_identifier_mismatch(
     ident: &Ident,
     end_ident: &Option<Ident>,
 ) -> Option<Message> {
     if let Some(end_ident) = end_ident {
         if ident.item != end_ident.item {
-            return Some(Message::error(
-                &end_ident.pos,
-                format!("End identifier mismatch, expected {}", ident.item.name()),
-            ));
+            return Some(
+                Message::error(
+                    &end_ident.pos,
+                    format!("End identifier mismatch, expected {}", ident.item.name()),
+                    MessageCode::EndIdentifierMismatch,
+                )
+                .related(&ident.pos, "Opening identifier")
+                .with_replacement(ident.item.name().to_string()),
+            );
         }
     }
     None
 }
+
+/// The end label of a statement shall repeat its label, a statement without label has no end label
+pub fn error_on_end_label_mismatch(
+    label: Option<&Ident>,
+    end_label: &Option<Ident>,
+) -> Option<Message> {
+    match (label, end_label) {
+        (Some(label), _) => error_on_end_identifier_mismatch(label, end_label),
+        (None, Some(end_label)) => Some(
+            Message::error(
+                &end_label.pos,
+                format!(
+                    "End label {} given for a statement without label",
+                    end_label.item.name()
+                ),
+                MessageCode::EndIdentifierMismatch,
+            )
+            .with_replacement(""),
+        ),
+        (None, None) => None,
+    }
+}
+
+/// Report a construct which was added in a later revision than the selected standard
+pub fn check_standard(
+    stream: &TokenStream,
+    item: impl AsRef<SrcPos>,
+    required: VHDLStandard,
+    construct: &str,
+    messages: &mut dyn MessageHandler,
+) {
+    let standard = stream.standard();
+    if standard < required {
+        messages.push(Message::error(
+            item,
+            format!(
+                "{} requires {} or later, the selected standard is {}",
+                construct, required, standard
+            ),
+            MessageCode::UnsupportedInStandard,
+        ));
+    }
+}
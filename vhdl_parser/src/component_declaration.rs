@@ -24,7 +24,7 @@ pub fn parse_optional_generic_list(
                 let new_list = parse_generic_interface_list(stream, messages)?;
                 stream.expect_kind(SemiColon)?;
                 if list.is_some() {
-                    messages.push(Message::error(token, "Duplicate generic clause"));
+                    messages.push(Message::syntax_error(token, "Duplicate generic clause"));
                 } else {
                     list = Some(new_list);
                 }
@@ -49,7 +49,7 @@ pub fn parse_optional_port_list(
                 let new_list = parse_port_interface_list(stream, messages)?;
                 stream.expect_kind(SemiColon)?;
                 if list.is_some() {
-                    messages.push(Message::error(token, "Duplicate port clause"));
+                    messages.push(Message::syntax_error(token, "Duplicate port clause"));
                 } else {
                     list = Some(new_list);
                 }
@@ -58,7 +58,7 @@ pub fn parse_optional_port_list(
                 stream.move_after(&token);
                 parse_generic_interface_list(stream, messages)?;
                 stream.expect_kind(SemiColon)?;
-                messages.push(Message::error(
+                messages.push(Message::syntax_error(
                     token,
                     "Generic clause must come before port clause",
                 ));
@@ -216,7 +216,7 @@ end
         let (result, messages) = code.with_partial_stream_messages(parse_optional_generic_list);
         assert_eq!(
             messages,
-            vec![Message::error(
+            vec![Message::syntax_error(
                 &code.s("generic", 2).pos(),
                 "Duplicate generic clause"
             )]
@@ -240,7 +240,10 @@ end
         let (result, messages) = code.with_partial_stream_messages(parse_optional_port_list);
         assert_eq!(
             messages,
-            vec![Message::error(code.s("port", 2), "Duplicate port clause")]
+            vec![Message::syntax_error(
+                code.s("port", 2),
+                "Duplicate port clause"
+            )]
         );
         assert_eq!(result, Ok(Some(vec![code.s1("foo : natural").port()])),);
     }
@@ -261,7 +264,7 @@ end
         let (result, messages) = code.with_partial_stream_messages(parse_optional_port_list);
         assert_eq!(
             messages,
-            vec![Message::error(
+            vec![Message::syntax_error(
                 code.s1("generic"),
                 "Generic clause must come before port clause"
             )]
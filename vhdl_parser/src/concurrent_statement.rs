@@ -9,9 +9,11 @@ use crate::ast::{
     ConcurrentAssertStatement, ConcurrentProcedureCall, ConcurrentSignalAssignment,
     ConcurrentStatement, Conditional, Declaration, ForGenerateStatement, FunctionCall,
     GenerateBody, Ident, IfGenerateStatement, InstantiatedUnit, InstantiationStatement,
-    LabeledConcurrentStatement, Name, ProcessStatement, SensitivityList, Target,
+    LabeledConcurrentStatement, Name, ProcessStatement, PslDirectiveKind, SensitivityList, Target,
+};
+use crate::common::{
+    check_standard, error_on_end_identifier_mismatch, error_on_end_label_mismatch,
 };
-use crate::common::error_on_end_identifier_mismatch;
 use crate::declarative_part::{is_declarative_part, parse_declarative_part};
 use crate::expression::parse_aggregate_leftpar_known;
 use crate::expression::{parse_choices, parse_expression};
@@ -20,6 +22,7 @@ use crate::names::{
     expression_to_ident, into_selected_name, parse_association_list, parse_name_initial_token,
     parse_selected_name, to_simple_name,
 };
+use crate::psl::{is_psl_assertion, parse_psl_directive_known_keyword};
 use crate::range::parse_discrete_range;
 use crate::sequential_statement::{
     parse_assert_statement_known_keyword, parse_labeled_sequential_statements, parse_selection,
@@ -28,11 +31,13 @@ use crate::sequential_statement::{
 use crate::source::WithPos;
 use crate::tokenizer::{Kind::*, Token};
 use crate::tokenstream::TokenStream;
+use crate::vhdl_standard::VHDLStandard;
 use crate::waveform::{parse_delay_mechanism, parse_waveform};
 
 /// LRM 11.2 Block statement
 pub fn parse_block_statement(
     stream: &mut TokenStream,
+    label: Option<&Ident>,
     messages: &mut dyn MessageHandler,
 ) -> ParseResult<BlockStatement> {
     let token = stream.peek_expect()?;
@@ -45,7 +50,8 @@ pub fn parse_block_statement(
             LeftPar => {
                 stream.move_after(&token);
                 let expr = parse_expression(stream)?;
-                stream.pop_if_kind(RightPar)?;
+                stream.expect_kind(RightPar)?;
+                stream.pop_if_kind(Is)?;
                 Some(expr)
             }
             _ => None,
@@ -54,8 +60,8 @@ pub fn parse_block_statement(
     let decl = parse_declarative_part(stream, messages, true)?;
     let statements = parse_labeled_concurrent_statements(stream, messages)?;
     stream.expect_kind(Block)?;
-    // @TODO check name
-    stream.pop_if_kind(Identifier)?;
+    let end_label = stream.pop_optional_ident()?;
+    push_some(messages, error_on_end_label_mismatch(label, &end_label));
     stream.expect_kind(SemiColon)?;
     Ok(BlockStatement {
         guard_condition,
@@ -67,6 +73,7 @@ pub fn parse_block_statement(
 /// LRM 11.3 Process statement
 pub fn parse_process_statement(
     stream: &mut TokenStream,
+    label: Option<&Ident>,
     postponed: bool,
     messages: &mut dyn MessageHandler,
 ) -> ParseResult<ProcessStatement> {
@@ -78,6 +85,13 @@ pub fn parse_process_statement(
                 let token = stream.expect()?;
 
                 if token.kind == All {
+                    check_standard(
+                        stream,
+                        &token,
+                        VHDLStandard::VHDL2008,
+                        "Process sensitivity list 'all'",
+                        messages,
+                    );
                     stream.expect_kind(RightPar)?;
                     Some(SensitivityList::All)
                 } else {
@@ -88,7 +102,7 @@ pub fn parse_process_statement(
                             RightPar => {
                                 if names.is_empty() {
                                     messages.push(
-                                        Message::error(token, "Processes with sensitivity lists must contain at least one element.")
+                                        Message::syntax_error(token, "Processes with sensitivity lists must contain at least one element.")
                                     );
                                 }
                                 break Some(SensitivityList::Names(names));
@@ -110,9 +124,17 @@ pub fn parse_process_statement(
     let decl = parse_declarative_part(stream, messages, true)?;
     let (statements, end_token) = parse_labeled_sequential_statements(stream, messages)?;
     try_token_kind!(end_token, End => {});
+    if let Some(token) = stream.pop_if_kind(Postponed)? {
+        if !postponed {
+            messages.push(Message::syntax_error(
+                token,
+                "'postponed' at the end of a process which is not postponed",
+            ));
+        }
+    }
     stream.expect_kind(Process)?;
-    // @TODO check name
-    stream.pop_if_kind(Identifier)?;
+    let end_label = stream.pop_optional_ident()?;
+    push_some(messages, error_on_end_label_mismatch(label, &end_label));
     stream.expect_kind(SemiColon)?;
     Ok(ProcessStatement {
         postponed,
@@ -138,7 +160,7 @@ fn to_procedure_call(
                 parameters: vec![],
             },
         }),
-        Target::Aggregate(..) => Err(Message::error(
+        Target::Aggregate(..) => Err(Message::syntax_error(
             target,
             "Expected procedure call, got aggregate",
         )),
@@ -149,14 +171,12 @@ fn parse_assignment_or_procedure_call(
     stream: &mut TokenStream,
     token: &Token,
     target: WithPos<Target>,
+    postponed: bool,
 ) -> ParseResult<ConcurrentStatement> {
     match_token_kind!(
     token,
     LTE => {
-        // @TODO postponed
-        let postponed = false;
-        // @TODO guarded
-        let guarded = false;
+        let guarded = stream.skip_if_kind(Guarded)?;
         let delay_mechanism = parse_delay_mechanism(stream)?;
         Ok(ConcurrentStatement::Assignment(ConcurrentSignalAssignment {
             postponed,
@@ -167,7 +187,7 @@ fn parse_assignment_or_procedure_call(
         }))
     },
     SemiColon => {
-        Ok(ConcurrentStatement::ProcedureCall(to_procedure_call(target, false)?))
+        Ok(ConcurrentStatement::ProcedureCall(to_procedure_call(target, postponed)?))
     })
 }
 
@@ -179,8 +199,7 @@ fn parse_selected_signal_assignment(
     stream.expect_kind(Select)?;
     let target = parse_target(stream)?;
     stream.expect_kind(LTE)?;
-    // @TODO guarded
-    let guarded = false;
+    let guarded = stream.skip_if_kind(Guarded)?;
     let delay_mechanism = parse_delay_mechanism(stream)?;
     let rhs = AssignmentRightHand::Selected(parse_selection(stream, expression, parse_waveform)?);
     Ok(ConcurrentSignalAssignment {
@@ -202,6 +221,36 @@ pub fn parse_concurrent_assert_statement(
     })
 }
 
+fn psl_directive(
+    stream: &mut TokenStream,
+    kind: PslDirectiveKind,
+) -> ParseResult<ConcurrentStatement> {
+    Ok(ConcurrentStatement::PslDirective(
+        parse_psl_directive_known_keyword(stream, kind)?,
+    ))
+}
+
+/// An assertion which is not a VHDL assertion is a PSL assert directive in VHDL-2008
+/// when it contains PSL operators or keywords, otherwise the VHDL syntax error is kept
+fn parse_concurrent_assert_or_psl_directive(
+    stream: &mut TokenStream,
+) -> ParseResult<ConcurrentStatement> {
+    let state = stream.state();
+    match parse_concurrent_assert_statement(stream, false) {
+        Ok(assert) => Ok(ConcurrentStatement::Assert(assert)),
+        Err(err) => {
+            if stream.standard() < VHDLStandard::VHDL2008 {
+                return Err(err);
+            }
+            stream.set_state(state);
+            if !is_psl_assertion(stream).unwrap_or(false) {
+                return Err(err);
+            }
+            psl_directive(stream, PslDirectiveKind::Assert)
+        }
+    }
+}
+
 pub fn parse_instantiation_statement(
     stream: &mut TokenStream,
     unit: InstantiatedUnit,
@@ -299,6 +348,7 @@ fn parse_generate_body(
 /// 11.8 Generate statements
 fn parse_for_generate_statement(
     stream: &mut TokenStream,
+    label: Option<&Ident>,
     messages: &mut dyn MessageHandler,
 ) -> ParseResult<ForGenerateStatement> {
     let index_name = stream.expect_ident()?;
@@ -307,8 +357,8 @@ fn parse_for_generate_statement(
     stream.expect_kind(Generate)?;
     let body = parse_generate_body(stream, None, messages)?;
     stream.expect_kind(Generate)?;
-    // @TODO check identifier
-    stream.pop_if_kind(Identifier)?;
+    let end_label = stream.pop_optional_ident()?;
+    push_some(messages, error_on_end_label_mismatch(label, &end_label));
     stream.expect_kind(SemiColon)?;
 
     Ok(ForGenerateStatement {
@@ -321,6 +371,7 @@ fn parse_for_generate_statement(
 /// 11.8 Generate statements
 fn parse_if_generate_statement(
     stream: &mut TokenStream,
+    label: Option<&Ident>,
     messages: &mut dyn MessageHandler,
 ) -> ParseResult<IfGenerateStatement> {
     let mut conditionals = Vec::new();
@@ -373,8 +424,8 @@ fn parse_if_generate_statement(
     }
 
     stream.expect_kind(Generate)?;
-    // @TODO check identifier
-    stream.pop_if_kind(Identifier)?;
+    let end_label = stream.pop_optional_ident()?;
+    push_some(messages, error_on_end_label_mismatch(label, &end_label));
     stream.expect_kind(SemiColon)?;
 
     Ok(IfGenerateStatement {
@@ -386,6 +437,7 @@ fn parse_if_generate_statement(
 /// 11.8 Generate statements
 fn parse_case_generate_statement(
     stream: &mut TokenStream,
+    label: Option<&Ident>,
     messages: &mut dyn MessageHandler,
 ) -> ParseResult<CaseGenerateStatement> {
     let expression = parse_expression(stream)?;
@@ -419,8 +471,8 @@ fn parse_case_generate_statement(
     }
 
     stream.expect_kind(Generate)?;
-    // @TODO check identifier
-    stream.pop_if_kind(Identifier)?;
+    let end_label = stream.pop_optional_ident()?;
+    push_some(messages, error_on_end_label_mismatch(label, &end_label));
     stream.expect_kind(SemiColon)?;
 
     Ok(CaseGenerateStatement {
@@ -432,16 +484,17 @@ fn parse_case_generate_statement(
 pub fn parse_concurrent_statement(
     stream: &mut TokenStream,
     token: Token,
+    label: Option<&Ident>,
     messages: &mut dyn MessageHandler,
 ) -> ParseResult<ConcurrentStatement> {
     let statement = {
         try_token_kind!(
             token,
             Block => {
-                ConcurrentStatement::Block(parse_block_statement(stream, messages)?)
+                ConcurrentStatement::Block(parse_block_statement(stream, label, messages)?)
             },
             Process => {
-                ConcurrentStatement::Process(parse_process_statement(stream, false, messages)?)
+                ConcurrentStatement::Process(parse_process_statement(stream, label, false, messages)?)
             },
             Component => {
                 let unit = InstantiatedUnit::Component(parse_selected_name(stream)?);
@@ -465,20 +518,30 @@ pub fn parse_concurrent_statement(
                 let unit = InstantiatedUnit::Entity(name, arch);
                 ConcurrentStatement::Instance(parse_instantiation_statement(stream, unit)?)
             },
-            For => ConcurrentStatement::ForGenerate(parse_for_generate_statement(stream, messages)?),
-            If => ConcurrentStatement::IfGenerate(parse_if_generate_statement(stream, messages)?),
-            Case => ConcurrentStatement::CaseGenerate(parse_case_generate_statement(stream, messages)?),
-            Assert => ConcurrentStatement::Assert(parse_concurrent_assert_statement(stream, false)?),
+            For => ConcurrentStatement::ForGenerate(parse_for_generate_statement(stream, label, messages)?),
+            If => ConcurrentStatement::IfGenerate(parse_if_generate_statement(stream, label, messages)?),
+            Case => ConcurrentStatement::CaseGenerate(parse_case_generate_statement(stream, label, messages)?),
+            Assert => parse_concurrent_assert_or_psl_directive(stream)?,
+            Assume => psl_directive(stream, PslDirectiveKind::Assume)?,
+            AssumeGuarantee => psl_directive(stream, PslDirectiveKind::AssumeGuarantee)?,
+            Restrict => psl_directive(stream, PslDirectiveKind::Restrict)?,
+            RestrictGuarantee => psl_directive(stream, PslDirectiveKind::RestrictGuarantee)?,
+            Cover => psl_directive(stream, PslDirectiveKind::Cover)?,
             Postponed => {
                 let token = stream.expect()?;
                 match token.kind {
-                    Process => ConcurrentStatement::Process(parse_process_statement(stream, true, messages)?),
+                    Process => ConcurrentStatement::Process(parse_process_statement(stream, label, true, messages)?),
                     Assert => ConcurrentStatement::Assert(parse_concurrent_assert_statement(stream, true)?),
                     With => ConcurrentStatement::Assignment(parse_selected_signal_assignment(stream, true)?),
+                    LeftPar => {
+                        let target = parse_aggregate_leftpar_known(stream)?.map_into(Target::Aggregate);
+                        let token = stream.expect()?;
+                        parse_assignment_or_procedure_call(stream, &token, target, true)?
+                    }
                     _ => {
                         let target = parse_name_initial_token(stream, token)?.map_into(Target::Name);
-                        stream.expect_kind(SemiColon)?;
-                        ConcurrentStatement::ProcedureCall(to_procedure_call(target, true)?)
+                        let token = stream.expect()?;
+                        parse_assignment_or_procedure_call(stream, &token, target, true)?
                     }
                 }
             },
@@ -493,14 +556,14 @@ pub fn parse_concurrent_statement(
                     }
                     _ => {
                         stream.move_after(&token);
-                        parse_assignment_or_procedure_call(stream, &token, name.map_into(Target::Name))?
+                        parse_assignment_or_procedure_call(stream, &token, name.map_into(Target::Name), false)?
                     }
                 }
             },
             LeftPar => {
                 let target = parse_aggregate_leftpar_known(stream)?.map_into(Target::Aggregate);
                 let token = stream.expect()?;
-                parse_assignment_or_procedure_call(stream, &token, target)?
+                parse_assignment_or_procedure_call(stream, &token, target, false)?
             }
         )
     };
@@ -513,17 +576,112 @@ pub fn parse_labeled_concurrent_statements_end_token(
 ) -> ParseResult<(Vec<LabeledConcurrentStatement>, Token)> {
     let mut statements = Vec::new();
     loop {
+        let state = stream.state();
         let token = stream.expect()?;
         match token.kind {
             End | Elsif | Else | When => {
                 break Ok((statements, token));
             }
-            _ => {
-                statements.push(parse_labeled_concurrent_statement_initial_token(
-                    stream, token, messages,
-                )?);
+            _ => match parse_labeled_concurrent_statement_initial_token(stream, token, messages) {
+                Ok(statement) => statements.push(statement),
+                Err(err) => {
+                    stream.set_state(state);
+                    match recover_concurrent_statement(stream) {
+                        Ok(()) => messages.push(err),
+                        // A tokenizing error is found again by the recovery and only reported once
+                        Err(recover_err) => {
+                            if recover_err != err {
+                                messages.push(err);
+                            }
+                            return Err(recover_err);
+                        }
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// Skip a concurrent statement which failed to parse, starting from its first token
+/// Nested block, process and generate statements are skipped until their end, otherwise the
+/// statement ends at the next semicolon or before an end or the keyword or label of a new statement
+fn recover_concurrent_statement(stream: &mut TokenStream) -> ParseResult<()> {
+    if stream.next_kinds_are(&[Identifier, Colon])? {
+        stream.pop()?;
+        stream.pop()?;
+    }
+
+    let mut nesting = Vec::new();
+    // The generate keyword of an elsif or else alternative does not start a new generate statement
+    let mut alternative = false;
+    let mut first = true;
+    loop {
+        let token = stream.peek_expect()?;
+        let new_statement = !first && nesting.is_empty();
+        match token.kind {
+            Block | Process | Postponed | Assert | With if new_statement => {
+                return Ok(());
+            }
+            Identifier if new_statement && stream.next_kinds_are(&[Identifier, Colon])? => {
+                return Ok(());
+            }
+            Postponed => {
+                // The statement starts at the keyword after postponed
+                stream.move_after(&token);
+                continue;
+            }
+            Block | Process => {
+                nesting.push(token.kind);
+            }
+            Generate => {
+                if alternative {
+                    alternative = false;
+                } else {
+                    nesting.push(Generate);
+                }
             }
+            Elsif | Else if nesting.last() == Some(&Generate) => {
+                alternative = true;
+            }
+            SemiColon => {
+                alternative = false;
+                if nesting.is_empty() {
+                    stream.move_after(&token);
+                    return Ok(());
+                }
+            }
+            End => {
+                let state = stream.state();
+                stream.move_after(&token);
+                stream.skip_if_kind(Postponed)?;
+                let kind = stream.peek_kind()?;
+                match kind {
+                    Some(Block) | Some(Process) | Some(Generate) if !nesting.is_empty() => {
+                        stream.pop()?;
+                        nesting.pop();
+                        if nesting.is_empty() {
+                            stream.pop_optional_ident()?;
+                            stream.skip_if_kind(SemiColon)?;
+                            return Ok(());
+                        }
+                    }
+                    // Inner end of a generate statement alternative
+                    Some(SemiColon) | Some(Identifier) if nesting.last() == Some(&Generate) => {}
+                    Some(If) | Some(Case) | Some(Loop) | Some(Function) | Some(Procedure)
+                    | Some(Record) | Some(Units) | Some(Protected) | Some(Component)
+                        if !nesting.is_empty() => {}
+                    _ => {
+                        // End of the enclosing statement or design unit
+                        stream.set_state(state);
+                        return Ok(());
+                    }
+                }
+                continue;
+            }
+            _ => {}
         }
+        stream.move_after(&token);
+        first = false;
     }
 }
 
@@ -546,18 +704,18 @@ pub fn parse_labeled_concurrent_statement_initial_token(
         if token.kind == Colon {
             let label = Some(to_simple_name(name)?);
             let token = stream.expect()?;
-            let statement = parse_concurrent_statement(stream, token, messages)?;
+            let statement = parse_concurrent_statement(stream, token, label.as_ref(), messages)?;
             Ok(LabeledConcurrentStatement { label, statement })
         } else {
             let target = name.map_into(Target::Name);
-            let statement = parse_assignment_or_procedure_call(stream, &token, target)?;
+            let statement = parse_assignment_or_procedure_call(stream, &token, target, false)?;
             Ok(LabeledConcurrentStatement {
                 label: None,
                 statement,
             })
         }
     } else {
-        let statement = parse_concurrent_statement(stream, token, messages)?;
+        let statement = parse_concurrent_statement(stream, token, None, messages)?;
         Ok(LabeledConcurrentStatement {
             label: None,
             statement,
@@ -578,7 +736,8 @@ pub fn parse_labeled_concurrent_statement(
 mod tests {
     use super::*;
     use crate::ast::{Alternative, AssertStatement, DelayMechanism, Selection};
-    use crate::test_util::Code;
+    use crate::message::MessageCode;
+    use crate::test_util::{check_messages, Code};
 
     #[test]
     fn test_concurrent_procedure() {
@@ -711,6 +870,36 @@ end block;
         assert_eq!(stmt.statement, ConcurrentStatement::Block(block));
     }
 
+    #[test]
+    fn test_guarded_block_with_is() {
+        let code = Code::new(
+            "\
+name : block (cond) is
+begin
+  foo <= guarded bar;
+end block;
+",
+        );
+        let assign = ConcurrentSignalAssignment {
+            postponed: false,
+            guarded: true,
+            target: code.s1("foo").name().map_into(Target::Name),
+            delay_mechanism: None,
+            rhs: AssignmentRightHand::Simple(code.s1("bar").waveform()),
+        };
+        let block = BlockStatement {
+            guard_condition: Some(code.s1("cond").expr()),
+            decl: vec![],
+            statements: vec![LabeledConcurrentStatement {
+                label: None,
+                statement: ConcurrentStatement::Assignment(assign),
+            }],
+        };
+        let stmt = code.with_stream_no_messages(parse_labeled_concurrent_statement);
+        assert_eq!(stmt.label, Some(code.s1("name").ident()));
+        assert_eq!(stmt.statement, ConcurrentStatement::Block(block));
+    }
+
     #[test]
     fn test_process_statement() {
         let code = Code::new(
@@ -771,6 +960,54 @@ end process;
         assert_eq!(stmt.statement, ConcurrentStatement::Process(process));
     }
 
+    #[test]
+    fn test_postponed_process_statement_end_postponed() {
+        let code = Code::new(
+            "\
+name : postponed process
+begin
+end postponed process name;
+",
+        );
+        let process = ProcessStatement {
+            postponed: true,
+            sensitivity_list: None,
+            decl: vec![],
+            statements: vec![],
+        };
+        let stmt = code.with_stream_no_messages(parse_labeled_concurrent_statement);
+        assert_eq!(stmt.label, Some(code.s1("name").ident()));
+        assert_eq!(stmt.statement, ConcurrentStatement::Process(process));
+    }
+
+    #[test]
+    fn test_end_postponed_of_process_which_is_not_postponed() {
+        let code = Code::new(
+            "\
+process
+begin
+end postponed process;
+",
+        );
+        let (stmt, messages) = code.with_stream_messages(parse_labeled_concurrent_statement);
+        assert_eq!(
+            stmt.statement,
+            ConcurrentStatement::Process(ProcessStatement {
+                postponed: false,
+                sensitivity_list: None,
+                decl: vec![],
+                statements: vec![],
+            })
+        );
+        check_messages(
+            messages,
+            vec![Message::syntax_error(
+                code.s1("postponed"),
+                "'postponed' at the end of a process which is not postponed",
+            )],
+        );
+    }
+
     #[test]
     fn test_process_statement_sensitivity() {
         let code = Code::new(
@@ -812,7 +1049,7 @@ end process;
         };
         assert_eq!(
             messages,
-            vec![Message::error(
+            vec![Message::syntax_error(
                 code.s1(")"),
                 "Processes with sensitivity lists must contain at least one element."
             )]
@@ -846,6 +1083,33 @@ end process;
         assert_eq!(stmt.statement, ConcurrentStatement::Process(process));
     }
 
+    #[test]
+    fn test_process_statement_all_requires_vhdl_2008() {
+        let code = Code::with_standard(
+            "\
+process (all) is
+begin
+end process;
+",
+            VHDLStandard::VHDL1993,
+        );
+        let (stmt, messages) = code.with_stream_messages(parse_labeled_concurrent_statement);
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s1("all"),
+                "Process sensitivity list 'all' requires VHDL-2008 or later, the selected standard is VHDL-93",
+                MessageCode::UnsupportedInStandard,
+            )],
+        );
+        match stmt.statement {
+            ConcurrentStatement::Process(process) => {
+                assert_eq!(process.sensitivity_list, Some(SensitivityList::All))
+            }
+            _ => panic!("Expected process"),
+        }
+    }
+
     #[test]
     fn test_concurrent_assert() {
         let code = Code::new(
@@ -905,6 +1169,75 @@ foo <= bar(2 to 3);
         assert_eq!(stmt.statement, ConcurrentStatement::Assignment(assign));
     }
 
+    #[test]
+    fn test_postponed_guarded_signal_assignment() {
+        let code = Code::new(
+            "\
+postponed foo <= guarded transport bar;
+",
+        );
+        let assign = ConcurrentSignalAssignment {
+            postponed: true,
+            guarded: true,
+            target: code.s1("foo").name().map_into(Target::Name),
+            delay_mechanism: Some(DelayMechanism::Transport),
+            rhs: AssignmentRightHand::Simple(code.s1("bar").waveform()),
+        };
+        let stmt = code.with_stream_no_messages(parse_labeled_concurrent_statement);
+        assert_eq!(stmt.label, None);
+        assert_eq!(stmt.statement, ConcurrentStatement::Assignment(assign));
+    }
+
+    #[test]
+    fn test_postponed_aggregate_signal_assignment() {
+        let code = Code::new(
+            "\
+postponed (foo, bar) <= baz;
+",
+        );
+        let assign = ConcurrentSignalAssignment {
+            postponed: true,
+            guarded: false,
+            target: code
+                .s1("(foo, bar)")
+                .aggregate()
+                .map_into(Target::Aggregate),
+            delay_mechanism: None,
+            rhs: AssignmentRightHand::Simple(code.s1("baz").waveform()),
+        };
+        let stmt = code.with_stream_no_messages(parse_labeled_concurrent_statement);
+        assert_eq!(stmt.statement, ConcurrentStatement::Assignment(assign));
+    }
+
+    #[test]
+    fn parse_guarded_selected_signal_assignment() {
+        let code = Code::new(
+            "\
+with sel select
+   foo <= guarded bar when others;",
+        );
+
+        let selection = Selection {
+            expression: code.s1("sel").expr(),
+            alternatives: vec![Alternative {
+                choices: code.s1("others").choices(),
+                item: code.s1("bar").waveform(),
+            }],
+        };
+
+        let stmt = code.with_stream_no_messages(parse_labeled_concurrent_statement);
+        assert_eq!(
+            stmt.statement,
+            ConcurrentStatement::Assignment(ConcurrentSignalAssignment {
+                postponed: false,
+                guarded: true,
+                target: code.s1("foo").name().map_into(Target::Name),
+                delay_mechanism: None,
+                rhs: AssignmentRightHand::Selected(selection),
+            })
+        );
+    }
+
     #[test]
     fn parse_selected_signal_assignment() {
         let code = Code::new(
@@ -1366,8 +1699,11 @@ end generate;
             messages,
             vec![Message::error(
                 code.s1("alt4"),
-                "End identifier mismatch, expected alt3"
-            )]
+                "End identifier mismatch, expected alt3",
+                MessageCode::EndIdentifierMismatch
+            )
+            .related(code.s1("alt3"), "Opening identifier")
+            .with_replacement("alt3")]
         );
     }
     #[test]
@@ -1488,4 +1824,89 @@ end generate;
         assert_eq!(stmt.label, Some(code.s1("gen").ident()));
         assert_eq!(stmt.statement, ConcurrentStatement::CaseGenerate(gen));
     }
+
+    #[test]
+    fn test_end_label_mismatch() {
+        let code = Code::new(
+            "\
+blk: block
+begin
+  proc: process
+  begin
+  end process proc2;
+
+  gen: for i in 0 to 1 generate
+  end generate gen;
+end block blk2;
+",
+        );
+        let (_, messages) = code.with_stream_messages(parse_labeled_concurrent_statement);
+        check_messages(
+            messages,
+            vec![
+                Message::error(
+                    code.s1("proc2"),
+                    "End identifier mismatch, expected proc",
+                    MessageCode::EndIdentifierMismatch,
+                )
+                .related(code.s1("proc"), "Opening identifier")
+                .with_replacement("proc"),
+                Message::error(
+                    code.s1("blk2"),
+                    "End identifier mismatch, expected blk",
+                    MessageCode::EndIdentifierMismatch,
+                )
+                .related(code.s1("blk"), "Opening identifier")
+                .with_replacement("blk"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_recovers_from_errors_in_statements() {
+        let code = Code::new(
+            "\
+blk : block
+begin
+  a <= ;
+  proc : process (clk = 1) is
+  begin
+    wait;
+  end process;
+  gen : for i in 0 to generate
+    x <= y;
+  end generate;
+  b <= c;
+  f <= g
+  inst : entity work.foo;
+end block;
+",
+        );
+        let (stmt, messages) = code.with_stream_messages(parse_labeled_concurrent_statement);
+        assert_eq!(stmt.label, Some(code.s1("blk").ident()));
+        assert_eq!(
+            stmt.statement,
+            ConcurrentStatement::Block(BlockStatement {
+                guard_condition: None,
+                decl: vec![],
+                statements: vec![
+                    code.s1("b <= c;").concurrent_statement(),
+                    code.s1("inst : entity work.foo;").concurrent_statement(),
+                ],
+            })
+        );
+        check_messages(
+            messages,
+            vec![
+                Message::syntax_error(code.s(";", 1), "Expected {expression}"),
+                Message::syntax_error(
+                    code.s1("= 1").s1("="),
+                    "Expected '{identifier}', '{character}', '{string}' or 'all'",
+                ),
+                Message::syntax_error(code.s1("generate"), "Expected {expression}")
+                    .when("parsing discrete_range"),
+                Message::syntax_error(code.s1("inst"), "Expected 'when' or ';'"),
+            ],
+        );
+    }
 }
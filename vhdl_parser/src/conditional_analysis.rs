@@ -0,0 +1,756 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
+
+//! VHDL-2019 conditional analysis such as `if TOOL_NAME = "x" then ... `end if
+
+use self::fnv::FnvHashMap;
+use crate::message::{Message, MessageCode, MessageHandler, ParseResult};
+use crate::source::SrcPos;
+use crate::tokenizer::{
+    merge_ranges, raw_literal_end, raw_next_line, Kind::*, Token, TokenState, Value,
+};
+use crate::tokenstream::TokenStream;
+use crate::vhdl_standard::VHDLStandard;
+use fnv;
+
+/// The values of conditional analysis identifiers, the names are case insensitive
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct ConditionalAnalysis {
+    values: FnvHashMap<String, String>,
+}
+
+impl ConditionalAnalysis {
+    pub fn new() -> ConditionalAnalysis {
+        ConditionalAnalysis::default()
+    }
+
+    pub fn define(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_uppercase(), value.to_owned());
+    }
+
+    /// Define an identifier from a string such as TOOL_NAME=vhdl_ls
+    pub fn define_str(&mut self, definition: &str) -> Result<(), String> {
+        let mut parts = definition.splitn(2, '=');
+        match (parts.next(), parts.next()) {
+            (Some(name), Some(value)) if !name.trim().is_empty() => {
+                self.define(name.trim(), value);
+                Ok(())
+            }
+            _ => Err(format!(
+                "Invalid definition '{}', expected NAME=VALUE",
+                definition
+            )),
+        }
+    }
+
+    /// Values of the other identifiers take precedence
+    pub fn append(&mut self, other: ConditionalAnalysis) {
+        self.values.extend(other.values);
+    }
+
+    /// The value of an identifier where VHDL_VERSION is predefined by the standard
+    pub fn value(&self, name: &str, standard: VHDLStandard) -> Option<String> {
+        let name = name.to_uppercase();
+        if let Some(value) = self.values.get(&name) {
+            Some(value.clone())
+        } else if name == "VHDL_VERSION" {
+            Some(vhdl_version(standard).to_owned())
+        } else {
+            None
+        }
+    }
+}
+
+fn vhdl_version(standard: VHDLStandard) -> &'static str {
+    match standard {
+        VHDLStandard::VHDL1993 => "1993",
+        VHDLStandard::VHDL2002 => "2002",
+        VHDLStandard::VHDL2008 => "2008",
+        VHDLStandard::VHDL2019 => "2019",
+    }
+}
+
+/// An `if directive which has not yet been closed by `end if
+struct Branch {
+    pos: SrcPos,
+    parent_active: bool,
+    // Some alternative of the directive has been active
+    taken: bool,
+    active: bool,
+    seen_else: bool,
+}
+
+impl Branch {
+    fn alternative(&mut self, condition: bool) {
+        self.active = self.parent_active && !self.taken && condition;
+        self.taken |= self.active;
+    }
+}
+
+/// Evaluate the conditional analysis directives of the stream
+/// The directives and the inactive code are skipped when the stream is parsed afterwards
+pub fn analyse_directives(
+    stream: &mut TokenStream,
+    identifiers: &ConditionalAnalysis,
+    messages: &mut dyn MessageHandler,
+) {
+    let initial_state = stream.state();
+    let mut skipped = Vec::new();
+    let mut inactive_regions = Vec::new();
+    let mut branches: Vec<Branch> = Vec::new();
+    let mut inactive_start = None;
+    let mut last_end = 0;
+
+    loop {
+        let state = stream.state();
+        let token = match stream.pop() {
+            Ok(Some(token)) => token,
+            Ok(None) => break,
+            // Errors are reported when parsing, resume on the next line
+            Err(_) => match raw_next_line(stream.tokenizer.code_bytes(), state.start()) {
+                Some(next) => {
+                    stream.set_state(TokenState::at(next));
+                    continue;
+                }
+                None => break,
+            },
+        };
+        last_end = token_end(&token);
+
+        if token.kind != GraveAccent {
+            continue;
+        }
+
+        let was_active = is_active(&branches);
+        let start = token.pos.start;
+        let end = match analyse_directive(stream, &token, identifiers, &mut branches, messages) {
+            Ok(end) => end,
+            Err(msg) => {
+                if was_active {
+                    messages.push(msg);
+                }
+                // Skip the tokens of the erroneous directive
+                skip_line(stream, start, stream.state().start())
+            }
+        };
+        last_end = end;
+        skipped.push((start, end));
+
+        if was_active && !is_active(&branches) {
+            inactive_start = Some(end);
+        } else if !was_active && is_active(&branches) {
+            if let Some(inactive_start) = inactive_start.take() {
+                let length = start - inactive_start;
+                inactive_regions.push(token.pos.source.pos(inactive_start, length));
+                skipped.push((inactive_start, start));
+            }
+        }
+
+        if !is_active(&branches) {
+            // Inactive code need not be valid VHDL and is not tokenized
+            let bytes = stream.tokenizer.code_bytes();
+            let next = next_directive(bytes, end);
+            let code_end = trimmed_end(bytes).max(end);
+            match next {
+                Some(next) => stream.set_state(TokenState::at(next)),
+                None => {
+                    last_end = code_end;
+                    break;
+                }
+            }
+        }
+    }
+
+    if let Some(inactive_start) = inactive_start {
+        let source = &branches[0].pos.source;
+        inactive_regions.push(source.pos(inactive_start, last_end - inactive_start));
+        skipped.push((inactive_start, last_end));
+    }
+
+    for branch in branches {
+        messages.push(Message::syntax_error(
+            branch.pos,
+            "Missing `end if of conditional analysis directive",
+        ));
+    }
+
+    skipped.sort();
+    stream.set_state(initial_state);
+    stream
+        .tokenizer
+        .set_skipped(merge_ranges(skipped), inactive_regions);
+}
+
+fn is_active(branches: &[Branch]) -> bool {
+    branches.last().map(|branch| branch.active).unwrap_or(true)
+}
+
+/// Analyse the directive after the grave accent and return the end position of the directive
+fn analyse_directive(
+    stream: &mut TokenStream,
+    grave_accent: &Token,
+    identifiers: &ConditionalAnalysis,
+    branches: &mut Vec<Branch>,
+    messages: &mut dyn MessageHandler,
+) -> ParseResult<usize> {
+    let was_active = is_active(branches);
+    let token = stream.expect()?;
+    let pos = grave_accent.pos.combine(&token);
+    let mut end = token_end(&token);
+
+    match token.kind {
+        If => {
+            if was_active && stream.standard() < VHDLStandard::VHDL2019 {
+                messages.push(Message::error(
+                    &pos,
+                    format!(
+                        "Conditional analysis requires {} or later, the selected standard is {}",
+                        VHDLStandard::VHDL2019,
+                        stream.standard()
+                    ),
+                    MessageCode::UnsupportedInStandard,
+                ));
+            }
+
+            let mut undefined = Vec::new();
+            let condition = parse_condition(stream, identifiers, &mut undefined);
+            if was_active {
+                for msg in undefined {
+                    messages.push(msg);
+                }
+            }
+
+            // An erroneous condition is false so that the `end if still matches
+            let mut branch = Branch {
+                pos,
+                parent_active: was_active,
+                taken: false,
+                active: false,
+                seen_else: false,
+            };
+            branch.alternative(*condition.as_ref().unwrap_or(&false));
+            branches.push(branch);
+            end = condition.map(|_| stream.state().start())?;
+        }
+        Elsif => {
+            let branch = last_branch(branches, &token)?;
+            if branch.seen_else {
+                return Err(Message::syntax_error(&token, "`elsif after `else"));
+            }
+
+            let mut undefined = Vec::new();
+            let condition = parse_condition(stream, identifiers, &mut undefined);
+            // The condition is only evaluated when no previous alternative was active
+            if branch.parent_active && !branch.taken {
+                for msg in undefined {
+                    messages.push(msg);
+                }
+            }
+            branch.alternative(*condition.as_ref().unwrap_or(&false));
+            end = condition.map(|_| stream.state().start())?;
+        }
+        Else => {
+            let branch = last_branch(branches, &token)?;
+            if branch.seen_else {
+                return Err(Message::syntax_error(&token, "Duplicate `else"));
+            }
+            branch.seen_else = true;
+            branch.alternative(true);
+        }
+        End => {
+            last_branch(branches, &token)?;
+            branches.pop();
+
+            let state = stream.state();
+            if let Some(if_token) = stream.pop_if_kind(If)? {
+                if stream.tokenizer.newline_between(end, if_token.pos.start) {
+                    // An if statement on the next line
+                    stream.set_state(state);
+                } else {
+                    end = token_end(&if_token);
+                }
+            }
+        }
+        Identifier => {
+            let name = token
+                .clone()
+                .expect_ident()?
+                .item
+                .name_utf8()
+                .to_lowercase();
+            if name == "warning" || name == "error" {
+                let literal = stream.expect_kind(StringLiteral)?;
+                end = token_end(&literal);
+                if was_active {
+                    let pos = grave_accent.pos.combine(&literal);
+                    let message = string_value(&literal);
+                    messages.push(if name == "warning" {
+                        Message::warning(pos, message, MessageCode::ToolDirective)
+                    } else {
+                        Message::error(pos, message, MessageCode::ToolDirective)
+                    });
+                }
+            } else {
+                // Other tool directives are implementation defined and ignored
+                end = skip_line(stream, token.pos.start, end);
+            }
+        }
+        _ => {
+            return Err(Message::syntax_error(
+                &token,
+                "Expected conditional analysis directive",
+            ));
+        }
+    }
+
+    Ok(end)
+}
+
+fn last_branch<'a>(branches: &'a mut [Branch], token: &Token) -> ParseResult<&'a mut Branch> {
+    branches.last_mut().ok_or_else(|| {
+        let name = match token.kind {
+            Elsif => "elsif",
+            Else => "else",
+            _ => "end",
+        };
+        Message::syntax_error(token, format!("`{} without `if", name))
+    })
+}
+
+fn token_end(token: &Token) -> usize {
+    token.pos.start + token.pos.length
+}
+
+fn string_value(token: &Token) -> String {
+    match token.value {
+        Value::String(ref string) => string.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// The position of the next grave accent outside of comments, string and character literals
+fn next_directive(bytes: &[u8], mut pos: usize) -> Option<usize> {
+    while pos < bytes.len() {
+        if let Some(end) = raw_literal_end(bytes, pos) {
+            pos = end;
+            continue;
+        }
+        match bytes[pos] {
+            b'`' => return Some(pos),
+            b'-' if bytes.get(pos + 1) == Some(&b'-') => {
+                pos = raw_next_line(bytes, pos)?;
+            }
+            _ => {
+                pos += 1;
+            }
+        }
+    }
+    None
+}
+
+/// The end of the code without trailing whitespace
+fn trimmed_end(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map(|idx| idx + 1)
+        .unwrap_or(0)
+}
+
+/// Skip the remaining tokens on the line which starts at the position
+/// Returns the end of the last skipped token or the given end when there are none
+fn skip_line(stream: &mut TokenStream, start: usize, mut end: usize) -> usize {
+    loop {
+        let state = stream.state();
+        match stream.pop() {
+            Ok(Some(next)) if !stream.tokenizer.newline_between(start, next.pos.start) => {
+                end = token_end(&next);
+            }
+            _ => {
+                stream.set_state(state);
+                return end;
+            }
+        }
+    }
+}
+
+/// Parse the expression and the then keyword of an `if or `elsif directive
+fn parse_condition(
+    stream: &mut TokenStream,
+    identifiers: &ConditionalAnalysis,
+    undefined: &mut Vec<Message>,
+) -> ParseResult<bool> {
+    let condition = parse_expression(stream, identifiers, undefined)?;
+    stream.expect_kind(Then)?;
+    Ok(condition)
+}
+
+/// conditional_analysis_expression ::= relation { logical_operator relation }
+/// The logical operator may not change without parenthesis
+/// Undefined identifiers have the empty string as value and are reported to the undefined vector
+fn parse_expression(
+    stream: &mut TokenStream,
+    identifiers: &ConditionalAnalysis,
+    undefined: &mut Vec<Message>,
+) -> ParseResult<bool> {
+    let mut value = parse_relation(stream, identifiers, undefined)?;
+    let mut operator = None;
+
+    while let Some(token) = stream.peek()? {
+        if ![And, Or, Xor, Xnor].contains(&token.kind) {
+            break;
+        }
+        if operator.is_some() && operator != Some(token.kind) {
+            return Err(Message::syntax_error(
+                &token,
+                "Mixed logical operators require parenthesis",
+            ));
+        }
+        stream.move_after(&token);
+        operator = Some(token.kind);
+
+        let rhs = parse_relation(stream, identifiers, undefined)?;
+        value = match token.kind {
+            And => value && rhs,
+            Or => value || rhs,
+            Xor => value != rhs,
+            _ => value == rhs,
+        };
+    }
+
+    Ok(value)
+}
+
+/// relation ::= ( expression ) | not ( expression ) | identifier relational_operator string_literal
+fn parse_relation(
+    stream: &mut TokenStream,
+    identifiers: &ConditionalAnalysis,
+    undefined: &mut Vec<Message>,
+) -> ParseResult<bool> {
+    let token = stream.expect()?;
+    match token.kind {
+        LeftPar => {
+            let value = parse_expression(stream, identifiers, undefined)?;
+            stream.expect_kind(RightPar)?;
+            Ok(value)
+        }
+        Not => {
+            stream.expect_kind(LeftPar)?;
+            let value = parse_expression(stream, identifiers, undefined)?;
+            stream.expect_kind(RightPar)?;
+            Ok(!value)
+        }
+        Identifier => {
+            let ident = token.expect_ident()?;
+            let operator = stream.expect()?;
+            let literal = string_value(&stream.expect_kind(StringLiteral)?);
+
+            let name = ident.item.name_utf8();
+            let value = match identifiers.value(&name, stream.standard()) {
+                Some(value) => value,
+                None => {
+                    undefined.push(Message::error(
+                        &ident,
+                        format!("Undefined conditional analysis identifier '{}'", name),
+                        MessageCode::ToolDirective,
+                    ));
+                    String::new()
+                }
+            };
+
+            Ok(try_token_kind!(
+                operator,
+                EQ => value == literal,
+                NE => value != literal,
+                LT => value < literal,
+                LTE => value <= literal,
+                GT => value > literal,
+                GTE => value >= literal
+            ))
+        }
+        _ => Err(Message::syntax_error(
+            &token,
+            "Expected conditional analysis identifier, 'not' or '('",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{DesignFile, HasIdent};
+    use crate::parser::VHDLParser;
+    use crate::source::Source;
+    use crate::symbol_table::SymbolTable;
+    use crate::synthesis_pragma::TranslateOff;
+    use crate::test_util::{check_messages, check_no_messages, Code};
+    use std::sync::Arc;
+
+    fn parse(code: &Code, identifiers: &[(&str, &str)]) -> (DesignFile, Vec<Message>) {
+        parse_source(
+            &code.symtab,
+            &code.pos().source,
+            code.standard(),
+            identifiers,
+        )
+    }
+
+    /// Parse code which cannot be tokenized in its entirety
+    fn parse_str(code: &str, identifiers: &[(&str, &str)]) -> (DesignFile, Vec<Message>) {
+        parse_source(
+            &Arc::new(SymbolTable::new()),
+            &Source::from_str(code),
+            VHDLStandard::VHDL2019,
+            identifiers,
+        )
+    }
+
+    fn parse_source(
+        symtab: &Arc<SymbolTable>,
+        source: &Source,
+        standard: VHDLStandard,
+        identifiers: &[(&str, &str)],
+    ) -> (DesignFile, Vec<Message>) {
+        let mut conditional_analysis = ConditionalAnalysis::new();
+        for (name, value) in identifiers {
+            conditional_analysis.define(name, value);
+        }
+        let parser = VHDLParser {
+            symtab: symtab.clone(),
+            conditional_analysis,
+            translate_off: TranslateOff::Analyse,
+        };
+
+        let mut messages = Vec::new();
+        let design_file = parser
+            .parse_design_source(source, standard, &mut messages)
+            .unwrap();
+        (design_file, messages)
+    }
+
+    fn unit_names(design_file: &DesignFile) -> Vec<String> {
+        design_file
+            .design_units
+            .iter()
+            .map(|unit| unit.ident().item.name_utf8())
+            .collect()
+    }
+
+    #[test]
+    fn selects_active_alternative() {
+        let code = Code::with_standard(
+            "\
+`if TOOL_NAME = \"sim\" then
+entity sim_ent is end entity;
+`elsif tool_name = \"synth\" then
+entity synth_ent is end entity;
+`else
+entity other_ent is end entity;
+`end if
+entity common is end entity;
+",
+            VHDLStandard::VHDL2019,
+        );
+
+        let (design_file, messages) = parse(&code, &[("TOOL_NAME", "synth")]);
+        check_no_messages(&messages);
+        assert_eq!(unit_names(&design_file), vec!["synth_ent", "common"]);
+        assert_eq!(
+            design_file.inactive_regions,
+            vec![
+                code.s1("\nentity sim_ent is end entity;\n").pos(),
+                code.s1("\nentity other_ent is end entity;\n").pos()
+            ]
+        );
+
+        let (design_file, messages) = parse(&code, &[("TOOL_NAME", "lint")]);
+        check_no_messages(&messages);
+        assert_eq!(unit_names(&design_file), vec!["other_ent", "common"]);
+    }
+
+    #[test]
+    fn nested_directives_and_expressions() {
+        let code = Code::with_standard(
+            "\
+`if VHDL_VERSION >= \"2019\" and not (TOOL_TYPE = \"SYNTHESIS\") then
+  `if (TOOL_VENDOR = \"a\" or TOOL_VENDOR = \"b\") then
+entity inner is end entity;
+  `end if
+entity outer is end entity;
+`end
+",
+            VHDLStandard::VHDL2019,
+        );
+
+        let (design_file, messages) =
+            parse(&code, &[("TOOL_TYPE", "SIMULATION"), ("TOOL_VENDOR", "b")]);
+        check_no_messages(&messages);
+        assert_eq!(unit_names(&design_file), vec!["inner", "outer"]);
+
+        let (design_file, messages) =
+            parse(&code, &[("TOOL_TYPE", "SYNTHESIS"), ("TOOL_VENDOR", "b")]);
+        check_no_messages(&messages);
+        assert!(design_file.design_units.is_empty());
+        assert_eq!(design_file.inactive_regions.len(), 1);
+    }
+
+    #[test]
+    fn no_errors_within_inactive_code() {
+        let code = Code::with_standard(
+            "\
+`if TOOL_NAME = \"other\" then
+  this is not vhdl; `foo
+  `warning \"not shown\"
+  `if UNDEFINED = \"\" then
+  `end if
+`end if
+",
+            VHDLStandard::VHDL2019,
+        );
+
+        let (design_file, messages) = parse(&code, &[("TOOL_NAME", "vhdl_ls")]);
+        check_no_messages(&messages);
+        assert!(design_file.design_units.is_empty());
+    }
+
+    #[test]
+    fn inactive_code_is_not_tokenized() {
+        let code = "\
+`if TOOL_NAME = \"other\" then
+  report \"unterminated;
+  don't stop here -- `end if
+  c := '`';
+  # other tool syntax $ \"`end if\"
+`else
+entity ent is end entity;
+`end if
+entity last is end entity;
+";
+
+        let (design_file, messages) = parse_str(code, &[("TOOL_NAME", "vhdl_ls")]);
+        check_no_messages(&messages);
+        assert_eq!(unit_names(&design_file), vec!["ent", "last"]);
+    }
+
+    #[test]
+    fn end_if_directive_followed_by_if_statement() {
+        let code = Code::with_standard(
+            "\
+architecture a of e is
+begin
+  process
+  begin
+    `if TOOL_NAME = \"sim\" then
+    report \"sim\";
+    `end
+    if true then
+    end if;
+  end process;
+end architecture;
+",
+            VHDLStandard::VHDL2019,
+        );
+
+        let (design_file, messages) = parse(&code, &[("TOOL_NAME", "synth")]);
+        check_no_messages(&messages);
+        assert_eq!(unit_names(&design_file), vec!["a"]);
+    }
+
+    #[test]
+    fn directive_errors() {
+        let code = Code::with_standard(
+            "\
+`if UNDEFINED = \"x\" then
+`end if
+`else
+`warning \"look out\"
+`error \"stop\"
+`if TOOL_NAME = \"x\" and TOOL_NAME = \"y\" or TOOL_NAME = \"z\" then
+`end if
+`if TOOL_NAME = \"x\" then
+",
+            VHDLStandard::VHDL2019,
+        );
+
+        let (_, messages) = parse(&code, &[("TOOL_NAME", "x")]);
+        check_messages(
+            messages,
+            vec![
+                Message::error(
+                    code.s1("UNDEFINED"),
+                    "Undefined conditional analysis identifier 'UNDEFINED'",
+                    MessageCode::ToolDirective,
+                ),
+                Message::syntax_error(code.s1("else"), "`else without `if"),
+                Message::warning(
+                    code.s1("`warning \"look out\""),
+                    "look out",
+                    MessageCode::ToolDirective,
+                ),
+                Message::error(
+                    code.s1("`error \"stop\""),
+                    "stop",
+                    MessageCode::ToolDirective,
+                ),
+                Message::syntax_error(
+                    code.s1(" or ").s1("or"),
+                    "Mixed logical operators require parenthesis",
+                ),
+                Message::syntax_error(
+                    code.s("`if", 3),
+                    "Missing `end if of conditional analysis directive",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn conditional_analysis_requires_vhdl_2019() {
+        let code = Code::with_standard(
+            "\
+`if VHDL_VERSION = \"2008\" then
+entity ent is end entity;
+`end if
+",
+            VHDLStandard::VHDL2008,
+        );
+
+        let (design_file, messages) = parse(&code, &[]);
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s1("`if"),
+                "Conditional analysis requires VHDL-2019 or later, the selected standard is VHDL-2008",
+                MessageCode::UnsupportedInStandard,
+            )],
+        );
+        assert_eq!(unit_names(&design_file), vec!["ent"]);
+    }
+
+    #[test]
+    fn define_from_str() {
+        let mut identifiers = ConditionalAnalysis::new();
+        assert_eq!(identifiers.define_str("tool_name=vhdl_ls"), Ok(()));
+        assert_eq!(identifiers.define_str("EMPTY="), Ok(()));
+        assert_eq!(
+            identifiers.define_str("=value"),
+            Err("Invalid definition '=value', expected NAME=VALUE".to_owned())
+        );
+        assert_eq!(
+            identifiers.value("TOOL_NAME", VHDLStandard::VHDL2019),
+            Some("vhdl_ls".to_owned())
+        );
+        assert_eq!(
+            identifiers.value("empty", VHDLStandard::VHDL2019),
+            Some("".to_owned())
+        );
+        assert_eq!(
+            identifiers.value("vhdl_version", VHDLStandard::VHDL1993),
+            Some("1993".to_owned())
+        );
+        assert_eq!(identifiers.value("other", VHDLStandard::VHDL2019), None);
+    }
+}
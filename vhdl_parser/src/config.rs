@@ -10,20 +10,114 @@ use toml;
 
 use self::fnv::FnvHashMap;
 use self::toml::Value;
+use crate::analysis;
+use crate::conditional_analysis::ConditionalAnalysis;
+use crate::generic_values::GenericValues;
+use crate::import::{self, ImportedFile};
+use crate::message::{Message, MessageCategory, MessageCode, Severity};
+use crate::synthesis_pragma::TranslateOff;
+use crate::vhdl_standard::VHDLStandard;
 use fnv;
 use std::fs::File;
 use std::io;
 use std::io::prelude::*;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
+#[derive(Default)]
 pub struct Config {
     // A map from library name to file name
     libraries: FnvHashMap<String, LibraryConfig>,
+    severities: SeverityMap,
+    // The standard of libraries which do not select their own
+    standard: Option<VHDLStandard>,
+    conditional_analysis: ConditionalAnalysis,
+    generics: GenericValues,
+    translate_off: Option<TranslateOff>,
+    // Problems which did not prevent reading the config such as patterns without matches
+    warnings: Vec<String>,
+}
+
+/// Overrides the severity of messages by code or category
+/// A severity of None means the message is not shown
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct SeverityMap {
+    codes: FnvHashMap<MessageCode, Option<Severity>>,
+    categories: FnvHashMap<MessageCategory, Option<Severity>>,
+}
+
+impl SeverityMap {
+    /// Settings of the other map take precedence
+    fn append(&mut self, other: SeverityMap) {
+        self.codes.extend(other.codes);
+        self.categories.extend(other.categories);
+    }
+
+    /// Keys are message codes, message names or categories
+    /// Values are error, warning, info, hint or off
+    fn from_toml(table: &toml::value::Table) -> Result<SeverityMap, String> {
+        let mut severities = SeverityMap::default();
+
+        for (key, value) in table.iter() {
+            let value = value
+                .as_str()
+                .ok_or_else(|| format!("severity of {} is not a string", key))?;
+
+            let severity = match value {
+                "error" => Some(Severity::Error),
+                "warning" => Some(Severity::Warning),
+                "info" => Some(Severity::Info),
+                "hint" => Some(Severity::Hint),
+                "off" => None,
+                _ => {
+                    return Err(format!(
+                        "Invalid severity '{}' of {}, expected 'error', 'warning', 'info', 'hint' or 'off'",
+                        value, key
+                    ));
+                }
+            };
+
+            if let Ok(code) = key.parse::<MessageCode>() {
+                severities.codes.insert(code, severity);
+            } else if let Ok(category) = key.parse::<MessageCategory>() {
+                severities.categories.insert(category, severity);
+            } else {
+                return Err(format!("Unknown message code or category '{}'", key));
+            }
+        }
+
+        Ok(severities)
+    }
+
+    /// The configured severity where a code takes precedence over its category
+    pub fn severity(&self, message: &Message) -> Option<Severity> {
+        if let Some(severity) = self.codes.get(&message.code) {
+            *severity
+        } else if let Some(severity) = self.categories.get(&message.category()) {
+            *severity
+        } else {
+            Some(message.severity)
+        }
+    }
+
+    /// Change the severity of messages and remove messages which are turned off
+    pub fn apply(&self, messages: Vec<Message>) -> Vec<Message> {
+        messages
+            .into_iter()
+            .filter_map(|mut message| {
+                message.severity = self.severity(&message)?;
+                Some(message)
+            })
+            .collect()
+    }
 }
 
 pub struct LibraryConfig {
     name: String,
     files: Vec<String>,
+    is_third_party: bool,
+    standard: Option<VHDLStandard>,
+    // The config file which defined the library
+    config_file: Option<PathBuf>,
 }
 
 impl LibraryConfig {
@@ -34,18 +128,42 @@ impl LibraryConfig {
     pub fn name(&self) -> &str {
         self.name.as_str()
     }
+
+    /// Third party libraries are analysed but their messages are not shown
+    pub fn is_third_party(&self) -> bool {
+        self.is_third_party
+    }
+
+    /// The standard selected for this library, None means the global standard is used
+    pub fn standard(&self) -> Option<VHDLStandard> {
+        self.standard
+    }
+
+    /// The config file which defined the library if it was read from a file
+    pub fn config_file(&self) -> Option<&Path> {
+        self.config_file.as_ref().map(|path| path.as_path())
+    }
 }
 
 impl Config {
     pub fn from_str(string: &str, parent: &Path) -> Result<Config, String> {
-        let config = string.parse::<Value>().map_err(|err| err.to_string())?;
+        let config: Value = toml::from_str(string).map_err(|err| err.to_string())?;
         let mut libraries = FnvHashMap::default();
+        let mut warnings = Vec::new();
 
-        let libs = config
-            .get("libraries")
-            .ok_or("missing field libraries")?
-            .as_table()
-            .ok_or("libraries must be a table")?;
+        let import = config.get("import");
+        if let Some(import) = import {
+            let import = import.as_table().ok_or("import must be a table")?;
+            libraries.extend(import_libraries(import, parent)?);
+        }
+
+        let empty = toml::value::Table::new();
+        let libs = match config.get("libraries") {
+            Some(libs) => libs.as_table().ok_or("libraries must be a table")?,
+            // All libraries may be imported
+            None if import.is_some() => &empty,
+            None => return Err("missing field libraries".to_owned()),
+        };
 
         for (name, lib) in libs.iter() {
             let file_arr = lib
@@ -54,30 +172,155 @@ impl Config {
                 .as_array()
                 .ok_or_else(|| format!("files for library {} is not array", name))?;
 
+            let mut exclude = Vec::new();
+            if let Some(exclude_arr) = lib.get("exclude") {
+                let exclude_arr = exclude_arr
+                    .as_array()
+                    .ok_or_else(|| format!("exclude for library {} is not array", name))?;
+
+                for pattern in exclude_arr.iter() {
+                    let pattern = pattern
+                        .as_str()
+                        .ok_or_else(|| format!("not a string {}", pattern))?;
+                    let pattern = resolve_path(pattern, parent)?;
+                    exclude.push(
+                        glob::Pattern::new(&pattern).map_err(|err| {
+                            format!("Invalid exclude pattern {} ({})", pattern, err)
+                        })?,
+                    );
+                }
+            }
+
             let mut files = Vec::new();
             for file in file_arr.iter() {
                 let file = file
                     .as_str()
                     .ok_or_else(|| format!("not a string {}", file))?;
 
-                let path = parent.join(file);
-                let path = path
-                    .to_str()
-                    .ok_or_else(|| format!("Could not convert {:?} to string", path))?
-                    .to_owned();
-                files.push(path);
+                let pattern = resolve_path(file, parent)?;
+                let paths = expand_glob(&pattern, &mut warnings)?;
+                if paths.is_empty() {
+                    warnings.push(format!(
+                        "Pattern {} of library {} matches no files",
+                        pattern, name
+                    ));
+                }
+                for path in paths {
+                    if !exclude
+                        .iter()
+                        .any(|pattern| pattern.matches_with(&path, match_options()))
+                    {
+                        files.push(path);
+                    }
+                }
             }
 
+            let is_third_party = match lib.get("is_third_party") {
+                Some(value) => value
+                    .as_bool()
+                    .ok_or_else(|| format!("is_third_party for library {} is not a bool", name))?,
+                None => false,
+            };
+
+            let standard = match lib.get("standard") {
+                Some(value) => Some(
+                    parse_standard(value).map_err(|msg| format!("{} for library {}", msg, name))?,
+                ),
+                None => None,
+            };
+
             libraries.insert(
                 name.to_owned(),
                 LibraryConfig {
                     name: name.to_owned(),
                     files,
+                    is_third_party,
+                    standard,
+                    config_file: None,
                 },
             );
         }
 
-        Ok(Config { libraries })
+        let severities = match config.get("diagnostics") {
+            Some(diagnostics) => SeverityMap::from_toml(
+                diagnostics
+                    .as_table()
+                    .ok_or("diagnostics must be a table")?,
+            )?,
+            None => SeverityMap::default(),
+        };
+
+        let standard = match config.get("standard") {
+            Some(value) => Some(parse_standard(value)?),
+            None => None,
+        };
+
+        let mut conditional_analysis = ConditionalAnalysis::new();
+        if let Some(identifiers) = config.get("conditional_analysis") {
+            let identifiers = identifiers
+                .as_table()
+                .ok_or("conditional_analysis must be a table")?;
+            for (name, value) in identifiers.iter() {
+                let value = value.as_str().ok_or_else(|| {
+                    format!("conditional analysis identifier {} is not a string", name)
+                })?;
+                conditional_analysis.define(name, value);
+            }
+        }
+
+        let mut generics = GenericValues::new();
+        if let Some(values) = config.get("generics") {
+            let values = values.as_table().ok_or("generics must be a table")?;
+            for (name, value) in values.iter() {
+                match value {
+                    // The generics of a single entity
+                    Value::Table(entity_values) => {
+                        for (generic_name, value) in entity_values.iter() {
+                            let qualified_name = format!("{}.{}", name, generic_name);
+                            generics.define(
+                                &qualified_name,
+                                parse_generic_value(&qualified_name, value)?,
+                            );
+                        }
+                    }
+                    _ => generics.define(name, parse_generic_value(name, value)?),
+                }
+            }
+        }
+
+        let translate_off = match config.get("translate_off") {
+            Some(value) => Some(
+                value
+                    .as_str()
+                    .ok_or_else(|| format!("translate_off {} is not a string", value))?
+                    .parse()?,
+            ),
+            None => None,
+        };
+
+        Ok(Config {
+            libraries,
+            severities,
+            standard,
+            conditional_analysis,
+            generics,
+            translate_off,
+            warnings,
+        })
+    }
+
+    /// Create a config from the output of VUnit --export-json
+    /// Relative file names are relative to the parent folder
+    pub fn from_vunit_export(json: &str, parent: &Path) -> Result<Config, String> {
+        Ok(Config {
+            libraries: group_by_library(import::vunit_json(json, parent)?),
+            severities: SeverityMap::default(),
+            standard: None,
+            conditional_analysis: ConditionalAnalysis::new(),
+            generics: GenericValues::new(),
+            translate_off: None,
+            warnings: Vec::new(),
+        })
     }
 
     pub fn read_file_path(file_name: &Path) -> io::Result<Config> {
@@ -87,7 +330,46 @@ impl Config {
 
         let parent = file_name.parent().unwrap();
 
-        Config::from_str(&contents, parent).map_err(|msg| io::Error::new(io::ErrorKind::Other, msg))
+        let mut config = Config::from_str(&contents, parent)
+            .map_err(|msg| io::Error::new(io::ErrorKind::Other, msg))?;
+
+        for library in config.libraries.values_mut() {
+            library.config_file = Some(file_name.to_owned());
+        }
+
+        Ok(config)
+    }
+
+    /// Merge another config into this config
+    /// Libraries, severities, conditional analysis identifiers and generics of the other config replace those with the same name
+    /// The standard and translate_off mode of the other config replace those of this one if they are set
+    pub fn append(&mut self, other: Config) {
+        self.libraries.extend(other.libraries);
+        self.severities.append(other.severities);
+        self.conditional_analysis.append(other.conditional_analysis);
+        self.generics.append(other.generics);
+        if other.standard.is_some() {
+            self.standard = other.standard;
+        }
+        if other.translate_off.is_some() {
+            self.translate_off = other.translate_off;
+        }
+        self.warnings.extend(other.warnings);
+    }
+
+    /// Remove the warnings found when reading the config such that they are only reported once
+    pub fn take_warnings(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    /// The standard of libraries which do not select their own
+    pub fn standard(&self) -> VHDLStandard {
+        self.standard.unwrap_or_default()
+    }
+
+    /// The standard which the files of the library are parsed and analyzed according to
+    pub fn library_standard(&self, library: &LibraryConfig) -> VHDLStandard {
+        library.standard().unwrap_or_else(|| self.standard())
     }
 
     pub fn get_library<'a>(&'a self, name: &str) -> Option<&'a LibraryConfig> {
@@ -97,6 +379,228 @@ impl Config {
     pub fn iter_libraries(&self) -> impl Iterator<Item = &LibraryConfig> {
         self.libraries.values()
     }
+
+    pub fn severities(&self) -> &SeverityMap {
+        &self.severities
+    }
+
+    /// The values of conditional analysis identifiers such as TOOL_NAME
+    pub fn conditional_analysis(&self) -> &ConditionalAnalysis {
+        &self.conditional_analysis
+    }
+
+    /// Identifiers such as those given on the command line replace the values of the config
+    pub fn append_conditional_analysis(&mut self, identifiers: ConditionalAnalysis) {
+        self.conditional_analysis.append(identifiers);
+    }
+
+    /// The values of generic constants supplied at elaboration
+    pub fn generics(&self) -> &GenericValues {
+        &self.generics
+    }
+
+    /// Generics such as those given on the command line replace the values of the config
+    pub fn append_generics(&mut self, generics: GenericValues) {
+        self.generics.append(generics);
+    }
+
+    /// How the code between translate_off and translate_on pragmas is analysed
+    pub fn translate_off(&self) -> TranslateOff {
+        self.translate_off.unwrap_or_default()
+    }
+}
+
+/// Read the libraries of the files listed in the import table
+/// Each key is a file format and each value is a file name or a list of file names
+fn import_libraries(
+    import: &toml::value::Table,
+    parent: &Path,
+) -> Result<FnvHashMap<String, LibraryConfig>, String> {
+    let mut imported = Vec::new();
+
+    for (key, value) in import.iter() {
+        let file_names = match value {
+            Value::String(file_name) => vec![file_name.as_str()],
+            Value::Array(file_names) => file_names
+                .iter()
+                .map(|file_name| {
+                    file_name
+                        .as_str()
+                        .ok_or_else(|| format!("not a string {}", file_name))
+                })
+                .collect::<Result<_, _>>()?,
+            _ => return Err(format!("import {} is not a string or array", key)),
+        };
+
+        for file_name in file_names {
+            let file_name = PathBuf::from(resolve_path(file_name, parent)?);
+            let contents = std::fs::read_to_string(&file_name)
+                .map_err(|err| format!("Could not read {} ({})", file_name.display(), err))?;
+            let folder = file_name.parent().unwrap_or(parent);
+
+            let files = match key.as_str() {
+                "vunit_json" => import::vunit_json(&contents, folder),
+                "vivado_xpr" => import::vivado_xpr(&contents, &file_name),
+                "quartus_qsf" => import::quartus_qsf(&contents, folder),
+                "modelsim" => import::modelsim(&contents, folder),
+                "fusesoc_core" => import::fusesoc_core(&contents, folder),
+                _ => {
+                    return Err(format!(
+                        "Unknown import '{}', expected 'vunit_json', 'vivado_xpr', 'quartus_qsf', 'modelsim' or 'fusesoc_core'",
+                        key
+                    ));
+                }
+            };
+
+            imported.extend(files.map_err(|msg| format!("{} in {}", msg, file_name.display()))?);
+        }
+    }
+
+    Ok(group_by_library(imported))
+}
+
+/// Group imported files by library keeping the order of the files
+fn group_by_library(imported: Vec<ImportedFile>) -> FnvHashMap<String, LibraryConfig> {
+    let mut libraries: FnvHashMap<String, LibraryConfig> = FnvHashMap::default();
+
+    for ImportedFile {
+        library_name,
+        file_name,
+    } in imported
+    {
+        let library = libraries
+            .entry(library_name.clone())
+            .or_insert_with(|| LibraryConfig {
+                name: library_name,
+                files: Vec::new(),
+                is_third_party: false,
+                standard: None,
+                config_file: None,
+            });
+
+        if !library.files.contains(&file_name) {
+            library.files.push(file_name);
+        }
+    }
+
+    libraries
+}
+
+/// A generic value is an integer, a float or a boolean
+fn parse_generic_value(name: &str, value: &Value) -> Result<analysis::Value, String> {
+    match value {
+        Value::Integer(value) => Ok(analysis::Value::Integer(*value)),
+        Value::Float(value) => Ok(analysis::Value::Real(*value)),
+        Value::Boolean(value) => Ok(analysis::Value::Boolean(*value)),
+        _ => Err(format!(
+            "generic {} is not an integer, float or boolean",
+            name
+        )),
+    }
+}
+
+/// The standard may be given as a string such as '93' or as an integer such as 2008
+fn parse_standard(value: &Value) -> Result<VHDLStandard, String> {
+    match value {
+        Value::String(string) => string.parse(),
+        Value::Integer(integer) => integer.to_string().parse(),
+        _ => Err(format!("standard {} is not a string", value)),
+    }
+}
+
+/// Substitute environment variables and make the path relative to the parent folder
+pub(crate) fn resolve_path(path: &str, parent: &Path) -> Result<String, String> {
+    let path = substitute_variables(path, |name| std::env::var(name).ok())?;
+    let path = parent.join(path);
+    path.to_str()
+        .ok_or_else(|| format!("Could not convert {:?} to string", path))
+        .map(|path| path.to_owned())
+}
+
+/// Substitute $NAME and ${NAME} with the value of the variable
+fn substitute_variables(
+    string: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<String, String> {
+    let mut result = String::new();
+    let mut chars = string.chars().peekable();
+
+    while let Some(chr) = chars.next() {
+        if chr != '$' {
+            result.push(chr);
+            continue;
+        }
+
+        let mut name = String::new();
+        if chars.peek() == Some(&'{') {
+            chars.next();
+            loop {
+                match chars.next() {
+                    Some('}') => break,
+                    Some(chr) => name.push(chr),
+                    None => return Err(format!("Missing '}}' in {}", string)),
+                }
+            }
+        } else {
+            while let Some(&chr) = chars.peek() {
+                if chr.is_ascii_alphanumeric() || chr == '_' {
+                    name.push(chr);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+        }
+
+        if name.is_empty() {
+            result.push('$');
+        } else {
+            let value =
+                lookup(&name).ok_or_else(|| format!("Environment variable {} is not set", name))?;
+            result.push_str(&value);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Wildcards of include and exclude patterns do not match the path separator
+fn match_options() -> glob::MatchOptions {
+    glob::MatchOptions {
+        require_literal_separator: true,
+        ..glob::MatchOptions::new()
+    }
+}
+
+/// Expand a glob pattern into the sorted list of matching files
+/// Paths without glob characters are kept even when they do not exist
+/// Folders which cannot be read are skipped with a warning
+fn expand_glob(pattern: &str, warnings: &mut Vec<String>) -> Result<Vec<String>, String> {
+    if !pattern.contains(['*', '?', '[']) {
+        return Ok(vec![pattern.to_owned()]);
+    }
+
+    let paths = glob::glob_with(pattern, match_options())
+        .map_err(|err| format!("Invalid pattern {} ({})", pattern, err))?;
+
+    let mut files = Vec::new();
+    for path in paths {
+        let path = match path {
+            Ok(path) => path,
+            Err(err) => {
+                warnings.push(err.to_string());
+                continue;
+            }
+        };
+        if path.is_file() {
+            let path = path
+                .to_str()
+                .ok_or_else(|| format!("Could not convert {:?} to string", path))?;
+            files.push(path.to_owned());
+        }
+    }
+    files.sort();
+    Ok(files)
 }
 
 #[cfg(test)]
@@ -157,6 +661,553 @@ lib1.files = [
 
         assert_eq!(lib1.file_names(), &[pkg1_path, tb_ent_path]);
         assert_eq!(lib2.file_names(), &[pkg2_path, absolute_vhd]);
+        assert!(!lib1.is_third_party());
+    }
+
+    #[test]
+    fn append_config() {
+        let parent = Path::new("");
+        let mut config = Config::from_str(
+            "
+[libraries]
+ieee.files = ['user/ieee.vhd']
+lib.files = ['user/lib.vhd']
+
+[diagnostics]
+lint = 'off'
+E0201 = 'hint'
+",
+            parent,
+        )
+        .unwrap();
+
+        config.append(
+            Config::from_str(
+                "
+[libraries]
+lib.files = ['project/lib.vhd']
+
+[diagnostics]
+E0201 = 'warning'
+",
+                parent,
+            )
+            .unwrap(),
+        );
+
+        let mut libraries: Vec<&str> = config.iter_libraries().map(|lib| lib.name()).collect();
+        libraries.sort();
+        assert_eq!(libraries, &["ieee", "lib"]);
+        assert_eq!(
+            config.get_library("lib").unwrap().file_names(),
+            &["project/lib.vhd".to_owned()]
+        );
+
+        let code = crate::test_util::Code::new("foo");
+        let severities = config.severities();
+        assert_eq!(
+            severities.severity(&Message::error(code.pos(), "", MessageCode::UnresolvedName)),
+            Some(Severity::Warning)
+        );
+        assert_eq!(
+            severities.severity(&Message::hint(
+                code.pos(),
+                "",
+                MessageCode::UnnecessaryLibraryClause
+            )),
+            None
+        );
+    }
+
+    #[test]
+    fn read_file_path_sets_config_file() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let file_name = tempdir.path().join("vhdl_ls.toml");
+        std::fs::write(&file_name, "[libraries]\nlib.files = []\n").unwrap();
+
+        let config = Config::read_file_path(&file_name).unwrap();
+        assert_eq!(
+            config.get_library("lib").unwrap().config_file(),
+            Some(file_name.as_path())
+        );
+    }
+
+    #[test]
+    fn third_party_library() {
+        let config = Config::from_str(
+            "
+[libraries]
+vendor.files = ['vendor.vhd']
+vendor.is_third_party = true
+",
+            Path::new(""),
+        )
+        .unwrap();
+        assert!(config.get_library("vendor").unwrap().is_third_party());
+
+        assert_eq!(
+            Config::from_str(
+                "[libraries]\nvendor.files = []\nvendor.is_third_party = 'yes'",
+                Path::new("")
+            )
+            .err(),
+            Some("is_third_party for library vendor is not a bool".to_owned())
+        );
+    }
+
+    #[test]
+    fn standard_from_str() {
+        let config = Config::from_str(
+            "
+standard = '2002'
+
+[libraries]
+lib.files = []
+legacy.files = []
+legacy.standard = 93
+",
+            Path::new(""),
+        )
+        .unwrap();
+        assert_eq!(config.standard(), VHDLStandard::VHDL2002);
+
+        let lib = config.get_library("lib").unwrap();
+        assert_eq!(lib.standard(), None);
+        assert_eq!(config.library_standard(lib), VHDLStandard::VHDL2002);
+
+        let legacy = config.get_library("legacy").unwrap();
+        assert_eq!(legacy.standard(), Some(VHDLStandard::VHDL1993));
+        assert_eq!(config.library_standard(legacy), VHDLStandard::VHDL1993);
+
+        let config = Config::from_str("[libraries]", Path::new("")).unwrap();
+        assert_eq!(config.standard(), VHDLStandard::VHDL2008);
+    }
+
+    #[test]
+    fn append_standard() {
+        let mut config = Config::from_str("standard = '93'\n[libraries]", Path::new("")).unwrap();
+        config.append(Config::from_str("[libraries]", Path::new("")).unwrap());
+        assert_eq!(config.standard(), VHDLStandard::VHDL1993);
+        config.append(Config::from_str("standard = '2019'\n[libraries]", Path::new("")).unwrap());
+        assert_eq!(config.standard(), VHDLStandard::VHDL2019);
+    }
+
+    #[test]
+    fn standard_errors() {
+        assert_eq!(
+            Config::from_str("standard = '2007'\n[libraries]", Path::new("")).err(),
+            Some("Invalid standard '2007', expected '93', '2002', '2008' or '2019'".to_owned())
+        );
+        assert_eq!(
+            Config::from_str(
+                "[libraries]\nlib.files = []\nlib.standard = 2007",
+                Path::new("")
+            )
+            .err(),
+            Some(
+                "Invalid standard '2007', expected '93', '2002', '2008' or '2019' for library lib"
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            Config::from_str("standard = true\n[libraries]", Path::new("")).err(),
+            Some("standard true is not a string".to_owned())
+        );
+    }
+
+    #[test]
+    fn generics_from_str() {
+        let mut config = Config::from_str(
+            "
+[libraries]
+
+[generics]
+WIDTH = 8
+ratio = 0.5
+fifo.depth = 16
+fifo.enable = true
+",
+            Path::new(""),
+        )
+        .unwrap();
+
+        let mut command_line = GenericValues::new();
+        command_line.define("fifo.width", analysis::Value::Integer(4));
+        config.append_generics(command_line);
+
+        let generics = config.generics();
+        assert_eq!(
+            generics.value("ent", "width"),
+            Some(&analysis::Value::Integer(8))
+        );
+        assert_eq!(
+            generics.value("fifo", "width"),
+            Some(&analysis::Value::Integer(4))
+        );
+        assert_eq!(
+            generics.value("ent", "RATIO"),
+            Some(&analysis::Value::Real(0.5))
+        );
+        assert_eq!(
+            generics.value("FIFO", "depth"),
+            Some(&analysis::Value::Integer(16))
+        );
+        assert_eq!(
+            generics.value("fifo", "enable"),
+            Some(&analysis::Value::Boolean(true))
+        );
+        assert_eq!(generics.value("ent", "depth"), None);
+
+        assert_eq!(
+            Config::from_str(
+                "[libraries]
+[generics]
+width = 'eight'",
+                Path::new("")
+            )
+            .err(),
+            Some("generic width is not an integer, float or boolean".to_owned())
+        );
+    }
+
+    #[test]
+    fn translate_off_from_str() {
+        let mut config = Config::from_str("[libraries]", Path::new("")).unwrap();
+        assert_eq!(config.translate_off(), TranslateOff::Analyse);
+
+        config.append(
+            Config::from_str("translate_off = 'skip'\n[libraries]", Path::new("")).unwrap(),
+        );
+        assert_eq!(config.translate_off(), TranslateOff::Skip);
+
+        assert_eq!(
+            Config::from_str("translate_off = 'ignore'\n[libraries]", Path::new("")).err(),
+            Some("translate_off must be 'analyse' or 'skip', got 'ignore'".to_owned())
+        );
     }
 
+    #[test]
+    fn conditional_analysis_from_str() {
+        let mut config = Config::from_str(
+            "
+[libraries]
+
+[conditional_analysis]
+TOOL_NAME = 'vhdl_ls'
+tool_type = 'SIMULATION'
+",
+            Path::new(""),
+        )
+        .unwrap();
+
+        let mut command_line = ConditionalAnalysis::new();
+        command_line.define("tool_type", "SYNTHESIS");
+        config.append_conditional_analysis(command_line);
+
+        let identifiers = config.conditional_analysis();
+        assert_eq!(
+            identifiers.value("tool_name", VHDLStandard::VHDL2019),
+            Some("vhdl_ls".to_owned())
+        );
+        assert_eq!(
+            identifiers.value("TOOL_TYPE", VHDLStandard::VHDL2019),
+            Some("SYNTHESIS".to_owned())
+        );
+
+        assert_eq!(
+            Config::from_str(
+                "[libraries]\n[conditional_analysis]\nfoo = 1",
+                Path::new("")
+            )
+            .err(),
+            Some("conditional analysis identifier foo is not a string".to_owned())
+        );
+    }
+
+    #[test]
+    fn diagnostics_from_str() {
+        use crate::test_util::Code;
+
+        let config = Config::from_str(
+            "
+[libraries]
+
+[diagnostics]
+unresolved-name = 'warning'
+E0202 = 'off'
+visibility = 'hint'
+",
+            Path::new(""),
+        )
+        .unwrap();
+
+        let code = Code::new("foo");
+        let severities = config.severities();
+        assert_eq!(
+            severities.severity(&Message::error(code.pos(), "", MessageCode::UnresolvedName)),
+            Some(Severity::Warning)
+        );
+        assert_eq!(
+            severities.severity(&Message::error(
+                code.pos(),
+                "",
+                MessageCode::DuplicateDeclaration
+            )),
+            None
+        );
+        assert_eq!(
+            severities.severity(&Message::error(
+                code.pos(),
+                "",
+                MessageCode::InvalidSelectedName
+            )),
+            Some(Severity::Hint)
+        );
+        assert_eq!(
+            severities.severity(&Message::syntax_error(code.pos(), "")),
+            Some(Severity::Error)
+        );
+
+        let messages = severities.apply(vec![
+            Message::error(code.pos(), "", MessageCode::DuplicateDeclaration),
+            Message::syntax_error(code.pos(), ""),
+        ]);
+        assert_eq!(messages, vec![Message::syntax_error(code.pos(), "")]);
+    }
+
+    #[test]
+    fn diagnostics_errors() {
+        let parent = Path::new("");
+        assert_eq!(
+            Config::from_str("[libraries]\n[diagnostics]\nfoo = 'off'", parent).err(),
+            Some("Unknown message code or category 'foo'".to_owned())
+        );
+        assert_eq!(
+            Config::from_str("[libraries]\n[diagnostics]\nlint = 'loud'", parent).err(),
+            Some(
+                "Invalid severity 'loud' of lint, expected 'error', 'warning', 'info', 'hint' or 'off'"
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn substitute_variables_in_path() {
+        let lookup = |name: &str| match name {
+            "XILINX" => Some("/opt/xilinx".to_owned()),
+            _ => None,
+        };
+        assert_eq!(
+            substitute_variables("$XILINX/unisims.vhd", lookup),
+            Ok("/opt/xilinx/unisims.vhd".to_owned())
+        );
+        assert_eq!(
+            substitute_variables("${XILINX}_old/unisims.vhd", lookup),
+            Ok("/opt/xilinx_old/unisims.vhd".to_owned())
+        );
+        assert_eq!(
+            substitute_variables("price$.vhd", lookup),
+            Ok("price$.vhd".to_owned())
+        );
+        assert_eq!(
+            substitute_variables("$MISSING/unisims.vhd", lookup),
+            Err("Environment variable MISSING is not set".to_owned())
+        );
+        assert_eq!(
+            substitute_variables("${XILINX/unisims.vhd", lookup),
+            Err("Missing '}' in ${XILINX/unisims.vhd".to_owned())
+        );
+    }
+
+    #[test]
+    fn config_with_glob_and_exclude() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let parent = tempdir.path();
+        for file_name in &["src/a.vhd", "src/sub/b.vhd", "src/sub/b_old.vhd", "src/c.v"] {
+            let path = parent.join(file_name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "").unwrap();
+        }
+
+        let config = Config::from_str(
+            "
+[libraries]
+lib.files = ['src/**/*.vhd', 'missing.vhd']
+lib.exclude = ['src/**/*_old.vhd']
+",
+            parent,
+        )
+        .unwrap();
+
+        let expected: Vec<String> = ["src/a.vhd", "src/sub/b.vhd", "missing.vhd"]
+            .iter()
+            .map(|file_name| parent.join(file_name).to_str().unwrap().to_owned())
+            .collect();
+        assert_eq!(config.get_library("lib").unwrap().file_names(), &expected);
+    }
+
+    #[test]
+    fn exclude_wildcard_does_not_match_separator() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let parent = tempdir.path();
+        for file_name in &["src/a_old.vhd", "src/sub/b_old.vhd"] {
+            let path = parent.join(file_name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "").unwrap();
+        }
+
+        let mut config = Config::from_str(
+            "
+[libraries]
+lib.files = ['src/**/*.vhd']
+lib.exclude = ['src/*_old.vhd']
+",
+            parent,
+        )
+        .unwrap();
+
+        let expected = vec![parent
+            .join("src/sub/b_old.vhd")
+            .to_str()
+            .unwrap()
+            .to_owned()];
+        assert_eq!(config.get_library("lib").unwrap().file_names(), &expected);
+        assert_eq!(config.take_warnings(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn warns_about_pattern_without_matches() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let parent = tempdir.path();
+
+        let mut config = Config::from_str(
+            "
+[libraries]
+lib.files = ['src/*.vhd']
+",
+            parent,
+        )
+        .unwrap();
+
+        assert!(config.get_library("lib").unwrap().file_names().is_empty());
+        assert_eq!(
+            config.take_warnings(),
+            vec![format!(
+                "Pattern {} of library lib matches no files",
+                parent.join("src/*.vhd").to_str().unwrap()
+            )]
+        );
+        assert!(config.take_warnings().is_empty());
+    }
+
+    #[test]
+    fn config_from_vunit_export() {
+        let parent = Path::new("parent_folder");
+        let config = Config::from_vunit_export(
+            r#"{
+  "export_format_version": {"major": 1, "minor": 0, "patch": 0},
+  "files": [
+    {"file_name": "src/pkg.vhd", "library_name": "lib"},
+    {"file_name": "src/tb.vhd", "library_name": "lib"},
+    {"file_name": "src/pkg.vhd", "library_name": "lib"},
+    {"file_name": "vunit/core.vhd", "library_name": "vunit_lib"}
+  ],
+  "tests": []
+}"#,
+            parent,
+        )
+        .unwrap();
+
+        let mut libraries: Vec<&str> = config.iter_libraries().map(|lib| lib.name()).collect();
+        libraries.sort();
+        assert_eq!(libraries, &["lib", "vunit_lib"]);
+
+        let to_path = |file_name: &str| parent.join(file_name).to_str().unwrap().to_owned();
+        assert_eq!(
+            config.get_library("lib").unwrap().file_names(),
+            &[to_path("src/pkg.vhd"), to_path("src/tb.vhd")]
+        );
+        assert_eq!(
+            config.get_library("vunit_lib").unwrap().file_names(),
+            &[to_path("vunit/core.vhd")]
+        );
+    }
+
+    #[test]
+    fn vunit_export_errors() {
+        let parent = Path::new("");
+        assert!(Config::from_vunit_export("{", parent).is_err());
+        assert_eq!(
+            Config::from_vunit_export("{}", parent).err(),
+            Some("missing field files in VUnit export".to_owned())
+        );
+        assert_eq!(
+            Config::from_vunit_export(r#"{"files": [{"file_name": "a.vhd"}]}"#, parent).err(),
+            Some(
+                r#"missing field library_name in VUnit export of {"file_name":"a.vhd"}"#.to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn import_vunit_export() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let parent = tempdir.path();
+        std::fs::create_dir(parent.join("build")).unwrap();
+        std::fs::write(
+            parent.join("build").join("export.json"),
+            r#"{"files": [
+                {"file_name": "../src/pkg.vhd", "library_name": "lib"},
+                {"file_name": "/vunit/core.vhd", "library_name": "vunit_lib"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let config = Config::from_str(
+            "
+[import]
+vunit_json = 'build/export.json'
+
+[libraries]
+vunit_lib.files = ['vunit/*.vhd']
+vunit_lib.is_third_party = true
+",
+            parent,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.get_library("lib").unwrap().file_names(),
+            &[parent
+                .join("build")
+                .join("../src/pkg.vhd")
+                .to_str()
+                .unwrap()
+                .to_owned()]
+        );
+        // Explicit libraries take precedence over imported ones
+        assert!(config.get_library("vunit_lib").unwrap().is_third_party());
+    }
+
+    #[test]
+    fn import_errors() {
+        let parent = Path::new("missing_folder");
+        let err = Config::from_str("[import]\nvunit_json = 'export.json'", parent).err();
+        assert!(err.unwrap().starts_with("Could not read"));
+
+        let tempdir = tempfile::tempdir().unwrap();
+        let parent = tempdir.path();
+        std::fs::write(parent.join("export.json"), "{}").unwrap();
+        assert_eq!(
+            Config::from_str("[import]\nfoo = 'export.json'", parent).err(),
+            Some("Unknown import 'foo', expected 'vunit_json', 'vivado_xpr', 'quartus_qsf', 'modelsim' or 'fusesoc_core'".to_owned())
+        );
+        assert_eq!(
+            Config::from_str("[import]\nvunit_json = 'export.json'", parent).err(),
+            Some(format!(
+                "missing field files in VUnit export in {}",
+                parent.join("export.json").display()
+            ))
+        );
+    }
 }
@@ -5,15 +5,22 @@
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
 use crate::ast::{
-    BindingIndication, BlockConfiguration, ComponentConfiguration, ComponentSpecification,
-    ConfigurationDeclaration, ConfigurationDeclarativeItem, ConfigurationItem,
-    ConfigurationSpecification, EntityAspect, InstantiationList, Name, VUnitBindingIndication,
+    AssociationElement, Attribute, BindingIndication, BlockConfiguration, ComponentConfiguration,
+    ComponentSpecification, ConfigurationDeclaration, ConfigurationDeclarativeItem,
+    ConfigurationItem, ConfigurationSpecification, EntityAspect, Group, InstantiationList, Name,
+    VUnitBindingIndication,
 };
+use crate::attributes::parse_attribute;
 use crate::common::error_on_end_identifier_mismatch;
 use crate::context::parse_use_clause_no_keyword;
+use crate::group::parse_group;
 use crate::message::{Message, MessageHandler, ParseResult};
-use crate::names::{parse_name, parse_name_initial_token, parse_selected_name, to_simple_name};
+use crate::names::{
+    parse_association_list, parse_name, parse_name_initial_token, parse_selected_name,
+    to_simple_name,
+};
 use crate::source::WithPos;
+use crate::tokenizer::Kind;
 use crate::tokenizer::Kind::*;
 use crate::tokenstream::TokenStream;
 
@@ -41,14 +48,25 @@ fn parse_entity_aspect(stream: &mut TokenStream) -> ParseResult<EntityAspect> {
     Ok(entity_aspect)
 }
 
+/// The optional generic map or port map of a binding indication
+fn parse_optional_map(
+    stream: &mut TokenStream,
+    kind: Kind,
+) -> ParseResult<Option<Vec<AssociationElement>>> {
+    if stream.skip_if_kind(kind)? {
+        stream.expect_kind(Map)?;
+        Ok(Some(parse_association_list(stream)?))
+    } else {
+        Ok(None)
+    }
+}
+
 fn parse_binding_indication_known_entity_aspect(
     entity_aspect: Option<EntityAspect>,
     stream: &mut TokenStream,
 ) -> ParseResult<BindingIndication> {
-    // @TODO generic map
-    let generic_map = None;
-    // @TODO port  map
-    let port_map = None;
+    let generic_map = parse_optional_map(stream, Generic)?;
+    let port_map = parse_optional_map(stream, Port)?;
     stream.expect_kind(SemiColon)?;
     Ok(BindingIndication {
         entity_aspect,
@@ -77,6 +95,14 @@ fn parse_component_configuration_known_spec(
         token,
         End => (None, Vec::new()),
         For => (None, Vec::new()),
+        Generic | Port => {
+            let bind_ind = parse_binding_indication_known_entity_aspect(None, stream)?;
+            if stream.skip_if_kind(Use)? {
+                (Some(bind_ind), parse_vunit_binding_indication_list_known_keyword(stream)?)
+            } else {
+                (Some(bind_ind), Vec::new())
+            }
+        },
         Use => {
             stream.move_after(&token);
             if stream.peek_kind()? == Some(Vunit) {
@@ -203,8 +229,10 @@ fn parse_block_configuration_known_name(
     messages: &mut dyn MessageHandler,
 ) -> ParseResult<BlockConfiguration> {
     let block_spec = name;
-    // @TODO use clauses
-    let use_clauses = Vec::new();
+    let mut use_clauses = Vec::new();
+    while let Some(token) = stream.pop_if_kind(Use)? {
+        use_clauses.push(parse_use_clause_no_keyword(token, stream)?);
+    }
     let mut items = Vec::new();
 
     loop {
@@ -294,6 +322,32 @@ pub fn parse_configuration_declaration(
                     parse_use_clause_no_keyword(token, stream)?,
                 ));
             }
+            Attribute => {
+                for attribute in parse_attribute(stream, messages)? {
+                    match attribute {
+                        Attribute::Specification(spec) => {
+                            decl.push(ConfigurationDeclarativeItem::Attribute(spec));
+                        }
+                        Attribute::Declaration(attr_decl) => {
+                            messages.push(Message::syntax_error(
+                                &attr_decl.ident,
+                                "Attribute declarations are not allowed in configuration declarations",
+                            ));
+                        }
+                    }
+                }
+            }
+            Group => match parse_group(stream)? {
+                Group::Declaration(group_decl) => {
+                    decl.push(ConfigurationDeclarativeItem::Group(group_decl));
+                }
+                Group::Template(template_decl) => {
+                    messages.push(Message::syntax_error(
+                        &template_decl.ident,
+                        "Group template declarations are not allowed in configuration declarations",
+                    ));
+                }
+            },
             _ => break Vec::new(),
         }
     };
@@ -347,16 +401,20 @@ pub fn parse_configuration_specification(
                 })
             }
         }
-        ComponentSpecificationOrName::Name(name) => {
-            Err(Message::error(name, "Expected component specification"))
-        }
+        ComponentSpecificationOrName::Name(name) => Err(Message::syntax_error(
+            name,
+            "Expected component specification",
+        )),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_util::Code;
+    use crate::ast::{
+        AttributeSpecification, EntityClass, EntityName, EntityTag, GroupDeclaration,
+    };
+    use crate::test_util::{check_messages, Code};
 
     #[test]
     fn empty_configuration() {
@@ -872,4 +930,150 @@ end configuration cfg;
             }
         );
     }
+
+    #[test]
+    fn configuration_specification_with_maps() {
+        let code = Code::new(
+            "for all : comp use entity work.foo(rtl) generic map (width => 8) port map (clk => clk, q => open);",
+        );
+
+        assert_eq!(
+            code.with_stream(parse_configuration_specification),
+            ConfigurationSpecification {
+                spec: ComponentSpecification {
+                    instantiation_list: InstantiationList::All,
+                    component_name: code.s1("comp").selected_name(),
+                },
+                bind_ind: BindingIndication {
+                    entity_aspect: Some(EntityAspect::Entity(
+                        code.s1("work.foo").selected_name(),
+                        Some(code.s1("rtl").ident())
+                    )),
+                    generic_map: Some(code.s1("(width => 8)").association_list()),
+                    port_map: Some(code.s1("(clk => clk, q => open)").association_list()),
+                },
+                vunit_bind_inds: Vec::new()
+            }
+        );
+    }
+
+    #[test]
+    fn configuration_specification_port_map_without_entity_aspect() {
+        let code = Code::new("for u1 : comp port map (clk => clk);");
+
+        assert_eq!(
+            code.with_stream(parse_configuration_specification),
+            ConfigurationSpecification {
+                spec: ComponentSpecification {
+                    instantiation_list: InstantiationList::Labels(vec![code.s1("u1").ident()]),
+                    component_name: code.s1("comp").selected_name(),
+                },
+                bind_ind: BindingIndication {
+                    entity_aspect: None,
+                    generic_map: None,
+                    port_map: Some(code.s1("(clk => clk)").association_list()),
+                },
+                vunit_bind_inds: Vec::new()
+            }
+        );
+    }
+
+    #[test]
+    fn configuration_with_attributes_maps_and_use_clauses() {
+        let code = Code::new(
+            "\
+configuration cfg of entity_name is
+  attribute delay of cfg : configuration is 2 ns;
+  for rtl
+    use lib.pkg.all;
+    for inst : comp
+      generic map (width => 8);
+    end for;
+  end for;
+end configuration cfg;
+",
+        );
+        assert_eq!(
+            code.with_stream_no_messages(parse_configuration_declaration),
+            ConfigurationDeclaration {
+                ident: code.s1("cfg").ident(),
+                entity_name: code.s1("entity_name").selected_name(),
+                decl: vec![ConfigurationDeclarativeItem::Attribute(
+                    AttributeSpecification {
+                        ident: code.s1("delay").ident(),
+                        entity_name: EntityName::Name(EntityTag {
+                            designator: code.s("cfg", 2).designator(),
+                            signature: None
+                        }),
+                        entity_class: EntityClass::Configuration,
+                        expr: code.s1("2 ns").expr(),
+                    }
+                )],
+                vunit_bind_inds: Vec::new(),
+                block_config: BlockConfiguration {
+                    block_spec: code.s1("rtl").name(),
+                    use_clauses: vec![code.s1("use lib.pkg.all;").use_clause()],
+                    items: vec![ConfigurationItem::Component(ComponentConfiguration {
+                        spec: ComponentSpecification {
+                            instantiation_list: InstantiationList::Labels(vec![code
+                                .s1("inst")
+                                .ident()]),
+                            component_name: code.s1("comp").selected_name()
+                        },
+                        bind_ind: Some(BindingIndication {
+                            entity_aspect: None,
+                            generic_map: Some(code.s1("(width => 8)").association_list()),
+                            port_map: None,
+                        }),
+                        vunit_bind_inds: Vec::new(),
+                        block_config: None,
+                    })],
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn configuration_attribute_declaration_is_an_error() {
+        let code = Code::new(
+            "\
+configuration cfg of entity_name is
+  attribute delay : time;
+  for rtl
+  end for;
+end configuration cfg;
+",
+        );
+        let (cfg, messages) = code.with_stream_messages(parse_configuration_declaration);
+        assert_eq!(cfg.decl, vec![]);
+        check_messages(
+            messages,
+            vec![Message::syntax_error(
+                code.s1("delay"),
+                "Attribute declarations are not allowed in configuration declarations",
+            )],
+        );
+    }
+
+    #[test]
+    fn configuration_with_group_declaration() {
+        let code = Code::new(
+            "\
+configuration cfg of entity_name is
+  group path : pin2pin (clk, data);
+  for rtl
+  end for;
+end configuration cfg;
+",
+        );
+        let cfg = code.with_stream_no_messages(parse_configuration_declaration);
+        assert_eq!(
+            cfg.decl,
+            vec![ConfigurationDeclarativeItem::Group(GroupDeclaration {
+                ident: code.s1("path").ident(),
+                template_name: code.s1("pin2pin").selected_name(),
+                constituents: vec![code.s1("clk").name(), code.s1("data").name()],
+            })]
+        );
+    }
 }
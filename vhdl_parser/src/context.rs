@@ -120,7 +120,7 @@ pub fn parse_context(
                     pos: name.pos,
                 },
                 _ => {
-                    return Err(Message::error(&name, "Expected simple name"));
+                    return Err(Message::syntax_error(&name, "Expected simple name"));
                 }
             }
         };
@@ -155,6 +155,7 @@ pub fn parse_context(
 mod tests {
     use super::*;
 
+    use crate::message::MessageCode;
     use crate::test_util::Code;
 
     #[test]
@@ -286,8 +287,11 @@ end context ident2;
             messages,
             vec![Message::error(
                 code.s1("ident2"),
-                "End identifier mismatch, expected ident"
-            )]
+                "End identifier mismatch, expected ident",
+                MessageCode::EndIdentifierMismatch
+            )
+            .related(code.s1("ident"), "Opening identifier")
+            .with_replacement("ident")]
         );
         assert_eq!(
             context,
@@ -336,5 +340,4 @@ end context;
             })
         )
     }
-
 }
@@ -10,9 +10,14 @@ use crate::attributes::parse_attribute;
 use crate::component_declaration::parse_component_declaration;
 use crate::configuration::parse_configuration_specification;
 use crate::context::parse_use_clause;
+use crate::group::parse_group;
 use crate::message::{MessageHandler, ParseResult};
+use crate::mode_view_declaration::parse_mode_view_declaration;
 use crate::names::{parse_association_list, parse_selected_name};
-use crate::object_declaration::{parse_file_declaration, parse_object_declaration};
+use crate::object_declaration::{
+    parse_disconnection_specification, parse_file_declaration, parse_object_declaration,
+};
+use crate::psl::parse_psl_declaration;
 use crate::subprogram::parse_subprogram;
 use crate::tokenizer::{Kind, Kind::*, Token};
 use crate::tokenstream::{Recover, TokenStream};
@@ -49,7 +54,8 @@ pub fn is_declarative_part(stream: &mut TokenStream, begin_is_end: bool) -> Pars
 fn check_declarative_part(token: &Token, may_end: bool, may_begin: bool) -> ParseResult<()> {
     match token.kind {
         Use | Type | Subtype | Shared | Constant | Signal | Variable | File | Component
-        | Attribute | Alias | Impure | Function | Procedure | Package | For => Ok(()),
+        | Attribute | Group | Alias | Impure | Function | Procedure | Package | For | View
+        | Default | Property | Sequence => Ok(()),
         Begin if may_begin => Ok(()),
         End if may_end => Ok(()),
         _ => {
@@ -74,85 +80,152 @@ pub fn parse_declarative_part(
     Ok(decl)
 }
 
-pub fn parse_declarative_part_leave_end_token(
+/// True if the token kind starts a declaration
+pub fn is_declaration_start(kind: &Kind) -> bool {
+    match kind {
+        Type | Subtype | Component | Impure | Function | Procedure | Package | For | File
+        | Shared | Constant | Signal | Variable | Attribute | Group | Use | Alias | View
+        | Default | Property | Sequence | Disconnect => true,
+        _ => false,
+    }
+}
+
+/// Parse a single declaration, the initial token must be a declaration start
+/// A declaration which fails to parse is reported and skipped
+pub fn parse_declaration(
     stream: &mut TokenStream,
     messages: &mut dyn MessageHandler,
-) -> ParseResult<Vec<Declaration>> {
-    let mut declarations: Vec<Declaration> = Vec::new();
-
-    fn is_recover_token(kind: &Kind) -> bool {
-        match kind {
-            Type | Subtype | Component | Impure | Function | Procedure | Package | For | File
-            | Shared | Constant | Signal | Variable | Attribute | Use | Alias => true,
-            _ => false,
+    declarations: &mut Vec<Declaration>,
+) -> ParseResult<()> {
+    let state = stream.state();
+    if let Err(err) = parse_declaration_no_recover(stream, messages, declarations) {
+        stream.set_state(state);
+        match recover_declaration(stream) {
+            Ok(()) => messages.push(err),
+            // A tokenizing error is found again by the recovery and only reported once
+            Err(recover_err) => {
+                if recover_err != err {
+                    messages.push(err);
+                }
+                return Err(recover_err);
+            }
         }
-    };
+    }
+    Ok(())
+}
 
-    while let Some(token) = stream.peek()? {
+/// Skip a declaration which failed to parse, starting from its first token
+/// The declaration ends before the next declaration, begin or end unless the end belongs to the
+/// declaration such as the end of a record type, component or subprogram body
+fn recover_declaration(stream: &mut TokenStream) -> ParseResult<()> {
+    stream.expect()?;
+    loop {
+        let token = stream.peek_expect()?;
         match token.kind {
-            Begin | End => break,
-            Type | Subtype | Component | Impure | Function | Procedure | Package | For => {
-                let decl = match token.kind {
-                    Type | Subtype => {
-                        parse_type_declaration(stream, messages).map(|d| Declaration::Type(d))?
-                    }
-                    Component => parse_component_declaration(stream, messages)
-                        .map(|d| Declaration::Component(d))?,
-                    Impure | Function | Procedure => parse_subprogram(stream, messages)?,
-                    Package => {
-                        parse_package_instantiation(stream).map(|d| Declaration::Package(d))?
-                    }
-                    For => parse_configuration_specification(stream)
-                        .map(|d| Declaration::Configuration(d))?,
-                    _ => unreachable!(),
-                };
-                declarations.push(decl);
-            }
-
-            File | Shared | Constant | Signal | Variable | Attribute => {
-                let decls: ParseResult<Vec<Declaration>> = match token.kind {
-                    File => parse_file_declaration(stream)
-                        .map(|decls| decls.into_iter().map(|d| Declaration::File(d)).collect()),
-                    Shared | Constant | Signal | Variable => parse_object_declaration(stream)
-                        .map(|decls| decls.into_iter().map(|d| Declaration::Object(d)).collect()),
-                    Attribute => parse_attribute(stream).map(|decls| {
-                        decls
-                            .into_iter()
-                            .map(|d| Declaration::Attribute(d))
-                            .collect()
-                    }),
-                    _ => unreachable!(),
-                };
-                match decls.or_recover_until(stream, messages, is_recover_token) {
-                    Ok(ref mut decls) => declarations.append(decls),
-                    Err(err) => {
-                        messages.push(err);
-                        continue;
+            End => {
+                let state = stream.state();
+                stream.move_after(&token);
+                match stream.peek_kind()? {
+                    Some(Record) | Some(Units) | Some(Protected) | Some(Component)
+                    | Some(Function) | Some(Procedure) => {}
+                    _ => {
+                        stream.set_state(state);
+                        return Ok(());
                     }
                 }
             }
+            Begin => return Ok(()),
+            ref kind if is_declaration_start(kind) => return Ok(()),
+            _ => stream.move_after(&token),
+        }
+    }
+}
 
-            Use | Alias => {
-                let decl: ParseResult<Declaration> = match token.kind {
-                    Use => parse_use_clause(stream).map(|d| Declaration::Use(d)),
-                    Alias => parse_alias_declaration(stream).map(|d| Declaration::Alias(d)),
-                    _ => unreachable!(),
-                };
-                match decl.or_recover_until(stream, messages, is_recover_token) {
-                    Ok(decl) => declarations.push(decl),
-                    Err(err) => {
-                        messages.push(err);
-                        continue;
-                    }
+fn parse_declaration_no_recover(
+    stream: &mut TokenStream,
+    messages: &mut dyn MessageHandler,
+    declarations: &mut Vec<Declaration>,
+) -> ParseResult<()> {
+    let token = stream.peek_expect()?;
+    match token.kind {
+        Type | Subtype | Component | Impure | Function | Procedure | Package | For | View
+        | Default | Property | Sequence | Group | Disconnect => {
+            let decl = match token.kind {
+                Type | Subtype => {
+                    parse_type_declaration(stream, messages).map(|d| Declaration::Type(d))?
                 }
-            }
+                Component => parse_component_declaration(stream, messages)
+                    .map(|d| Declaration::Component(d))?,
+                Impure | Function | Procedure => parse_subprogram(stream, messages)?,
+                Package => parse_package_instantiation(stream).map(|d| Declaration::Package(d))?,
+                For => parse_configuration_specification(stream)
+                    .map(|d| Declaration::Configuration(d))?,
+                View => {
+                    parse_mode_view_declaration(stream, messages).map(|d| Declaration::View(d))?
+                }
+                Default | Property | Sequence => {
+                    parse_psl_declaration(stream).map(Declaration::Psl)?
+                }
+                Group => parse_group(stream).map(Declaration::Group)?,
+                Disconnect => {
+                    parse_disconnection_specification(stream).map(Declaration::Disconnection)?
+                }
+                _ => unreachable!(),
+            };
+            declarations.push(decl);
+        }
+
+        File | Shared | Constant | Signal | Variable | Attribute => {
+            let decls: ParseResult<Vec<Declaration>> = match token.kind {
+                File => parse_file_declaration(stream)
+                    .map(|decls| decls.into_iter().map(|d| Declaration::File(d)).collect()),
+                Shared | Constant | Signal | Variable => parse_object_declaration(stream)
+                    .map(|decls| decls.into_iter().map(|d| Declaration::Object(d)).collect()),
+                Attribute => parse_attribute(stream, messages).map(|decls| {
+                    decls
+                        .into_iter()
+                        .map(|d| Declaration::Attribute(d))
+                        .collect()
+                }),
+                _ => unreachable!(),
+            };
+            declarations.append(&mut decls?);
+        }
 
+        Use | Alias => {
+            let decl: ParseResult<Declaration> = match token.kind {
+                Use => parse_use_clause(stream).map(|d| Declaration::Use(d)),
+                Alias => parse_alias_declaration(stream, messages).map(|d| Declaration::Alias(d)),
+                _ => unreachable!(),
+            };
+            declarations.push(decl?);
+        }
+
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+
+pub fn parse_declarative_part_leave_end_token(
+    stream: &mut TokenStream,
+    messages: &mut dyn MessageHandler,
+) -> ParseResult<Vec<Declaration>> {
+    let mut declarations: Vec<Declaration> = Vec::new();
+
+    while let Some(token) = stream.peek()? {
+        match token.kind {
+            Begin | End => break,
+            ref kind if is_declaration_start(kind) => {
+                parse_declaration(stream, messages, &mut declarations)?;
+            }
             _ => {
                 messages.push(token.kinds_error(&[
                     Type, Subtype, Component, Impure, Function, Procedure, Package, For, File,
                     Shared, Constant, Signal, Variable, Attribute, Use, Alias,
                 ]));
-                stream.skip_until(is_recover_token)?;
+                stream.skip_until(|kind| {
+                    is_declaration_start(kind) || *kind == Begin || *kind == End
+                })?;
                 continue;
             }
         }
@@ -226,13 +299,14 @@ constant x: natural := 5;
                 class: ObjectClass::Constant,
                 ident: code.s1("x").ident(),
                 subtype_indication: code.s1("natural").subtype_indication(),
+                signal_kind: None,
                 expression: Some(code.s1("5").expr())
             })])
         );
 
         assert_eq!(
             msgs,
-            vec![Message::error(
+            vec![Message::syntax_error(
                 code.s1("var").pos(),
                 "Expected 'type', 'subtype', 'component', 'impure', \
                  'function', 'procedure', 'package', 'for', 'file', \
@@ -242,6 +316,42 @@ constant x: natural := 5;
         );
     }
 
+    #[test]
+    fn parse_declarative_part_recover_until_begin() {
+        let code = Code::new(
+            "\
+type rec_t is record
+  field : ;
+end record;
+constant x: natural := 5;
+signal s : bit
+begin",
+        );
+        let (decls, msgs) = code.with_stream_messages(|stream, messages| {
+            parse_declarative_part(stream, messages, true)
+        });
+        assert_eq!(
+            decls,
+            vec![Declaration::Object(ObjectDeclaration {
+                class: ObjectClass::Constant,
+                ident: code.s1("x").ident(),
+                subtype_indication: code.s1("natural").subtype_indication(),
+                signal_kind: None,
+                expression: Some(code.s1("5").expr())
+            })]
+        );
+        assert_eq!(
+            msgs,
+            vec![
+                Message::syntax_error(
+                    code.s(";", 1),
+                    "Expected '{identifier}', '{string}' or '{character}'"
+                ),
+                Message::syntax_error(code.s1("begin"), "Expected ';'"),
+            ]
+        );
+    }
+
     #[test]
     fn parse_declarative_part_error() {
         // Just checking that there is not an infinite loop
@@ -22,7 +22,8 @@ use crate::declarative_part::{
     parse_declarative_part, parse_declarative_part_leave_end_token, parse_package_instantiation,
 };
 use crate::interface_declaration::parse_generic_interface_list;
-use crate::message::{Message, MessageHandler, ParseResult};
+use crate::message::{Message, MessageCode, MessageHandler, ParseResult};
+use crate::psl::parse_verification_unit;
 use crate::source::WithPos;
 
 /// Parse an entity declaration, token is initial entity token
@@ -166,6 +167,112 @@ fn context_item_message(context_item: &ContextItem, message: impl AsRef<str>) ->
     format!("{} {}", prefix, message.as_ref())
 }
 
+/// Skip a design unit which failed to parse until the start of the next design unit or context clause
+/// The keywords which start a design unit also occur within design units such as in
+/// 'end entity' or 'inst : entity', so the preceding token must not be part of such a construct
+/// A package declaration may also be nested in a declarative part, so the regions opened by 'is'
+/// and closed by 'end' are counted to only resync on a package outside of any region
+/// Tokenizing errors are reported and skipped
+fn recover_design_unit(stream: &mut TokenStream, messages: &mut dyn MessageHandler) {
+    let mut previous = None;
+    let mut depth = 0usize;
+    let mut paren_depth = 0usize;
+    // An 'is' which does not open a region closed by 'end' in the current declaration
+    let mut skip_is = false;
+
+    loop {
+        let token = match stream.peek() {
+            Ok(Some(token)) => token,
+            Ok(None) => return,
+            Err(err) => {
+                stream.tokenizer.skip_error(&err);
+                messages.push(err);
+                continue;
+            }
+        };
+
+        match token.kind {
+            Library | Context | Entity | Architecture | Vunit | Vmode | Vprop | Configuration
+            | Package
+                if previous.is_some() =>
+            {
+                let within_unit = match previous {
+                    Some(Colon) | Some(End) | Some(Use) | Some(LeftPar) | Some(Comma) => true,
+                    // A package instantiation within a declarative part
+                    _ => {
+                        matches!(
+                            stream.next_kinds_are(&[Package, Identifier, Is, New]),
+                            Ok(true)
+                        ) || (token.kind == Package && depth > 0)
+                    }
+                };
+                if !within_unit {
+                    return;
+                }
+            }
+            _ => {}
+        }
+
+        match token.kind {
+            LeftPar => paren_depth += 1,
+            RightPar => paren_depth = paren_depth.saturating_sub(1),
+            SemiColon | Begin => skip_is = false,
+            Subtype | Alias | Attribute | File | Group => skip_is = true,
+            // These may be opened without 'is'
+            Process | Block | Component | Units if previous != Some(End) => {
+                depth += 1;
+                skip_is = true;
+            }
+            Is if !skip_is && paren_depth == 0 => {
+                let state = stream.state();
+                stream.move_after(&token);
+                let opens_region = match stream.peek() {
+                    Ok(Some(next)) => !matches!(
+                        next.kind,
+                        New | LeftPar | Array | Access | File | Range | BOX
+                    ),
+                    _ => true,
+                };
+                stream.set_state(state);
+                if opens_region {
+                    depth += 1;
+                }
+            }
+            End => depth = depth.saturating_sub(1),
+            // Statements which are not counted
+            If | Loop | Generate | For if previous == Some(End) => depth += 1,
+            _ => {}
+        }
+
+        previous = Some(token.kind);
+        stream.move_after(&token);
+    }
+}
+
+/// A package declaration, package body or package instantiation
+fn parse_package_design_unit(
+    stream: &mut TokenStream,
+    context_clause: &mut Vec<WithPos<ContextItem>>,
+    messages: &mut dyn MessageHandler,
+) -> ParseResult<AnyDesignUnit> {
+    if stream.next_kinds_are(&[Package, Body])? {
+        let package_body = parse_package_body(stream, messages)?;
+        Ok(AnyDesignUnit::Secondary(SecondaryUnit::PackageBody(
+            to_design_unit(context_clause, package_body),
+        )))
+    } else if stream.next_kinds_are(&[Package, Identifier, Is, New])? {
+        let inst = parse_package_instantiation(stream)?;
+        Ok(AnyDesignUnit::Primary(PrimaryUnit::PackageInstance(
+            to_design_unit(context_clause, inst),
+        )))
+    } else {
+        let package = parse_package_declaration(stream, messages)?;
+        Ok(AnyDesignUnit::Primary(PrimaryUnit::PackageDeclaration(
+            to_design_unit(context_clause, package),
+        )))
+    }
+}
+
 pub fn parse_design_file(
     stream: &mut TokenStream,
     messages: &mut dyn MessageHandler,
@@ -173,98 +280,124 @@ pub fn parse_design_file(
     let mut context_clause = vec![];
     let mut design_units = vec![];
 
-    while let Some(token) = stream.peek()? {
-        try_token_kind!(
-            token,
-            Library => {
-                match parse_library_clause(stream) {
-                    Ok(library) => {
-                        context_clause.push(library.map_into(ContextItem::Library));
-                    },
-                    Err(msg) => messages.push(msg),
-                }
-            },
-            Use => {
-                match parse_use_clause(stream) {
-                    Ok(use_clause) => {
-                        context_clause.push(use_clause.map_into(ContextItem::Use));
-                    },
-                    Err(msg) => messages.push(msg),
-                }
-            },
+    loop {
+        let token = match stream.peek() {
+            Ok(Some(token)) => token,
+            Ok(None) => break,
+            Err(err) => {
+                stream.tokenizer.skip_error(&err);
+                messages.push(err);
+                continue;
+            }
+        };
+        let state = stream.state();
+        let result = match token.kind {
+            Library => parse_library_clause(stream)
+                .map(|library| context_clause.push(library.map_into(ContextItem::Library))),
+            Use => parse_use_clause(stream)
+                .map(|use_clause| context_clause.push(use_clause.map_into(ContextItem::Use))),
             Context => match parse_context(stream, messages) {
                 Ok(DeclarationOrReference::Declaration(context_decl)) => {
                     if !context_clause.is_empty() {
-                        let mut message = Message::error(&context_decl.ident, "Context declaration may not be preceeded by a context clause");
+                        let mut message = Message::syntax_error(
+                            &context_decl.ident,
+                            "Context declaration may not be preceeded by a context clause",
+                        );
 
                         for context_item in context_clause.iter() {
-                            message.add_related(&context_item, context_item_message(&context_item.item, "may not come before context declaration"));
+                            message.add_related(
+                                &context_item,
+                                context_item_message(
+                                    &context_item.item,
+                                    "may not come before context declaration",
+                                ),
+                            );
                         }
 
                         messages.push(message);
                         context_clause.clear();
                     }
 
-                    design_units.push(AnyDesignUnit::Primary(PrimaryUnit::ContextDeclaration(context_decl)));
+                    design_units.push(AnyDesignUnit::Primary(PrimaryUnit::ContextDeclaration(
+                        context_decl,
+                    )));
+                    Ok(())
                 }
                 Ok(DeclarationOrReference::Reference(context_ref)) => {
                     context_clause.push(context_ref.map_into(ContextItem::Context));
+                    Ok(())
                 }
-                Err(msg) => messages.push(msg),
+                Err(msg) => Err(msg),
             },
-            Entity => match parse_entity_declaration(stream, messages) {
-                Ok(entity) => {
-                    design_units.push(AnyDesignUnit::Primary(PrimaryUnit::EntityDeclaration(to_design_unit(&mut context_clause, entity))));
-                }
-                Err(msg) => messages.push(msg),
-            },
-
-            Architecture => match parse_architecture_body(stream, messages) {
-                Ok(architecture) => {
-                    design_units.push(AnyDesignUnit::Secondary(SecondaryUnit::Architecture(to_design_unit(&mut context_clause, architecture))));
-                }
-                Err(msg) => messages.push(msg),
-            },
-
-            Configuration => match parse_configuration_declaration(stream, messages) {
-                Ok(configuration) => {
-                    design_units.push(AnyDesignUnit::Primary(PrimaryUnit::Configuration(to_design_unit(&mut context_clause, configuration))));
-                }
-                Err(msg) => messages.push(msg),
-            },
-            Package => {
-                if stream.next_kinds_are(&[Package, Body])? {
-                    match parse_package_body(stream, messages) {
-                        Ok(package_body) => {
-                            design_units.push(AnyDesignUnit::Secondary(SecondaryUnit::PackageBody(to_design_unit(&mut context_clause, package_body))));
-                        }
-                        Err(msg) => messages.push(msg),
-                    };
-                } else if stream.next_kinds_are(&[Package, Identifier, Is, New])? {
-                    match parse_package_instantiation(stream) {
-                        Ok(inst) => design_units.push(AnyDesignUnit::Primary(PrimaryUnit::PackageInstance(to_design_unit(&mut context_clause, inst)))),
-                        Err(msg) => messages.push(msg),
-                    }
-                } else {
-                    match parse_package_declaration(stream, messages) {
-                        Ok(package) => {
-                            design_units.push(AnyDesignUnit::Primary(PrimaryUnit::PackageDeclaration(to_design_unit(&mut context_clause, package))))
-                        }
-                        Err(msg) => messages.push(msg),
-                    };
-                }
+            Entity => parse_entity_declaration(stream, messages).map(|entity| {
+                design_units.push(AnyDesignUnit::Primary(PrimaryUnit::EntityDeclaration(
+                    to_design_unit(&mut context_clause, entity),
+                )))
+            }),
+            Architecture => parse_architecture_body(stream, messages).map(|architecture| {
+                design_units.push(AnyDesignUnit::Secondary(SecondaryUnit::Architecture(
+                    to_design_unit(&mut context_clause, architecture),
+                )))
+            }),
+            Vunit | Vmode | Vprop => parse_verification_unit(stream, messages).map(|vunit| {
+                design_units.push(AnyDesignUnit::Primary(PrimaryUnit::VerificationUnit(
+                    to_design_unit(&mut context_clause, vunit),
+                )))
+            }),
+            Configuration => {
+                parse_configuration_declaration(stream, messages).map(|configuration| {
+                    design_units.push(AnyDesignUnit::Primary(PrimaryUnit::Configuration(
+                        to_design_unit(&mut context_clause, configuration),
+                    )))
+                })
             }
-        );
+            Package => parse_package_design_unit(stream, &mut context_clause, messages)
+                .map(|unit| design_units.push(unit)),
+            _ => Err(token.kinds_error(&[
+                Library,
+                Use,
+                Context,
+                Entity,
+                Architecture,
+                Vunit,
+                Vmode,
+                Vprop,
+                Configuration,
+                Package,
+            ])),
+        };
+
+        if let Err(msg) = result {
+            // The context clause belonged to the design unit which failed to parse
+            if token.kind != Library && token.kind != Use {
+                context_clause.clear();
+            }
+            stream.set_state(state);
+            let mut recovery_messages = Vec::new();
+            recover_design_unit(stream, &mut recovery_messages);
+            // A tokenizing error within the design unit is found again by the recovery
+            if !recovery_messages.contains(&msg) {
+                messages.push(msg);
+            }
+            for message in recovery_messages {
+                messages.push(message);
+            }
+        }
     }
 
     for context_item in context_clause {
         messages.push(Message::warning(
             &context_item,
             context_item_message(&context_item.item, "not associated with any design unit"),
+            MessageCode::UnassociatedContextClause,
         ));
     }
 
-    Ok(DesignFile { design_units })
+    Ok(DesignFile {
+        design_units,
+        inactive_regions: stream.tokenizer.inactive_regions().to_vec(),
+        translate_off_regions: stream.tokenizer.translate_off_regions().to_vec(),
+    })
 }
 
 #[cfg(test)]
@@ -670,7 +803,9 @@ end entity;
                             statements: vec![],
                         }
                     }
-                ))]
+                ))],
+                inactive_regions: vec![],
+                translate_off_regions: vec![],
             }
         );
     }
@@ -691,21 +826,26 @@ context lib.ctx;
                 Message::warning(
                     code.s1("library lib;"),
                     "Library clause not associated with any design unit",
+                    MessageCode::UnassociatedContextClause,
                 ),
                 Message::warning(
                     code.s1("use lib.foo;"),
                     "Use clause not associated with any design unit",
+                    MessageCode::UnassociatedContextClause,
                 ),
                 Message::warning(
                     code.s1("context lib.ctx;"),
                     "Context reference not associated with any design unit",
+                    MessageCode::UnassociatedContextClause,
                 ),
             ],
         );
         assert_eq!(
             design_file,
             DesignFile {
-                design_units: vec![]
+                design_units: vec![],
+                inactive_regions: vec![],
+                translate_off_regions: vec![],
             }
         );
     }
@@ -728,7 +868,7 @@ end entity;
         let (design_file, messages) = code.with_stream_messages(parse_design_file);
         check_messages(
             messages,
-            vec![Message::error(
+            vec![Message::syntax_error(
                 code.s1("ctx"),
                 "Context declaration may not be preceeded by a context clause",
             )
@@ -754,4 +894,134 @@ end entity;
         }
     }
 
+    #[test]
+    fn recovers_from_errors_in_design_units() {
+        let code = Code::new(
+            "
+library lib;
+entity ent
+end entity;
+
+architecture arch of ent is
+begin
+  inst : entity work.foo;
+end architecture;
+
+package pkg is
+  constant c : natural := ;
+end package;
+
+garbage;
+
+package pkg2 is
+end package;
+",
+        );
+        let (design_file, messages) = code.with_stream_messages(parse_design_file);
+        check_messages(
+            messages,
+            vec![
+                Message::syntax_error(code.s1("end"), "Expected 'is'"),
+                Message::syntax_error(code.s1(":= ;").s1(";"), "Expected {expression}"),
+                Message::syntax_error(
+                    code.s1("garbage"),
+                    "Expected 'library', 'use', 'context', 'entity', 'architecture', \
+                     'vunit', 'vmode', 'vprop', 'configuration' or 'package'",
+                ),
+            ],
+        );
+
+        let idents: Vec<_> = design_file
+            .design_units
+            .iter()
+            .map(|unit| unit.ident().clone())
+            .collect();
+        assert_eq!(
+            idents,
+            vec![
+                code.s1("arch of").s1("arch").ident(),
+                code.s1("pkg").ident(),
+                code.s1("pkg2").ident()
+            ]
+        );
+
+        // The context clause of the entity which failed to parse is not used
+        match design_file.design_units.first().unwrap() {
+            AnyDesignUnit::Secondary(SecondaryUnit::Architecture(arch)) => {
+                assert_eq!(arch.context_clause.len(), 0);
+            }
+            _ => panic!("Expected architecture"),
+        }
+    }
+
+    #[test]
+    fn recovers_from_tokenizing_errors() {
+        let code = Code::new(
+            "
+entity ent is
+end entity;
+
+architecture arch of ent is
+  signal s : bit $ := '0';
+begin
+end architecture;
+
+$ package pkg is
+end package;
+",
+        );
+        let (design_file, messages) = code.with_stream_messages(parse_design_file);
+        check_messages(
+            messages,
+            vec![
+                Message::syntax_error(code.s("$", 1), "Illegal token"),
+                Message::syntax_error(code.s("$", 2), "Illegal token"),
+            ],
+        );
+
+        // Identifiers cannot be created from the code since it does not tokenize
+        let names: Vec<_> = design_file
+            .design_units
+            .iter()
+            .map(|unit| unit.ident().item.name_utf8())
+            .collect();
+        assert_eq!(names, vec!["ent", "pkg"]);
+    }
+
+    #[test]
+    fn does_not_recover_at_nested_package() {
+        let code = Code::new(
+            "
+architecture arch is
+  package nested is
+    type rec_t is record
+      f : natural;
+    end record;
+    function fun return natural;
+  end package;
+  component comp
+    port (p : bit);
+  end component;
+  package nested_body is
+  end package;
+begin
+end architecture;
+
+package pkg is
+end package;
+",
+        );
+        let (design_file, messages) = code.with_stream_messages(parse_design_file);
+        check_messages(
+            messages,
+            vec![Message::syntax_error(code.s1("is"), "Expected 'of'")],
+        );
+
+        let idents: Vec<_> = design_file
+            .design_units
+            .iter()
+            .map(|unit| unit.ident().clone())
+            .collect();
+        assert_eq!(idents, vec![code.s1("pkg").ident()]);
+    }
 }
@@ -108,14 +108,14 @@ pub fn parse_aggregate_initial_choices(
                     result.push(ElementAssociation::Positional(choice.clone()));
                     return Ok(WithPos::from(result, token))
                 } else {
-                    return Err(Message::error(&token, "Expected => after others"));
+                    return Err(Message::syntax_error(&token, "Expected => after others"));
                 }
             },
             Comma => {
                 if let [Choice::Expression(ref choice)] = *choices.as_slice() {
                     result.push(ElementAssociation::Positional(choice.clone()));
                 } else {
-                    return Err(Message::error(&token, "Expected => after others"));
+                    return Err(Message::syntax_error(&token, "Expected => after others"));
                 }
                 choices = parse_choices(stream)?;
             },
@@ -355,7 +355,7 @@ fn parse_primary_initial_token(
                     pos,
                 })
             } else {
-                Err(Message::error(&token, "Expected {expression}"))
+                Err(Message::syntax_error(&token, "Expected {expression}"))
             }
         }
     }
@@ -1094,18 +1094,24 @@ mod tests {
         let code = Code::new("fun(,)");
         assert_eq!(
             code.with_partial_stream(parse_expression),
-            Err(Message::error(&code.s1(",").pos(), "Expected {expression}"))
+            Err(Message::syntax_error(
+                &code.s1(",").pos(),
+                "Expected {expression}"
+            ))
         );
 
         let code = Code::new("fun(arg0,)");
         assert_eq!(
             code.with_partial_stream(parse_expression),
-            Err(Message::error(&code.s1(")").pos(), "Expected {expression}"))
+            Err(Message::syntax_error(
+                &code.s1(")").pos(),
+                "Expected {expression}"
+            ))
         );
         let code = Code::new("fun(arg0,,)");
         assert_eq!(
             code.with_partial_stream(parse_expression),
-            Err(Message::error(
+            Err(Message::syntax_error(
                 &code.s(",", 2).pos(),
                 "Expected {expression}"
             ))
@@ -1150,5 +1156,4 @@ mod tests {
 
         assert_expression_is("and 1 + 2", "((And Integer(1)) Plus Integer(2))");
     }
-
 }
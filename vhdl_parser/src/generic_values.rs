@@ -0,0 +1,109 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
+
+//! Values of generic constants supplied at elaboration such as WIDTH=8 or fifo.DEPTH=16
+
+use self::fnv::FnvHashMap;
+use crate::analysis::Value;
+use fnv;
+
+/// The values of generic constants, the names are case insensitive
+/// A name such as WIDTH applies to the generics of all entities
+/// A name such as fifo.WIDTH only applies to the generics of entity fifo and takes precedence
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct GenericValues {
+    values: FnvHashMap<String, Value>,
+}
+
+impl GenericValues {
+    pub fn new() -> GenericValues {
+        GenericValues::default()
+    }
+
+    pub fn define(&mut self, name: &str, value: Value) {
+        self.values.insert(name.to_lowercase(), value);
+    }
+
+    /// Define a generic from a string such as WIDTH=8, fifo.RATIO=0.5 or ENABLE=true
+    pub fn define_str(&mut self, definition: &str) -> Result<(), String> {
+        let mut parts = definition.splitn(2, '=');
+        match (parts.next(), parts.next()) {
+            (Some(name), Some(value)) if !name.trim().is_empty() => {
+                let value = parse_value(value.trim()).ok_or_else(|| {
+                    format!(
+                        "Invalid value of generic '{}', expected an integer, real or boolean",
+                        definition
+                    )
+                })?;
+                self.define(name.trim(), value);
+                Ok(())
+            }
+            _ => Err(format!(
+                "Invalid generic '{}', expected NAME=VALUE",
+                definition
+            )),
+        }
+    }
+
+    /// Values of the other generics take precedence
+    pub fn append(&mut self, other: GenericValues) {
+        self.values.extend(other.values);
+    }
+
+    /// The value of a generic of an entity
+    pub fn value(&self, entity_name: &str, generic_name: &str) -> Option<&Value> {
+        let generic_name = generic_name.to_lowercase();
+        self.values
+            .get(&format!("{}.{}", entity_name.to_lowercase(), generic_name))
+            .or_else(|| self.values.get(&generic_name))
+    }
+}
+
+fn parse_value(value: &str) -> Option<Value> {
+    if value.eq_ignore_ascii_case("true") {
+        Some(Value::Boolean(true))
+    } else if value.eq_ignore_ascii_case("false") {
+        Some(Value::Boolean(false))
+    } else if let Ok(value) = value.parse::<i64>() {
+        Some(Value::Integer(value))
+    } else if let Ok(value) = value.parse::<f64>() {
+        Some(Value::Real(value))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn define_str() {
+        let mut generics = GenericValues::new();
+        generics.define_str("WIDTH=8").unwrap();
+        generics.define_str("fifo.ratio = 0.5").unwrap();
+        generics.define_str("Enable=TRUE").unwrap();
+
+        assert_eq!(generics.value("ent", "width"), Some(&Value::Integer(8)));
+        assert_eq!(generics.value("FIFO", "RATIO"), Some(&Value::Real(0.5)));
+        assert_eq!(generics.value("ent", "ratio"), None);
+        assert_eq!(generics.value("ent", "enable"), Some(&Value::Boolean(true)));
+
+        assert!(generics.define_str("WIDTH").is_err());
+        assert!(generics.define_str("=8").is_err());
+        assert!(generics.define_str("WIDTH=eight").is_err());
+    }
+
+    #[test]
+    fn entity_value_takes_precedence() {
+        let mut generics = GenericValues::new();
+        generics.define("width", Value::Integer(8));
+        generics.define("fifo.width", Value::Integer(16));
+
+        assert_eq!(generics.value("fifo", "WIDTH"), Some(&Value::Integer(16)));
+        assert_eq!(generics.value("other", "WIDTH"), Some(&Value::Integer(8)));
+    }
+}
@@ -0,0 +1,148 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
+
+use crate::ast::{EntityClassEntry, Group, GroupDeclaration, GroupTemplateDeclaration, Name};
+use crate::attributes::parse_entity_class;
+use crate::message::ParseResult;
+use crate::names::{parse_name, parse_selected_name};
+use crate::source::WithPos;
+use crate::tokenizer::Kind::*;
+use crate::tokenstream::TokenStream;
+
+/// LRM 6.9 Group template declarations
+fn parse_entity_class_entry_list(stream: &mut TokenStream) -> ParseResult<Vec<EntityClassEntry>> {
+    stream.expect_kind(LeftPar)?;
+    let mut entries = Vec::new();
+    loop {
+        let entity_class = parse_entity_class(stream)?;
+        let is_box = stream.skip_if_kind(BOX)?;
+        entries.push(EntityClassEntry {
+            entity_class,
+            is_box,
+        });
+
+        if is_box {
+            // Only the last entry may be followed by <>
+            stream.expect_kind(RightPar)?;
+            break;
+        }
+
+        let token = stream.expect()?;
+        try_token_kind!(
+            token,
+            Comma => {},
+            RightPar => {
+                break;
+            }
+        );
+    }
+    Ok(entries)
+}
+
+/// LRM 6.10 Group declarations
+fn parse_group_constituent_list(stream: &mut TokenStream) -> ParseResult<Vec<WithPos<Name>>> {
+    stream.expect_kind(LeftPar)?;
+    let mut constituents = Vec::new();
+    loop {
+        // A character literal is also parsed as a name
+        constituents.push(parse_name(stream)?);
+
+        let token = stream.expect()?;
+        try_token_kind!(
+            token,
+            Comma => {},
+            RightPar => {
+                break;
+            }
+        );
+    }
+    Ok(constituents)
+}
+
+pub fn parse_group(stream: &mut TokenStream) -> ParseResult<Group> {
+    stream.expect_kind(Group)?;
+    let ident = stream.expect_ident()?;
+    let token = stream.expect()?;
+
+    let group = try_token_kind!(
+        token,
+        Is => {
+            let entries = parse_entity_class_entry_list(stream)?;
+            Group::Template(GroupTemplateDeclaration { ident, entries })
+        },
+        Colon => {
+            let template_name = parse_selected_name(stream)?;
+            let constituents = parse_group_constituent_list(stream)?;
+            Group::Declaration(GroupDeclaration {
+                ident,
+                template_name,
+                constituents,
+            })
+        }
+    );
+    stream.expect_kind(SemiColon)?;
+    Ok(group)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::EntityClass;
+    use crate::test_util::Code;
+
+    #[test]
+    fn parses_group_template_declaration() {
+        let code = Code::new("group pin2pin is (signal, signal);");
+        assert_eq!(
+            code.with_stream(parse_group),
+            Group::Template(GroupTemplateDeclaration {
+                ident: code.s1("pin2pin").ident(),
+                entries: vec![
+                    EntityClassEntry {
+                        entity_class: EntityClass::Signal,
+                        is_box: false
+                    },
+                    EntityClassEntry {
+                        entity_class: EntityClass::Signal,
+                        is_box: false
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn parses_group_template_declaration_with_box() {
+        let code = Code::new("group resource is (label <>);");
+        assert_eq!(
+            code.with_stream(parse_group),
+            Group::Template(GroupTemplateDeclaration {
+                ident: code.s1("resource").ident(),
+                entries: vec![EntityClassEntry {
+                    entity_class: EntityClass::Label,
+                    is_box: true
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn parses_group_declaration() {
+        let code = Code::new("group path : pin2pin (clk, 'a', work.pkg.sig);");
+        assert_eq!(
+            code.with_stream(parse_group),
+            Group::Declaration(GroupDeclaration {
+                ident: code.s1("path").ident(),
+                template_name: code.s1("pin2pin").selected_name(),
+                constituents: vec![
+                    code.s1("clk").name(),
+                    code.s1("'a'").name(),
+                    code.s1("work.pkg.sig").name()
+                ]
+            })
+        );
+    }
+}
@@ -0,0 +1,593 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
+
+//! Import of the library mapping from the project files of other tools
+
+use crate::config::resolve_path;
+use fnv::FnvHashMap;
+use std::path::Path;
+use yaml_rust::{Yaml, YamlLoader};
+
+/// A VHDL source file and the library it belongs to
+#[derive(PartialEq, Debug)]
+pub struct ImportedFile {
+    pub library_name: String,
+    pub file_name: String,
+}
+
+impl ImportedFile {
+    fn new(library_name: &str, file_name: &Path) -> Result<ImportedFile, String> {
+        Ok(ImportedFile {
+            library_name: library_name.to_owned(),
+            file_name: file_name
+                .to_str()
+                .ok_or_else(|| format!("Could not convert {:?} to string", file_name))?
+                .to_owned(),
+        })
+    }
+}
+
+fn is_vhdl_file(file_name: &str) -> bool {
+    let file_name = file_name.to_lowercase();
+    file_name.ends_with(".vhd") || file_name.ends_with(".vhdl")
+}
+
+/// The output of VUnit --export-json
+pub fn vunit_json(json: &str, parent: &Path) -> Result<Vec<ImportedFile>, String> {
+    let export: serde_json::Value =
+        serde_json::from_str(json).map_err(|err| format!("Invalid VUnit export ({})", err))?;
+
+    let files = export
+        .get("files")
+        .ok_or("missing field files in VUnit export")?
+        .as_array()
+        .ok_or("files in VUnit export is not array")?;
+
+    let mut imported = Vec::new();
+    for file in files.iter() {
+        let get_str = |field: &str| {
+            file.get(field)
+                .and_then(|value| value.as_str())
+                .ok_or_else(|| format!("missing field {} in VUnit export of {}", field, file))
+        };
+        let file_name = get_str("file_name")?;
+        let library_name = get_str("library_name")?;
+        imported.push(ImportedFile::new(library_name, &parent.join(file_name))?);
+    }
+    Ok(imported)
+}
+
+/// A Vivado .xpr project file
+/// Files without a Library attribute belong to xil_defaultlib
+pub fn vivado_xpr(xml: &str, file_name: &Path) -> Result<Vec<ImportedFile>, String> {
+    let document = roxmltree::Document::parse(xml)
+        .map_err(|err| format!("Invalid Vivado project ({})", err))?;
+
+    let root = document.root_element();
+    if !root.has_tag_name("Project") {
+        return Err(format!(
+            "Invalid Vivado project, expected root element Project, got {}",
+            root.tag_name().name()
+        ));
+    }
+
+    let parent = file_name.parent().unwrap_or_else(|| Path::new(""));
+    let project_name = file_name
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("");
+    let source_dir = parent.join(format!("{}.srcs", project_name));
+
+    let mut imported = Vec::new();
+    for file in root.descendants().filter(|node| node.has_tag_name("File")) {
+        let path = file
+            .attribute("Path")
+            .ok_or("File without Path in Vivado project")?;
+        if !is_vhdl_file(path) {
+            continue;
+        }
+
+        let path = if let Some(rest) = path.strip_prefix("$PPRDIR/") {
+            parent.join(rest)
+        } else if let Some(rest) = path.strip_prefix("$PSRCDIR/") {
+            source_dir.join(rest)
+        } else if path.starts_with('$') {
+            return Err(format!(
+                "Unsupported variable in {}, only $PPRDIR and $PSRCDIR are supported",
+                path
+            ));
+        } else {
+            parent.join(path)
+        };
+
+        let library_name = file
+            .descendants()
+            .find(|node| node.has_tag_name("Attr") && node.attribute("Name") == Some("Library"))
+            .and_then(|node| node.attribute("Val"))
+            .unwrap_or("xil_defaultlib");
+
+        imported.push(ImportedFile::new(library_name, &path)?);
+    }
+    Ok(imported)
+}
+
+/// Split a Tcl command into words, braces and quotes group words
+fn tcl_words(line: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut chars = line.chars().peekable();
+
+    loop {
+        while chars.peek().map(|chr| chr.is_whitespace()) == Some(true) {
+            chars.next();
+        }
+
+        let mut word = String::new();
+        match chars.next() {
+            None => break,
+            Some('"') => loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some(chr) => word.push(chr),
+                    None => return Err(format!("Missing '\"' in {}", line)),
+                }
+            },
+            Some('{') => {
+                let mut depth = 1;
+                loop {
+                    match chars.next() {
+                        Some('{') => {
+                            depth += 1;
+                            word.push('{');
+                        }
+                        Some('}') => {
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                            word.push('}');
+                        }
+                        Some(chr) => word.push(chr),
+                        None => return Err(format!("Missing '}}' in {}", line)),
+                    }
+                }
+            }
+            Some(chr) => {
+                word.push(chr);
+                while let Some(&chr) = chars.peek() {
+                    if chr.is_whitespace() {
+                        break;
+                    }
+                    word.push(chr);
+                    chars.next();
+                }
+            }
+        }
+        words.push(word);
+    }
+
+    Ok(words)
+}
+
+/// Quartus assignments which refer to files with other assignments
+const QUARTUS_UNSUPPORTED: &[&str] = &[
+    "QIP_FILE",
+    "QSYS_FILE",
+    "IP_FILE",
+    "SIP_FILE",
+    "SOURCE_TCL_SCRIPT_FILE",
+];
+
+/// A Quartus .qsf settings file
+/// Files without a -library option belong to work
+pub fn quartus_qsf(contents: &str, parent: &Path) -> Result<Vec<ImportedFile>, String> {
+    let mut imported = Vec::new();
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let words = tcl_words(line)?;
+        if words.first().map(|word| word.as_str()) != Some("set_global_assignment") {
+            continue;
+        }
+
+        let mut options = FnvHashMap::default();
+        let mut value = None;
+        let mut words = words.iter().skip(1);
+        while let Some(word) = words.next() {
+            if word == "-remove" {
+                continue;
+            } else if word.starts_with('-') {
+                options.insert(word.as_str(), words.next().map(|word| word.as_str()));
+            } else {
+                value = Some(word.as_str());
+            }
+        }
+
+        let name = options.get("-name").cloned().unwrap_or(None);
+        match name {
+            Some("VHDL_FILE") => {}
+            Some(name) if QUARTUS_UNSUPPORTED.contains(&name) => {
+                return Err(format!(
+                    "{} is not supported in {}, only VHDL_FILE assignments are imported",
+                    name, line
+                ));
+            }
+            _ => continue,
+        }
+
+        let value = value.ok_or_else(|| format!("Missing file name in {}", line))?;
+        if value.contains(['$', '[']) {
+            return Err(format!("Tcl substitution is not supported in {}", line));
+        }
+        let library_name = options.get("-library").cloned().unwrap_or(None);
+        imported.push(ImportedFile::new(
+            library_name.unwrap_or("work"),
+            &parent.join(value),
+        )?);
+    }
+
+    Ok(imported)
+}
+
+/// A ModelSim .mpf project file or a modelsim.ini file with a [Project] section
+/// Files are compiled into the library of their compile_to property
+pub fn modelsim(contents: &str, parent: &Path) -> Result<Vec<ImportedFile>, String> {
+    let mut section = String::new();
+    let mut project = FnvHashMap::default();
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(';') {
+            continue;
+        }
+
+        if line.starts_with('[') && line.ends_with(']') {
+            section = line[1..line.len() - 1].trim().to_owned();
+        } else if section == "Project" {
+            if let Some(idx) = line.find('=') {
+                project.insert(line[..idx].trim(), line[idx + 1..].trim());
+            }
+        }
+    }
+
+    if project.is_empty() {
+        return Err("No [Project] section with source files, \
+                    a modelsim.ini without a project only maps libraries to compiled folders"
+            .to_owned());
+    }
+
+    let count = match project.get("Project_Files_Count") {
+        Some(count) => count
+            .parse::<usize>()
+            .map_err(|_| format!("Invalid Project_Files_Count {}", count))?,
+        None => 0,
+    };
+
+    let mut imported = Vec::new();
+    for idx in 0..count {
+        let file_name = project
+            .get(format!("Project_File_{}", idx).as_str())
+            .ok_or_else(|| format!("Missing Project_File_{}", idx))?;
+        let properties = project
+            .get(format!("Project_File_P_{}", idx).as_str())
+            .map(|properties| tcl_words(properties))
+            .unwrap_or_else(|| Ok(Vec::new()))?;
+
+        let property = |name: &str| {
+            properties
+                .chunks(2)
+                .find(|pair| pair[0] == name)
+                .and_then(|pair| pair.get(1))
+                .map(|value| value.as_str())
+        };
+
+        let is_vhdl = match property("file_type") {
+            Some(file_type) => file_type == "vhdl",
+            None => is_vhdl_file(file_name),
+        };
+        if !is_vhdl {
+            continue;
+        }
+
+        let path = resolve_path(file_name, parent)?;
+        imported.push(ImportedFile::new(
+            property("compile_to").unwrap_or("work"),
+            Path::new(&path),
+        )?);
+    }
+
+    Ok(imported)
+}
+
+/// A FuseSoC CAPI2 .core file
+/// Files of all filesets are imported into their logical_name or work
+pub fn fusesoc_core(contents: &str, parent: &Path) -> Result<Vec<ImportedFile>, String> {
+    let first_line = contents
+        .lines()
+        .map(|line| line.trim())
+        .find(|line| !line.is_empty() && !line.starts_with('#'));
+    if first_line.map(|line| line.starts_with("CAPI=2")) != Some(true) {
+        return Err("Only CAPI=2 core files are supported".to_owned());
+    }
+
+    let documents = YamlLoader::load_from_str(contents)
+        .map_err(|err| format!("Invalid core file ({})", err))?;
+    let core = documents.first().ok_or("Empty core file")?;
+
+    let filesets = match core["filesets"] {
+        Yaml::Hash(ref filesets) => filesets,
+        Yaml::BadValue => return Ok(Vec::new()),
+        _ => return Err("filesets must be a map".to_owned()),
+    };
+
+    let mut imported = Vec::new();
+    for (name, fileset) in filesets.iter() {
+        let name = name.as_str().unwrap_or("");
+        let default_type = fileset["file_type"].as_str();
+        let default_library = fileset["logical_name"].as_str().unwrap_or("work");
+
+        let files = match fileset["files"] {
+            Yaml::Array(ref files) => files,
+            Yaml::BadValue => continue,
+            _ => return Err(format!("files of fileset {} must be a list", name)),
+        };
+
+        for file in files.iter() {
+            let (file_name, attributes) = match file {
+                Yaml::String(file_name) => (file_name.as_str(), &Yaml::BadValue),
+                Yaml::Hash(hash) if hash.len() == 1 => {
+                    let (file_name, attributes) = hash.iter().next().unwrap();
+                    let file_name = file_name
+                        .as_str()
+                        .ok_or_else(|| format!("Invalid file in fileset {}", name))?;
+                    (file_name, attributes)
+                }
+                _ => return Err(format!("Invalid file in fileset {}", name)),
+            };
+
+            if file_name.contains('?') {
+                return Err(format!(
+                    "Conditional file {} in fileset {} is not supported",
+                    file_name, name
+                ));
+            }
+
+            let is_vhdl = match attributes["file_type"].as_str().or(default_type) {
+                Some(file_type) => file_type.starts_with("vhdlSource"),
+                None => is_vhdl_file(file_name),
+            };
+            if !is_vhdl {
+                continue;
+            }
+
+            let library_name = attributes["logical_name"]
+                .as_str()
+                .unwrap_or(default_library);
+            imported.push(ImportedFile::new(library_name, &parent.join(file_name))?);
+        }
+    }
+
+    Ok(imported)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn imported(parent: &Path, files: &[(&str, &str)]) -> Vec<ImportedFile> {
+        files
+            .iter()
+            .map(|(library_name, file_name)| {
+                ImportedFile::new(library_name, &parent.join(file_name)).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn import_vivado_xpr() {
+        let file_name = Path::new("proj").join("top.xpr");
+        let parent = Path::new("proj");
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Project Version="7" Minor="44" Path="/work/proj/top.xpr">
+  <FileSets Version="1" Minor="31">
+    <FileSet Name="sources_1" Type="DesignSrcs" RelSrcDir="$PSRCDIR/sources_1">
+      <File Path="$PPRDIR/../src/top.vhd">
+        <FileInfo>
+          <Attr Name="Library" Val="lib"/>
+          <Attr Name="UsedIn" Val="synthesis"/>
+        </FileInfo>
+      </File>
+      <File Path="$PSRCDIR/sources_1/new/pkg.vhdl"/>
+      <File Path="$PSRCDIR/sources_1/ip/clk/clk.xci"/>
+      <File Path="$PPRDIR/../src/core.v"/>
+    </FileSet>
+    <FileSet Name="sim_1" Type="SimulationSrcs" RelSrcDir="$PSRCDIR/sim_1">
+      <File Path="/abs/tb_top.vhd"/>
+    </FileSet>
+  </FileSets>
+</Project>
+"#;
+        assert_eq!(
+            vivado_xpr(xml, &file_name),
+            Ok(imported(
+                parent,
+                &[
+                    ("lib", "../src/top.vhd"),
+                    ("xil_defaultlib", "top.srcs/sources_1/new/pkg.vhdl"),
+                    ("xil_defaultlib", "/abs/tb_top.vhd")
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn vivado_xpr_errors() {
+        let file_name = Path::new("top.xpr");
+        assert!(vivado_xpr("<Project>", file_name)
+            .unwrap_err()
+            .starts_with("Invalid Vivado project"));
+        assert_eq!(
+            vivado_xpr("<Solution/>", file_name),
+            Err("Invalid Vivado project, expected root element Project, got Solution".to_owned())
+        );
+        assert_eq!(
+            vivado_xpr(
+                r#"<Project><File Path="$PGENDIR/gen.vhd"/></Project>"#,
+                file_name
+            ),
+            Err(
+                "Unsupported variable in $PGENDIR/gen.vhd, only $PPRDIR and $PSRCDIR are supported"
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn import_quartus_qsf() {
+        let parent = Path::new("proj");
+        let qsf = r#"
+# Comment
+set_global_assignment -name FAMILY "Cyclone V"
+set_global_assignment -name TOP_LEVEL_ENTITY top
+set_global_assignment -name VHDL_FILE src/top.vhd
+set_global_assignment -name VHDL_FILE "src/my pkg.vhd" -library lib
+set_global_assignment -library {lib} -name VHDL_FILE {src/util.vhdl} -hdl_version VHDL_2008
+set_global_assignment -name VERILOG_FILE src/core.v
+set_location_assignment PIN_A1 -to clk
+"#;
+        assert_eq!(
+            quartus_qsf(qsf, parent),
+            Ok(imported(
+                parent,
+                &[
+                    ("work", "src/top.vhd"),
+                    ("lib", "src/my pkg.vhd"),
+                    ("lib", "src/util.vhdl")
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn quartus_qsf_errors() {
+        let parent = Path::new("");
+        assert_eq!(
+            quartus_qsf("set_global_assignment -name QIP_FILE ip/pll.qip", parent),
+            Err("QIP_FILE is not supported in set_global_assignment -name QIP_FILE ip/pll.qip, only VHDL_FILE assignments are imported".to_owned())
+        );
+        assert_eq!(
+            quartus_qsf(
+                "set_global_assignment -name VHDL_FILE $SRC/top.vhd",
+                parent
+            ),
+            Err(
+                "Tcl substitution is not supported in set_global_assignment -name VHDL_FILE $SRC/top.vhd"
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            quartus_qsf("set_global_assignment -name VHDL_FILE \"top.vhd", parent),
+            Err("Missing '\"' in set_global_assignment -name VHDL_FILE \"top.vhd".to_owned())
+        );
+    }
+
+    #[test]
+    fn import_modelsim() {
+        let parent = Path::new("proj");
+        let mpf = r#"
+[Library]
+others = $MODEL_TECH/../modelsim.ini
+lib = lib
+
+[Project]
+; Comment
+Project_Version = 6
+Project_Files_Count = 3
+Project_File_0 = src/top.vhd
+Project_File_P_0 = vhdl_novitalcheck 0 file_type vhdl group_id 0 vhdl_options {} compile_to lib compile_order 1
+Project_File_1 = src/core.v
+Project_File_P_1 = file_type verilog group_id 0 compile_to work
+Project_File_2 = src/pkg.vhd
+Project_File_P_2 = file_type vhdl vhdl_use_explicit_decl 1
+"#;
+        assert_eq!(
+            modelsim(mpf, parent),
+            Ok(imported(
+                parent,
+                &[("lib", "src/top.vhd"), ("work", "src/pkg.vhd")]
+            ))
+        );
+    }
+
+    #[test]
+    fn modelsim_errors() {
+        let parent = Path::new("");
+        assert!(modelsim("[Library]\nlib = lib\n", parent)
+            .unwrap_err()
+            .starts_with("No [Project] section"));
+        assert_eq!(
+            modelsim("[Project]\nProject_Files_Count = 1\n", parent),
+            Err("Missing Project_File_0".to_owned())
+        );
+    }
+
+    #[test]
+    fn import_fusesoc_core() {
+        let parent = Path::new("cores");
+        let core = r#"CAPI=2:
+name: ::top:1.0
+
+filesets:
+  rtl:
+    files:
+      - rtl/pkg.vhd
+      - rtl/top.vhd: {logical_name: other}
+      - rtl/core.v: {file_type: verilogSource}
+    file_type: vhdlSource-2008
+    logical_name: lib
+  tb:
+    files:
+      - tb/tb_top.vhd
+      - tb/tb.sv
+  empty:
+    depend: ["::util:1.0"]
+
+targets:
+  default:
+    filesets: [rtl]
+"#;
+        assert_eq!(
+            fusesoc_core(core, parent),
+            Ok(imported(
+                parent,
+                &[
+                    ("lib", "rtl/pkg.vhd"),
+                    ("other", "rtl/top.vhd"),
+                    ("work", "tb/tb_top.vhd")
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn fusesoc_core_errors() {
+        let parent = Path::new("");
+        assert_eq!(
+            fusesoc_core("[main]\nname = top\n", parent),
+            Err("Only CAPI=2 core files are supported".to_owned())
+        );
+        assert_eq!(
+            fusesoc_core(
+                "CAPI=2:\nfilesets:\n  rtl:\n    files:\n      - \"tool_ghdl? (tb.vhd)\"\n",
+                parent
+            ),
+            Err("Conditional file tool_ghdl? (tb.vhd) in fileset rtl is not supported".to_owned())
+        );
+    }
+}
@@ -7,18 +7,23 @@
 /// LRM 6.5 Interface declarations
 use crate::ast::{
     InterfaceDeclaration, InterfaceFileDeclaration, InterfaceObjectDeclaration,
-    InterfacePackageDeclaration, InterfacePackageGenericMapAspect, Mode, ObjectClass,
-    SubprogramDefault,
+    InterfacePackageDeclaration, InterfacePackageGenericMapAspect, InterfaceViewDeclaration, Mode,
+    ObjectClass, SubprogramDefault,
 };
 
+use crate::common::check_standard;
 use crate::message::{push_result, Message, MessageHandler, ParseResult};
+use crate::mode_view_declaration::parse_mode_view_indication_known_keyword;
 use crate::names::{parse_association_list_no_leftpar, parse_identifier_list, parse_selected_name};
-use crate::object_declaration::{parse_file_declaration_no_semi, parse_optional_assignment};
+use crate::object_declaration::{
+    parse_file_declaration_no_semi, parse_optional_assignment, parse_optional_signal_kind,
+};
 use crate::subprogram::parse_subprogram_declaration_no_semi;
 use crate::subtype_indication::parse_subtype_indication;
 use crate::tokenizer::Kind::*;
 use crate::tokenizer::{kinds_str, Kind, Token};
 use crate::tokenstream::TokenStream;
+use crate::vhdl_standard::VHDLStandard;
 
 fn parse_optional_mode(stream: &mut TokenStream) -> ParseResult<Option<Mode>> {
     Ok(match stream.peek_kind()? {
@@ -62,13 +67,13 @@ fn parse_interface_file_declaration(
     let file_objects = parse_file_declaration_no_semi(stream)?;
     for file_object in file_objects.iter() {
         if file_object.open_info.is_some() {
-            return Err(Message::error(
+            return Err(Message::syntax_error(
                 &file_object.ident,
                 "interface_file_declaration may not have file open information",
             ));
         }
         if file_object.file_name.is_some() {
-            return Err(Message::error(
+            return Err(Message::syntax_error(
                 &file_object.ident,
                 "interface_file_declaration may not have file name",
             ));
@@ -99,6 +104,28 @@ fn parse_interface_object_declaration(
 
     stream.expect_kind(Colon)?;
 
+    if let Some(token) = stream.pop_if_kind(View)? {
+        if list_type == InterfaceListType::Generic
+            || explicit_object_class.unwrap_or(ObjectClass::Signal) != ObjectClass::Signal
+        {
+            return Err(Message::syntax_error(
+                &token,
+                "Mode view indication is only allowed for signals",
+            ));
+        }
+
+        let view = parse_mode_view_indication_known_keyword(stream)?;
+        return Ok(idents
+            .into_iter()
+            .map(|ident| {
+                InterfaceDeclaration::View(InterfaceViewDeclaration {
+                    ident,
+                    view: view.clone(),
+                })
+            })
+            .collect());
+    }
+
     let mode = parse_optional_mode(stream)?;
 
     let mode_pos = match mode {
@@ -117,13 +144,26 @@ fn parse_interface_object_declaration(
     };
 
     let subtype = parse_subtype_indication(stream)?;
+    let signal_kind = {
+        if object_class == ObjectClass::Signal {
+            if let Some(token) = stream.pop_if_kind(Register)? {
+                return Err(Message::syntax_error(
+                    &token,
+                    "Interface signal declaration may only have signal kind bus",
+                ));
+            }
+            parse_optional_signal_kind(stream)?
+        } else {
+            None
+        }
+    };
     let expr = parse_optional_assignment(stream)?;
 
     // @TODO maybe move this to a semantic check?
     for ident in idents.iter() {
         if object_class == ObjectClass::Constant && mode != Mode::In {
             let pos = mode_pos.as_ref().unwrap_or(&ident.pos);
-            return Err(Message::error(
+            return Err(Message::syntax_error(
                 &pos,
                 "Interface constant declaration may only have mode=in",
             ));
@@ -131,7 +171,7 @@ fn parse_interface_object_declaration(
 
         if list_type == InterfaceListType::Port && object_class != ObjectClass::Signal {
             let pos = object_class_pos.as_ref().unwrap_or(&ident.pos);
-            return Err(Message::error(
+            return Err(Message::syntax_error(
                 &pos,
                 "Port list only allows signal object class",
             ));
@@ -139,7 +179,7 @@ fn parse_interface_object_declaration(
 
         if list_type == InterfaceListType::Generic && object_class != ObjectClass::Constant {
             let pos = object_class_pos.as_ref().unwrap_or(&ident.pos);
-            return Err(Message::error(
+            return Err(Message::syntax_error(
                 &pos,
                 "Generic list only allows constant object class",
             ));
@@ -154,6 +194,7 @@ fn parse_interface_object_declaration(
                 class: object_class,
                 ident,
                 subtype_indication: subtype.clone(),
+                signal_kind,
                 expression: expr.clone(),
             })
         })
@@ -229,17 +270,20 @@ fn parse_interface_declaration(
         },
         File => parse_interface_file_declaration(stream),
         Type => {
+            check_standard(stream, &token, VHDLStandard::VHDL2008, "Generic type", messages);
             stream.move_after(&token);
             let ident = stream.expect_ident()?;
             Ok(vec![InterfaceDeclaration::Type(ident)])
         },
         Function | Procedure | Impure => {
+            check_standard(stream, &token, VHDLStandard::VHDL2008, "Generic subprogram", messages);
             let decl = parse_subprogram_declaration_no_semi(stream, messages)?;
             let default = parse_subprogram_default(stream)?;
 
             Ok(vec![InterfaceDeclaration::Subprogram(decl, default)])
         },
         Package => {
+            check_standard(stream, &token, VHDLStandard::VHDL2008, "Generic package", messages);
             stream.move_after(&token);
             Ok(vec![InterfaceDeclaration::Package (parse_interface_package_declaration_known_keyword(stream)?)])
         }
@@ -253,8 +297,9 @@ fn parse_semicolon_separator(stream: &mut TokenStream) -> ParseResult<()> {
     try_token_kind!(token,
                       SemiColon => {
                           stream.move_after(&token);
-                          if stream.peek_expect()?.kind == RightPar {
-                              return Err(Message::error(&token,
+                          // VHDL-2019 allows a trailing semicolon
+                          if stream.peek_expect()?.kind == RightPar && stream.standard() < VHDLStandard::VHDL2019 {
+                              return Err(Message::syntax_error(&token,
                                                         format!("Last interface element may not end with {}",
                                                     kinds_str(&[SemiColon]))));
                           }
@@ -401,6 +446,7 @@ pub fn parse_generic(stream: &mut TokenStream) -> ParseResult<InterfaceDeclarati
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ast::{ModeViewIndication, SignalKind};
     use crate::test_util::Code;
     use crate::tokenizer::kinds_error;
 
@@ -415,6 +461,7 @@ mod tests {
                     class: ObjectClass::Constant,
                     ident: code.s1("foo").ident(),
                     subtype_indication: code.s1("natural").subtype_indication(),
+                    signal_kind: None,
                     expression: None
                 }),
                 InterfaceDeclaration::Object(InterfaceObjectDeclaration {
@@ -422,6 +469,7 @@ mod tests {
                     class: ObjectClass::Constant,
                     ident: code.s1("bar").ident(),
                     subtype_indication: code.s1("natural").subtype_indication(),
+                    signal_kind: None,
                     expression: None
                 })
             ]
@@ -438,6 +486,7 @@ mod tests {
                 class: ObjectClass::Constant,
                 ident: code.s1("foo").ident(),
                 subtype_indication: code.s1("std_logic").subtype_indication(),
+                signal_kind: None,
                 expression: None
             })
         );
@@ -455,12 +504,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parses_interface_signal_with_bus_kind() {
+        let code = Code::new("signal foo : inout resolved_bit bus");
+        assert_eq!(
+            code.with_stream(parse_port),
+            InterfaceDeclaration::Object(InterfaceObjectDeclaration {
+                mode: Mode::InOut,
+                class: ObjectClass::Signal,
+                ident: code.s1("foo").ident(),
+                subtype_indication: code.s1("resolved_bit").subtype_indication(),
+                signal_kind: Some(SignalKind::Bus),
+                expression: None
+            })
+        );
+    }
+
+    #[test]
+    fn interface_signal_may_not_have_register_kind() {
+        let code = Code::new("signal foo : inout resolved_bit register");
+        assert_eq!(
+            code.with_stream_err(parse_port),
+            Message::syntax_error(
+                code.s1("register"),
+                "Interface signal declaration may only have signal kind bus"
+            )
+        );
+    }
+
     #[test]
     fn parses_interface_file_declaration_no_open_info() {
         let code = Code::new("file foo : text open read_mode");
         assert_eq!(
             code.with_stream_err(parse_parameter),
-            Message::error(
+            Message::syntax_error(
                 code.s1("foo"),
                 "interface_file_declaration may not have file open information"
             )
@@ -472,7 +549,7 @@ mod tests {
         let code = Code::new("file foo : text is \"file_name\"");
         assert_eq!(
             code.with_stream_err(parse_parameter),
-            Message::error(
+            Message::syntax_error(
                 code.s1("foo"),
                 "interface_file_declaration may not have file name"
             )
@@ -489,6 +566,7 @@ mod tests {
                 class: ObjectClass::Signal,
                 ident: code.s1("foo").ident(),
                 subtype_indication: code.s1("std_logic").subtype_indication(),
+                signal_kind: None,
                 expression: None
             })
         );
@@ -557,6 +635,7 @@ mod tests {
                 class: ObjectClass::Constant,
                 ident: code.s1("foo").ident(),
                 subtype_indication: code.s1("std_logic").subtype_indication(),
+                signal_kind: None,
                 expression: None
             })
         );
@@ -573,6 +652,7 @@ mod tests {
                 class: ObjectClass::Signal,
                 ident: code.s1("foo").ident(),
                 subtype_indication: code.s1("std_logic").subtype_indication(),
+                signal_kind: None,
                 expression: None
             })
         );
@@ -583,7 +663,7 @@ mod tests {
         let code = Code::new("foo : out boolean");
         assert_eq!(
             code.with_partial_stream(parse_generic),
-            Err(Message::error(
+            Err(Message::syntax_error(
                 &code.s1("out").pos(),
                 "Interface constant declaration may only have mode=in"
             ))
@@ -626,13 +706,76 @@ bar : natural)",
         );
         assert_eq!(
             messages,
-            vec![Message::error(
+            vec![Message::syntax_error(
                 code.s(";", 2),
                 "Last interface element may not end with ';'"
             )]
         );
     }
 
+    #[test]
+    fn test_parse_interface_list_trailing_semi_colon_in_vhdl_2019() {
+        let code = Code::with_standard(
+            "\
+(constant foo : std_logic;
+ bar : natural;
+)",
+            VHDLStandard::VHDL2019,
+        );
+
+        assert_eq!(
+            code.with_stream_no_messages(parse_generic_interface_list),
+            vec![
+                code.s1("constant foo : std_logic").generic(),
+                code.s1("bar : natural").generic()
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_port_with_mode_view() {
+        let code = Code::with_standard(
+            "\
+(signal foo : view master;
+ bar : view (slave) of bus_array_t)",
+            VHDLStandard::VHDL2019,
+        );
+
+        assert_eq!(
+            code.with_stream_no_messages(parse_port_interface_list),
+            vec![
+                InterfaceDeclaration::View(InterfaceViewDeclaration {
+                    ident: code.s1("foo").ident(),
+                    view: ModeViewIndication {
+                        is_array: false,
+                        name: code.s1("master").name(),
+                        subtype_indication: None,
+                    }
+                }),
+                InterfaceDeclaration::View(InterfaceViewDeclaration {
+                    ident: code.s1("bar").ident(),
+                    view: ModeViewIndication {
+                        is_array: true,
+                        name: code.s1("slave").name(),
+                        subtype_indication: Some(code.s1("bus_array_t").subtype_indication()),
+                    }
+                })
+            ]
+        );
+    }
+
+    #[test]
+    fn mode_view_is_only_allowed_for_signals() {
+        let code = Code::with_standard("constant foo : view master", VHDLStandard::VHDL2019);
+        assert_eq!(
+            code.parse(parse_generic),
+            Err(Message::syntax_error(
+                code.s1("view"),
+                "Mode view indication is only allowed for signals"
+            ))
+        );
+    }
+
     #[test]
     fn test_parse_port_interface_list() {
         let code = Code::new(
@@ -697,7 +840,7 @@ bar : natural)",
         let (_, messages) = code.with_stream_messages(parse_generic_interface_list);
         assert_eq!(
             messages,
-            vec![Message::error(
+            vec![Message::syntax_error(
                 code.s1("signal"),
                 "Generic list only allows constant object class"
             )]
@@ -710,7 +853,7 @@ bar : natural)",
         let (_, messages) = code.with_stream_messages(parse_port_interface_list);
         assert_eq!(
             messages,
-            vec![Message::error(
+            vec![Message::syntax_error(
                 code.s1("constant"),
                 "Port list only allows signal object class"
             )]
@@ -853,5 +996,4 @@ package foo is new lib.pkg
             })
         );
     }
-
 }
@@ -10,37 +10,56 @@ mod tokenizer;
 mod alias_declaration;
 mod analysis;
 mod attributes;
+mod baseline;
+mod builtin_libraries;
 mod common;
 mod component_declaration;
 mod concurrent_statement;
+mod conditional_analysis;
 mod config;
 mod configuration;
 mod context;
 mod declarative_part;
 mod design_unit;
 mod expression;
+mod generic_values;
+mod group;
+mod import;
 mod interface_declaration;
 mod latin_1;
 mod message;
+mod message_format;
+mod mode_view_declaration;
 mod names;
 mod object_declaration;
 mod parser;
 mod project;
+mod psl;
 mod range;
 mod sequential_statement;
 mod source;
 mod subprogram;
 mod subtype_indication;
+mod suppression;
 mod symbol_table;
+mod synthesis_pragma;
 mod tokenstream;
 mod type_declaration;
+mod vhdl_standard;
 mod waveform;
 
 #[cfg(test)]
 mod test_util;
 
-pub use crate::config::Config;
-pub use crate::message::{Message, Severity};
+pub use crate::analysis::Value;
+pub use crate::baseline::Baseline;
+pub use crate::conditional_analysis::ConditionalAnalysis;
+pub use crate::config::{Config, SeverityMap};
+pub use crate::generic_values::GenericValues;
+pub use crate::message::{Message, MessageCategory, MessageCode, Severity};
+pub use crate::message_format::MessageFormat;
 pub use crate::parser::{ParserError, ParserResult, VHDLParser};
 pub use crate::project::Project;
-pub use crate::source::{Source, SrcPos};
+pub use crate::source::{Position, Range, Source, SrcPos};
+pub use crate::synthesis_pragma::TranslateOff;
+pub use crate::vhdl_standard::VHDLStandard;
@@ -12,10 +12,14 @@
 #[macro_use]
 extern crate clap;
 
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 use vhdl_parser::ast::{AnyDesignUnit, PrimaryUnit, SecondaryUnit};
-use vhdl_parser::{Config, Message, ParserError, Project, Severity, VHDLParser};
+use vhdl_parser::{
+    Baseline, ConditionalAnalysis, Config, GenericValues, Message, MessageFormat, ParserError,
+    Project, Severity, VHDLParser,
+};
 
 fn main() {
     use clap::{App, Arg};
@@ -46,36 +50,256 @@ fn main() {
                 .long("--config")
                 .takes_value(true)
                 .conflicts_with("files"))
+        .arg(
+            Arg::with_name("message-format")
+                .help("The format used when printing messages")
+                .long("--message-format")
+                .takes_value(true)
+                .possible_values(MessageFormat::variants())
+                .default_value("human"))
+        .arg(
+            Arg::with_name("check")
+                .help("Exit with a non-zero status when errors are found and show a summary per library")
+                .long("--check"))
+        .arg(
+            Arg::with_name("max-warnings")
+                .help("In check mode also exit with a non-zero status when there are more warnings than this")
+                .long("--max-warnings")
+                .takes_value(true)
+                .requires("check"))
+        .arg(
+            Arg::with_name("baseline")
+                .help("Only show messages which are not in this baseline file")
+                .long("--baseline")
+                .takes_value(true)
+                .requires("config"))
+        .arg(
+            Arg::with_name("write-baseline")
+                .help("Write all messages to this baseline file")
+                .long("--write-baseline")
+                .takes_value(true)
+                .requires("config"))
+        .arg(
+            Arg::with_name("define")
+                .help("Define a conditional analysis identifier such as TOOL_NAME=vhdl_parser, replacing the value of the config file")
+                .short("-D")
+                .long("--define")
+                .takes_value(true)
+                .number_of_values(1)
+                .multiple(true))
+        .arg(
+            Arg::with_name("generic")
+                .help("Supply the value of a generic such as WIDTH=8 or fifo.DEPTH=16 at elaboration, replacing the value of the config file")
+                .short("-g")
+                .long("--generic")
+                .takes_value(true)
+                .number_of_values(1)
+                .multiple(true)
+                .requires("config"))
         .get_matches();
 
     let show = matches.is_present("show");
     let num_threads = value_t_or_exit!(matches.value_of("num-threads"), usize);
-    let parser = VHDLParser::new();
+    let format = value_t_or_exit!(matches.value_of("message-format"), MessageFormat);
+    let check = matches.is_present("check");
+    let max_warnings = if matches.is_present("max-warnings") {
+        Some(value_t_or_exit!(matches.value_of("max-warnings"), usize))
+    } else {
+        None
+    };
+
+    let mut identifiers = ConditionalAnalysis::new();
+    for definition in matches.values_of("define").into_iter().flatten() {
+        if let Err(err) = identifiers.define_str(definition) {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
+
+    let mut generics = GenericValues::new();
+    for definition in matches.values_of("generic").into_iter().flatten() {
+        if let Err(err) = generics.define_str(definition) {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    }
+
+    let mut parser = VHDLParser::new();
+    parser.conditional_analysis = identifiers.clone();
+
+    let mut passed = true;
 
     if let Some(files) = matches.values_of("files") {
-        parse(
+        let counts = parse(
             &parser.clone(),
             files.map(|s| s.to_owned()).collect(),
             num_threads,
             show,
-        )
+            format,
+        );
+        passed &= counts.passes(max_warnings);
     }
 
     if let Some(file_name) = matches.value_of("config") {
-        let config =
-            Config::read_file_path(Path::new(file_name)).expect("Failed to read config file");
+        let mut config = match Config::read_file_path(Path::new(file_name)) {
+            Ok(config) => config,
+            Err(err) => {
+                eprintln!("Failed to read config file {} ({})", file_name, err);
+                std::process::exit(1);
+            }
+        };
+        config.append_conditional_analysis(identifiers);
+        config.append_generics(generics);
+
+        let warnings = config.take_warnings();
+        if !warnings.is_empty() {
+            if format == MessageFormat::Human {
+                println!("Warnings when reading config {}:", file_name);
+                for warning in warnings {
+                    println!("{}", warning);
+                }
+                println!();
+            } else {
+                eprintln!("Warnings when reading config {}:", file_name);
+                for warning in warnings {
+                    eprintln!("{}", warning);
+                }
+            }
+        }
 
         let mut errors = Vec::new();
         let mut project = Project::from_config(&config, num_threads, &mut errors);
-        show_messages(&project.analyse());
 
         if !errors.is_empty() {
-            println!("Errors when reading config {}:", file_name);
-            for error in errors {
-                println!("{}", error);
+            passed = false;
+            if format == MessageFormat::Human {
+                println!("Errors when reading config {}:", file_name);
+                for error in errors {
+                    println!("{}", error);
+                }
+                println!();
+            } else {
+                eprintln!("Errors when reading config {}:", file_name);
+                for error in errors {
+                    eprintln!("{}", error);
+                }
+            }
+        }
+
+        let mut messages = project.analyse();
+
+        if let Some(baseline_file_name) = matches.value_of("write-baseline") {
+            let baseline = Baseline::from_messages(&project, &messages);
+            if let Err(err) = baseline.write_file_path(Path::new(baseline_file_name)) {
+                eprintln!(
+                    "Failed to write baseline file {} ({})",
+                    baseline_file_name, err
+                );
+                std::process::exit(1);
+            }
+        }
+
+        if let Some(baseline_file_name) = matches.value_of("baseline") {
+            let baseline = match Baseline::read_file_path(Path::new(baseline_file_name)) {
+                Ok(baseline) => baseline,
+                Err(err) => {
+                    eprintln!(
+                        "Failed to read baseline file {} ({})",
+                        baseline_file_name, err
+                    );
+                    std::process::exit(1);
+                }
+            };
+            messages = baseline.filter_new(&project, messages);
+        }
+
+        show_messages(&messages, format);
+
+        if check {
+            let summary = library_summary(&config, &messages);
+            let counts = MessageCounts::from_messages(&messages);
+            passed &= counts.passes(max_warnings);
+
+            if format == MessageFormat::Human {
+                print!("{}", summary);
+            } else {
+                eprint!("{}", summary);
+            }
+        }
+    }
+
+    if check && !passed {
+        std::process::exit(1);
+    }
+}
+
+#[derive(Default)]
+struct MessageCounts {
+    errors: usize,
+    warnings: usize,
+}
+
+impl MessageCounts {
+    fn from_messages(messages: &[Message]) -> MessageCounts {
+        let mut counts = MessageCounts::default();
+        for message in messages {
+            counts.add(message);
+        }
+        counts
+    }
+
+    fn add(&mut self, message: &Message) {
+        match message.severity {
+            Severity::Error => self.errors += 1,
+            Severity::Warning => self.warnings += 1,
+            _ => {}
+        }
+    }
+
+    /// Errors always fail the check, warnings only fail when above the limit
+    fn passes(&self, max_warnings: Option<usize>) -> bool {
+        self.errors == 0 && self.warnings <= max_warnings.unwrap_or(usize::MAX)
+    }
+
+    fn show(&self) -> String {
+        format!("{} errors, {} warnings", self.errors, self.warnings)
+    }
+}
+
+/// Show the number of errors and warnings within each library
+fn library_summary(config: &Config, messages: &[Message]) -> String {
+    let mut file_to_libraries: HashMap<&str, Vec<&str>> = HashMap::new();
+    let mut library_counts: BTreeMap<&str, MessageCounts> = BTreeMap::new();
+
+    for library in config.iter_libraries() {
+        library_counts.insert(library.name(), MessageCounts::default());
+        for file_name in library.file_names() {
+            file_to_libraries
+                .entry(file_name.as_str())
+                .or_default()
+                .push(library.name());
+        }
+    }
+
+    let mut total = MessageCounts::default();
+    for message in messages {
+        total.add(message);
+        if let Some(library_names) = file_to_libraries.get(message.pos.source.file_name()) {
+            for library_name in library_names {
+                if let Some(counts) = library_counts.get_mut(library_name) {
+                    counts.add(message);
+                }
             }
         }
     }
+
+    let mut result = String::new();
+    result.push_str("Summary:\n");
+    for (library_name, counts) in library_counts.iter() {
+        result.push_str(&format!("  {}: {}\n", library_name, counts.show()));
+    }
+    result.push_str(&format!("Total: {}\n", total.show()));
+    result
 }
 
 fn show_design_unit(design_unit: &AnyDesignUnit) {
@@ -129,6 +353,16 @@ fn show_design_unit(design_unit: &AnyDesignUnit) {
                     &inst.package_name
                 );
             }
+            PrimaryUnit::VerificationUnit(ref vunit) => {
+                let vunit = &vunit.unit;
+                println!("{} {}", vunit.kind, vunit.ident.item.name());
+                if !vunit.decl.is_empty() {
+                    println!("  with {} declarations", vunit.decl.len())
+                }
+                if !vunit.statements.is_empty() {
+                    println!("  with {} statements", vunit.statements.len())
+                }
+            }
         },
         AnyDesignUnit::Secondary(ref secondary) => match secondary {
             SecondaryUnit::Architecture(ref arch) => {
@@ -156,29 +390,49 @@ fn show_design_unit(design_unit: &AnyDesignUnit) {
     }
 }
 
-fn show_messages(messages: &[Message]) {
-    for message in messages {
-        println!("{}", message.show());
-    }
+fn show_messages(messages: &[Message], format: MessageFormat) {
+    print!("{}", format.format(messages));
 }
 
-fn parse(parser: &VHDLParser, file_names: Vec<String>, num_threads: usize, show: bool) {
+fn parse(
+    parser: &VHDLParser,
+    file_names: Vec<String>,
+    num_threads: usize,
+    show: bool,
+    format: MessageFormat,
+) -> MessageCounts {
     let mut num_errors = 0;
     let mut num_warnings = 0;
 
-    for (file_name, messages, design_file) in parser.parse_design_files(file_names, num_threads) {
+    // Machine readable formats are printed as a single document at the end
+    let human = format == MessageFormat::Human;
+    let mut all_messages = Vec::new();
+
+    for (file_name, mut messages, design_file, suppressions) in
+        parser.parse_design_files(file_names, num_threads)
+    {
+        messages.retain(|message| !suppressions.is_suppressed(message));
         let design_file = match design_file {
             Ok(design_file) => design_file,
             Err(ParserError::Message(msg)) => {
-                println!("Error when parsing {}", file_name);
-                show_messages(&messages);
-                println!("{}", msg.show());
+                if human {
+                    println!("Error when parsing {}", file_name);
+                    show_messages(&messages, format);
+                    println!("{}", msg.show());
+                } else {
+                    all_messages.append(&mut messages);
+                    all_messages.push(msg);
+                }
                 num_errors += 1;
                 continue;
             }
             Err(ParserError::IOError(err)) => {
-                println!("Error when parsing {}", file_name);
-                println!("{}", err);
+                if human {
+                    println!("Error when parsing {}", file_name);
+                    println!("{}", err);
+                } else {
+                    eprintln!("Error when parsing {} ({})", file_name, err);
+                }
                 num_errors += 1;
                 continue;
             }
@@ -205,12 +459,27 @@ fn parse(parser: &VHDLParser, file_names: Vec<String>, num_threads: usize, show:
             };
         }
 
-        show_messages(&messages);
+        if human {
+            show_messages(&messages, format);
+        } else {
+            all_messages.append(&mut messages);
+        }
+
         if file_has_errors {
             num_errors += 1;
         }
     }
 
+    let counts = MessageCounts {
+        errors: num_errors,
+        warnings: num_warnings,
+    };
+
+    if !human {
+        show_messages(&all_messages, format);
+        return counts;
+    }
+
     println!();
     println!("Summary:");
     if num_warnings > 0 {
@@ -224,4 +493,6 @@ fn parse(parser: &VHDLParser, file_names: Vec<String>, num_threads: usize, show:
     } else {
         println!("OK: Found no errors");
     }
+
+    counts
 }
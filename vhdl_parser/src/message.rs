@@ -15,39 +15,275 @@ pub enum Severity {
     Error,
 }
 
+/// A coarse grouping of message codes
+#[derive(PartialEq, Debug, Clone, Copy, Eq, Hash, PartialOrd, Ord)]
+pub enum MessageCategory {
+    Syntax,
+    Library,
+    Visibility,
+    Types,
+    Lint,
+}
+
+impl MessageCategory {
+    pub fn all() -> &'static [MessageCategory] {
+        use MessageCategory::*;
+        &[Syntax, Library, Visibility, Types, Lint]
+    }
+
+    pub fn name(self) -> &'static str {
+        use MessageCategory::*;
+        match self {
+            Syntax => "syntax",
+            Library => "library",
+            Visibility => "visibility",
+            Types => "types",
+            Lint => "lint",
+        }
+    }
+}
+
+impl std::fmt::Display for MessageCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl std::str::FromStr for MessageCategory {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<MessageCategory, String> {
+        for category in MessageCategory::all() {
+            if string.eq_ignore_ascii_case(category.name()) {
+                return Ok(*category);
+            }
+        }
+        Err(format!("Unknown message category '{}'", string))
+    }
+}
+
+/// A stable code identifying the kind of a message
+/// The codes may be used to suppress or configure messages and must not be renumbered
+#[derive(PartialEq, Debug, Clone, Copy, Eq, Hash, PartialOrd, Ord)]
+pub enum MessageCode {
+    SyntaxError,
+    EndIdentifierMismatch,
+    UnassociatedContextClause,
+    UnsupportedInStandard,
+    ToolDirective,
+
+    DuplicateDesignUnit,
+    MissingPrimaryUnit,
+    SecondaryUnitBeforePrimary,
+    ConfigurationLibraryMismatch,
+    CircularDependency,
+    MissingLibrary,
+    UnnecessaryLibraryClause,
+
+    UnresolvedName,
+    DuplicateDeclaration,
+    InvalidSelectedName,
+    MissingFullDeclaration,
+    IllegalDeferredConstant,
+    BindingMismatch,
+    EntityClassMismatch,
+    NullRange,
+    RangeOutOfBounds,
+    LengthMismatch,
+    RangeTypeMismatch,
+}
+
+impl MessageCode {
+    pub fn all() -> &'static [MessageCode] {
+        use MessageCode::*;
+        &[
+            SyntaxError,
+            EndIdentifierMismatch,
+            UnassociatedContextClause,
+            UnsupportedInStandard,
+            ToolDirective,
+            DuplicateDesignUnit,
+            MissingPrimaryUnit,
+            SecondaryUnitBeforePrimary,
+            ConfigurationLibraryMismatch,
+            CircularDependency,
+            MissingLibrary,
+            UnnecessaryLibraryClause,
+            UnresolvedName,
+            DuplicateDeclaration,
+            InvalidSelectedName,
+            MissingFullDeclaration,
+            IllegalDeferredConstant,
+            BindingMismatch,
+            EntityClassMismatch,
+            NullRange,
+            RangeOutOfBounds,
+            LengthMismatch,
+            RangeTypeMismatch,
+        ]
+    }
+
+    /// The numeric code such as E0202
+    pub fn code(self) -> &'static str {
+        use MessageCode::*;
+        match self {
+            SyntaxError => "E0001",
+            EndIdentifierMismatch => "E0002",
+            UnassociatedContextClause => "E0003",
+            UnsupportedInStandard => "E0004",
+            ToolDirective => "E0005",
+            DuplicateDesignUnit => "E0101",
+            MissingPrimaryUnit => "E0102",
+            SecondaryUnitBeforePrimary => "E0103",
+            ConfigurationLibraryMismatch => "E0104",
+            CircularDependency => "E0105",
+            MissingLibrary => "E0106",
+            UnnecessaryLibraryClause => "E0107",
+            UnresolvedName => "E0201",
+            DuplicateDeclaration => "E0202",
+            InvalidSelectedName => "E0203",
+            MissingFullDeclaration => "E0204",
+            IllegalDeferredConstant => "E0205",
+            BindingMismatch => "E0206",
+            EntityClassMismatch => "E0207",
+            NullRange => "E0208",
+            RangeOutOfBounds => "E0209",
+            LengthMismatch => "E0210",
+            RangeTypeMismatch => "E0211",
+        }
+    }
+
+    /// The human readable name such as duplicate-declaration
+    pub fn name(self) -> &'static str {
+        use MessageCode::*;
+        match self {
+            SyntaxError => "syntax-error",
+            EndIdentifierMismatch => "end-identifier-mismatch",
+            UnassociatedContextClause => "unassociated-context-clause",
+            UnsupportedInStandard => "unsupported-in-standard",
+            ToolDirective => "tool-directive",
+            DuplicateDesignUnit => "duplicate-design-unit",
+            MissingPrimaryUnit => "missing-primary-unit",
+            SecondaryUnitBeforePrimary => "secondary-unit-before-primary",
+            ConfigurationLibraryMismatch => "configuration-library-mismatch",
+            CircularDependency => "circular-dependency",
+            MissingLibrary => "missing-library",
+            UnnecessaryLibraryClause => "unnecessary-library-clause",
+            UnresolvedName => "unresolved-name",
+            DuplicateDeclaration => "duplicate-declaration",
+            InvalidSelectedName => "invalid-selected-name",
+            MissingFullDeclaration => "missing-full-declaration",
+            IllegalDeferredConstant => "illegal-deferred-constant",
+            BindingMismatch => "binding-mismatch",
+            EntityClassMismatch => "entity-class-mismatch",
+            NullRange => "null-range",
+            RangeOutOfBounds => "range-out-of-bounds",
+            LengthMismatch => "length-mismatch",
+            RangeTypeMismatch => "range-type-mismatch",
+        }
+    }
+
+    pub fn category(self) -> MessageCategory {
+        use MessageCode::*;
+        match self {
+            SyntaxError
+            | EndIdentifierMismatch
+            | UnassociatedContextClause
+            | UnsupportedInStandard
+            | ToolDirective => MessageCategory::Syntax,
+            DuplicateDesignUnit
+            | MissingPrimaryUnit
+            | SecondaryUnitBeforePrimary
+            | ConfigurationLibraryMismatch
+            | CircularDependency
+            | MissingLibrary => MessageCategory::Library,
+            UnresolvedName | DuplicateDeclaration | InvalidSelectedName => {
+                MessageCategory::Visibility
+            }
+            MissingFullDeclaration
+            | IllegalDeferredConstant
+            | BindingMismatch
+            | EntityClassMismatch
+            | RangeOutOfBounds
+            | LengthMismatch
+            | RangeTypeMismatch => MessageCategory::Types,
+            UnnecessaryLibraryClause | NullRange => MessageCategory::Lint,
+        }
+    }
+}
+
+impl std::fmt::Display for MessageCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.code(), self.name())
+    }
+}
+
+impl std::str::FromStr for MessageCode {
+    type Err = String;
+
+    /// Parse either the numeric code or the name
+    fn from_str(string: &str) -> Result<MessageCode, String> {
+        for code in MessageCode::all() {
+            if string.eq_ignore_ascii_case(code.code()) || string.eq_ignore_ascii_case(code.name())
+            {
+                return Ok(*code);
+            }
+        }
+        Err(format!("Unknown message code '{}'", string))
+    }
+}
+
 #[must_use]
 #[derive(PartialEq, Debug, Clone, Eq, Hash)]
 pub struct Message {
     pub pos: SrcPos,
     pub message: String,
     pub severity: Severity,
+    pub code: MessageCode,
     pub related: Vec<(SrcPos, String)>,
+    /// A suggested replacement of the code at the position of the message
+    pub replacement: Option<String>,
 }
 
 impl Message {
-    pub fn new(item: impl AsRef<SrcPos>, msg: impl Into<String>, severity: Severity) -> Message {
+    pub fn new(
+        item: impl AsRef<SrcPos>,
+        msg: impl Into<String>,
+        severity: Severity,
+        code: MessageCode,
+    ) -> Message {
         Message {
             pos: item.as_ref().clone(),
             message: msg.into(),
             severity,
+            code,
             related: vec![],
+            replacement: None,
         }
     }
 
-    pub fn error(item: impl AsRef<SrcPos>, msg: impl Into<String>) -> Message {
-        Self::new(item, msg, Severity::Error)
+    pub fn syntax_error(item: impl AsRef<SrcPos>, msg: impl Into<String>) -> Message {
+        Self::new(item, msg, Severity::Error, MessageCode::SyntaxError)
+    }
+
+    pub fn error(item: impl AsRef<SrcPos>, msg: impl Into<String>, code: MessageCode) -> Message {
+        Self::new(item, msg, Severity::Error, code)
+    }
+
+    pub fn warning(item: impl AsRef<SrcPos>, msg: impl Into<String>, code: MessageCode) -> Message {
+        Self::new(item, msg, Severity::Warning, code)
     }
 
-    pub fn warning(item: impl AsRef<SrcPos>, msg: impl Into<String>) -> Message {
-        Self::new(item, msg, Severity::Warning)
+    pub fn hint(item: impl AsRef<SrcPos>, msg: impl Into<String>, code: MessageCode) -> Message {
+        Self::new(item, msg, Severity::Hint, code)
     }
 
-    pub fn hint(item: impl AsRef<SrcPos>, msg: impl Into<String>) -> Message {
-        Self::new(item, msg, Severity::Hint)
+    pub fn info(item: impl AsRef<SrcPos>, msg: impl Into<String>, code: MessageCode) -> Message {
+        Self::new(item, msg, Severity::Info, code)
     }
 
-    pub fn info(item: impl AsRef<SrcPos>, msg: impl Into<String>) -> Message {
-        Self::new(item, msg, Severity::Info)
+    pub fn category(&self) -> MessageCategory {
+        self.code.category()
     }
 
     pub fn when(self, message: impl AsRef<str>) -> Message {
@@ -55,7 +291,9 @@ impl Message {
             message: format!("{}, when {}", &self.message, message.as_ref()),
             pos: self.pos,
             severity: self.severity,
+            code: self.code,
             related: vec![],
+            replacement: self.replacement,
         }
     }
 
@@ -65,6 +303,13 @@ impl Message {
         msg
     }
 
+    pub fn with_replacement(self, replacement: impl Into<String>) -> Message {
+        Message {
+            replacement: Some(replacement.into()),
+            ..self
+        }
+    }
+
     pub fn add_related(&mut self, item: impl AsRef<SrcPos>, message: impl Into<String>) {
         self.related
             .push((item.as_ref().to_owned(), message.into()));
@@ -78,6 +323,7 @@ impl Message {
                 pos,
                 format!("related: {}", msg),
                 Severity::Hint,
+                self.code,
             ));
         }
         messages
@@ -133,7 +379,7 @@ mod tests {
     fn show_warning() {
         let code = Code::new("hello\nworld\nline\n");
         assert_eq!(
-            Message::warning(code.s1("world"), "Greetings").show(),
+            Message::warning(code.s1("world"), "Greetings", MessageCode::UnresolvedName).show(),
             "\
 warning: Greetings
   --> {unknown file}:2
@@ -150,7 +396,7 @@ warning: Greetings
     fn show_error() {
         let code = Code::new("hello\nworld\nline\n");
         assert_eq!(
-            Message::error(code.s1("world"), "Greetings").show(),
+            Message::syntax_error(code.s1("world"), "Greetings").show(),
             "\
 error: Greetings
   --> {unknown file}:2
@@ -167,8 +413,8 @@ error: Greetings
     fn show_related() {
         let code = Code::new("hello\nworld\nline\n");
 
-        let err =
-            Message::error(code.s1("line"), "Greetings").related(code.s1("hello"), "From here");
+        let err = Message::syntax_error(code.s1("line"), "Greetings")
+            .related(code.s1("hello"), "From here");
 
         assert_eq!(
             err.show(),
@@ -192,4 +438,38 @@ error: Greetings
         );
     }
 
+    #[test]
+    fn parse_message_code() {
+        assert_eq!("E0202".parse(), Ok(MessageCode::DuplicateDeclaration));
+        assert_eq!("e0202".parse(), Ok(MessageCode::DuplicateDeclaration));
+        assert_eq!(
+            "duplicate-declaration".parse(),
+            Ok(MessageCode::DuplicateDeclaration)
+        );
+        assert!("E9999".parse::<MessageCode>().is_err());
+    }
+
+    #[test]
+    fn message_codes_are_unique() {
+        let codes: std::collections::HashSet<_> =
+            MessageCode::all().iter().map(|code| code.code()).collect();
+        let names: std::collections::HashSet<_> =
+            MessageCode::all().iter().map(|code| code.name()).collect();
+        assert_eq!(codes.len(), MessageCode::all().len());
+        assert_eq!(names.len(), MessageCode::all().len());
+    }
+
+    #[test]
+    fn message_category() {
+        assert_eq!("Lint".parse(), Ok(MessageCategory::Lint));
+        assert!("style".parse::<MessageCategory>().is_err());
+        assert_eq!(
+            MessageCode::UnresolvedName.category(),
+            MessageCategory::Visibility
+        );
+        assert_eq!(
+            Message::syntax_error(Code::new("foo").pos(), "").category(),
+            MessageCategory::Syntax
+        );
+    }
 }
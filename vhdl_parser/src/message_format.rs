@@ -0,0 +1,414 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
+
+//! Machine readable formatting of messages
+
+use crate::message::{Message, Severity};
+use crate::source::SrcPos;
+use serde_json::{json, Value};
+use std::path::{Component, Path};
+use std::str::FromStr;
+
+/// The SARIF base URI of file names relative to the working directory
+const SRCROOT: &str = "%SRCROOT%";
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum MessageFormat {
+    /// Pretty printed with code context, see Message::show
+    Human,
+    /// A json array with one object per message
+    Json,
+    /// A SARIF 2.1.0 log
+    Sarif,
+    /// One file:line:col: severity: message line per message
+    Gcc,
+}
+
+impl FromStr for MessageFormat {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<MessageFormat, String> {
+        match string {
+            "human" => Ok(MessageFormat::Human),
+            "json" => Ok(MessageFormat::Json),
+            "sarif" => Ok(MessageFormat::Sarif),
+            "gcc" => Ok(MessageFormat::Gcc),
+            _ => Err(format!(
+                "Unknown message format '{}', expected 'human', 'json', 'sarif' or 'gcc'",
+                string
+            )),
+        }
+    }
+}
+
+impl MessageFormat {
+    pub fn variants() -> &'static [&'static str] {
+        &["human", "json", "sarif", "gcc"]
+    }
+
+    /// Format all messages as a single document
+    pub fn format(self, messages: &[Message]) -> String {
+        match self {
+            MessageFormat::Human => {
+                let mut result = String::new();
+                for message in messages {
+                    result.push_str(&message.show());
+                    result.push('\n');
+                }
+                result
+            }
+            MessageFormat::Json => to_json(messages).to_string(),
+            MessageFormat::Sarif => to_sarif(messages).to_string(),
+            MessageFormat::Gcc => {
+                let mut result = String::new();
+                for message in messages {
+                    result.push_str(&to_gcc(message));
+                }
+                result
+            }
+        }
+    }
+}
+
+fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "info",
+        Severity::Hint => "hint",
+    }
+}
+
+/// All line and column numbers are one based
+fn json_location(pos: &SrcPos) -> Value {
+    let range = pos.range();
+    json!({
+        "file": pos.source.file_name(),
+        "line": range.start.line + 1,
+        "column": range.start.character + 1,
+        "end_line": range.end.line + 1,
+        "end_column": range.end.character + 1,
+    })
+}
+
+fn to_json(messages: &[Message]) -> Value {
+    let messages: Vec<Value> = messages
+        .iter()
+        .map(|message| {
+            let mut value = json_location(&message.pos);
+            value["severity"] = json!(severity_name(message.severity));
+            value["code"] = json!(message.code.code());
+            value["category"] = json!(message.category().name());
+            value["message"] = json!(message.message);
+            value["related"] = Value::Array(
+                message
+                    .related
+                    .iter()
+                    .map(|(pos, msg)| {
+                        let mut related = json_location(pos);
+                        related["message"] = json!(msg);
+                        related
+                    })
+                    .collect(),
+            );
+            value
+        })
+        .collect();
+    Value::Array(messages)
+}
+
+/// Percent encode a path segment of a URI
+fn percent_encode(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// The path as forward slash separated and percent encoded segments
+fn uri_path(path: &Path) -> String {
+    let mut segments = Vec::new();
+    for component in path.components() {
+        match component {
+            // A drive letter such as C: is kept as is
+            Component::Prefix(prefix) => {
+                segments.push(prefix.as_os_str().to_string_lossy().into_owned())
+            }
+            Component::RootDir if segments.is_empty() => segments.push(String::new()),
+            Component::RootDir | Component::CurDir => {}
+            Component::ParentDir => segments.push("..".to_owned()),
+            Component::Normal(name) => segments.push(percent_encode(&name.to_string_lossy())),
+        }
+    }
+    segments.join("/")
+}
+
+fn file_uri(path: &Path) -> String {
+    let path = uri_path(path);
+    if path.starts_with('/') {
+        format!("file://{}", path)
+    } else {
+        format!("file:///{}", path)
+    }
+}
+
+/// SARIF 3.4 File names within the working directory are relative to %SRCROOT%,
+/// other file names are absolute file URIs
+fn sarif_artifact_location(file_name: &str, working_directory: Option<&Path>) -> Value {
+    let path = Path::new(file_name);
+    let relative = if path.is_absolute() {
+        working_directory.and_then(|directory| path.strip_prefix(directory).ok())
+    } else {
+        Some(path)
+    };
+
+    match relative {
+        Some(relative) => json!({
+            "uri": uri_path(relative),
+            "uriBaseId": SRCROOT,
+        }),
+        None => json!({
+            "uri": file_uri(path),
+        }),
+    }
+}
+
+fn sarif_location(pos: &SrcPos, working_directory: Option<&Path>) -> Value {
+    let range = pos.range();
+    json!({
+        "physicalLocation": {
+            "artifactLocation": sarif_artifact_location(pos.source.file_name(), working_directory),
+            "region": {
+                "startLine": range.start.line + 1,
+                "startColumn": range.start.character + 1,
+                "endLine": range.end.line + 1,
+                "endColumn": range.end.character + 1,
+            }
+        }
+    })
+}
+
+fn to_sarif(messages: &[Message]) -> Value {
+    let working_directory = std::env::current_dir().ok();
+    let working_directory = working_directory.as_deref();
+
+    let results: Vec<Value> = messages
+        .iter()
+        .map(|message| {
+            let level = match message.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Info | Severity::Hint => "note",
+            };
+
+            let related: Vec<Value> = message
+                .related
+                .iter()
+                .map(|(pos, msg)| {
+                    let mut location = sarif_location(pos, working_directory);
+                    location["message"] = json!({ "text": msg });
+                    location
+                })
+                .collect();
+
+            json!({
+                "ruleId": message.code.code(),
+                "level": level,
+                "message": {
+                    "text": message.message,
+                },
+                "locations": [sarif_location(&message.pos, working_directory)],
+                "relatedLocations": related,
+            })
+        })
+        .collect();
+
+    let mut run = json!({
+        "tool": {
+            "driver": {
+                "name": env!("CARGO_PKG_NAME"),
+                "version": env!("CARGO_PKG_VERSION"),
+                "informationUri": env!("CARGO_PKG_REPOSITORY"),
+            }
+        },
+        "results": results,
+    });
+    if let Some(working_directory) = working_directory {
+        // A base URI shall end with a slash
+        run["originalUriBaseIds"] = json!({
+            SRCROOT: {
+                "uri": format!("{}/", file_uri(working_directory).trim_end_matches('/')),
+            }
+        });
+    }
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [run],
+    })
+}
+
+fn gcc_line(pos: &SrcPos, severity: &str, message: &str) -> String {
+    let range = pos.range();
+    format!(
+        "{}:{}:{}: {}: {}\n",
+        pos.source.file_name(),
+        range.start.line + 1,
+        range.start.character + 1,
+        severity,
+        message
+    )
+}
+
+fn to_gcc(message: &Message) -> String {
+    let mut result = gcc_line(
+        &message.pos,
+        severity_name(message.severity),
+        &format!("{} [{}]", message.message, message.code.code()),
+    );
+    for (pos, msg) in message.related.iter() {
+        result.push_str(&gcc_line(pos, "note", msg));
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::MessageCode;
+    use crate::test_util::Code;
+
+    #[test]
+    fn parse_message_format() {
+        assert_eq!("json".parse(), Ok(MessageFormat::Json));
+        assert_eq!("sarif".parse(), Ok(MessageFormat::Sarif));
+        assert_eq!("gcc".parse(), Ok(MessageFormat::Gcc));
+        assert_eq!("human".parse(), Ok(MessageFormat::Human));
+        assert!("xml".parse::<MessageFormat>().is_err());
+    }
+
+    #[test]
+    fn format_gcc() {
+        let code = Code::new("hello\nworld\nline\n");
+        let message = Message::syntax_error(code.s1("rld"), "Greetings")
+            .related(code.s1("line"), "From here");
+
+        assert_eq!(
+            MessageFormat::Gcc.format(&[message]),
+            "\
+{unknown file}:2:3: error: Greetings [E0001]
+{unknown file}:3:1: note: From here
+"
+        );
+    }
+
+    #[test]
+    fn format_json() {
+        let code = Code::new("hello\nworld\nline\n");
+        let message = Message::warning(code.s1("rld"), "Greetings", MessageCode::UnresolvedName)
+            .related(code.s1("line"), "From here");
+
+        let value: Value = serde_json::from_str(&MessageFormat::Json.format(&[message])).unwrap();
+        assert_eq!(
+            value,
+            json!([{
+                "file": "{unknown file}",
+                "line": 2,
+                "column": 3,
+                "end_line": 2,
+                "end_column": 6,
+                "severity": "warning",
+                "code": "E0201",
+                "category": "visibility",
+                "message": "Greetings",
+                "related": [{
+                    "file": "{unknown file}",
+                    "line": 3,
+                    "column": 1,
+                    "end_line": 3,
+                    "end_column": 5,
+                    "message": "From here",
+                }]
+            }])
+        );
+    }
+
+    #[test]
+    fn format_sarif() {
+        let code = Code::new("hello\nworld\nline\n");
+        let message = Message::hint(code.s1("rld"), "Greetings", MessageCode::UnresolvedName);
+
+        let value: Value = serde_json::from_str(&MessageFormat::Sarif.format(&[message])).unwrap();
+        assert_eq!(value["version"], json!("2.1.0"));
+        assert_eq!(
+            value["runs"][0]["results"],
+            json!([{
+                "ruleId": "E0201",
+                "level": "note",
+                "message": {
+                    "text": "Greetings",
+                },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": {
+                            "uri": "%7Bunknown%20file%7D",
+                            "uriBaseId": "%SRCROOT%",
+                        },
+                        "region": {
+                            "startLine": 2,
+                            "startColumn": 3,
+                            "endLine": 2,
+                            "endColumn": 6,
+                        }
+                    }
+                }],
+                "relatedLocations": [],
+            }])
+        );
+        assert_eq!(
+            value["runs"][0]["originalUriBaseIds"]["%SRCROOT%"]["uri"],
+            json!(format!(
+                "{}/",
+                file_uri(&std::env::current_dir().unwrap()).trim_end_matches('/')
+            ))
+        );
+    }
+
+    #[test]
+    fn sarif_artifact_location_of_file_names() {
+        let working_directory = std::env::current_dir().unwrap();
+        let inside = working_directory.join("src").join("my ent.vhd");
+        let inside = inside.to_str().unwrap();
+        assert_eq!(
+            sarif_artifact_location(inside, Some(&working_directory)),
+            json!({"uri": "src/my%20ent.vhd", "uriBaseId": "%SRCROOT%"})
+        );
+        assert_eq!(
+            sarif_artifact_location("../lib/pkg#1.vhd", Some(&working_directory)),
+            json!({"uri": "../lib/pkg%231.vhd", "uriBaseId": "%SRCROOT%"})
+        );
+
+        let outside = working_directory
+            .parent()
+            .unwrap()
+            .join("other")
+            .join("ent.vhd");
+        let outside = outside.to_str().unwrap();
+        let sub_directory = working_directory.join("sub");
+        assert_eq!(
+            sarif_artifact_location(outside, Some(&sub_directory)),
+            json!({ "uri": file_uri(Path::new(outside)) })
+        );
+        assert!(file_uri(Path::new(outside)).starts_with("file:///"));
+        assert!(file_uri(Path::new(outside)).ends_with("/other/ent.vhd"));
+    }
+}
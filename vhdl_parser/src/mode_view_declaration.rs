@@ -0,0 +1,161 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
+
+//! VHDL-2019 mode view declarations
+
+use crate::ast::{ElementMode, Mode, ModeViewDeclaration, ModeViewElement, ModeViewIndication};
+use crate::common::error_on_end_identifier_mismatch;
+use crate::message::{push_some, MessageHandler, ParseResult};
+use crate::names::{parse_identifier_list, parse_name};
+use crate::subtype_indication::parse_subtype_indication;
+use crate::tokenizer::Kind::*;
+use crate::tokenstream::TokenStream;
+
+/// Parse a mode view indication after the view keyword
+pub fn parse_mode_view_indication_known_keyword(
+    stream: &mut TokenStream,
+) -> ParseResult<ModeViewIndication> {
+    let is_array = stream.skip_if_kind(LeftPar)?;
+    let name = parse_name(stream)?;
+    if is_array {
+        stream.expect_kind(RightPar)?;
+    }
+
+    let subtype_indication = {
+        if stream.skip_if_kind(Of)? {
+            Some(parse_subtype_indication(stream)?)
+        } else {
+            None
+        }
+    };
+
+    Ok(ModeViewIndication {
+        is_array,
+        name,
+        subtype_indication,
+    })
+}
+
+fn parse_element_mode(stream: &mut TokenStream) -> ParseResult<ElementMode> {
+    let token = stream.expect()?;
+    Ok(try_token_kind!(
+        token,
+        In => ElementMode::Simple(Mode::In),
+        Out => ElementMode::Simple(Mode::Out),
+        InOut => ElementMode::Simple(Mode::InOut),
+        Buffer => ElementMode::Simple(Mode::Buffer),
+        Linkage => ElementMode::Simple(Mode::Linkage),
+        View => ElementMode::View(parse_mode_view_indication_known_keyword(stream)?)
+    ))
+}
+
+pub fn parse_mode_view_declaration(
+    stream: &mut TokenStream,
+    messages: &mut dyn MessageHandler,
+) -> ParseResult<ModeViewDeclaration> {
+    stream.expect_kind(View)?;
+    let ident = stream.expect_ident()?;
+    stream.expect_kind(Of)?;
+    let subtype_indication = parse_subtype_indication(stream)?;
+    stream.expect_kind(Is)?;
+
+    let mut elements = Vec::new();
+    loop {
+        let token = stream.peek_expect()?;
+        if token.kind == End {
+            stream.move_after(&token);
+            break;
+        }
+
+        let names = parse_identifier_list(stream)?;
+        stream.expect_kind(Colon)?;
+        let mode = parse_element_mode(stream)?;
+        stream.expect_kind(SemiColon)?;
+        elements.push(ModeViewElement { names, mode });
+    }
+
+    stream.expect_kind(View)?;
+    let end_ident = stream.pop_optional_ident()?;
+    push_some(
+        messages,
+        error_on_end_identifier_mismatch(&ident, &end_ident),
+    );
+    stream.expect_kind(SemiColon)?;
+
+    Ok(ModeViewDeclaration {
+        ident,
+        subtype_indication,
+        elements,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Code;
+    use crate::vhdl_standard::VHDLStandard;
+
+    #[test]
+    fn parse_mode_view_declaration_with_elements() {
+        let code = Code::with_standard(
+            "\
+view master of bus_t is
+  addr, data : out;
+  ack : in;
+  sub : view sub_master;
+  arr : view (sub_slave);
+end view master;",
+            VHDLStandard::VHDL2019,
+        );
+
+        assert_eq!(
+            code.with_stream_no_messages(parse_mode_view_declaration),
+            ModeViewDeclaration {
+                ident: code.s1("master").ident(),
+                subtype_indication: code.s1("bus_t").subtype_indication(),
+                elements: vec![
+                    ModeViewElement {
+                        names: vec![code.s1("addr").ident(), code.s1("data").ident()],
+                        mode: ElementMode::Simple(Mode::Out),
+                    },
+                    ModeViewElement {
+                        names: vec![code.s1("ack").ident()],
+                        mode: ElementMode::Simple(Mode::In),
+                    },
+                    ModeViewElement {
+                        names: vec![code.s1("sub").ident()],
+                        mode: ElementMode::View(ModeViewIndication {
+                            is_array: false,
+                            name: code.s1("sub_master").name(),
+                            subtype_indication: None,
+                        }),
+                    },
+                    ModeViewElement {
+                        names: vec![code.s1("arr").ident()],
+                        mode: ElementMode::View(ModeViewIndication {
+                            is_array: true,
+                            name: code.s1("sub_slave").name(),
+                            subtype_indication: None,
+                        }),
+                    },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn parse_mode_view_indication_converse() {
+        let code = Code::with_standard("master'converse of bus_t", VHDLStandard::VHDL2019);
+        assert_eq!(
+            code.with_stream(parse_mode_view_indication_known_keyword),
+            ModeViewIndication {
+                is_array: false,
+                name: code.s1("master'converse").name(),
+                subtype_indication: Some(code.s1("bus_t").subtype_indication()),
+            }
+        );
+    }
+}
@@ -12,6 +12,7 @@ use crate::ast::{
 };
 use crate::expression::{parse_expression, parse_expression_initial_token};
 use crate::message::{Message, ParseResult};
+use crate::psl::is_repetition;
 use crate::source::WithPos;
 use crate::subprogram::parse_signature;
 use crate::subtype_indication::parse_subtype_indication;
@@ -55,7 +56,7 @@ pub fn into_selected_name(name: WithPos<Name>) -> ParseResult<WithPos<SelectedNa
             SelectedName::Designator(designator),
             name.pos,
         )),
-        _ => Err(Message::error(&name, "Expected selected name")),
+        _ => Err(Message::syntax_error(&name, "Expected selected name")),
     }
 }
 
@@ -70,7 +71,7 @@ pub fn to_simple_name(name: WithPos<Name>) -> ParseResult<Ident> {
             item: ident,
             pos: name.pos,
         }),
-        _ => Err(Message::error(&name, "Expected selected name")),
+        _ => Err(Message::syntax_error(&name, "Expected selected name")),
     }
 }
 
@@ -103,27 +104,27 @@ fn expression_to_name(expr: WithPos<Expression>) -> ParseResult<WithPos<Name>> {
             item: Name::Designator(Designator::Character(val)),
             pos: expr.pos,
         }),
-        _ => Err(Message::error(&expr, "Expected name")),
+        _ => Err(Message::syntax_error(&expr, "Expected name")),
     }
 }
 
 fn actual_to_expression(actual: WithPos<ActualPart>) -> ParseResult<WithPos<Expression>> {
     match actual.item {
         ActualPart::Expression(expr) => Ok(WithPos::from(expr, actual.pos)),
-        _ => Err(Message::error(&actual, "Expected expression")),
+        _ => Err(Message::syntax_error(&actual, "Expected expression")),
     }
 }
 
 fn actual_part_to_name(actual: WithPos<ActualPart>) -> ParseResult<WithPos<Name>> {
     match actual.item {
         ActualPart::Expression(expr) => expression_to_name(WithPos::from(expr, actual.pos)),
-        _ => Err(Message::error(&actual, "Expected name")),
+        _ => Err(Message::syntax_error(&actual, "Expected name")),
     }
 }
 
 fn assoc_to_expression(assoc: AssociationElement) -> ParseResult<WithPos<Expression>> {
     match assoc.formal {
-        Some(name) => Err(Message::error(&name, "Expected expression")),
+        Some(name) => Err(Message::syntax_error(&name, "Expected expression")),
         None => actual_to_expression(assoc.actual),
     }
 }
@@ -312,7 +313,10 @@ pub fn parse_name_initial_token(
                     WithPos::from(Name::Designator(designator), suffix.pos)
                 }
                 DesignatorOrAll::All => {
-                    return Err(Message::error(suffix.pos, "Illegal prefix 'all' for name"));
+                    return Err(Message::syntax_error(
+                        suffix.pos,
+                        "Illegal prefix 'all' for name",
+                    ));
                 }
             }
         }
@@ -345,13 +349,22 @@ pub fn parse_name_initial_token(
                 }
             }
             LeftSquare => {
+                // A PSL repetition such as a[*2] is not a signature
+                if is_repetition(stream)? {
+                    break;
+                }
                 let state = stream.state();
-                let signature = Some(parse_signature(stream)?);
+                let mut signature_messages = Vec::new();
+                let signature = Some(parse_signature(stream, &mut signature_messages)?);
                 if !stream.skip_if_kind(Tick)? {
                     // Alias may have prefix[signature] without tick
                     stream.set_state(state);
                     break;
                 }
+                // @TODO names have no message handler to report recoverable errors to
+                if let Some(msg) = signature_messages.pop() {
+                    return Err(msg);
+                }
                 name = parse_attribute_name(stream, name, signature)?;
             }
             Tick => {
@@ -913,7 +926,7 @@ mod tests {
         let code = Code::new("all");
         assert_eq!(
             code.with_partial_stream(parse_name),
-            Err(Message::error(
+            Err(Message::syntax_error(
                 code.s1("all"),
                 "Illegal prefix 'all' for name"
             ))
@@ -925,11 +938,10 @@ mod tests {
         let code = Code::new("all.foo");
         assert_eq!(
             code.with_partial_stream(parse_name),
-            Err(Message::error(
+            Err(Message::syntax_error(
                 code.s1("all"),
                 "Illegal prefix 'all' for name"
             ))
         );
     }
-
 }
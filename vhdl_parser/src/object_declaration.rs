@@ -5,10 +5,13 @@
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
 /// LRM 6.4.2 Object Declarations
-use crate::ast::{Expression, FileDeclaration, ObjectClass, ObjectDeclaration};
+use crate::ast::{
+    DisconnectionSpecification, Expression, FileDeclaration, GuardedSignalList, ObjectClass,
+    ObjectDeclaration, SignalKind,
+};
 use crate::expression::parse_expression;
 use crate::message::ParseResult;
-use crate::names::parse_identifier_list;
+use crate::names::{parse_identifier_list, parse_name, parse_selected_name};
 use crate::source::WithPos;
 use crate::subtype_indication::parse_subtype_indication;
 use crate::tokenizer::Kind::*;
@@ -25,6 +28,17 @@ pub fn parse_optional_assignment(
     }
 }
 
+/// LRM 6.4.2.3 The register or bus signal kind of a guarded signal
+pub fn parse_optional_signal_kind(stream: &mut TokenStream) -> ParseResult<Option<SignalKind>> {
+    if stream.skip_if_kind(Register)? {
+        Ok(Some(SignalKind::Register))
+    } else if stream.skip_if_kind(Bus)? {
+        Ok(Some(SignalKind::Bus))
+    } else {
+        Ok(None)
+    }
+}
+
 fn parse_object_declaration_kind(
     stream: &mut TokenStream,
     class: ObjectClass,
@@ -32,6 +46,11 @@ fn parse_object_declaration_kind(
     let idents = parse_identifier_list(stream)?;
     stream.expect_kind(Colon)?;
     let subtype = parse_subtype_indication(stream)?;
+    let signal_kind = if class == ObjectClass::Signal {
+        parse_optional_signal_kind(stream)?
+    } else {
+        None
+    };
     let opt_expression = parse_optional_assignment(stream)?;
 
     Ok(idents
@@ -40,6 +59,7 @@ fn parse_object_declaration_kind(
             class,
             ident,
             subtype_indication: subtype.clone(),
+            signal_kind,
             expression: opt_expression.clone(),
         })
         .collect())
@@ -102,6 +122,34 @@ pub fn parse_file_declaration(stream: &mut TokenStream) -> ParseResult<Vec<FileD
     Ok(result)
 }
 
+/// LRM 7.4 Disconnection specification
+pub fn parse_disconnection_specification(
+    stream: &mut TokenStream,
+) -> ParseResult<DisconnectionSpecification> {
+    stream.expect_kind(Disconnect)?;
+    let signals = if stream.skip_if_kind(Others)? {
+        GuardedSignalList::Others
+    } else if stream.skip_if_kind(All)? {
+        GuardedSignalList::All
+    } else {
+        let mut names = vec![parse_name(stream)?];
+        while stream.skip_if_kind(Comma)? {
+            names.push(parse_name(stream)?);
+        }
+        GuardedSignalList::Names(names)
+    };
+    stream.expect_kind(Colon)?;
+    let type_mark = parse_selected_name(stream)?;
+    stream.expect_kind(After)?;
+    let after = parse_expression(stream)?;
+    stream.expect_kind(SemiColon)?;
+    Ok(DisconnectionSpecification {
+        signals,
+        type_mark,
+        after,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,6 +164,7 @@ mod tests {
                 class: ObjectClass::Constant,
                 ident: code.s1("foo").ident(),
                 subtype_indication: code.s1("natural").subtype_indication(),
+                signal_kind: None,
                 expression: None
             }]
         );
@@ -130,6 +179,7 @@ mod tests {
                 class: ObjectClass::Signal,
                 ident: code.s1("foo").ident(),
                 subtype_indication: code.s1("natural").subtype_indication(),
+                signal_kind: None,
                 expression: None
             }]
         );
@@ -144,6 +194,7 @@ mod tests {
                 class: ObjectClass::Variable,
                 ident: code.s1("foo").ident(),
                 subtype_indication: code.s1("natural").subtype_indication(),
+                signal_kind: None,
                 expression: None
             }]
         );
@@ -158,6 +209,7 @@ mod tests {
                 class: ObjectClass::SharedVariable,
                 ident: code.s1("foo").ident(),
                 subtype_indication: code.s1("natural").subtype_indication(),
+                signal_kind: None,
                 expression: None
             }]
         );
@@ -214,11 +266,46 @@ mod tests {
                 class: ObjectClass::Constant,
                 ident: code.s1("foo").ident(),
                 subtype_indication: code.s1("natural").subtype_indication(),
+                signal_kind: None,
                 expression: Some(code.s1("0").expr())
             }]
         );
     }
 
+    #[test]
+    fn parses_guarded_signals() {
+        let code = Code::new("signal foo, bar : resolved_bit register := '0';");
+        let objects = vec![
+            ObjectDeclaration {
+                class: ObjectClass::Signal,
+                ident: code.s1("foo").ident(),
+                subtype_indication: code.s1("resolved_bit").subtype_indication(),
+                signal_kind: Some(SignalKind::Register),
+                expression: Some(code.s1("'0'").expr()),
+            },
+            ObjectDeclaration {
+                class: ObjectClass::Signal,
+                ident: code.s1("bar").ident(),
+                subtype_indication: code.s1("resolved_bit").subtype_indication(),
+                signal_kind: Some(SignalKind::Register),
+                expression: Some(code.s1("'0'").expr()),
+            },
+        ];
+        assert_eq!(code.with_stream(parse_object_declaration), objects);
+
+        let code = Code::new("signal foo : resolved_bit bus;");
+        assert_eq!(
+            code.with_stream(parse_object_declaration),
+            vec![ObjectDeclaration {
+                class: ObjectClass::Signal,
+                ident: code.s1("foo").ident(),
+                subtype_indication: code.s1("resolved_bit").subtype_indication(),
+                signal_kind: Some(SignalKind::Bus),
+                expression: None
+            }]
+        );
+    }
+
     #[test]
     fn parses_identifier_list() {
         let code = Code::new("constant foo, bar : natural := 0;");
@@ -228,16 +315,50 @@ mod tests {
                 class: ObjectClass::Constant,
                 ident: code.s1("foo").ident(),
                 subtype_indication: code.s1("natural").subtype_indication(),
+                signal_kind: None,
                 expression: Some(code.s1("0").expr()),
             },
             ObjectDeclaration {
                 class: ObjectClass::Constant,
                 ident: code.s1("bar").ident(),
                 subtype_indication: code.s1("natural").subtype_indication(),
+                signal_kind: None,
                 expression: Some(code.s1("0").expr()),
             },
         ];
 
         assert_eq!(code.with_stream(parse_object_declaration), objects);
     }
+
+    #[test]
+    fn parses_disconnection_specification() {
+        let code = Code::new("disconnect foo, bar(0) : resolved_bit after 1 ns;");
+        assert_eq!(
+            code.with_stream(parse_disconnection_specification),
+            DisconnectionSpecification {
+                signals: GuardedSignalList::Names(vec![
+                    code.s1("foo").name(),
+                    code.s1("bar(0)").name()
+                ]),
+                type_mark: code.s1("resolved_bit").selected_name(),
+                after: code.s1("1 ns").expr()
+            }
+        );
+
+        let code = Code::new("disconnect others : resolved_bit after 0 ns;");
+        assert_eq!(
+            code.with_stream(parse_disconnection_specification),
+            DisconnectionSpecification {
+                signals: GuardedSignalList::Others,
+                type_mark: code.s1("resolved_bit").selected_name(),
+                after: code.s1("0 ns").expr()
+            }
+        );
+
+        let code = Code::new("disconnect all : resolved_bit after 0 ns;");
+        assert_eq!(
+            code.with_stream(parse_disconnection_specification).signals,
+            GuardedSignalList::All
+        );
+    }
 }
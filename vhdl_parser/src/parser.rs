@@ -5,14 +5,18 @@
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
 use crate::ast::DesignFile;
+use crate::conditional_analysis::{analyse_directives, ConditionalAnalysis};
 use crate::design_unit::parse_design_file;
 use crate::latin_1::Latin1String;
 use crate::message::{Message, MessageHandler};
 use crate::source::Source;
+use crate::suppression::{may_contain_suppressions, Suppressions};
 use crate::symbol_table::Symbol;
 use crate::symbol_table::SymbolTable;
+use crate::synthesis_pragma::{find_translate_off_regions, may_contain_pragmas, TranslateOff};
 use crate::tokenizer::Tokenizer;
 use crate::tokenstream::TokenStream;
+use crate::vhdl_standard::VHDLStandard;
 use std::convert::From;
 use std::io;
 use std::sync::mpsc::{sync_channel, Receiver, SyncSender};
@@ -31,6 +35,10 @@ pub enum ParserError {
 #[derive(Clone)]
 pub struct VHDLParser {
     pub symtab: Arc<SymbolTable>,
+    // The values of the conditional analysis identifiers of all files
+    pub conditional_analysis: ConditionalAnalysis,
+    // How the code between translate_off and translate_on pragmas is analysed
+    pub translate_off: TranslateOff,
 }
 
 pub type ParserResult = Result<DesignFile, ParserError>;
@@ -51,6 +59,8 @@ impl VHDLParser {
     pub fn new() -> VHDLParser {
         VHDLParser {
             symtab: Arc::new(SymbolTable::new()),
+            conditional_analysis: ConditionalAnalysis::new(),
+            translate_off: TranslateOff::default(),
         }
     }
 
@@ -61,28 +71,66 @@ impl VHDLParser {
     pub fn parse_design_source(
         &self,
         source: &Source,
+        standard: VHDLStandard,
         messages: &mut dyn MessageHandler,
     ) -> ParserResult {
-        let code = source.contents()?;
-        let tokenizer = Tokenizer::new(self.symtab.clone(), source.clone(), code);
+        self.parse_design_source_with_suppressions(source, standard, messages)
+            .0
+    }
+
+    /// Parse a source and find its suppression comments
+    /// The suppressions are kept apart from the design file since they also apply to syntax errors
+    pub fn parse_design_source_with_suppressions(
+        &self,
+        source: &Source,
+        standard: VHDLStandard,
+        messages: &mut dyn MessageHandler,
+    ) -> (ParserResult, Suppressions) {
+        let code = match source.contents() {
+            Ok(code) => code,
+            Err(err) => return (Err(err.into()), Suppressions::default()),
+        };
+        // Only files with grave accents may contain conditional analysis directives
+        let has_directives = code.bytes.contains(&b'`');
+        let has_pragmas = may_contain_pragmas(&code.bytes);
+        let has_suppressions = may_contain_suppressions(&code.bytes);
+        let tokenizer =
+            Tokenizer::with_standard(self.symtab.clone(), source.clone(), code.clone(), standard);
         let mut stream = TokenStream::new(tokenizer);
-        Ok(parse_design_file(&mut stream, messages)?)
+        if has_directives {
+            analyse_directives(&mut stream, &self.conditional_analysis, messages);
+        }
+        if has_pragmas {
+            find_translate_off_regions(&mut stream, self.translate_off);
+        }
+        // The comments are taken from the tokens of the parser
+        if has_suppressions {
+            stream.tokenizer.start_collecting_comments();
+        }
+        let result = parse_design_file(&mut stream, messages).map_err(ParserError::from);
+        let suppressions = if has_suppressions {
+            Suppressions::from_comments(&code, stream.tokenizer.take_collected_comments())
+        } else {
+            Suppressions::default()
+        };
+        (result, suppressions)
     }
 
     pub fn parse_design_file(
         &self,
         file_name: &str,
+        standard: VHDLStandard,
         messages: &mut dyn MessageHandler,
     ) -> ParserResult {
         let source = Source::from_file(file_name);
-        Ok(self.parse_design_source(&source, messages)?)
+        Ok(self.parse_design_source(&source, standard, messages)?)
     }
 
     pub fn parse_design_files<T>(
         &self,
         files_to_parse: Vec<T>,
         num_threads: usize,
-    ) -> impl Iterator<Item = (T, Vec<Message>, ParserResult)>
+    ) -> impl Iterator<Item = (T, Vec<Message>, ParserResult, Suppressions)>
     where
         T: FileToParse + Send + 'static,
     {
@@ -98,6 +146,10 @@ impl Default for VHDLParser {
 
 pub trait FileToParse {
     fn file_name(&self) -> &str;
+
+    fn standard(&self) -> VHDLStandard {
+        VHDLStandard::default()
+    }
 }
 
 impl FileToParse for String {
@@ -106,7 +158,7 @@ impl FileToParse for String {
     }
 }
 
-type ParallelResult<T> = (T, Vec<Message>, ParserResult);
+type ParallelResult<T> = (T, Vec<Message>, ParserResult, Suppressions);
 
 struct ParallelParser<T> {
     result_receiver: Receiver<(usize, ParallelResult<Box<T>>)>,
@@ -128,9 +180,14 @@ impl<T: Send + FileToParse + 'static> ParallelParser<T> {
             match item {
                 Some((idx, file_to_parse)) => {
                     let mut messages = Vec::new();
-                    let result = parser.parse_design_file(file_to_parse.file_name(), &mut messages);
+                    let source = Source::from_file(file_to_parse.file_name());
+                    let (result, suppressions) = parser.parse_design_source_with_suppressions(
+                        &source,
+                        file_to_parse.standard(),
+                        &mut messages,
+                    );
                     output
-                        .send((idx, (file_to_parse, messages, result)))
+                        .send((idx, (file_to_parse, messages, result, suppressions)))
                         .unwrap();
                 }
                 None => {
@@ -195,7 +252,7 @@ impl<T> Iterator for ParallelParser<T> {
         };
 
         self.idx += 1;
-        let (file_to_parse, messages, result) = value;
-        Some((*file_to_parse, messages, result))
+        let (file_to_parse, messages, result, suppressions) = value;
+        Some((*file_to_parse, messages, result, suppressions))
     }
 }
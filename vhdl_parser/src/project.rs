@@ -4,15 +4,19 @@
 //
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
-use self::fnv::FnvHashMap;
-use crate::analysis::{Analyzer, DesignRoot, Library};
-use crate::ast::DesignFile;
-use crate::config::Config;
+use self::fnv::{FnvHashMap, FnvHashSet};
+use crate::analysis::{Analyzer, DesignRoot, Library, Value};
+use crate::ast::{AnyDesignUnit, DesignFile, HasIdent};
+use crate::builtin_libraries::BUILTIN_FILES;
+use crate::config::{Config, SeverityMap};
+use crate::generic_values::GenericValues;
 use crate::latin_1::Latin1String;
 use crate::message::Message;
 use crate::parser::{FileToParse, ParserError, VHDLParser};
-use crate::source::Source;
+use crate::source::{Source, SrcPos};
+use crate::suppression::Suppressions;
 use crate::symbol_table::Symbol;
+use crate::vhdl_standard::VHDLStandard;
 use fnv;
 use std::collections::hash_map::Entry;
 use std::io;
@@ -20,6 +24,15 @@ use std::io;
 pub struct Project {
     parser: VHDLParser,
     files: FnvHashMap<String, SourceFile>,
+    severities: SeverityMap,
+    third_party_libraries: FnvHashSet<Symbol>,
+    // The standard of new files and libraries which do not select their own
+    standard: VHDLStandard,
+    library_standards: FnvHashMap<Symbol, VHDLStandard>,
+    // The static values of constant declarations of the last analysis by file name
+    static_values: FnvHashMap<String, Vec<(SrcPos, Value)>>,
+    // The values of generics supplied at elaboration
+    generics: GenericValues,
 }
 
 pub struct FileError {
@@ -34,10 +47,23 @@ impl std::fmt::Display for FileError {
 }
 
 impl Project {
+    /// A project with only the builtin libraries
     pub fn new() -> Project {
+        let mut project = Project::empty();
+        project.add_builtin_libraries(&Config::default());
+        project
+    }
+
+    fn empty() -> Project {
         Project {
             parser: VHDLParser::new(),
             files: FnvHashMap::default(),
+            severities: SeverityMap::default(),
+            third_party_libraries: FnvHashSet::default(),
+            standard: VHDLStandard::default(),
+            library_standards: FnvHashMap::default(),
+            static_values: FnvHashMap::default(),
+            generics: GenericValues::new(),
         }
     }
 
@@ -46,7 +72,12 @@ impl Project {
         num_threads: usize,
         errors: &mut Vec<FileError>,
     ) -> Project {
-        let mut project = Project::new();
+        let mut project = Project::empty();
+        project.severities = config.severities().clone();
+        project.standard = config.standard();
+        project.parser.conditional_analysis = config.conditional_analysis().clone();
+        project.parser.translate_off = config.translate_off();
+        project.generics = config.generics().clone();
         let mut files_to_parse: FnvHashMap<&str, LibraryFileToParse> = FnvHashMap::default();
 
         for library in config.iter_libraries() {
@@ -54,15 +85,28 @@ impl Project {
                 Latin1String::from_utf8(library.name()).expect("Library name not latin-1 encoded");
             let library_name = project.parser.symbol(&library_name);
 
+            if library.is_third_party() {
+                project.third_party_libraries.insert(library_name.clone());
+            }
+
+            let standard = config.library_standard(library);
+            project
+                .library_standards
+                .insert(library_name.clone(), standard);
+
             for file_name in library.file_names() {
                 match files_to_parse.entry(file_name) {
                     Entry::Occupied(mut entry) => {
-                        entry.get_mut().library_names.push(library_name.clone());
+                        let file_to_parse = entry.get_mut();
+                        file_to_parse.library_names.push(library_name.clone());
+                        // A file in several libraries is parsed according to the newest standard
+                        file_to_parse.standard = file_to_parse.standard.max(standard);
                     }
                     Entry::Vacant(entry) => {
                         let file_to_parse = LibraryFileToParse {
                             library_names: vec![library_name.clone()],
                             file_name: file_name.clone(),
+                            standard,
                         };
 
                         entry.insert(file_to_parse);
@@ -71,9 +115,11 @@ impl Project {
             }
         }
 
+        project.add_builtin_libraries(config);
+
         let files_to_parse = files_to_parse.drain().map(|(_, v)| v).collect();
 
-        for (file_to_parse, mut parser_messages, design_file) in project
+        for (file_to_parse, mut parser_messages, design_file, suppressions) in project
             .parser
             .parse_design_files(files_to_parse, num_threads)
         {
@@ -96,8 +142,10 @@ impl Project {
                 file_to_parse.file_name,
                 SourceFile {
                     library_names: file_to_parse.library_names,
+                    standard: file_to_parse.standard,
                     parser_messages,
                     design_file,
+                    suppressions,
                 },
             );
         }
@@ -105,6 +153,47 @@ impl Project {
         project
     }
 
+    /// Add the bundled std and ieee libraries unless the config defines libraries with the same name
+    /// The builtin libraries are third party libraries so their messages are not shown
+    fn add_builtin_libraries(&mut self, config: &Config) {
+        for builtin_file in BUILTIN_FILES.iter() {
+            if config.get_library(builtin_file.library_name).is_some() {
+                continue;
+            }
+
+            let library_name = self
+                .parser
+                .symbol(&Latin1String::new(builtin_file.library_name.as_bytes()));
+            self.third_party_libraries.insert(library_name.clone());
+
+            let mut parser_messages = Vec::new();
+            let (design_file, suppressions) = self.parser.parse_design_source_with_suppressions(
+                &builtin_file.source(),
+                VHDLStandard::default(),
+                &mut parser_messages,
+            );
+            let design_file = match design_file {
+                Ok(design_file) => Some(design_file),
+                Err(ParserError::Message(msg)) => {
+                    parser_messages.push(msg);
+                    None
+                }
+                Err(ParserError::IOError(..)) => None,
+            };
+
+            self.files.insert(
+                builtin_file.file_name(),
+                SourceFile {
+                    library_names: vec![library_name],
+                    standard: VHDLStandard::default(),
+                    parser_messages,
+                    design_file,
+                    suppressions,
+                },
+            );
+        }
+    }
+
     pub fn update_source(&mut self, file_name: &str, source: &Source) -> io::Result<()> {
         let mut source_file = {
             if let Some(source_file) = self.files.remove(file_name) {
@@ -112,17 +201,22 @@ impl Project {
             } else {
                 SourceFile {
                     library_names: vec![],
+                    standard: self.standard,
                     parser_messages: vec![],
                     design_file: None,
+                    suppressions: Suppressions::default(),
                 }
             }
         };
         source_file.design_file = None;
         source_file.parser_messages.clear();
 
-        let design_file = self
-            .parser
-            .parse_design_source(source, &mut source_file.parser_messages);
+        let (design_file, suppressions) = self.parser.parse_design_source_with_suppressions(
+            source,
+            source_file.standard,
+            &mut source_file.parser_messages,
+        );
+        source_file.suppressions = suppressions;
 
         let result = match design_file {
             Ok(design_file) => {
@@ -172,16 +266,115 @@ impl Project {
 
         let work_sym = self.parser.symbol(&Latin1String::new(b"work"));
         for (library_name, design_files) in library_to_design_file.drain() {
-            root.add_library(Library::new(
-                library_name,
-                &work_sym,
-                design_files,
-                &mut messages,
-            ));
+            let mut library = Library::new(library_name, &work_sym, design_files, &mut messages);
+            if let Some(standard) = self.library_standards.get(&library.name) {
+                library.set_standard(*standard);
+            }
+            root.add_library(library);
+        }
+
+        let analyzer =
+            Analyzer::new(&root, &self.parser.symtab.clone()).with_generic_values(&self.generics);
+        analyzer.analyze(&mut messages);
+
+        self.static_values.clear();
+        for (pos, value) in analyzer.take_static_values() {
+            self.static_values
+                .entry(pos.source.file_name().to_owned())
+                .or_default()
+                .push((pos, value));
         }
 
-        Analyzer::new(&root, &self.parser.symtab.clone()).analyze(&mut messages);
+        let messages = messages
+            .into_iter()
+            .filter(|message| !self.is_third_party(message.pos.source.file_name()))
+            .collect();
+        self.severities.apply(self.remove_suppressed(messages))
+    }
+
+    /// A file is third party when all of its libraries are third party libraries
+    fn is_third_party(&self, file_name: &str) -> bool {
+        match self.files.get(file_name) {
+            Some(source_file) => {
+                !source_file.library_names.is_empty()
+                    && source_file
+                        .library_names
+                        .iter()
+                        .all(|library_name| self.third_party_libraries.contains(library_name))
+            }
+            None => false,
+        }
+    }
+
+    /// Remove messages suppressed by vhdl_ls: disable comments
+    /// The suppressions are found when each file is parsed
+    fn remove_suppressed(&self, messages: Vec<Message>) -> Vec<Message> {
         messages
+            .into_iter()
+            .filter(
+                |message| match self.files.get(message.pos.source.file_name()) {
+                    Some(source_file) => !source_file.suppressions.is_suppressed(message),
+                    None => true,
+                },
+            )
+            .collect()
+    }
+
+    /// The last design unit whose identifier starts before the source position
+    pub fn enclosing_design_unit(&self, pos: &SrcPos) -> Option<&AnyDesignUnit> {
+        let design_file = self
+            .files
+            .get(pos.source.file_name())?
+            .design_file
+            .as_ref()?;
+
+        let mut enclosing = None;
+        for design_unit in design_file.design_units.iter() {
+            let ident_pos = design_unit.pos();
+            if ident_pos.source == pos.source && ident_pos.start <= pos.start {
+                enclosing = Some(design_unit);
+            }
+        }
+        enclosing
+    }
+
+    /// The code of the file which is disabled by conditional analysis
+    pub fn inactive_regions(&self, file_name: &str) -> &[SrcPos] {
+        self.files
+            .get(file_name)
+            .and_then(|source_file| source_file.design_file.as_ref())
+            .map(|design_file| design_file.inactive_regions.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// The static values of the constants declared in the file
+    pub fn static_values(&self, file_name: &str) -> &[(SrcPos, Value)] {
+        self.static_values
+            .get(file_name)
+            .map(|values| values.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// The code of the file between translate_off and translate_on pragmas
+    pub fn translate_off_regions(&self, file_name: &str) -> &[SrcPos] {
+        self.files
+            .get(file_name)
+            .and_then(|source_file| source_file.design_file.as_ref())
+            .map(|design_file| design_file.translate_off_regions.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// The code of all files which is disabled by conditional analysis or translate_off pragmas
+    pub fn disabled_regions(&self) -> impl Iterator<Item = &SrcPos> + '_ {
+        self.files
+            .values()
+            .filter_map(|source_file| source_file.design_file.as_ref())
+            .flat_map(|design_file| {
+                design_file
+                    .inactive_regions
+                    .iter()
+                    .chain(design_file.translate_off_regions.iter())
+            })
     }
 }
 
@@ -194,16 +387,273 @@ impl Default for Project {
 struct LibraryFileToParse {
     library_names: Vec<Symbol>,
     file_name: String,
+    standard: VHDLStandard,
 }
 
 impl FileToParse for LibraryFileToParse {
     fn file_name(&self) -> &str {
         &self.file_name
     }
+
+    fn standard(&self) -> VHDLStandard {
+        self.standard
+    }
 }
 
 struct SourceFile {
     library_names: Vec<Symbol>,
+    standard: VHDLStandard,
     design_file: Option<DesignFile>,
     parser_messages: Vec<Message>,
+    // Found when parsing, they also apply to the syntax errors of a file which fails to parse
+    suppressions: Suppressions,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn builtin_libraries_have_no_messages() {
+        let config = Config::from_str("[libraries]", Path::new("")).unwrap();
+        let mut errors = Vec::new();
+        let mut project = Project::from_config(&config, 1, &mut errors);
+        assert!(errors.is_empty());
+
+        // Show the messages of the builtin libraries
+        project.third_party_libraries.clear();
+        assert_eq!(project.analyse(), vec![]);
+    }
+
+    #[test]
+    fn new_project_has_builtin_libraries() {
+        let mut project = Project::new();
+        assert!(project
+            .files
+            .contains_key("{builtin}/ieee/numeric_std.vhdl"));
+        assert!(project.files.contains_key("{builtin}/std/standard.vhd"));
+
+        project.third_party_libraries.clear();
+        assert_eq!(project.analyse(), vec![]);
+    }
+
+    #[test]
+    fn builtin_libraries_are_visible() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let parent = tempdir.path();
+        std::fs::write(
+            parent.join("ent.vhd"),
+            "\
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.unsigned;
+use ieee.math_real.all;
+use ieee.fixed_pkg.all;
+use ieee.float_pkg.all;
+use std.textio.all;
+
+entity ent is
+  port (
+    clk : in std_logic;
+    fixed : in ufixed(3 downto -4);
+    float : in float32;
+    data : in std_logic_vector(7 downto 0);
+    count : out unsigned(7 downto 0));
+end entity;
+",
+        )
+        .unwrap();
+
+        let config = Config::from_str("[libraries]\nlib.files = ['ent.vhd']", parent).unwrap();
+        let mut errors = Vec::new();
+        let mut project = Project::from_config(&config, 1, &mut errors);
+        assert!(errors.is_empty());
+        assert_eq!(project.analyse(), vec![]);
+    }
+
+    #[test]
+    fn library_standard_selects_keywords() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let parent = tempdir.path();
+        let code = "\
+entity ent is
+  port (context : in bit);
+end entity;
+";
+        std::fs::write(parent.join("legacy.vhd"), code).unwrap();
+        std::fs::write(parent.join("ent.vhd"), code).unwrap();
+
+        let config = Config::from_str(
+            "
+[libraries]
+legacy.files = ['legacy.vhd']
+legacy.standard = '93'
+lib.files = ['ent.vhd']
+",
+            parent,
+        )
+        .unwrap();
+        let mut errors = Vec::new();
+        let mut project = Project::from_config(&config, 1, &mut errors);
+        assert!(errors.is_empty());
+
+        // The default standard is VHDL-2008 where context is a keyword
+        let messages = project.analyse();
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].pos.source.file_name().ends_with("ent.vhd"));
+    }
+
+    #[test]
+    fn conditional_analysis_identifiers_from_config() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let parent = tempdir.path();
+        let file_name = parent.join("ent.vhd");
+        std::fs::write(
+            &file_name,
+            "\
+`if TOOL_NAME = \"vhdl_ls\" then
+entity ent is
+end entity;
+`else
+syntax error
+`end if
+",
+        )
+        .unwrap();
+
+        let config = Config::from_str(
+            "
+standard = '2019'
+
+[libraries]
+lib.files = ['ent.vhd']
+
+[conditional_analysis]
+tool_name = 'vhdl_ls'
+",
+            parent,
+        )
+        .unwrap();
+        let mut errors = Vec::new();
+        let mut project = Project::from_config(&config, 1, &mut errors);
+        assert!(errors.is_empty());
+        assert_eq!(project.analyse(), vec![]);
+
+        let inactive_regions = project.inactive_regions(file_name.to_str().unwrap());
+        assert_eq!(inactive_regions.len(), 1);
+        let region = &inactive_regions[0];
+        let contents = region.source.contents().unwrap();
+        assert_eq!(
+            &contents.bytes[region.start..region.start + region.length],
+            b"\nsyntax error\n"
+        );
+        assert_eq!(project.disabled_regions().collect::<Vec<_>>(), vec![region]);
+    }
+
+    #[test]
+    fn translate_off_regions_are_skipped_by_config() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let parent = tempdir.path();
+        let file_name = parent.join("ent.vhd");
+        std::fs::write(
+            &file_name,
+            "\
+entity ent is
+end entity;
+-- synthesis translate_off
+syntax error
+-- synthesis translate_on
+",
+        )
+        .unwrap();
+
+        let config = Config::from_str(
+            "
+translate_off = 'skip'
+
+[libraries]
+lib.files = ['ent.vhd']
+",
+            parent,
+        )
+        .unwrap();
+        let mut errors = Vec::new();
+        let mut project = Project::from_config(&config, 1, &mut errors);
+        assert!(errors.is_empty());
+        assert_eq!(project.analyse(), vec![]);
+        assert_eq!(
+            project
+                .translate_off_regions(file_name.to_str().unwrap())
+                .len(),
+            1
+        );
+        assert_eq!(project.disabled_regions().count(), 1);
+    }
+
+    #[test]
+    fn builtin_libraries_can_be_overridden() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let parent = tempdir.path();
+        std::fs::write(parent.join("ieee.vhd"), "package my_pkg is\nend package;\n").unwrap();
+        std::fs::write(
+            parent.join("ent.vhd"),
+            "\
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity ent is
+end entity;
+",
+        )
+        .unwrap();
+
+        let config = Config::from_str(
+            "[libraries]\nieee.files = ['ieee.vhd']\nlib.files = ['ent.vhd']",
+            parent,
+        )
+        .unwrap();
+        let mut errors = Vec::new();
+        let mut project = Project::from_config(&config, 1, &mut errors);
+        let messages = project.analyse();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(
+            messages[0].message,
+            "No primary unit 'std_logic_1164' within 'ieee'"
+        );
+    }
+
+    #[test]
+    fn third_party_messages_are_not_shown() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let parent = tempdir.path();
+        let code = "\
+entity ent is
+end entity ent2;
+";
+        std::fs::write(parent.join("vendor.vhd"), code).unwrap();
+        std::fs::write(parent.join("mine.vhd"), code).unwrap();
+
+        let config = Config::from_str(
+            "
+[libraries]
+vendor.files = ['vendor.vhd']
+vendor.is_third_party = true
+lib.files = ['mine.vhd']
+",
+            parent,
+        )
+        .unwrap();
+
+        let mut errors = Vec::new();
+        let mut project = Project::from_config(&config, 1, &mut errors);
+        assert!(errors.is_empty());
+
+        let messages = project.analyse();
+        let file_names: Vec<&Path> = messages
+            .iter()
+            .map(|message| Path::new(message.pos.source.file_name()))
+            .collect();
+        assert_eq!(file_names, vec![parent.join("mine.vhd")]);
+    }
 }
@@ -0,0 +1,1075 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
+
+//! PSL embedded in VHDL-2008
+
+use crate::ast::{
+    Declaration, LabeledConcurrentStatement, PslBinaryOperator, PslCount, PslDeclaration,
+    PslDirective, PslDirectiveKind, PslParameter, PslParameterKind, PslProperty,
+    PslPropertyDeclaration, PslQuantifier, PslRepetition, PslRepetitionKind,
+    PslSequenceDeclaration, PslSere, PslSereOperator, PslUnaryOperator, VerificationUnit,
+    VerificationUnitBinding, VerificationUnitKind,
+};
+use crate::common::parse_optional;
+use crate::concurrent_statement::parse_labeled_concurrent_statement_initial_token;
+use crate::declarative_part::{is_declaration_start, parse_declaration};
+use crate::expression::parse_expression;
+use crate::message::{Message, MessageHandler, ParseResult};
+use crate::names::{parse_identifier_list, parse_name, parse_selected_name};
+use crate::source::WithPos;
+use crate::tokenizer::{Kind::*, Token, Value};
+use crate::tokenstream::TokenStream;
+
+/// PSL keywords which are not reserved words of VHDL
+fn is_psl_keyword(token: &Token, keyword: &str) -> bool {
+    match token.value {
+        Value::Identifier(ref sym) if token.kind == Identifier => {
+            sym.name_utf8().eq_ignore_ascii_case(keyword)
+        }
+        _ => false,
+    }
+}
+
+/// PSL keywords of properties which are not reserved words of VHDL
+const PROPERTY_KEYWORDS: &[&str] = &[
+    "always",
+    "never",
+    "eventually",
+    "next_a",
+    "next_e",
+    "next_event",
+    "next_event_a",
+    "next_event_e",
+    "until_",
+    "before",
+    "before_",
+    "within",
+    "abort",
+    "async_abort",
+    "sync_abort",
+];
+
+/// True if the assertion after the assert keyword contains PSL operators or keywords
+/// The stream is not moved
+pub fn is_psl_assertion(stream: &mut TokenStream) -> ParseResult<bool> {
+    let state = stream.state();
+    let result = contains_psl_operator(stream);
+    stream.set_state(state);
+    result
+}
+
+fn contains_psl_operator(stream: &mut TokenStream) -> ParseResult<bool> {
+    while let Some(token) = stream.pop()? {
+        match token.kind {
+            SemiColon => break,
+            Next | Until | LeftBrace | RightBrace | LeftSquare | Bang | BangUnderscore
+            | ConcatConcat | MinusGT | LtMinusGT | CommAt => return Ok(true),
+            Bar if stream.peek_kind()? == Some(RightArrow) => return Ok(true),
+            _ if PROPERTY_KEYWORDS
+                .iter()
+                .any(|keyword| is_psl_keyword(&token, keyword)) =>
+            {
+                return Ok(true)
+            }
+            _ => {}
+        }
+    }
+    Ok(false)
+}
+
+fn expect_psl_keyword(stream: &mut TokenStream, keyword: &str) -> ParseResult<Token> {
+    let token = stream.expect()?;
+    if is_psl_keyword(&token, keyword) {
+        Ok(token)
+    } else {
+        Err(Message::syntax_error(
+            &token,
+            format!("Expected '{}'", keyword),
+        ))
+    }
+}
+
+/// PSL 1.1 6.1.1.1 The count of a repetition such as 2 or 1 to inf
+fn parse_count(stream: &mut TokenStream) -> ParseResult<PslCount> {
+    let low = parse_expression(stream)?;
+    if stream.skip_if_kind(To)? {
+        let token = stream.peek_expect()?;
+        let high = if is_psl_keyword(&token, "inf") {
+            stream.move_after(&token);
+            None
+        } else {
+            Some(parse_expression(stream)?)
+        };
+        Ok(PslCount::Range(low, high))
+    } else {
+        Ok(PslCount::Number(low))
+    }
+}
+
+/// PSL 1.1 6.1.1.1 SERE repetition operators, the [ is already consumed
+/// Returns the repetition and the closing ] token
+fn parse_repetition(stream: &mut TokenStream) -> ParseResult<(PslRepetition, Token)> {
+    let token = stream.expect()?;
+    let kind = try_token_kind!(
+        token,
+        Times => PslRepetitionKind::Consecutive,
+        Plus => PslRepetitionKind::OneOrMore,
+        EQ => PslRepetitionKind::NonConsecutive,
+        MinusGT => PslRepetitionKind::Goto
+    );
+
+    let count = {
+        if kind == PslRepetitionKind::OneOrMore || stream.peek_kind()? == Some(RightSquare) {
+            None
+        } else {
+            Some(parse_count(stream)?)
+        }
+    };
+    let end_token = stream.expect_kind(RightSquare)?;
+    Ok((PslRepetition { kind, count }, end_token))
+}
+
+/// A repetition such as [*2] follows, the [ is not a signature
+pub fn is_repetition(stream: &mut TokenStream) -> ParseResult<bool> {
+    for kind in [Times, Plus, EQ, MinusGT].iter() {
+        if stream.next_kinds_are(&[LeftSquare, *kind])? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// PSL 1.1 6.1.1 A boolean, braced SERE or repetition followed by any number of repetitions
+fn parse_sere_item(stream: &mut TokenStream) -> ParseResult<WithPos<PslSere>> {
+    let token = stream.peek_expect()?;
+    let mut sere = match token.kind {
+        LeftBrace => {
+            stream.move_after(&token);
+            let sere = parse_sere(stream)?;
+            let end_token = stream.expect_kind(RightBrace)?;
+            WithPos::from(sere.item, token.pos.combine_into(&end_token))
+        }
+        LeftSquare => {
+            stream.move_after(&token);
+            let (repetition, end_token) = parse_repetition(stream)?;
+            WithPos::from(
+                PslSere::Repetition(None, repetition),
+                token.pos.combine_into(&end_token),
+            )
+        }
+        _ => {
+            let expr = parse_expression(stream)?;
+            let pos = expr.pos.clone();
+            WithPos::from(PslSere::Boolean(expr), pos)
+        }
+    };
+
+    while is_repetition(stream)? {
+        stream.expect_kind(LeftSquare)?;
+        let (repetition, end_token) = parse_repetition(stream)?;
+        let pos = sere.pos.combine(&end_token);
+        sere = WithPos::from(PslSere::Repetition(Some(Box::new(sere)), repetition), pos);
+    }
+
+    Ok(sere)
+}
+
+fn binary_sere(
+    op: PslSereOperator,
+    lhs: WithPos<PslSere>,
+    rhs: WithPos<PslSere>,
+) -> WithPos<PslSere> {
+    let pos = lhs.pos.combine(&rhs.pos);
+    WithPos::from(PslSere::Binary(op, Box::new(lhs), Box::new(rhs)), pos)
+}
+
+/// PSL 1.1 6.1.1 Concatenation and fusion bind stronger than sequence composition
+fn parse_sere_concatenation(stream: &mut TokenStream) -> ParseResult<WithPos<PslSere>> {
+    let mut lhs = parse_sere_item(stream)?;
+    loop {
+        let op = match stream.peek_kind()? {
+            Some(SemiColon) => PslSereOperator::Concatenation,
+            Some(Colon) => PslSereOperator::Fusion,
+            _ => break,
+        };
+        stream.expect()?;
+        let rhs = parse_sere_item(stream)?;
+        lhs = binary_sere(op, lhs, rhs);
+    }
+    Ok(lhs)
+}
+
+/// PSL 1.1 6.1 The SERE within braces
+fn parse_sere(stream: &mut TokenStream) -> ParseResult<WithPos<PslSere>> {
+    let mut lhs = parse_sere_concatenation(stream)?;
+    loop {
+        let token = stream.peek_expect()?;
+        let op = match token.kind {
+            Bar => PslSereOperator::Or,
+            ConcatConcat => PslSereOperator::LengthMatchingAnd,
+            Concat => PslSereOperator::NonLengthMatchingAnd,
+            _ if is_psl_keyword(&token, "within") => PslSereOperator::Within,
+            _ => break,
+        };
+        stream.move_after(&token);
+        let rhs = parse_sere_concatenation(stream)?;
+        lhs = binary_sere(op, lhs, rhs);
+    }
+    Ok(lhs)
+}
+
+fn binary_property(
+    op: PslBinaryOperator,
+    lhs: WithPos<PslProperty>,
+    rhs: WithPos<PslProperty>,
+) -> WithPos<PslProperty> {
+    let pos = lhs.pos.combine(&rhs.pos);
+    WithPos::from(PslProperty::Binary(op, Box::new(lhs), Box::new(rhs)), pos)
+}
+
+fn boolean_property(stream: &mut TokenStream) -> ParseResult<WithPos<PslProperty>> {
+    let expr = parse_expression(stream)?;
+    let pos = expr.pos.clone();
+    Ok(WithPos::from(
+        PslProperty::Sequence {
+            sere: WithPos::from(PslSere::Boolean(expr), pos.clone()),
+            strong: false,
+        },
+        pos,
+    ))
+}
+
+/// PSL 1.1 6.2.1 Operators with a single operand which extends as far right as possible
+fn parse_unary_property(
+    stream: &mut TokenStream,
+    token: Token,
+    op: PslUnaryOperator,
+) -> ParseResult<WithPos<PslProperty>> {
+    let property = parse_psl_property(stream)?;
+    let pos = token.pos.combine_into(&property);
+    Ok(WithPos::from(
+        PslProperty::Unary(op, Box::new(property)),
+        pos,
+    ))
+}
+
+/// PSL 1.1 6.2.1.3 Operators whose operand is a property within parenthesis
+fn parse_parenthesized_unary_property(
+    stream: &mut TokenStream,
+    token: Token,
+    op: PslUnaryOperator,
+) -> ParseResult<WithPos<PslProperty>> {
+    let next_token = stream.peek_expect()?;
+    if next_token.kind != LeftPar {
+        return Err(Message::syntax_error(&next_token, "Expected '('"));
+    }
+    let property = parse_primary_property(stream)?;
+    let pos = token.pos.combine_into(&property);
+    Ok(WithPos::from(
+        PslProperty::Unary(op, Box::new(property)),
+        pos,
+    ))
+}
+
+/// PSL 1.1 6.2.1.3 next_a[k to l] and next_e[k to l], the keyword is already consumed
+fn parse_next_range(
+    stream: &mut TokenStream,
+    token: Token,
+    quantifier: PslQuantifier,
+) -> ParseResult<WithPos<PslProperty>> {
+    let strong = stream.skip_if_kind(Bang)?;
+    stream.expect_kind(LeftSquare)?;
+    let count = parse_count(stream)?;
+    stream.expect_kind(RightSquare)?;
+    parse_parenthesized_unary_property(
+        stream,
+        token,
+        PslUnaryOperator::NextRange {
+            quantifier,
+            strong,
+            count,
+        },
+    )
+}
+
+/// PSL 1.1 6.2.1.3 next_event(b)[n] and next_event_a(b)[k to l], the keyword is already consumed
+/// The count is required when there is a quantifier
+fn parse_next_event(
+    stream: &mut TokenStream,
+    token: Token,
+    quantifier: Option<PslQuantifier>,
+) -> ParseResult<WithPos<PslProperty>> {
+    let strong = stream.skip_if_kind(Bang)?;
+    stream.expect_kind(LeftPar)?;
+    let condition = parse_expression(stream)?;
+    stream.expect_kind(RightPar)?;
+    let count = {
+        if quantifier.is_some() || stream.peek_kind()? == Some(LeftSquare) {
+            stream.expect_kind(LeftSquare)?;
+            let count = parse_count(stream)?;
+            stream.expect_kind(RightSquare)?;
+            Some(count)
+        } else {
+            None
+        }
+    };
+    parse_parenthesized_unary_property(
+        stream,
+        token,
+        PslUnaryOperator::NextEvent {
+            quantifier,
+            strong,
+            condition,
+            count,
+        },
+    )
+}
+
+fn parse_primary_property(stream: &mut TokenStream) -> ParseResult<WithPos<PslProperty>> {
+    let token = stream.peek_expect()?;
+
+    if is_psl_keyword(&token, "next_a") {
+        stream.move_after(&token);
+        return parse_next_range(stream, token, PslQuantifier::All);
+    } else if is_psl_keyword(&token, "next_e") {
+        stream.move_after(&token);
+        return parse_next_range(stream, token, PslQuantifier::Exists);
+    } else if is_psl_keyword(&token, "next_event") {
+        stream.move_after(&token);
+        return parse_next_event(stream, token, None);
+    } else if is_psl_keyword(&token, "next_event_a") {
+        stream.move_after(&token);
+        return parse_next_event(stream, token, Some(PslQuantifier::All));
+    } else if is_psl_keyword(&token, "next_event_e") {
+        stream.move_after(&token);
+        return parse_next_event(stream, token, Some(PslQuantifier::Exists));
+    }
+
+    if is_psl_keyword(&token, "always") {
+        stream.move_after(&token);
+        return parse_unary_property(stream, token, PslUnaryOperator::Always);
+    } else if is_psl_keyword(&token, "never") {
+        stream.move_after(&token);
+        return parse_unary_property(stream, token, PslUnaryOperator::Never);
+    } else if is_psl_keyword(&token, "eventually") {
+        stream.move_after(&token);
+        stream.expect_kind(Bang)?;
+        return parse_unary_property(stream, token, PslUnaryOperator::Eventually);
+    }
+
+    match token.kind {
+        Next => {
+            stream.move_after(&token);
+            let strong = stream.skip_if_kind(Bang)?;
+            let count = {
+                if stream.skip_if_kind(LeftSquare)? {
+                    let count = parse_expression(stream)?;
+                    stream.expect_kind(RightSquare)?;
+                    Some(count)
+                } else {
+                    None
+                }
+            };
+            parse_unary_property(stream, token, PslUnaryOperator::Next { strong, count })
+        }
+        LeftPar => {
+            // A parenthesized boolean is parsed as a VHDL expression
+            let state = stream.state();
+            if let Ok(sere) = parse_sere_item(stream) {
+                let pos = sere.pos.clone();
+                let strong = stream.skip_if_kind(Bang)?;
+                return Ok(WithPos::from(PslProperty::Sequence { sere, strong }, pos));
+            }
+            stream.set_state(state);
+            stream.move_after(&token);
+            let property = parse_psl_property(stream)?;
+            let end_token = stream.expect_kind(RightPar)?;
+            Ok(WithPos::from(
+                property.item,
+                token.pos.combine_into(&end_token),
+            ))
+        }
+        _ => {
+            let sere = parse_sere_item(stream)?;
+            let mut pos = sere.pos.clone();
+            let strong = {
+                if let Some(token) = stream.pop_if_kind(Bang)? {
+                    pos = pos.combine(&token);
+                    true
+                } else {
+                    false
+                }
+            };
+            Ok(WithPos::from(PslProperty::Sequence { sere, strong }, pos))
+        }
+    }
+}
+
+/// PSL 1.1 5.3 The clock operator binds stronger than other property operators
+fn parse_clocked_property(stream: &mut TokenStream) -> ParseResult<WithPos<PslProperty>> {
+    let mut property = parse_primary_property(stream)?;
+    while stream.skip_if_kind(CommAt)? {
+        let clock = parse_expression(stream)?;
+        let pos = property.pos.combine(&clock);
+        property = WithPos::from(PslProperty::Clocked(Box::new(property), clock), pos);
+    }
+    Ok(property)
+}
+
+/// PSL 1.1 6.2.1.5.1 abort, async_abort and sync_abort
+fn parse_abort_property(stream: &mut TokenStream) -> ParseResult<WithPos<PslProperty>> {
+    let mut lhs = parse_clocked_property(stream)?;
+    loop {
+        let token = stream.peek_expect()?;
+        if ["abort", "async_abort", "sync_abort"]
+            .iter()
+            .any(|keyword| is_psl_keyword(&token, keyword))
+        {
+            stream.move_after(&token);
+            let rhs = boolean_property(stream)?;
+            lhs = binary_property(PslBinaryOperator::Abort, lhs, rhs);
+        } else {
+            break;
+        }
+    }
+    Ok(lhs)
+}
+
+/// PSL 1.1 6.2.1.4 until and before operators
+fn parse_bounding_property(stream: &mut TokenStream) -> ParseResult<WithPos<PslProperty>> {
+    let mut lhs = parse_abort_property(stream)?;
+    loop {
+        let token = stream.peek_expect()?;
+        let op = {
+            if token.kind == Until {
+                stream.move_after(&token);
+                if stream.skip_if_kind(BangUnderscore)? {
+                    PslBinaryOperator::Until {
+                        strong: true,
+                        inclusive: true,
+                    }
+                } else {
+                    PslBinaryOperator::Until {
+                        strong: stream.skip_if_kind(Bang)?,
+                        inclusive: false,
+                    }
+                }
+            } else if is_psl_keyword(&token, "until_") {
+                stream.move_after(&token);
+                PslBinaryOperator::Until {
+                    strong: false,
+                    inclusive: true,
+                }
+            } else if is_psl_keyword(&token, "before") {
+                stream.move_after(&token);
+                if stream.skip_if_kind(BangUnderscore)? {
+                    PslBinaryOperator::Before {
+                        strong: true,
+                        inclusive: true,
+                    }
+                } else {
+                    PslBinaryOperator::Before {
+                        strong: stream.skip_if_kind(Bang)?,
+                        inclusive: false,
+                    }
+                }
+            } else if is_psl_keyword(&token, "before_") {
+                stream.move_after(&token);
+                PslBinaryOperator::Before {
+                    strong: false,
+                    inclusive: true,
+                }
+            } else {
+                break;
+            }
+        };
+        let rhs = parse_abort_property(stream)?;
+        lhs = binary_property(op, lhs, rhs);
+    }
+    Ok(lhs)
+}
+
+/// PSL 1.1 6.2.1.6 Suffix implication |-> and |=>
+fn parse_suffix_implication(stream: &mut TokenStream) -> ParseResult<WithPos<PslProperty>> {
+    let lhs = parse_bounding_property(stream)?;
+
+    let overlapping = {
+        if stream.next_kinds_are(&[Bar, MinusGT])? {
+            true
+        } else if stream.next_kinds_are(&[Bar, RightArrow])? {
+            false
+        } else {
+            return Ok(lhs);
+        }
+    };
+    stream.expect_kind(Bar)?;
+    stream.expect()?;
+
+    let sequence = match lhs.item {
+        PslProperty::Sequence {
+            sere,
+            strong: false,
+        } => sere,
+        _ => {
+            return Err(Message::syntax_error(
+                &lhs.pos,
+                "Expected sequence before suffix implication",
+            ));
+        }
+    };
+
+    let property = parse_suffix_implication(stream)?;
+    let pos = sequence.pos.combine(&property.pos);
+    Ok(WithPos::from(
+        PslProperty::SuffixImplication {
+            sequence,
+            overlapping,
+            property: Box::new(property),
+        },
+        pos,
+    ))
+}
+
+/// PSL 1.1 6.2 Properties
+pub fn parse_psl_property(stream: &mut TokenStream) -> ParseResult<WithPos<PslProperty>> {
+    let lhs = parse_suffix_implication(stream)?;
+    let op = match stream.peek_kind()? {
+        Some(MinusGT) => PslBinaryOperator::Implication,
+        Some(LtMinusGT) => PslBinaryOperator::Equivalence,
+        _ => return Ok(lhs),
+    };
+    stream.expect()?;
+    let rhs = parse_psl_property(stream)?;
+    Ok(binary_property(op, lhs, rhs))
+}
+
+/// PSL 1.1 7.1 Verification directives, the directive keyword is already consumed
+pub fn parse_psl_directive_known_keyword(
+    stream: &mut TokenStream,
+    kind: PslDirectiveKind,
+) -> ParseResult<PslDirective> {
+    let property = parse_psl_property(stream)?;
+    let report = parse_optional(stream, Report, parse_expression)?;
+    let severity = parse_optional(stream, Severity, parse_expression)?;
+    stream.expect_kind(SemiColon)?;
+
+    Ok(PslDirective {
+        kind,
+        property,
+        report,
+        severity,
+    })
+}
+
+/// PSL 1.1 6.2.3.1 Formal parameter list of properties and sequences
+fn parse_optional_parameters(stream: &mut TokenStream) -> ParseResult<Vec<PslParameter>> {
+    let mut parameters = Vec::new();
+    if !stream.skip_if_kind(LeftPar)? {
+        return Ok(parameters);
+    }
+
+    loop {
+        let token = stream.expect()?;
+        let kind = match token.kind {
+            Property => PslParameterKind::Property,
+            Sequence => PslParameterKind::Sequence,
+            Constant => PslParameterKind::Const,
+            _ if is_psl_keyword(&token, "const") => PslParameterKind::Const,
+            _ if is_psl_keyword(&token, "boolean") => PslParameterKind::Boolean,
+            _ => {
+                return Err(Message::syntax_error(
+                    &token,
+                    "Expected 'const', 'boolean', 'property' or 'sequence'",
+                ));
+            }
+        };
+        let idents = parse_identifier_list(stream)?;
+        parameters.push(PslParameter { kind, idents });
+
+        let token = stream.expect()?;
+        try_token_kind!(
+            token,
+            SemiColon => continue,
+            RightPar => break
+        );
+    }
+
+    Ok(parameters)
+}
+
+/// PSL 1.1 6.2.3.1 Property and 6.1.3.1 sequence declarations and 5.3 default clock declaration
+pub fn parse_psl_declaration(stream: &mut TokenStream) -> ParseResult<PslDeclaration> {
+    let token = stream.expect()?;
+    let declaration = try_token_kind!(
+        token,
+        Default => {
+            expect_psl_keyword(stream, "clock")?;
+            stream.expect_kind(Is)?;
+            PslDeclaration::DefaultClock(parse_expression(stream)?)
+        },
+        Property => {
+            let ident = stream.expect_ident()?;
+            let parameters = parse_optional_parameters(stream)?;
+            stream.expect_kind(Is)?;
+            PslDeclaration::Property(PslPropertyDeclaration {
+                ident,
+                parameters,
+                property: parse_psl_property(stream)?,
+            })
+        },
+        Sequence => {
+            let ident = stream.expect_ident()?;
+            let parameters = parse_optional_parameters(stream)?;
+            stream.expect_kind(Is)?;
+            PslDeclaration::Sequence(PslSequenceDeclaration {
+                ident,
+                parameters,
+                sequence: parse_sere_item(stream)?,
+            })
+        }
+    );
+    stream.expect_kind(SemiColon)?;
+    Ok(declaration)
+}
+
+/// PSL 1.1 7.2 Verification units
+pub fn parse_verification_unit(
+    stream: &mut TokenStream,
+    messages: &mut dyn MessageHandler,
+) -> ParseResult<VerificationUnit> {
+    let token = stream.expect()?;
+    let kind = try_token_kind!(
+        token,
+        Vunit => VerificationUnitKind::VUnit,
+        Vmode => VerificationUnitKind::VMode,
+        Vprop => VerificationUnitKind::VProp
+    );
+    let ident = stream.expect_ident()?;
+
+    let binding = {
+        if stream.skip_if_kind(LeftPar)? {
+            let entity_name = parse_selected_name(stream)?;
+            let architecture_name = {
+                if stream.skip_if_kind(LeftPar)? {
+                    let ident = stream.expect_ident()?;
+                    stream.expect_kind(RightPar)?;
+                    Some(ident)
+                } else {
+                    None
+                }
+            };
+            stream.expect_kind(RightPar)?;
+            Some(VerificationUnitBinding {
+                entity_name,
+                architecture_name,
+            })
+        } else {
+            None
+        }
+    };
+
+    stream.expect_kind(LeftBrace)?;
+    let mut inherits = Vec::new();
+    let mut decl: Vec<Declaration> = Vec::new();
+    let mut statements: Vec<LabeledConcurrentStatement> = Vec::new();
+
+    loop {
+        let token = stream.peek_expect()?;
+        match token.kind {
+            RightBrace => {
+                stream.move_after(&token);
+                break;
+            }
+            // A for generate statement rather than a configuration specification
+            For => {
+                stream.move_after(&token);
+                statements.push(parse_labeled_concurrent_statement_initial_token(
+                    stream, token, messages,
+                )?);
+            }
+            ref kind if is_declaration_start(kind) => {
+                parse_declaration(stream, messages, &mut decl)?;
+            }
+            _ if is_psl_keyword(&token, "inherit") => {
+                stream.move_after(&token);
+                loop {
+                    inherits.push(parse_name(stream)?);
+                    if !stream.skip_if_kind(Comma)? {
+                        break;
+                    }
+                }
+                stream.expect_kind(SemiColon)?;
+            }
+            _ => {
+                stream.move_after(&token);
+                statements.push(parse_labeled_concurrent_statement_initial_token(
+                    stream, token, messages,
+                )?);
+            }
+        }
+    }
+
+    Ok(VerificationUnit {
+        kind,
+        ident,
+        binding,
+        inherits,
+        decl,
+        statements,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{ConcurrentStatement, DesignUnit, PrimaryUnit};
+    use crate::concurrent_statement::parse_labeled_concurrent_statement;
+    use crate::test_util::Code;
+
+    fn boolean(code: &Code) -> WithPos<PslSere> {
+        WithPos::from(PslSere::Boolean(code.expr()), code.pos())
+    }
+
+    fn sequence(code: &Code) -> WithPos<PslProperty> {
+        WithPos::from(
+            PslProperty::Sequence {
+                sere: boolean(code),
+                strong: false,
+            },
+            code.pos(),
+        )
+    }
+
+    #[test]
+    fn parse_assert_directive() {
+        let code = Code::new("assert always (req -> next ack) @ rising_edge(clk) report \"fail\";");
+        let stmt = code.with_stream_no_messages(parse_labeled_concurrent_statement);
+
+        let implication = WithPos::from(
+            PslProperty::Binary(
+                PslBinaryOperator::Implication,
+                Box::new(sequence(&code.s1("req"))),
+                Box::new(WithPos::from(
+                    PslProperty::Unary(
+                        PslUnaryOperator::Next {
+                            strong: false,
+                            count: None,
+                        },
+                        Box::new(sequence(&code.s1("ack"))),
+                    ),
+                    code.s1("next ack").pos(),
+                )),
+            ),
+            code.s1("(req -> next ack)").pos(),
+        );
+        let clocked = WithPos::from(
+            PslProperty::Clocked(Box::new(implication), code.s1("rising_edge(clk)").expr()),
+            code.s1("(req -> next ack) @ rising_edge(clk)").pos(),
+        );
+
+        assert_eq!(
+            stmt.statement,
+            ConcurrentStatement::PslDirective(PslDirective {
+                kind: PslDirectiveKind::Assert,
+                property: WithPos::from(
+                    PslProperty::Unary(PslUnaryOperator::Always, Box::new(clocked)),
+                    code.s1("always (req -> next ack) @ rising_edge(clk)").pos(),
+                ),
+                report: Some(code.s1("\"fail\"").expr()),
+                severity: None,
+            })
+        );
+    }
+
+    #[test]
+    fn vhdl_assert_is_not_psl() {
+        let code = Code::new("assert a = b severity error;");
+        let stmt = code.with_stream_no_messages(parse_labeled_concurrent_statement);
+        match stmt.statement {
+            ConcurrentStatement::Assert(..) => {}
+            other => panic!("Expected VHDL assertion, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn only_assertions_with_psl_operators_are_psl() {
+        let is_psl = |code: &str| {
+            Code::new(code)
+                .with_partial_stream(is_psl_assertion)
+                .unwrap()
+        };
+        assert!(!is_psl("a b;"));
+        assert!(!is_psl("a = ; report \"x\";"));
+        assert!(is_psl("always a;"));
+        assert!(is_psl("a -> b;"));
+        assert!(is_psl("(a |=> b);"));
+        assert!(is_psl("{a; b};"));
+        assert!(is_psl("next_e[1 to 2] (a);"));
+    }
+
+    #[test]
+    fn parse_next_range_properties() {
+        let code = Code::new("property p is next_e[1 to 3] (a) -> next_a![2 to 4] (b);");
+        let next_e = WithPos::from(
+            PslProperty::Unary(
+                PslUnaryOperator::NextRange {
+                    quantifier: PslQuantifier::Exists,
+                    strong: false,
+                    count: PslCount::Range(code.s1("1").expr(), Some(code.s1("3").expr())),
+                },
+                Box::new(sequence(&code.s1("(a)"))),
+            ),
+            code.s1("next_e[1 to 3] (a)").pos(),
+        );
+        let next_a = WithPos::from(
+            PslProperty::Unary(
+                PslUnaryOperator::NextRange {
+                    quantifier: PslQuantifier::All,
+                    strong: true,
+                    count: PslCount::Range(code.s1("2").expr(), Some(code.s1("4").expr())),
+                },
+                Box::new(sequence(&code.s1("(b)"))),
+            ),
+            code.s1("next_a![2 to 4] (b)").pos(),
+        );
+
+        assert_eq!(
+            code.with_stream(parse_psl_declaration),
+            PslDeclaration::Property(PslPropertyDeclaration {
+                ident: code.s1("p is").s1("p").ident(),
+                parameters: vec![],
+                property: WithPos::from(
+                    PslProperty::Binary(
+                        PslBinaryOperator::Implication,
+                        Box::new(next_e),
+                        Box::new(next_a)
+                    ),
+                    code.s1("next_e[1 to 3] (a) -> next_a![2 to 4] (b)").pos(),
+                ),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_next_event_properties() {
+        let code = Code::new("property p is next_event!(req)[2] (ack);");
+        assert_eq!(
+            code.with_stream(parse_psl_declaration),
+            PslDeclaration::Property(PslPropertyDeclaration {
+                ident: code.s1("p is").s1("p").ident(),
+                parameters: vec![],
+                property: WithPos::from(
+                    PslProperty::Unary(
+                        PslUnaryOperator::NextEvent {
+                            quantifier: None,
+                            strong: true,
+                            condition: code.s1("req").expr(),
+                            count: Some(PslCount::Number(code.s1("2").expr())),
+                        },
+                        Box::new(sequence(&code.s1("(ack)"))),
+                    ),
+                    code.s1("next_event!(req)[2] (ack)").pos(),
+                ),
+            })
+        );
+
+        let code = Code::new("property p is next_event_a(req)[1 to 2] (ack);");
+        match code.with_stream(parse_psl_declaration) {
+            PslDeclaration::Property(PslPropertyDeclaration { property, .. }) => {
+                assert_eq!(
+                    property.item,
+                    PslProperty::Unary(
+                        PslUnaryOperator::NextEvent {
+                            quantifier: Some(PslQuantifier::All),
+                            strong: false,
+                            condition: code.s1("req").expr(),
+                            count: Some(PslCount::Range(
+                                code.s1("1").expr(),
+                                Some(code.s1("2").expr())
+                            )),
+                        },
+                        Box::new(sequence(&code.s1("(ack)"))),
+                    )
+                );
+            }
+            other => panic!("Expected property declaration, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_strong_inclusive_until_and_before() {
+        let code = Code::new("property p is (a until!_ b) before!_ c;");
+        let until = WithPos::from(
+            PslProperty::Binary(
+                PslBinaryOperator::Until {
+                    strong: true,
+                    inclusive: true,
+                },
+                Box::new(sequence(&code.s1("a"))),
+                Box::new(sequence(&code.s1("b"))),
+            ),
+            code.s1("(a until!_ b)").pos(),
+        );
+
+        assert_eq!(
+            code.with_stream(parse_psl_declaration),
+            PslDeclaration::Property(PslPropertyDeclaration {
+                ident: code.s1("p is").s1("p").ident(),
+                parameters: vec![],
+                property: WithPos::from(
+                    PslProperty::Binary(
+                        PslBinaryOperator::Before {
+                            strong: true,
+                            inclusive: true,
+                        },
+                        Box::new(until),
+                        Box::new(sequence(&code.s1("c"))),
+                    ),
+                    code.s1("(a until!_ b) before!_ c").pos(),
+                ),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_sequence_declaration_with_repetitions() {
+        let code = Code::new("sequence handshake (boolean r, a) is {r; busy[*1 to inf]; a[->]};");
+        let repetition = WithPos::from(
+            PslSere::Repetition(
+                Some(Box::new(boolean(&code.s1("busy")))),
+                PslRepetition {
+                    kind: PslRepetitionKind::Consecutive,
+                    count: Some(PslCount::Range(code.s1("1").expr(), None)),
+                },
+            ),
+            code.s1("busy[*1 to inf]").pos(),
+        );
+        let goto = WithPos::from(
+            PslSere::Repetition(
+                Some(Box::new(boolean(&code.s1("a[->]").s1("a")))),
+                PslRepetition {
+                    kind: PslRepetitionKind::Goto,
+                    count: None,
+                },
+            ),
+            code.s1("a[->]").pos(),
+        );
+        let first = WithPos::from(
+            PslSere::Binary(
+                PslSereOperator::Concatenation,
+                Box::new(boolean(&code.s1("r;").s1("r"))),
+                Box::new(repetition),
+            ),
+            code.s1("r; busy[*1 to inf]").pos(),
+        );
+        let sere = WithPos::from(
+            PslSere::Binary(
+                PslSereOperator::Concatenation,
+                Box::new(first),
+                Box::new(goto),
+            ),
+            code.s1("{r; busy[*1 to inf]; a[->]}").pos(),
+        );
+
+        assert_eq!(
+            code.with_stream(parse_psl_declaration),
+            PslDeclaration::Sequence(PslSequenceDeclaration {
+                ident: code.s1("handshake").ident(),
+                parameters: vec![PslParameter {
+                    kind: PslParameterKind::Boolean,
+                    idents: vec![
+                        code.s1("r,").s1("r").ident(),
+                        code.s1(" a)").s1("a").ident()
+                    ],
+                }],
+                sequence: sere,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_suffix_implication() {
+        let code = Code::new("property p is {a && b} |=> c until! d;");
+        let sere = WithPos::from(
+            PslSere::Binary(
+                PslSereOperator::LengthMatchingAnd,
+                Box::new(boolean(&code.s1("a"))),
+                Box::new(boolean(&code.s1("b"))),
+            ),
+            code.s1("{a && b}").pos(),
+        );
+        let until = WithPos::from(
+            PslProperty::Binary(
+                PslBinaryOperator::Until {
+                    strong: true,
+                    inclusive: false,
+                },
+                Box::new(sequence(&code.s1("c"))),
+                Box::new(sequence(&code.s1("d"))),
+            ),
+            code.s1("c until! d").pos(),
+        );
+
+        assert_eq!(
+            code.with_stream(parse_psl_declaration),
+            PslDeclaration::Property(PslPropertyDeclaration {
+                ident: code.s1("p is").s1("p").ident(),
+                parameters: vec![],
+                property: WithPos::from(
+                    PslProperty::SuffixImplication {
+                        sequence: sere,
+                        overlapping: false,
+                        property: Box::new(until),
+                    },
+                    code.s1("{a && b} |=> c until! d").pos(),
+                ),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_default_clock_in_psl_comment() {
+        let code = Code::new("-- psl default clock is rising_edge(clk);");
+        assert_eq!(
+            code.with_stream(parse_psl_declaration),
+            PslDeclaration::DefaultClock(code.s1("rising_edge(clk)").expr())
+        );
+    }
+
+    #[test]
+    fn parse_verification_unit_bound_to_architecture() {
+        let code = Code::new(
+            "\
+vunit checks (lib.ent(rtl)) {
+  inherit common;
+  signal tmp : bit;
+  cover {req; ack};
+}",
+        );
+        let vunit = code.with_stream_no_messages(parse_verification_unit);
+        assert_eq!(vunit.kind, VerificationUnitKind::VUnit);
+        assert_eq!(vunit.ident, code.s1("checks").ident());
+        assert_eq!(
+            vunit.binding,
+            Some(VerificationUnitBinding {
+                entity_name: code.s1("lib.ent").selected_name(),
+                architecture_name: Some(code.s1("rtl").ident()),
+            })
+        );
+        assert_eq!(vunit.inherits, vec![code.s1("common").name()]);
+        assert_eq!(vunit.decl, code.s1("signal tmp : bit;").declarative_part());
+        assert_eq!(vunit.statements.len(), 1);
+
+        let design_file = code.design_file();
+        match design_file.design_units[0] {
+            crate::ast::AnyDesignUnit::Primary(PrimaryUnit::VerificationUnit(DesignUnit {
+                ref unit,
+                ..
+            })) => assert_eq!(unit, &vunit),
+            ref other => panic!("Expected verification unit, got {:?}", other),
+        }
+    }
+}
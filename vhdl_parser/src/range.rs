@@ -63,7 +63,7 @@ fn parse_name_or_range(stream: &mut TokenStream) -> ParseResult<NameOrRange> {
         return Ok(NameOrRange::Name(WithPos::from(*name, pos)));
     }
 
-    Err(Message::error(&expr, "Expected name or range"))
+    Err(Message::syntax_error(&expr, "Expected name or range"))
 }
 
 /// {selected_name}'range
@@ -72,7 +72,7 @@ fn parse_name_or_range(stream: &mut TokenStream) -> ParseResult<NameOrRange> {
 pub fn parse_range(stream: &mut TokenStream) -> ParseResult<WithPos<Range>> {
     match parse_name_or_range(stream)? {
         NameOrRange::Range(range) => Ok(range),
-        NameOrRange::Name(name) => Err(Message::error(&name, "Expected range")),
+        NameOrRange::Name(name) => Err(Message::syntax_error(&name, "Expected range")),
     }
 }
 
@@ -6,19 +6,20 @@
 
 use crate::ast::{
     Alternative, AssertStatement, AssignmentRightHand, CaseStatement, Conditional, Conditionals,
-    ExitStatement, Expression, FunctionCall, IfStatement, IterationScheme,
+    ExitStatement, Expression, ForceMode, FunctionCall, Ident, IfStatement, IterationScheme,
     LabeledSequentialStatement, LoopStatement, Name, NextStatement, ReportStatement,
-    ReturnStatement, Selection, SequentialStatement, SignalAssignment, Target, VariableAssignment,
-    WaitStatement, Waveform,
+    ReturnStatement, Selection, SequentialStatement, SignalAssignment, SignalForceAssignment,
+    SignalReleaseAssignment, Target, VariableAssignment, WaitStatement, Waveform,
 };
-use crate::common::parse_optional;
+use crate::common::{check_standard, error_on_end_label_mismatch, parse_optional};
 use crate::expression::{parse_aggregate_leftpar_known, parse_choices, parse_expression};
-use crate::message::{Message, MessageHandler, ParseResult};
+use crate::message::{push_some, Message, MessageHandler, ParseResult};
 use crate::names::{parse_name, parse_name_initial_token, to_simple_name};
 use crate::range::parse_discrete_range;
 use crate::source::WithPos;
 use crate::tokenizer::{Kind::*, Token};
 use crate::tokenstream::TokenStream;
+use crate::vhdl_standard::VHDLStandard;
 use crate::waveform::{parse_delay_mechanism, parse_waveform};
 
 /// LRM 10.2 Wait statement
@@ -73,25 +74,198 @@ pub fn parse_labeled_sequential_statements(
     stream: &mut TokenStream,
     messages: &mut dyn MessageHandler,
 ) -> ParseResult<(Vec<LabeledSequentialStatement>, Token)> {
+    let statements = parse_sequential_statements_before_end(stream, messages)?;
+    let end_token = stream.expect()?;
+    Ok((statements, end_token))
+}
+
+/// Parse sequential statements until an end, else, elsif or when which is not consumed
+fn parse_sequential_statements_before_end(
+    stream: &mut TokenStream,
+    messages: &mut dyn MessageHandler,
+) -> ParseResult<Vec<LabeledSequentialStatement>> {
     let mut statements = Vec::new();
     loop {
-        let token = stream.expect()?;
+        let token = stream.peek_expect()?;
         match token.kind {
             End | Else | Elsif | When => {
-                break Ok((statements, token));
+                break Ok(statements);
             }
             _ => {
-                statements.push(parse_sequential_statement_initial_token(
-                    stream, token, messages,
-                )?);
+                let state = stream.state();
+                stream.move_after(&token);
+                // The messages of a statement which fails to parse are reported by the recovery
+                let mut statement_messages = Vec::new();
+                match parse_sequential_statement_initial_token(
+                    stream,
+                    token,
+                    &mut statement_messages,
+                ) {
+                    Ok(statement) => {
+                        for message in statement_messages {
+                            messages.push(message);
+                        }
+                        statements.push(statement);
+                    }
+                    Err(err) => {
+                        stream.set_state(state);
+                        match recover_sequential_statement(stream, messages) {
+                            Ok(statement) => {
+                                messages.push(err);
+                                statements.push(statement);
+                            }
+                            // A tokenizing error is found again by the recovery and only reported once
+                            Err(recover_err) => {
+                                if recover_err != err {
+                                    messages.push(err);
+                                }
+                                return Err(recover_err);
+                            }
+                        }
+                    }
+                }
             }
         }
     }
 }
 
+/// Recover a sequential statement which failed to parse, starting from its first token
+/// The valid statements nested within an if, case or loop statement are kept
+fn recover_sequential_statement(
+    stream: &mut TokenStream,
+    messages: &mut dyn MessageHandler,
+) -> ParseResult<LabeledSequentialStatement> {
+    let label = if stream.next_kinds_are(&[Identifier, Colon])? {
+        let label = stream.expect_ident()?;
+        stream.expect_kind(Colon)?;
+        Some(label)
+    } else {
+        None
+    };
+
+    let statements = match stream.peek_kind()? {
+        Some(If) | Some(Case) | Some(Loop) | Some(While) | Some(For) => {
+            recover_compound_statement(stream, messages)?
+        }
+        _ => {
+            recover_simple_statement(stream)?;
+            Vec::new()
+        }
+    };
+
+    Ok(LabeledSequentialStatement {
+        label,
+        statement: SequentialStatement::Error(statements),
+    })
+}
+
+/// Skip the conditions and choices of an if, case or loop statement and parse its bodies
+/// The statement ends after its end or before the end of an enclosing statement
+fn recover_compound_statement(
+    stream: &mut TokenStream,
+    messages: &mut dyn MessageHandler,
+) -> ParseResult<Vec<LabeledSequentialStatement>> {
+    let first = stream.expect()?;
+    let (body_start, end_kind) = match first.kind {
+        If => (Then, If),
+        Case => (RightArrow, Case),
+        _ => (Loop, Loop),
+    };
+
+    let mut statements = Vec::new();
+    let mut in_body = first.kind == Loop;
+    loop {
+        if in_body {
+            statements.append(&mut parse_sequential_statements_before_end(
+                stream, messages,
+            )?);
+            in_body = false;
+        }
+
+        let token = stream.peek_expect()?;
+        match token.kind {
+            End => {
+                let state = stream.state();
+                stream.move_after(&token);
+                if stream.peek_kind()? == Some(end_kind) {
+                    stream.pop()?;
+                    stream.pop_optional_ident()?;
+                    stream.skip_if_kind(SemiColon)?;
+                } else {
+                    // End of the enclosing statement or subprogram
+                    stream.set_state(state);
+                }
+                return Ok(statements);
+            }
+            SemiColon => {
+                stream.move_after(&token);
+                return Ok(statements);
+            }
+            Else => {
+                in_body = true;
+            }
+            kind if kind == body_start => {
+                in_body = true;
+            }
+            _ => {}
+        }
+        stream.move_after(&token);
+    }
+}
+
+/// Skip a statement until the next semicolon or before an end or the start of a new statement
+fn recover_simple_statement(stream: &mut TokenStream) -> ParseResult<()> {
+    let first = stream.peek_expect()?;
+    if let End | Else | Elsif | When = first.kind {
+        // Only after a label
+        return Ok(());
+    }
+    stream.move_after(&first);
+
+    let mut previous = first.clone();
+    loop {
+        let token = stream.peek_expect()?;
+        let new_statement = match token.kind {
+            SemiColon => {
+                stream.move_after(&token);
+                return Ok(());
+            }
+            End | Elsif | If | Case | Loop | While | Wait | Exit | Next | Return | Assert => true,
+            For => first.kind != Wait,
+            Report => first.kind != Assert,
+            Null => stream.next_kinds_are(&[Null, SemiColon])?,
+            Identifier => {
+                stream.next_kinds_are(&[Identifier, Colon])?
+                    || stream.next_kinds_are(&[Identifier, ColonEq])?
+                    // A signal assignment cannot be told from a relation unless on a new line
+                    || (stream.next_kinds_are(&[Identifier, LTE])?
+                        && is_on_new_line(stream, &previous, &token))
+            }
+            _ => false,
+        };
+
+        if new_statement {
+            return Ok(());
+        }
+        stream.move_after(&token);
+        previous = token;
+    }
+}
+
+/// True if there is a line break between the tokens
+fn is_on_new_line(stream: &TokenStream, previous: &Token, token: &Token) -> bool {
+    let start = previous.pos.start + previous.pos.length;
+    stream
+        .tokenizer
+        .code_bytes()
+        .get(start..token.pos.start)
+        .is_some_and(|between| between.contains(&b'\n'))
+}
+
 /// LRM 10.8 If statement
 fn parse_if_statement_known_keyword(
     stream: &mut TokenStream,
+    label: Option<&Ident>,
     messages: &mut dyn MessageHandler,
 ) -> ParseResult<IfStatement> {
     let mut conditionals = Vec::new();
@@ -122,8 +296,6 @@ fn parse_if_statement_known_keyword(
                     end_token,
                     End => {
                         stream.expect_kind(If)?;
-                        // @TODO check end label
-                        stream.pop_if_kind(Identifier)?;
                         else_branch = Some(statements);
                         break;
                     }
@@ -137,6 +309,8 @@ fn parse_if_statement_known_keyword(
         );
     }
 
+    let end_label = stream.pop_optional_ident()?;
+    push_some(messages, error_on_end_label_mismatch(label, &end_label));
     stream.expect_kind(SemiColon)?;
     Ok(IfStatement {
         conditionals,
@@ -147,6 +321,7 @@ fn parse_if_statement_known_keyword(
 /// LRM 10.9 Case statement
 fn parse_case_statement_known_keyword(
     stream: &mut TokenStream,
+    label: Option<&Ident>,
     messages: &mut dyn MessageHandler,
 ) -> ParseResult<CaseStatement> {
     let expression = parse_expression(stream)?;
@@ -170,14 +345,14 @@ fn parse_case_statement_known_keyword(
             },
             End => {
                 stream.expect_kind(Case)?;
-                // @TODO check end label
-                stream.pop_if_kind(Identifier)?;
                 alternatives.push(alternative);
                 break;
             }
         );
     }
 
+    let end_label = stream.pop_optional_ident()?;
+    push_some(messages, error_on_end_label_mismatch(label, &end_label));
     stream.expect_kind(SemiColon)?;
     Ok(CaseStatement {
         expression,
@@ -189,6 +364,7 @@ fn parse_case_statement_known_keyword(
 fn parse_loop_statement_initial_token(
     stream: &mut TokenStream,
     token: &Token,
+    label: Option<&Ident>,
     messages: &mut dyn MessageHandler,
 ) -> ParseResult<LoopStatement> {
     let iteration_scheme = {
@@ -215,8 +391,8 @@ fn parse_loop_statement_initial_token(
         end_token,
         End => {
             stream.expect_kind(Loop)?;
-            // @TODO check end label
-            stream.pop_if_kind(Identifier)?;
+            let end_label = stream.pop_optional_ident()?;
+            push_some(messages, error_on_end_label_mismatch(label, &end_label));
         }
     );
 
@@ -392,26 +568,74 @@ where
     })
 }
 
+/// LRM 10.5.2 Simple signal assignments
+fn parse_force_mode(stream: &mut TokenStream) -> ParseResult<Option<ForceMode>> {
+    Ok(if stream.skip_if_kind(In)? {
+        Some(ForceMode::In)
+    } else if stream.skip_if_kind(Out)? {
+        Some(ForceMode::Out)
+    } else {
+        None
+    })
+}
+
+fn check_conditional_assignment<T>(
+    stream: &TokenStream,
+    target: &WithPos<Target>,
+    rhs: &AssignmentRightHand<T>,
+    messages: &mut dyn MessageHandler,
+) {
+    if let AssignmentRightHand::Conditional(..) = rhs {
+        check_standard(
+            stream,
+            target,
+            VHDLStandard::VHDL2008,
+            "Sequential conditional assignment",
+            messages,
+        );
+    }
+}
+
 fn parse_assignment_or_procedure_call(
     stream: &mut TokenStream,
     token: &Token,
     target: WithPos<Target>,
+    messages: &mut dyn MessageHandler,
 ) -> ParseResult<SequentialStatement> {
     Ok(try_token_kind!(
         token,
         ColonEq => {
+            let rhs = parse_variable_assignment_right_hand(stream)?;
+            check_conditional_assignment(stream, &target, &rhs, messages);
             SequentialStatement::VariableAssignment(VariableAssignment {
                 target,
-                rhs: parse_variable_assignment_right_hand(stream)?
+                rhs
             })
         },
         LTE => {
-            let delay_mechanism = parse_delay_mechanism(stream)?;
-            SequentialStatement::SignalAssignment(SignalAssignment {
-                target,
-                delay_mechanism,
-                rhs: parse_signal_assignment_right_hand(stream)?
-            })
+            if stream.skip_if_kind(Force)? {
+                SequentialStatement::SignalForceAssignment(SignalForceAssignment {
+                    target,
+                    force_mode: parse_force_mode(stream)?,
+                    rhs: parse_variable_assignment_right_hand(stream)?
+                })
+            } else if stream.skip_if_kind(Release)? {
+                let force_mode = parse_force_mode(stream)?;
+                stream.expect_kind(SemiColon)?;
+                SequentialStatement::SignalReleaseAssignment(SignalReleaseAssignment {
+                    target,
+                    force_mode
+                })
+            } else {
+                let delay_mechanism = parse_delay_mechanism(stream)?;
+                let rhs = parse_signal_assignment_right_hand(stream)?;
+                check_conditional_assignment(stream, &target, &rhs, messages);
+                SequentialStatement::SignalAssignment(SignalAssignment {
+                    target,
+                    delay_mechanism,
+                    rhs
+                })
+            }
         },
         SemiColon => {
             match target.item {
@@ -426,7 +650,7 @@ fn parse_assignment_or_procedure_call(
                         })
                 }
                 Target::Aggregate(..) => {
-                    return Err(Message::error(target, "Expected procedure call, got aggregate"));
+                    return Err(Message::syntax_error(target, "Expected procedure call, got aggregate"));
                 }
             }
         }
@@ -478,6 +702,7 @@ fn parse_selected_assignment(stream: &mut TokenStream) -> ParseResult<Sequential
 fn parse_unlabeled_sequential_statement(
     stream: &mut TokenStream,
     token: Token,
+    label: Option<&Ident>,
     messages: &mut dyn MessageHandler,
 ) -> ParseResult<SequentialStatement> {
     let statement = {
@@ -486,10 +711,10 @@ fn parse_unlabeled_sequential_statement(
             Wait => SequentialStatement::Wait(parse_wait_statement_known_keyword(stream)?),
             Assert => SequentialStatement::Assert(parse_assert_statement_known_keyword(stream)?),
             Report => SequentialStatement::Report(parse_report_statement_known_keyword(stream)?),
-            If => SequentialStatement::If(parse_if_statement_known_keyword(stream, messages)?),
-            Case => SequentialStatement::Case(parse_case_statement_known_keyword(stream, messages)?),
+            If => SequentialStatement::If(parse_if_statement_known_keyword(stream, label, messages)?),
+            Case => SequentialStatement::Case(parse_case_statement_known_keyword(stream, label, messages)?),
             For | Loop | While => {
-                SequentialStatement::Loop(parse_loop_statement_initial_token(stream, &token, messages)?)
+                SequentialStatement::Loop(parse_loop_statement_initial_token(stream, &token, label, messages)?)
             },
             Next => SequentialStatement::Next(parse_next_statement_known_keyword(stream)?),
             Exit => SequentialStatement::Exit(parse_exit_statement_known_keyword(stream)?),
@@ -499,12 +724,13 @@ fn parse_unlabeled_sequential_statement(
                 SequentialStatement::Null
             },
             With => {
+                check_standard(stream, &token, VHDLStandard::VHDL2008, "Sequential selected assignment", messages);
                 parse_selected_assignment(stream)?
             },
             Identifier|LeftPar => {
                 let target = parse_target_initial_token(stream, token)?;
                 let token = stream.expect()?;
-                parse_assignment_or_procedure_call(stream, &token, target)?
+                parse_assignment_or_procedure_call(stream, &token, target, messages)?
             }
         )
     };
@@ -531,18 +757,19 @@ pub fn parse_sequential_statement_initial_token(
         if token.kind == Colon {
             let label = Some(to_simple_name(name)?);
             let token = stream.expect()?;
-            let statement = parse_unlabeled_sequential_statement(stream, token, messages)?;
+            let statement =
+                parse_unlabeled_sequential_statement(stream, token, label.as_ref(), messages)?;
             Ok(LabeledSequentialStatement { label, statement })
         } else {
             let target = name.map_into(Target::Name);
-            let statement = parse_assignment_or_procedure_call(stream, &token, target)?;
+            let statement = parse_assignment_or_procedure_call(stream, &token, target, messages)?;
             Ok(LabeledSequentialStatement {
                 label: None,
                 statement,
             })
         }
     } else {
-        let statement = parse_unlabeled_sequential_statement(stream, token, messages)?;
+        let statement = parse_unlabeled_sequential_statement(stream, token, None, messages)?;
         Ok(LabeledSequentialStatement {
             label: None,
             statement,
@@ -554,8 +781,10 @@ pub fn parse_sequential_statement_initial_token(
 mod tests {
     use super::*;
     use crate::ast::{DelayMechanism, Ident};
+    use crate::message::MessageCode;
+    use crate::vhdl_standard::VHDLStandard;
 
-    use crate::test_util::Code;
+    use crate::test_util::{check_messages, Code};
 
     fn parse(code: &str) -> (Code, LabeledSequentialStatement) {
         let code = Code::new(code);
@@ -941,6 +1170,68 @@ with x(0) + 1 select
         );
     }
 
+    #[test]
+    fn parse_signal_force_assignment() {
+        let (code, statement) = parse("foo(0) <= force in bar(1,2);");
+        assert_eq!(
+            statement,
+            with_label(
+                None,
+                SequentialStatement::SignalForceAssignment(SignalForceAssignment {
+                    target: code.s1("foo(0)").name().map_into(Target::Name),
+                    force_mode: Some(ForceMode::In),
+                    rhs: AssignmentRightHand::Simple(code.s1("bar(1,2)").expr())
+                })
+            )
+        );
+    }
+
+    #[test]
+    fn parse_signal_release_assignment() {
+        let (code, statement) = parse("foo(0) <= release out;");
+        assert_eq!(
+            statement,
+            with_label(
+                None,
+                SequentialStatement::SignalReleaseAssignment(SignalReleaseAssignment {
+                    target: code.s1("foo(0)").name().map_into(Target::Name),
+                    force_mode: Some(ForceMode::Out),
+                })
+            )
+        );
+    }
+
+    #[test]
+    fn force_is_identifier_in_vhdl_93() {
+        let code = Code::with_standard("foo <= force;", VHDLStandard::VHDL1993);
+        let statement = code.with_stream_no_messages(parse_sequential_statement);
+        assert_eq!(
+            statement,
+            with_label(
+                None,
+                SequentialStatement::SignalAssignment(SignalAssignment {
+                    target: code.s1("foo").name().map_into(Target::Name),
+                    delay_mechanism: None,
+                    rhs: AssignmentRightHand::Simple(code.s1("force").waveform())
+                })
+            )
+        );
+    }
+
+    #[test]
+    fn sequential_conditional_assignment_requires_vhdl_2008() {
+        let code = Code::with_standard("foo := bar when cond else baz;", VHDLStandard::VHDL2002);
+        let (_, messages) = code.with_stream_messages(parse_sequential_statement);
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s1("foo"),
+                "Sequential conditional assignment requires VHDL-2008 or later, the selected standard is VHDL-2002",
+                MessageCode::UnsupportedInStandard,
+            )],
+        );
+    }
+
     #[test]
     fn parse_selected_signal_assignment() {
         let (code, statement) = parse(
@@ -1360,4 +1651,162 @@ end loop;
         let (_, statement) = parse("null;");
         assert_eq!(statement, with_label(None, SequentialStatement::Null));
     }
+
+    #[test]
+    fn end_label_mismatch() {
+        let code = Code::new(
+            "\
+lbl: if cond then
+  inner: loop
+  end loop outer;
+end if lbl2;",
+        );
+        let (_, messages) = code.with_stream_messages(parse_sequential_statement);
+        check_messages(
+            messages,
+            vec![
+                Message::error(
+                    code.s1("outer"),
+                    "End identifier mismatch, expected inner",
+                    MessageCode::EndIdentifierMismatch,
+                )
+                .related(code.s1("inner"), "Opening identifier")
+                .with_replacement("inner"),
+                Message::error(
+                    code.s1("lbl2"),
+                    "End identifier mismatch, expected lbl",
+                    MessageCode::EndIdentifierMismatch,
+                )
+                .related(code.s1("lbl"), "Opening identifier")
+                .with_replacement("lbl"),
+            ],
+        );
+    }
+
+    #[test]
+    fn end_label_of_statement_without_label() {
+        let code = Code::new(
+            "\
+case sel is
+  when others => null;
+end case lbl;",
+        );
+        let (_, messages) = code.with_stream_messages(parse_sequential_statement);
+        check_messages(
+            messages,
+            vec![Message::error(
+                code.s1("lbl"),
+                "End label lbl given for a statement without label",
+                MessageCode::EndIdentifierMismatch,
+            )
+            .with_replacement("")],
+        );
+    }
+
+    #[test]
+    fn recovers_from_errors_in_statements() {
+        let code = Code::new(
+            "\
+if cond then
+  x := ;
+  if a = then
+    y := 1;
+  end if;
+  z := 2
+  return;
+  lbl : null;
+end if;",
+        );
+        let (statement, messages) = code.with_stream_messages(parse_sequential_statement);
+        assert_eq!(
+            statement,
+            with_label(
+                None,
+                SequentialStatement::If(IfStatement {
+                    conditionals: vec![Conditional {
+                        condition: code.s1("cond").expr(),
+                        item: vec![
+                            with_label(None, SequentialStatement::Error(vec![])),
+                            with_label(
+                                None,
+                                SequentialStatement::Error(vec![code
+                                    .s1("y := 1;")
+                                    .sequential_statement()])
+                            ),
+                            with_label(None, SequentialStatement::Error(vec![])),
+                            code.s1("return;").sequential_statement(),
+                            code.s1("lbl : null;").sequential_statement()
+                        ]
+                    }],
+                    else_item: None
+                })
+            )
+        );
+        check_messages(
+            messages,
+            vec![
+                Message::syntax_error(code.s(";", 1), "Expected {expression}"),
+                Message::syntax_error(code.s("then", 2), "Expected {expression}"),
+                Message::syntax_error(code.s1("return"), "Expected 'when' or ';'"),
+            ],
+        );
+    }
+
+    #[test]
+    fn recovers_at_start_of_statements() {
+        let code = Code::new(
+            "\
+loop
+  x := 1 +
+  for i in 0 to 1 loop
+    y := 2;
+  end loop;
+  lbl : wait on ;
+  null;
+  z := 3 *
+  report \"msg\";
+  w := 4 5
+  v <= 6;
+  s <= a <= b c;
+end loop;",
+        );
+        let (statement, messages) = code.with_stream_messages(parse_sequential_statement);
+        assert_eq!(
+            statement,
+            with_label(
+                None,
+                SequentialStatement::Loop(LoopStatement {
+                    iteration_scheme: None,
+                    statements: vec![
+                        with_label(None, SequentialStatement::Error(vec![])),
+                        code.s1("for i in 0 to 1 loop\n    y := 2;\n  end loop;")
+                            .sequential_statement(),
+                        with_label(
+                            Some(code.s1("lbl").ident()),
+                            SequentialStatement::Error(vec![])
+                        ),
+                        code.s1("null;").sequential_statement(),
+                        with_label(None, SequentialStatement::Error(vec![])),
+                        code.s1("report \"msg\";").sequential_statement(),
+                        with_label(None, SequentialStatement::Error(vec![])),
+                        code.s1("v <= 6;").sequential_statement(),
+                        with_label(None, SequentialStatement::Error(vec![])),
+                    ]
+                })
+            )
+        );
+        check_messages(
+            messages,
+            vec![
+                Message::syntax_error(code.s("for", 1), "Expected {expression}"),
+                Message::syntax_error(
+                    code.s(";", 3),
+                    "Expected '{identifier}', '{character}', '{string}' or 'all'",
+                ),
+                Message::syntax_error(code.s1("report"), "Expected {expression}"),
+                Message::syntax_error(code.s1("5"), "Expected 'when' or ';'"),
+                Message::syntax_error(code.s1("c"), "Expected 'when' or ';'"),
+            ],
+        );
+    }
 }
@@ -170,6 +170,20 @@ pub struct Pos {
     pub length: usize,
 }
 
+/// Zero based line and character offset within a file
+#[derive(PartialEq, Clone, Copy, Debug, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub line: u64,
+    pub character: u64,
+}
+
+/// A range between two positions where the end is non-inclusive
+#[derive(PartialEq, Clone, Copy, Debug, Eq, Hash)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
+
 /// Lexical position in a file.
 #[derive(PartialEq, Clone, Debug, Eq, Hash)]
 pub struct SrcPos {
@@ -255,7 +269,7 @@ impl<T> WithPos<T> {
                 item,
                 pos: self.pos,
             }),
-            Err(msg) => Err(Message::error(&self.pos, msg)),
+            Err(msg) => Err(Message::syntax_error(&self.pos, msg)),
         }
     }
 
@@ -458,6 +472,40 @@ impl SrcPos {
         code_context
     }
 
+    /// The line and character range covered by the source position
+    pub fn range(&self) -> Range {
+        // @TODO handle errors
+        let contents = self.source.contents().unwrap();
+        let mut start = None;
+        let mut end = None;
+
+        let mut cursor = Position {
+            line: 0,
+            character: 0,
+        };
+        for (i, byte) in contents.bytes.iter().enumerate() {
+            if i == self.start {
+                start = Some(cursor);
+            }
+
+            if i == self.start + self.length {
+                end = Some(cursor);
+            }
+
+            if *byte == b'\n' {
+                cursor.line += 1;
+                cursor.character = 0;
+            } else {
+                cursor.character += 1;
+            };
+        }
+
+        Range {
+            start: start.unwrap_or(cursor),
+            end: end.unwrap_or(cursor),
+        }
+    }
+
     pub fn show(&self, message: &str) -> String {
         let (lineno, lineno_len, pretty_str) = self.lineno_and_code_context();
         let file_name = self.source.file_name();
@@ -685,6 +733,60 @@ line13",
         );
     }
 
+    #[test]
+    fn range_within_line() {
+        let source = Source::from_str("hello\nworld\nline\n");
+        assert_eq!(
+            source.first_substr_pos("orl").range(),
+            Range {
+                start: Position {
+                    line: 1,
+                    character: 1
+                },
+                end: Position {
+                    line: 1,
+                    character: 4
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn range_spanning_lines() {
+        let source = Source::from_str("hello\nworld\nline\n");
+        assert_eq!(
+            source.first_substr_pos("lo\nwo").range(),
+            Range {
+                start: Position {
+                    line: 0,
+                    character: 3
+                },
+                end: Position {
+                    line: 1,
+                    character: 2
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn range_at_eof() {
+        let source = Source::from_str("h");
+        assert_eq!(
+            source.pos(1, 1).range(),
+            Range {
+                start: Position {
+                    line: 0,
+                    character: 1
+                },
+                end: Position {
+                    line: 0,
+                    character: 1
+                },
+            }
+        );
+    }
+
     #[test]
     fn show_from_filename() {
         with_source_from_file("hello\nworld\nline\n", |source: Source| {
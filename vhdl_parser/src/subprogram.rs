@@ -8,20 +8,24 @@ use crate::ast::{
     Declaration, FunctionSpecification, ProcedureSpecification, Signature, SubprogramBody,
     SubprogramDeclaration, SubprogramDesignator,
 };
+use crate::common::check_standard;
 use crate::declarative_part::parse_declarative_part;
-use crate::interface_declaration::parse_parameter_interface_list;
-use crate::message::{Message, MessageHandler, ParseResult};
+use crate::interface_declaration::{parse_generic_interface_list, parse_parameter_interface_list};
+use crate::message::{push_some, Message, MessageCode, MessageHandler, ParseResult};
 use crate::names::parse_selected_name;
 use crate::sequential_statement::parse_labeled_sequential_statements;
 use crate::source::WithPos;
 use crate::tokenizer::Kind::*;
 use crate::tokenstream::TokenStream;
+use crate::vhdl_standard::VHDLStandard;
 
-pub fn parse_signature(stream: &mut TokenStream) -> ParseResult<Signature> {
+pub fn parse_signature(
+    stream: &mut TokenStream,
+    messages: &mut dyn MessageHandler,
+) -> ParseResult<Signature> {
     stream.expect_kind(LeftSquare)?;
     let mut type_marks = Vec::new();
     let mut return_mark = None;
-    let mut errmsg = None;
     loop {
         let token = stream.peek_expect()?;
 
@@ -41,7 +45,7 @@ pub fn parse_signature(stream: &mut TokenStream) -> ParseResult<Signature> {
                     Return => {
                         let new_return_mark = Some(parse_selected_name(stream)?);
                         if return_mark.is_some() {
-                            errmsg = Some(Message::error(sep_token, "Duplicate return in signature"));
+                            messages.push(Message::syntax_error(sep_token, "Duplicate return in signature"));
                         } else {
                             return_mark = new_return_mark;
                         }
@@ -52,7 +56,7 @@ pub fn parse_signature(stream: &mut TokenStream) -> ParseResult<Signature> {
                 stream.move_after(&token);
                 let new_return_mark = Some(parse_selected_name(stream)?);
                 if return_mark.is_some() {
-                    errmsg = Some(Message::error(token, "Duplicate return in signature"));
+                    messages.push(Message::syntax_error(token, "Duplicate return in signature"));
                 } else {
                     return_mark = new_return_mark;
                 }
@@ -63,11 +67,6 @@ pub fn parse_signature(stream: &mut TokenStream) -> ParseResult<Signature> {
             }
         )
     }
-    if let Some(msg) = errmsg {
-        // @TODO recoverable error should not return Err
-        return Err(msg);
-    }
-
     Ok(match return_mark {
         Some(return_mark) => Signature::Function(type_marks, return_mark),
         None => Signature::Procedure(type_marks),
@@ -103,8 +102,24 @@ pub fn parse_subprogram_declaration_no_semi(
 
     let designator = parse_designator(stream)?;
 
+    let generic_list = {
+        if let Some(token) = stream.pop_if_kind(Generic)? {
+            check_standard(
+                stream,
+                &token,
+                VHDLStandard::VHDL2008,
+                "Subprogram generic list",
+                messages,
+            );
+            Some(parse_generic_interface_list(stream, messages)?)
+        } else {
+            None
+        }
+    };
+
     let parameter_list = {
-        if stream.peek_kind()? == Some(LeftPar) {
+        let has_keyword = stream.skip_if_kind(Parameter)?;
+        if has_keyword || stream.peek_kind()? == Some(LeftPar) {
             parse_parameter_interface_list(stream, messages)?
         } else {
             Vec::new()
@@ -117,12 +132,14 @@ pub fn parse_subprogram_declaration_no_semi(
         Ok(SubprogramDeclaration::Function(FunctionSpecification {
             pure: is_pure,
             designator,
+            generic_list,
             parameter_list,
             return_type,
         }))
     } else {
         Ok(SubprogramDeclaration::Procedure(ProcedureSpecification {
             designator,
+            generic_list,
             parameter_list,
         }))
     }
@@ -137,6 +154,52 @@ pub fn parse_subprogram_declaration(
     res
 }
 
+fn designator_to_string(designator: &SubprogramDesignator) -> String {
+    match designator {
+        SubprogramDesignator::Identifier(ref sym) => sym.name().to_string(),
+        SubprogramDesignator::OperatorSymbol(ref string) => format!("\"{}\"", string),
+    }
+}
+
+/// The end designator shall repeat the designator of the subprogram specification
+fn error_on_end_designator_mismatch(
+    specification: &SubprogramDeclaration,
+    end_designator: &Option<WithPos<SubprogramDesignator>>,
+) -> Option<Message> {
+    let end_designator = end_designator.as_ref()?;
+    let designator = match specification {
+        SubprogramDeclaration::Procedure(ref procedure) => &procedure.designator,
+        SubprogramDeclaration::Function(ref function) => &function.designator,
+    };
+
+    let is_match = match (&designator.item, &end_designator.item) {
+        (
+            SubprogramDesignator::Identifier(ref sym),
+            SubprogramDesignator::Identifier(ref end_sym),
+        ) => sym == end_sym,
+        (
+            SubprogramDesignator::OperatorSymbol(ref string),
+            SubprogramDesignator::OperatorSymbol(ref end_string),
+        ) => string.to_lowercase() == end_string.to_lowercase(),
+        _ => false,
+    };
+
+    if is_match {
+        None
+    } else {
+        let expected = designator_to_string(&designator.item);
+        Some(
+            Message::error(
+                &end_designator.pos,
+                format!("End designator mismatch, expected {}", expected),
+                MessageCode::EndIdentifierMismatch,
+            )
+            .related(&designator.pos, "Opening designator")
+            .with_replacement(expected),
+        )
+    }
+}
+
 /// LRM 4.3 Subprogram bodies
 pub fn parse_subprogram_body(
     stream: &mut TokenStream,
@@ -156,8 +219,14 @@ pub fn parse_subprogram_body(
         end_token,
         End => {
             stream.pop_if_kind(end_kind)?;
-            stream.pop_if_kind(Identifier)?;
-            stream.pop_if_kind(StringLiteral)?;
+            let end_designator = {
+                if stream.peek_kind()? == Some(SemiColon) {
+                    None
+                } else {
+                    Some(parse_designator(stream)?)
+                }
+            };
+            push_some(messages, error_on_end_designator_mismatch(&specification, &end_designator));
             stream.expect_kind(SemiColon)?;
         }
     );
@@ -189,6 +258,7 @@ mod tests {
     use super::*;
 
     use crate::latin_1::Latin1String;
+    use crate::message::MessageCode;
     use crate::test_util::Code;
 
     #[test]
@@ -205,6 +275,7 @@ procedure foo;
                     .s1("foo")
                     .ident()
                     .map_into(SubprogramDesignator::Identifier),
+                generic_list: None,
                 parameter_list: Vec::new(),
             })
         );
@@ -225,6 +296,7 @@ function foo return lib.foo.natural;
                     .s1("foo")
                     .ident()
                     .map_into(SubprogramDesignator::Identifier),
+                generic_list: None,
                 parameter_list: Vec::new(),
                 return_type: code.s1("lib.foo.natural").selected_name()
             })
@@ -248,6 +320,7 @@ function \"+\" return lib.foo.natural;
                     )),
                     pos: code.s1("\"+\"").pos()
                 },
+                generic_list: None,
                 parameter_list: Vec::new(),
                 return_type: code.s1("lib.foo.natural").selected_name()
             })
@@ -269,6 +342,7 @@ impure function foo return lib.foo.natural;
                     .s1("foo")
                     .ident()
                     .map_into(SubprogramDesignator::Identifier),
+                generic_list: None,
                 parameter_list: Vec::new(),
                 return_type: code.s1("lib.foo.natural").selected_name()
             })
@@ -289,6 +363,7 @@ procedure foo(foo : natural);
                     .s1("foo")
                     .ident()
                     .map_into(SubprogramDesignator::Identifier),
+                generic_list: None,
                 parameter_list: vec![code.s1("foo : natural").parameter()],
             })
         );
@@ -309,6 +384,7 @@ function foo(foo : natural) return lib.foo.natural;
                     .s1("foo")
                     .ident()
                     .map_into(SubprogramDesignator::Identifier),
+                generic_list: None,
                 parameter_list: vec![code.s1("foo : natural").parameter()],
                 return_type: code.s1("lib.foo.natural").selected_name()
             })
@@ -319,7 +395,7 @@ function foo(foo : natural) return lib.foo.natural;
     pub fn parses_function_signature_only_return() {
         let code = Code::new("[return bar.type_mark]");
         assert_eq!(
-            code.with_stream(parse_signature),
+            code.with_stream_no_messages(parse_signature),
             Signature::Function(vec![], code.s1("bar.type_mark").selected_name())
         );
     }
@@ -328,7 +404,7 @@ function foo(foo : natural) return lib.foo.natural;
     pub fn parses_function_signature_one_argument() {
         let code = Code::new("[foo.type_mark return bar.type_mark]");
         assert_eq!(
-            code.with_stream(parse_signature),
+            code.with_stream_no_messages(parse_signature),
             Signature::Function(
                 vec![code.s1("foo.type_mark").selected_name()],
                 code.s1("bar.type_mark").selected_name()
@@ -340,7 +416,7 @@ function foo(foo : natural) return lib.foo.natural;
     pub fn parses_procedure_signature() {
         let code = Code::new("[foo.type_mark]");
         assert_eq!(
-            code.with_stream(parse_signature),
+            code.with_stream_no_messages(parse_signature),
             Signature::Procedure(vec![code.s1("foo.type_mark").selected_name()])
         );
     }
@@ -349,7 +425,7 @@ function foo(foo : natural) return lib.foo.natural;
     pub fn parses_function_signature_many_arguments() {
         let code = Code::new("[foo.type_mark, foo2.type_mark return bar.type_mark]");
         assert_eq!(
-            code.with_stream(parse_signature),
+            code.with_stream_no_messages(parse_signature),
             Signature::Function(
                 vec![
                     code.s1("foo.type_mark").selected_name(),
@@ -363,15 +439,34 @@ function foo(foo : natural) return lib.foo.natural;
     #[test]
     pub fn parses_function_signature_many_return_error() {
         let code = Code::new("[return bar.type_mark return bar2]");
+        let (signature, messages) = code.with_stream_messages(parse_signature);
         assert_eq!(
-            code.with_stream_err(parse_signature),
-            Message::error(code.s("return", 2), "Duplicate return in signature")
+            signature,
+            Signature::Function(vec![], code.s1("bar.type_mark").selected_name())
+        );
+        assert_eq!(
+            messages,
+            vec![Message::syntax_error(
+                code.s("return", 2),
+                "Duplicate return in signature"
+            )]
         );
 
         let code = Code::new("[foo return bar.type_mark return bar2]");
+        let (signature, messages) = code.with_stream_messages(parse_signature);
         assert_eq!(
-            code.with_stream_err(parse_signature),
-            Message::error(code.s("return", 2), "Duplicate return in signature")
+            signature,
+            Signature::Function(
+                vec![code.s1("foo").selected_name()],
+                code.s1("bar.type_mark").selected_name()
+            )
+        );
+        assert_eq!(
+            messages,
+            vec![Message::syntax_error(
+                code.s("return", 2),
+                "Duplicate return in signature"
+            )]
         );
     }
 
@@ -418,4 +513,75 @@ function foo(arg : natural) return natural;
         );
     }
 
+    #[test]
+    pub fn parses_subprogram_generic_list() {
+        let code = Code::new(
+            "\
+function foo generic (type t) parameter (arg : t) return t;
+",
+        );
+        assert_eq!(
+            code.with_stream_no_messages(parse_subprogram_declaration),
+            SubprogramDeclaration::Function(FunctionSpecification {
+                pure: true,
+                designator: code
+                    .s1("foo")
+                    .ident()
+                    .map_into(SubprogramDesignator::Identifier),
+                generic_list: Some(vec![code.s1("type t").generic()]),
+                parameter_list: vec![code.s1("arg : t").parameter()],
+                return_type: code.s1("t;").s1("t").selected_name()
+            })
+        );
+    }
+
+    #[test]
+    pub fn subprogram_generic_list_requires_vhdl_2008() {
+        let code = Code::with_standard(
+            "procedure foo generic (constant c : natural);",
+            VHDLStandard::VHDL2002,
+        );
+        let (_, messages) = code.with_stream_messages(parse_subprogram_declaration);
+        assert_eq!(
+            messages,
+            vec![Message::error(
+                code.s1("generic"),
+                "Subprogram generic list requires VHDL-2008 or later, the selected standard is VHDL-2002",
+                MessageCode::UnsupportedInStandard,
+            )]
+        );
+    }
+
+    #[test]
+    pub fn subprogram_end_designator_mismatch() {
+        let code = Code::new(
+            "\
+function foo return natural is
+begin
+end function bar;",
+        );
+        let (_, messages) = code.with_stream_messages(parse_subprogram);
+        assert_eq!(
+            messages,
+            vec![Message::error(
+                code.s1("bar"),
+                "End designator mismatch, expected foo",
+                MessageCode::EndIdentifierMismatch,
+            )
+            .related(code.s1("foo"), "Opening designator")
+            .with_replacement("foo")]
+        );
+    }
+
+    #[test]
+    pub fn subprogram_end_operator_designator() {
+        let code = Code::new(
+            "\
+function \"and\" (a, b : t) return t is
+begin
+end function \"AND\";",
+        );
+        let (_, messages) = code.with_stream_messages(parse_subprogram);
+        assert_eq!(messages, vec![]);
+    }
 }
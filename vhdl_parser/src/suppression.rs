@@ -0,0 +1,346 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
+
+//! Inline suppression of messages using comments
+//!
+//! -- vhdl_ls: disable-next-line [code ...]
+//! -- vhdl_ls: disable [code ...]
+//! -- vhdl_ls: enable [code ...]
+//!
+//! Codes are either numeric such as E0201 or names such as unresolved-name.
+//! When no code is given all messages are suppressed.
+
+use crate::latin_1::Latin1String;
+use crate::message::{Message, MessageCode};
+use crate::tokenizer::Comment;
+
+const PREFIX: &str = "vhdl_ls:";
+
+/// True if the code may contain suppression comments
+pub fn may_contain_suppressions(bytes: &[u8]) -> bool {
+    bytes
+        .windows(PREFIX.len())
+        .any(|window| window == PREFIX.as_bytes())
+}
+
+/// A byte range of a source where messages with some codes are suppressed
+#[derive(PartialEq, Debug, Clone)]
+struct SuppressedRegion {
+    start: usize,
+    end: usize,
+    // None means all codes
+    code: Option<MessageCode>,
+}
+
+#[derive(PartialEq, Debug, Default, Clone)]
+pub struct Suppressions {
+    regions: Vec<SuppressedRegion>,
+}
+
+impl Suppressions {
+    /// Find the suppression comments among the comments of a source
+    pub fn from_comments(contents: &Latin1String, comments: Vec<Comment>) -> Suppressions {
+        let bytes = &contents.bytes;
+        let end_of_line = |offset: usize| -> usize {
+            bytes[offset.min(bytes.len())..]
+                .iter()
+                .position(|byte| *byte == b'\n')
+                .map(|idx| offset + idx)
+                .unwrap_or_else(|| bytes.len())
+        };
+
+        let mut regions = Vec::new();
+        // Regions which have been disabled but not yet enabled again
+        let mut open: Vec<(Option<MessageCode>, usize)> = Vec::new();
+
+        for comment in comments {
+            let (directive, codes) = match parse_directive(&comment) {
+                Some(directive) => directive,
+                None => continue,
+            };
+
+            let comment_end = comment.pos.start + comment.pos.length;
+
+            match directive.as_str() {
+                "disable-next-line" => {
+                    let start = end_of_line(comment_end) + 1;
+                    let end = end_of_line(start);
+                    for code in codes {
+                        regions.push(SuppressedRegion { start, end, code });
+                    }
+                }
+                "disable" => {
+                    for code in codes {
+                        if !open.iter().any(|(open_code, _)| *open_code == code) {
+                            open.push((code, comment_end));
+                        }
+                    }
+                }
+                "enable" => {
+                    let enable_all = codes == [None];
+                    let mut still_open = Vec::new();
+                    for (code, start) in open.drain(..) {
+                        if enable_all || codes.contains(&code) {
+                            regions.push(SuppressedRegion {
+                                start,
+                                end: comment.pos.start,
+                                code,
+                            });
+                        } else {
+                            still_open.push((code, start));
+                        }
+                    }
+                    open = still_open;
+                }
+                _ => {}
+            }
+        }
+
+        // Regions which are never enabled again last until the end of the file
+        for (code, start) in open {
+            regions.push(SuppressedRegion {
+                start,
+                end: bytes.len(),
+                code,
+            });
+        }
+
+        Suppressions { regions }
+    }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    pub fn is_suppressed(&self, message: &Message) -> bool {
+        let start = message.pos.start;
+        self.regions.iter().any(|region| {
+            region.start <= start
+                && start <= region.end
+                && (region.code.is_none() || region.code == Some(message.code))
+        })
+    }
+}
+
+/// Parse the directive and codes of a suppression comment
+/// An empty list of codes is returned as [None] meaning all codes
+fn parse_directive(comment: &Comment) -> Option<(String, Vec<Option<MessageCode>>)> {
+    let value = comment.value.to_string();
+    let value = value.trim();
+    if !value.starts_with(PREFIX) {
+        return None;
+    }
+
+    let mut words = value[PREFIX.len()..]
+        .split(|chr: char| chr.is_whitespace() || chr == ',')
+        .filter(|word| !word.is_empty());
+
+    let directive = words.next()?.to_lowercase();
+    let words: Vec<&str> = words.collect();
+
+    let codes = if words.is_empty() {
+        vec![None]
+    } else {
+        // Unknown codes are ignored
+        words
+            .into_iter()
+            .filter_map(|word| word.parse().ok())
+            .map(Some)
+            .collect()
+    };
+
+    Some((directive, codes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::VHDLParser;
+    use crate::source::Source;
+    use crate::test_util::Code;
+
+    fn suppressions(code: &Code) -> Suppressions {
+        let (_, suppressions) = VHDLParser::new().parse_design_source_with_suppressions(
+            &code.pos().source,
+            code.standard(),
+            &mut Vec::new(),
+        );
+        suppressions
+    }
+
+    #[test]
+    fn disable_next_line() {
+        let code = Code::new(
+            "\
+-- vhdl_ls: disable-next-line unresolved-name
+foo bar
+foo bar
+",
+        );
+        let suppressions = suppressions(&code);
+        assert!(suppressions.is_suppressed(&Message::error(
+            code.s1("foo"),
+            "",
+            MessageCode::UnresolvedName
+        )));
+        assert!(suppressions.is_suppressed(&Message::error(
+            code.s1("bar"),
+            "",
+            MessageCode::UnresolvedName
+        )));
+        assert!(!suppressions.is_suppressed(&Message::error(
+            code.s1("foo"),
+            "",
+            MessageCode::DuplicateDeclaration
+        )));
+        assert!(!suppressions.is_suppressed(&Message::error(
+            code.s("foo", 2),
+            "",
+            MessageCode::UnresolvedName
+        )));
+    }
+
+    #[test]
+    fn disable_next_line_trailing_comment() {
+        let code = Code::new(
+            "\
+foo -- vhdl_ls: disable-next-line
+bar
+baz
+",
+        );
+        let suppressions = suppressions(&code);
+        assert!(!suppressions.is_suppressed(&Message::syntax_error(code.s1("foo"), "")));
+        assert!(suppressions.is_suppressed(&Message::syntax_error(code.s1("bar"), "")));
+        assert!(!suppressions.is_suppressed(&Message::syntax_error(code.s1("baz"), "")));
+    }
+
+    #[test]
+    fn disable_and_enable() {
+        let code = Code::new(
+            "\
+foo
+-- vhdl_ls: disable E0201, duplicate-declaration
+bar
+-- vhdl_ls: enable E0201
+baz
+",
+        );
+        let suppressions = suppressions(&code);
+        let unresolved = |substr| Message::error(code.s1(substr), "", MessageCode::UnresolvedName);
+        let duplicate =
+            |substr| Message::error(code.s1(substr), "", MessageCode::DuplicateDeclaration);
+
+        assert!(!suppressions.is_suppressed(&unresolved("foo")));
+        assert!(suppressions.is_suppressed(&unresolved("bar")));
+        assert!(!suppressions.is_suppressed(&unresolved("baz")));
+
+        assert!(!suppressions.is_suppressed(&duplicate("foo")));
+        assert!(suppressions.is_suppressed(&duplicate("bar")));
+        // Never enabled again
+        assert!(suppressions.is_suppressed(&duplicate("baz")));
+    }
+
+    #[test]
+    fn enable_without_codes_enables_all() {
+        let code = Code::new(
+            "\
+-- vhdl_ls: disable unresolved-name
+-- vhdl_ls: disable E0202
+foo
+-- vhdl_ls: enable
+bar
+",
+        );
+        let suppressions = suppressions(&code);
+        assert!(suppressions.is_suppressed(&Message::error(
+            code.s1("foo"),
+            "",
+            MessageCode::UnresolvedName
+        )));
+        assert!(suppressions.is_suppressed(&Message::error(
+            code.s1("foo"),
+            "",
+            MessageCode::DuplicateDeclaration
+        )));
+        assert!(!suppressions.is_suppressed(&Message::error(
+            code.s1("bar"),
+            "",
+            MessageCode::UnresolvedName
+        )));
+        assert!(!suppressions.is_suppressed(&Message::error(
+            code.s1("bar"),
+            "",
+            MessageCode::DuplicateDeclaration
+        )));
+    }
+
+    #[test]
+    fn project_removes_suppressed_messages() {
+        use crate::project::Project;
+
+        let mut project = Project::new();
+        let source = Source::inline_utf8(
+            "file.vhd",
+            "\
+entity ent is
+-- vhdl_ls: disable-next-line end-identifier-mismatch
+end entity ent2;
+
+entity ent3 is
+end entity ent4;
+",
+        )
+        .unwrap();
+        project.update_source("file.vhd", &source).unwrap();
+        let messages = project.analyse();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(
+            messages[0].message,
+            "End identifier mismatch, expected ent3"
+        );
+    }
+
+    #[test]
+    fn ignores_other_comments() {
+        let code = Code::new(
+            "\
+-- disable-next-line
+-- vhdl_ls: unknown-directive
+-- vhdl_ls: disable-next-line no-such-code
+foo
+",
+        );
+        let suppressions = suppressions(&code);
+        assert!(suppressions.is_empty());
+    }
+
+    #[test]
+    fn project_removes_suppressed_syntax_errors() {
+        use crate::project::Project;
+
+        let mut project = Project::new();
+        let source = Source::inline_utf8(
+            "file.vhd",
+            "\
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal s : bit
+    -- The comment is found although the next token fails to tokenize
+    -- vhdl_ls: disable-next-line syntax-error
+    $;
+begin
+end architecture;
+",
+        )
+        .unwrap();
+        project.update_source("file.vhd", &source).unwrap();
+        assert_eq!(project.analyse(), vec![]);
+    }
+}
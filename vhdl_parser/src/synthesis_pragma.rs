@@ -0,0 +1,334 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
+
+//! Synthesis pragmas such as -- synthesis translate_off ... -- synthesis translate_on
+
+use crate::tokenizer::{raw_literal_end, raw_next_line};
+use crate::tokenstream::TokenStream;
+use std::str::FromStr;
+
+/// How the code between translate_off and translate_on pragmas is analysed
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum TranslateOff {
+    /// The code is analysed as any other code, the regions are only reported
+    #[default]
+    Analyse,
+    /// The code is skipped such as code disabled by conditional analysis
+    Skip,
+}
+
+impl FromStr for TranslateOff {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<TranslateOff, String> {
+        match string {
+            "analyse" | "analyze" => Ok(TranslateOff::Analyse),
+            "skip" => Ok(TranslateOff::Skip),
+            _ => Err(format!(
+                "translate_off must be 'analyse' or 'skip', got '{}'",
+                string
+            )),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+enum Pragma {
+    TranslateOff,
+    TranslateOn,
+}
+
+/// The pragma of a comment such as -- pragma translate_off
+fn pragma(comment: &str) -> Option<Pragma> {
+    let value = comment.strip_prefix("--")?.to_lowercase();
+    let mut words = value.split_whitespace();
+
+    match words.next()? {
+        "synthesis" | "pragma" | "synopsys" => {}
+        _ => return None,
+    }
+
+    let pragma = match words.next()? {
+        "translate_off" => Pragma::TranslateOff,
+        "translate_on" => Pragma::TranslateOn,
+        _ => return None,
+    };
+
+    if words.next().is_some() {
+        None
+    } else {
+        Some(pragma)
+    }
+}
+
+/// The byte ranges of the -- comments of the code
+/// The code is scanned without tokenizing it such that a tokenizer error does not hide later comments
+fn raw_comments(bytes: &[u8]) -> Vec<(usize, usize)> {
+    let mut comments = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        if let Some(end) = raw_literal_end(bytes, pos) {
+            pos = end;
+            continue;
+        }
+        match bytes[pos] {
+            b'-' if bytes.get(pos + 1) == Some(&b'-') => {
+                let mut end = raw_next_line(bytes, pos).unwrap_or(bytes.len());
+                while end > pos && bytes[end - 1].is_ascii_whitespace() {
+                    end -= 1;
+                }
+                comments.push((pos, end));
+                pos = end;
+            }
+            _ => {
+                pos += 1;
+            }
+        }
+    }
+    comments
+}
+
+/// True if the code may contain translate_off or translate_on pragmas
+pub fn may_contain_pragmas(bytes: &[u8]) -> bool {
+    bytes
+        .windows(b"translate_o".len())
+        .any(|window| window.eq_ignore_ascii_case(b"translate_o"))
+}
+
+/// Find the code between translate_off and translate_on pragmas of the stream
+/// A translate_off pragma without a matching translate_on lasts until the end of the file
+/// The code is skipped when the stream is parsed afterwards if the mode is Skip
+pub fn find_translate_off_regions(stream: &mut TokenStream, mode: TranslateOff) {
+    let tokenizer = &mut stream.tokenizer;
+    let bytes = tokenizer.code_bytes();
+    let mut pragmas = Vec::new();
+    for (start, end) in raw_comments(bytes) {
+        // Comments within code disabled by conditional analysis are ignored
+        if tokenizer.is_skipped(start) {
+            continue;
+        }
+        let comment = String::from_utf8_lossy(&bytes[start..end]);
+        if let Some(pragma) = pragma(&comment) {
+            pragmas.push((pragma, start, end));
+        }
+    }
+
+    let mut regions = Vec::new();
+    let mut skipped = Vec::new();
+    let mut translate_off = None;
+    let code_length = tokenizer.code_length();
+
+    for (pragma, start, end) in pragmas {
+        match (pragma, translate_off) {
+            (Pragma::TranslateOff, None) => {
+                translate_off = Some((start, end));
+            }
+            (Pragma::TranslateOn, Some((off_start, off_end))) => {
+                regions.push((off_start, start));
+                skipped.push((off_end, start));
+                translate_off = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let Some((off_start, off_end)) = translate_off {
+        regions.push((off_start, code_length));
+        skipped.push((off_end, code_length));
+    }
+
+    let source = tokenizer.source();
+    let regions = regions
+        .into_iter()
+        .map(|(start, end)| source.pos(start, end - start))
+        .collect();
+
+    if mode == TranslateOff::Skip {
+        tokenizer.set_translate_off_regions(regions, skipped);
+    } else {
+        tokenizer.set_translate_off_regions(regions, Vec::new());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{DesignFile, HasIdent};
+    use crate::message::Message;
+    use crate::parser::VHDLParser;
+    use crate::source::Source;
+    use crate::test_util::{check_no_messages, Code};
+    use crate::vhdl_standard::VHDLStandard;
+
+    fn parse(code: &Code, translate_off: TranslateOff) -> (DesignFile, Vec<Message>) {
+        let mut parser = VHDLParser::new();
+        parser.symtab = code.symtab.clone();
+        parser.translate_off = translate_off;
+
+        let mut messages = Vec::new();
+        let design_file = parser
+            .parse_design_source(&code.pos().source, code.standard(), &mut messages)
+            .unwrap();
+        (design_file, messages)
+    }
+
+    /// Parse code which cannot be tokenized in its entirety
+    fn parse_str(code: &str, translate_off: TranslateOff) -> (DesignFile, Vec<Message>) {
+        let mut parser = VHDLParser::new();
+        parser.translate_off = translate_off;
+
+        let mut messages = Vec::new();
+        let design_file = parser
+            .parse_design_source(
+                &Source::from_str(code),
+                VHDLStandard::default(),
+                &mut messages,
+            )
+            .unwrap();
+        (design_file, messages)
+    }
+
+    fn unit_names(design_file: &DesignFile) -> Vec<String> {
+        design_file
+            .design_units
+            .iter()
+            .map(|unit| unit.ident().item.name_utf8())
+            .collect()
+    }
+
+    fn region_texts(code: &Code, design_file: &DesignFile) -> Vec<String> {
+        let contents = code.pos().source.contents().unwrap();
+        design_file
+            .translate_off_regions
+            .iter()
+            .map(|region| {
+                String::from_utf8(
+                    contents.bytes[region.start..region.start + region.length].to_vec(),
+                )
+                .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn translate_off_regions_are_reported() {
+        let code = Code::new(
+            "\
+entity ent is
+end entity;
+
+-- synthesis translate_off
+entity tb is
+end entity;
+-- synthesis translate_on
+
+-- PRAGMA TRANSLATE_OFF
+entity tb2 is
+end entity;
+-- synopsys translate_on
+",
+        );
+        let (design_file, messages) = parse(&code, TranslateOff::Analyse);
+        check_no_messages(&messages);
+        assert_eq!(unit_names(&design_file), vec!["ent", "tb", "tb2"]);
+        assert_eq!(
+            region_texts(&code, &design_file),
+            vec![
+                "-- synthesis translate_off\nentity tb is\nend entity;\n",
+                "-- PRAGMA TRANSLATE_OFF\nentity tb2 is\nend entity;\n"
+            ]
+        );
+    }
+
+    #[test]
+    fn translate_off_regions_are_skipped() {
+        let code = Code::new(
+            "\
+entity ent is
+  port (
+    clk : in bit;
+    -- pragma translate_off
+    not valid vhdl
+    -- pragma translate_on
+    rst : in bit
+  );
+end entity;
+
+-- synthesis translate_off
+entity tb is
+",
+        );
+        let (design_file, messages) = parse(&code, TranslateOff::Skip);
+        check_no_messages(&messages);
+        assert_eq!(unit_names(&design_file), vec!["ent"]);
+        assert_eq!(
+            region_texts(&code, &design_file),
+            vec![
+                "-- pragma translate_off\n    not valid vhdl\n    ",
+                "-- synthesis translate_off\nentity tb is\n"
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenizer_errors_do_not_hide_translate_on() {
+        let code = "\
+-- synthesis translate_off
+entity tb is
+  constant msg : string := \"unterminated;
+end entity;
+-- synthesis translate_on
+entity ent is
+end entity;
+";
+        let (design_file, messages) = parse_str(code, TranslateOff::Skip);
+        check_no_messages(&messages);
+        assert_eq!(unit_names(&design_file), vec!["ent"]);
+        assert_eq!(design_file.translate_off_regions.len(), 1);
+    }
+
+    #[test]
+    fn pragmas_in_strings_are_ignored() {
+        let code = Code::new(
+            "\
+entity ent is
+  constant msg : string := \"-- synthesis translate_off\";
+end entity;
+",
+        );
+        let (design_file, messages) = parse(&code, TranslateOff::Skip);
+        check_no_messages(&messages);
+        assert_eq!(unit_names(&design_file), vec!["ent"]);
+        assert_eq!(design_file.translate_off_regions, vec![]);
+    }
+
+    #[test]
+    fn other_comments_are_not_pragmas() {
+        let code = Code::new(
+            "\
+-- translate_off
+-- synthesis translate_off please
+-- pragma translate_on
+entity ent is
+end entity;
+",
+        );
+        let (design_file, messages) = parse(&code, TranslateOff::Skip);
+        check_no_messages(&messages);
+        assert_eq!(unit_names(&design_file), vec!["ent"]);
+        assert_eq!(design_file.translate_off_regions, vec![]);
+    }
+
+    #[test]
+    fn translate_off_from_str() {
+        assert_eq!("skip".parse(), Ok(TranslateOff::Skip));
+        assert_eq!("analyse".parse(), Ok(TranslateOff::Analyse));
+        assert_eq!(
+            "off".parse::<TranslateOff>(),
+            Err("translate_off must be 'analyse' or 'skip', got 'off'".to_owned())
+        );
+    }
+}
@@ -29,6 +29,7 @@ use crate::subtype_indication::parse_subtype_indication;
 use crate::symbol_table::{Symbol, SymbolTable};
 use crate::tokenizer::Tokenizer;
 use crate::tokenstream::TokenStream;
+use crate::vhdl_standard::VHDLStandard;
 use crate::waveform::parse_waveform;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
@@ -47,16 +48,27 @@ impl CodeBuilder {
     }
 
     pub fn code_from_source(&self, source: Source) -> Code {
+        self.code_from_source_with_standard(source, VHDLStandard::default())
+    }
+
+    pub fn code_from_source_with_standard(&self, source: Source, standard: VHDLStandard) -> Code {
         let pos = source.entire_pos();
         let code = Code {
             source,
             symtab: self.symtab.clone(),
             pos,
+            standard,
         };
 
         // Ensure symbol table is populated
         code.with_stream(|stream| {
-            while stream.pop()?.is_some() {}
+            loop {
+                match stream.pop() {
+                    Ok(Some(_)) => {}
+                    Ok(None) => break,
+                    Err(err) => stream.tokenizer.skip_error(&err),
+                }
+            }
             Ok(())
         });
 
@@ -67,6 +79,11 @@ impl CodeBuilder {
         self.code_from_source(Source::from_str(code))
     }
 
+    /// Code which is tokenized and parsed according to another standard than the default
+    pub fn code_with_standard(&self, code: &str, standard: VHDLStandard) -> Code {
+        self.code_from_source_with_standard(Source::from_str(code), standard)
+    }
+
     pub fn symbol(&self, name: &str) -> Symbol {
         self.symtab.insert_utf8(name)
     }
@@ -77,6 +94,7 @@ pub struct Code {
     source: Source,
     pub symtab: Arc<SymbolTable>,
     pos: SrcPos,
+    standard: VHDLStandard,
 }
 
 impl Code {
@@ -84,12 +102,17 @@ impl Code {
         CodeBuilder::new().code(code)
     }
 
+    pub fn with_standard(code: &str, standard: VHDLStandard) -> Code {
+        CodeBuilder::new().code_with_standard(code, standard)
+    }
+
     /// Create new Code from n:th occurence of substr
     pub fn s(&self, substr: &str, occurence: usize) -> Code {
         Code {
             source: self.source.clone(),
             symtab: self.symtab.clone(),
             pos: self.pos().substr_pos(&self.source, substr, occurence),
+            standard: self.standard,
         }
     }
 
@@ -112,9 +135,14 @@ impl Code {
             source: self.source.clone(),
             symtab: self.symtab.clone(),
             pos: self.source.pos(start.start, length),
+            standard: self.standard,
         }
     }
 
+    pub fn standard(&self) -> VHDLStandard {
+        self.standard
+    }
+
     pub fn pos(self: &Self) -> SrcPos {
         self.pos.clone()
     }
@@ -126,7 +154,12 @@ impl Code {
     {
         let latin1 = self.source.contents().unwrap();
         let latin1 = Latin1String::new(&latin1.bytes[..self.pos.start + self.pos.length]);
-        let tokenizer = Tokenizer::new(self.symtab.clone(), self.source.clone(), Arc::new(latin1));
+        let tokenizer = Tokenizer::with_standard(
+            self.symtab.clone(),
+            self.source.clone(),
+            Arc::new(latin1),
+            self.standard,
+        );
         let mut stream = TokenStream::new(tokenizer);
         forward(&mut stream, &self.pos);
         parse_fun(&mut stream)
@@ -149,10 +182,11 @@ impl Code {
     where
         F: FnOnce(&mut TokenStream) -> R,
     {
-        let tokenizer = Tokenizer::new(
+        let tokenizer = Tokenizer::with_standard(
             self.symtab.clone(),
             self.source.clone(),
             self.source.contents().unwrap(),
+            self.standard,
         );
         let mut stream = TokenStream::new(tokenizer);
         parse_fun(&mut stream)
@@ -274,7 +308,7 @@ impl Code {
     }
 
     pub fn signature(&self) -> Signature {
-        self.parse_ok(parse_signature)
+        self.parse_ok_no_messages(parse_signature)
     }
 
     /// Return symbol from symbol table
@@ -504,8 +538,8 @@ mod tests {
     fn check_messages_ok() {
         let code = Code::new("foo bar");
         check_messages(
-            vec![Message::error(code.s1("foo"), "hello")],
-            vec![Message::error(code.s1("foo"), "hello")],
+            vec![Message::syntax_error(code.s1("foo"), "hello")],
+            vec![Message::syntax_error(code.s1("foo"), "hello")],
         )
     }
 
@@ -514,12 +548,12 @@ mod tests {
         let code = Code::new("foo bar");
         check_messages(
             vec![
-                Message::error(code.s1("foo"), "hello"),
-                Message::error(code.s1("bar"), "msg"),
+                Message::syntax_error(code.s1("foo"), "hello"),
+                Message::syntax_error(code.s1("bar"), "msg"),
             ],
             vec![
-                Message::error(code.s1("bar"), "msg"),
-                Message::error(code.s1("foo"), "hello"),
+                Message::syntax_error(code.s1("bar"), "msg"),
+                Message::syntax_error(code.s1("foo"), "hello"),
             ],
         )
     }
@@ -529,8 +563,8 @@ mod tests {
     fn check_messages_not_ok_mismatch() {
         let code = Code::new("foo bar");
         check_messages(
-            vec![Message::error(code.s1("bar"), "msg")],
-            vec![Message::error(code.s1("foo"), "hello")],
+            vec![Message::syntax_error(code.s1("bar"), "msg")],
+            vec![Message::syntax_error(code.s1("foo"), "hello")],
         )
     }
 
@@ -540,10 +574,10 @@ mod tests {
         let code = Code::new("foo bar");
         check_messages(
             vec![
-                Message::error(code.s1("bar"), "msg"),
-                Message::error(code.s1("bar"), "msg"),
+                Message::syntax_error(code.s1("bar"), "msg"),
+                Message::syntax_error(code.s1("bar"), "msg"),
             ],
-            vec![Message::error(code.s1("bar"), "msg")],
+            vec![Message::syntax_error(code.s1("bar"), "msg")],
         )
     }
 
@@ -552,10 +586,10 @@ mod tests {
     fn check_messages_not_ok_missing() {
         let code = Code::new("foo bar");
         check_messages(
-            vec![Message::error(code.s1("bar"), "msg")],
+            vec![Message::syntax_error(code.s1("bar"), "msg")],
             vec![
-                Message::error(code.s1("bar"), "msg"),
-                Message::error(code.s1("bar"), "missing"),
+                Message::syntax_error(code.s1("bar"), "msg"),
+                Message::syntax_error(code.s1("bar"), "missing"),
             ],
         )
     }
@@ -566,10 +600,10 @@ mod tests {
         let code = Code::new("foo bar");
         check_messages(
             vec![
-                Message::error(code.s1("bar"), "msg"),
-                Message::error(code.s1("bar"), "unexpected"),
+                Message::syntax_error(code.s1("bar"), "msg"),
+                Message::syntax_error(code.s1("bar"), "unexpected"),
             ],
-            vec![Message::error(code.s1("bar"), "msg")],
+            vec![Message::syntax_error(code.s1("bar"), "msg")],
         )
     }
 }
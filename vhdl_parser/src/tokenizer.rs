@@ -13,6 +13,7 @@ use crate::ast;
 use crate::ast::{BaseSpecifier, Ident};
 use crate::latin_1::Latin1String;
 use crate::symbol_table::{Symbol, SymbolTable};
+use crate::vhdl_standard::{VHDLStandard, VHDLStandard::*};
 use std::sync::Arc;
 
 /// The kind of a Token
@@ -27,6 +28,8 @@ pub enum Kind {
     Process,
     Generate,
     Postponed,
+    Guarded,
+    Disconnect,
     Library,
     Use,
     Context,
@@ -77,6 +80,8 @@ pub enum Kind {
     Buffer,
     Linkage,
     Signal,
+    Register,
+    Bus,
     Constant,
     Variable,
     File,
@@ -89,6 +94,9 @@ pub enum Kind {
     Subtype,
     Access,
     Units,
+    Group,
+    Label,
+    Literal,
     New,
     Array,
     Protected,
@@ -96,6 +104,24 @@ pub enum Kind {
     Function,
     Procedure,
     Vunit,
+    Force,
+    Release,
+    Parameter,
+    Private,
+    View,
+
+    // PSL reserved words
+    Assume,
+    AssumeGuarantee,
+    Cover,
+    Fairness,
+    Property,
+    Restrict,
+    RestrictGuarantee,
+    Sequence,
+    Strong,
+    Vmode,
+    Vprop,
 
     // Unary operators
     Abs,
@@ -160,10 +186,20 @@ pub enum Kind {
     GtGt,
     Circ,
     CommAt,
+    GraveAccent,
     Concat,
     Comma,
     ColonEq,
     RightArrow,
+
+    // PSL delimiters
+    LeftBrace,
+    RightBrace,
+    Bang,
+    BangUnderscore,
+    ConcatConcat,
+    MinusGT,
+    LtMinusGT,
 }
 use self::Kind::*;
 
@@ -226,6 +262,8 @@ pub fn kind_str(kind: Kind) -> &'static str {
         Process => &"process",
         Generate => &"generate",
         Postponed => &"postponed",
+        Guarded => &"guarded",
+        Disconnect => "disconnect",
         Library => &"library",
         Use => &"use",
         Context => &"context",
@@ -276,6 +314,8 @@ pub fn kind_str(kind: Kind) -> &'static str {
         Buffer => &"buffer",
         Linkage => &"linkage",
         Signal => &"signal",
+        Register => &"register",
+        Bus => &"bus",
         Constant => &"constant",
         Variable => &"variable",
         File => &"file",
@@ -288,6 +328,9 @@ pub fn kind_str(kind: Kind) -> &'static str {
         Subtype => &"subtype",
         Access => &"access",
         Units => &"units",
+        Group => &"group",
+        Label => &"label",
+        Literal => &"literal",
         New => &"new",
         Array => &"array",
         Protected => &"protected",
@@ -295,6 +338,24 @@ pub fn kind_str(kind: Kind) -> &'static str {
         Function => &"function",
         Procedure => &"procedure",
         Vunit => &"vunit",
+        Force => &"force",
+        Release => &"release",
+        Parameter => &"parameter",
+        Private => &"private",
+        View => &"view",
+
+        // PSL reserved words
+        Assume => &"assume",
+        AssumeGuarantee => &"assume_guarantee",
+        Cover => &"cover",
+        Fairness => &"fairness",
+        Property => &"property",
+        Restrict => &"restrict",
+        RestrictGuarantee => &"restrict_guarantee",
+        Sequence => &"sequence",
+        Strong => &"strong",
+        Vmode => &"vmode",
+        Vprop => &"vprop",
 
         // Unary operators
         Abs => &"abs",
@@ -359,10 +420,20 @@ pub fn kind_str(kind: Kind) -> &'static str {
         GtGt => &">>",
         Circ => &"^",
         CommAt => &"@",
+        GraveAccent => &"`",
         Concat => &"&",
         Comma => &",",
         ColonEq => &":=",
         RightArrow => &"=>",
+
+        // PSL delimiters
+        LeftBrace => &"{",
+        RightBrace => &"}",
+        Bang => &"!",
+        BangUnderscore => "!_",
+        ConcatConcat => &"&&",
+        MinusGT => &"->",
+        LtMinusGT => &"<->",
     }
 }
 
@@ -431,7 +502,7 @@ impl Into<SrcPos> for Token {
 }
 
 pub fn kinds_error<T: AsRef<SrcPos>>(pos: T, kinds: &[Kind]) -> Message {
-    Message::error(
+    Message::syntax_error(
         pos.as_ref(),
         format!("Expected {}", kinds_str(&kinds)).as_str(),
     )
@@ -673,6 +744,19 @@ impl TokenState {
         }
     }
 
+    /// The state at a byte position where no token has been seen before
+    pub fn at(start: usize) -> TokenState {
+        TokenState {
+            last_token_kind: None,
+            start,
+        }
+    }
+
+    /// The byte position of the next token or its leading comments
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
     /// Set state to after token
     pub fn set_after(&mut self, token: &Token) {
         self.last_token_kind = Some(token.kind);
@@ -971,7 +1055,28 @@ fn parse_basic_identifier_or_keyword(
     }
 }
 
-fn get_leading_comments(buffer: &mut Latin1String, cursor: &mut ByteCursor) -> Vec<Comment> {
+/// PSL 1.1 Annex B, a comment starting with the psl keyword contains PSL code
+/// Returns the length of the prefix after the leading --
+fn psl_comment_prefix(cursor: &ByteCursor) -> Option<usize> {
+    let bytes = &cursor.code.bytes[cursor.idx..];
+    let start = bytes
+        .iter()
+        .position(|byte| *byte != b' ' && *byte != b'\t')?;
+    let keyword = bytes.get(start..start + 3)?;
+    if !keyword.eq_ignore_ascii_case(b"psl") {
+        return None;
+    }
+    match bytes.get(start + 3) {
+        None | Some(b' ') | Some(b'\t') | Some(b'\r') | Some(b'\n') => Some(start + 3),
+        _ => None,
+    }
+}
+
+fn get_leading_comments(
+    buffer: &mut Latin1String,
+    cursor: &mut ByteCursor,
+    psl: bool,
+) -> Vec<Comment> {
     let mut comments: Vec<Comment> = Vec::new();
     while let Some(byte) = cursor.pop() {
         match byte {
@@ -980,6 +1085,15 @@ fn get_leading_comments(buffer: &mut Latin1String, cursor: &mut ByteCursor) -> V
             }
             b'-' => {
                 if cursor.skip_if(b'-') {
+                    let psl_prefix = if psl {
+                        psl_comment_prefix(cursor)
+                    } else {
+                        None
+                    };
+                    if let Some(length) = psl_prefix {
+                        cursor.set(cursor.pos() + length);
+                        continue;
+                    }
                     comments.push(parse_comment(buffer, cursor));
                 } else {
                     cursor.back();
@@ -995,7 +1109,11 @@ fn get_leading_comments(buffer: &mut Latin1String, cursor: &mut ByteCursor) -> V
     comments
 }
 
-fn get_trailing_comment(buffer: &mut Latin1String, cursor: &mut ByteCursor) -> Option<Comment> {
+fn get_trailing_comment(
+    buffer: &mut Latin1String,
+    cursor: &mut ByteCursor,
+    psl: bool,
+) -> Option<Comment> {
     while let Some(byte) = cursor.pop() {
         let comment = match byte {
             b' ' | b'\t' => {
@@ -1004,6 +1122,11 @@ fn get_trailing_comment(buffer: &mut Latin1String, cursor: &mut ByteCursor) -> O
             b'\r' | b'\n' => None,
             b'-' => {
                 if cursor.skip_if(b'-') {
+                    if psl && psl_comment_prefix(cursor).is_some() {
+                        cursor.back();
+                        cursor.back();
+                        return None;
+                    }
                     Some(parse_comment(buffer, cursor))
                 } else {
                     cursor.back();
@@ -1022,121 +1145,217 @@ fn get_trailing_comment(buffer: &mut Latin1String, cursor: &mut ByteCursor) -> O
     None
 }
 
+/// Merge sorted ranges which overlap or touch
+pub(crate) fn merge_ranges(ranges: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        if let Some(last) = merged.last_mut() {
+            if start <= last.1 {
+                last.1 = last.1.max(end);
+                continue;
+            }
+        }
+        merged.push((start, end));
+    }
+    merged
+}
+
+/// The start of the line after the position
+pub(crate) fn raw_next_line(bytes: &[u8], pos: usize) -> Option<usize> {
+    bytes[pos..]
+        .iter()
+        .position(|b| *b == b'\n')
+        .map(|idx| pos + idx + 1)
+}
+
+/// The end of the string or character literal which starts at the position
+/// Used to scan code without tokenizing it, a string literal without an end quote ends at the end of the line
+pub(crate) fn raw_literal_end(bytes: &[u8], pos: usize) -> Option<usize> {
+    match bytes[pos] {
+        b'\'' if bytes.get(pos + 2) == Some(&b'\'') => Some(pos + 3),
+        b'"' => {
+            let line_end = raw_next_line(bytes, pos).unwrap_or(bytes.len());
+            match bytes[pos + 1..line_end].iter().position(|b| *b == b'"') {
+                Some(idx) => Some(pos + idx + 2),
+                None => Some(line_end),
+            }
+        }
+        _ => None,
+    }
+}
+
 #[derive(Clone)]
 pub struct Tokenizer {
     keywords: FnvHashMap<&'static [u8], Kind>,
+    standard: VHDLStandard,
     symtab: Arc<SymbolTable>,
     buffer: Latin1String,
     state: TokenState,
     source: Source,
     cursor: ByteCursor,
     final_comments: Option<Vec<Comment>>,
+    // All comments by their start when collected, a comment is seen again when backtracking
+    collected_comments: Option<FnvHashMap<usize, Comment>>,
+    // Sorted byte ranges removed by conditional analysis
+    skipped: Vec<(usize, usize)>,
+    inactive_regions: Vec<SrcPos>,
+    translate_off_regions: Vec<SrcPos>,
     pub range_ident: Symbol,
     pub reverse_range_ident: Symbol,
 }
 
+/// The reserved words of VHDL-93
+const KEYWORDS: &[(&str, Kind)] = &[
+    ("architecture", Architecture),
+    ("entity", Entity),
+    ("configuration", Configuration),
+    ("package", Package),
+    ("block", Block),
+    ("process", Process),
+    ("generate", Generate),
+    ("postponed", Postponed),
+    ("guarded", Guarded),
+    ("disconnect", Disconnect),
+    ("library", Library),
+    ("use", Use),
+    ("body", Body),
+    ("component", Component),
+    ("is", Is),
+    ("return", Return),
+    ("null", Null),
+    ("of", Of),
+    ("on", On),
+    ("generic", Generic),
+    ("map", Map),
+    ("port", Port),
+    ("attribute", Attribute),
+    ("begin", Begin),
+    ("end", End),
+    ("if", If),
+    ("loop", Loop),
+    ("while", While),
+    ("case", Case),
+    ("else", Else),
+    ("elsif", Elsif),
+    ("then", Then),
+    ("when", When),
+    ("with", With),
+    ("select", Select),
+    ("next", Next),
+    ("exit", Exit),
+    ("for", For),
+    ("assert", Assert),
+    ("report", Report),
+    ("severity", Severity),
+    ("wait", Wait),
+    ("after", After),
+    ("transport", Transport),
+    ("inertial", Inertial),
+    ("reject", Reject),
+    ("unaffected", Unaffected),
+    ("until", Until),
+    ("all", All),
+    ("range", Range),
+    ("downto", Downto),
+    ("to", To),
+    ("in", In),
+    ("out", Out),
+    ("inout", InOut),
+    ("buffer", Buffer),
+    ("linkage", Linkage),
+    ("signal", Signal),
+    ("register", Register),
+    ("bus", Bus),
+    ("constant", Constant),
+    ("variable", Variable),
+    ("file", File),
+    ("open", Open),
+    ("alias", Alias),
+    ("shared", Shared),
+    ("others", Others),
+    ("record", Record),
+    ("type", Type),
+    ("subtype", Subtype),
+    ("access", Access),
+    ("units", Units),
+    ("group", Group),
+    ("label", Label),
+    ("literal", Literal),
+    ("new", New),
+    ("array", Array),
+    ("impure", Impure),
+    ("function", Function),
+    ("procedure", Procedure),
+    ("abs", Abs),
+    ("not", Not),
+    ("and", And),
+    ("or", Or),
+    ("nand", Nand),
+    ("nor", Nor),
+    ("xor", Xor),
+    ("xnor", Xnor),
+    ("sll", SLL),
+    ("srl", SRL),
+    ("sla", SLA),
+    ("sra", SRA),
+    ("rol", ROL),
+    ("ror", ROR),
+    ("mod", Mod),
+    ("rem", Rem),
+];
+
+/// Reserved words which were added in later revisions of the standard
+const NEWER_KEYWORDS: &[(VHDLStandard, &str, Kind)] = &[
+    (VHDL2002, "protected", Protected),
+    (VHDL2008, "context", Context),
+    (VHDL2008, "default", Default),
+    (VHDL2008, "vunit", Vunit),
+    (VHDL2008, "force", Force),
+    (VHDL2008, "release", Release),
+    (VHDL2008, "parameter", Parameter),
+    (VHDL2008, "assume", Assume),
+    (VHDL2008, "assume_guarantee", AssumeGuarantee),
+    (VHDL2008, "cover", Cover),
+    (VHDL2008, "fairness", Fairness),
+    (VHDL2008, "property", Property),
+    (VHDL2008, "restrict", Restrict),
+    (VHDL2008, "restrict_guarantee", RestrictGuarantee),
+    (VHDL2008, "sequence", Sequence),
+    (VHDL2008, "strong", Strong),
+    (VHDL2008, "vmode", Vmode),
+    (VHDL2008, "vprop", Vprop),
+    (VHDL2019, "private", Private),
+    (VHDL2019, "view", View),
+];
+
+fn keywords(standard: VHDLStandard) -> FnvHashMap<&'static [u8], Kind> {
+    let newer = NEWER_KEYWORDS
+        .iter()
+        .filter(|(since, ..)| *since <= standard)
+        .map(|(_, string, kind)| (*string, *kind));
+
+    KEYWORDS
+        .iter()
+        .cloned()
+        .chain(newer)
+        .map(|(string, kind)| (string.as_bytes(), kind))
+        .collect()
+}
+
 impl Tokenizer {
+    #[cfg(test)]
     pub fn new(symtab: Arc<SymbolTable>, source: Source, code: Arc<Latin1String>) -> Tokenizer {
-        let keywords = [
-            ("architecture", Architecture),
-            ("entity", Entity),
-            ("configuration", Configuration),
-            ("package", Package),
-            ("block", Block),
-            ("process", Process),
-            ("generate", Generate),
-            ("postponed", Postponed),
-            ("library", Library),
-            ("use", Use),
-            ("context", Context),
-            ("body", Body),
-            ("component", Component),
-            ("is", Is),
-            ("return", Return),
-            ("null", Null),
-            ("of", Of),
-            ("on", On),
-            ("generic", Generic),
-            ("map", Map),
-            ("default", Default),
-            ("port", Port),
-            ("attribute", Attribute),
-            ("begin", Begin),
-            ("end", End),
-            ("if", If),
-            ("loop", Loop),
-            ("while", While),
-            ("case", Case),
-            ("else", Else),
-            ("elsif", Elsif),
-            ("then", Then),
-            ("when", When),
-            ("with", With),
-            ("select", Select),
-            ("next", Next),
-            ("exit", Exit),
-            ("for", For),
-            ("assert", Assert),
-            ("report", Report),
-            ("severity", Severity),
-            ("wait", Wait),
-            ("after", After),
-            ("transport", Transport),
-            ("inertial", Inertial),
-            ("reject", Reject),
-            ("unaffected", Unaffected),
-            ("until", Until),
-            ("all", All),
-            ("range", Range),
-            ("downto", Downto),
-            ("to", To),
-            ("in", In),
-            ("out", Out),
-            ("inout", InOut),
-            ("buffer", Buffer),
-            ("linkage", Linkage),
-            ("signal", Signal),
-            ("constant", Constant),
-            ("variable", Variable),
-            ("file", File),
-            ("open", Open),
-            ("alias", Alias),
-            ("shared", Shared),
-            ("others", Others),
-            ("record", Record),
-            ("type", Type),
-            ("subtype", Subtype),
-            ("access", Access),
-            ("units", Units),
-            ("new", New),
-            ("array", Array),
-            ("protected", Protected),
-            ("impure", Impure),
-            ("function", Function),
-            ("procedure", Procedure),
-            ("abs", Abs),
-            ("not", Not),
-            ("and", And),
-            ("or", Or),
-            ("nand", Nand),
-            ("nor", Nor),
-            ("xor", Xor),
-            ("xnor", Xnor),
-            ("sll", SLL),
-            ("srl", SRL),
-            ("sla", SLA),
-            ("sra", SRA),
-            ("rol", ROL),
-            ("ror", ROR),
-            ("mod", Mod),
-            ("rem", Rem),
-            ("vunit", Vunit),
-        ];
-
-        let keywords: FnvHashMap<&[u8], Kind> = keywords
-            .into_iter()
-            .map(|(string, kind)| (string.as_bytes(), *kind))
-            .collect();
+        Tokenizer::with_standard(symtab, source, code, VHDLStandard::default())
+    }
+
+    /// A tokenizer using the reserved words of the standard
+    pub fn with_standard(
+        symtab: Arc<SymbolTable>,
+        source: Source,
+        code: Arc<Latin1String>,
+        standard: VHDLStandard,
+    ) -> Tokenizer {
+        let keywords = keywords(standard);
 
         let range_ident = symtab.insert(&Latin1String::new(b"range"));
         let reverse_range_ident = symtab.insert(&Latin1String::new(b"reverse_range"));
@@ -1145,23 +1364,32 @@ impl Tokenizer {
 
         Tokenizer {
             keywords,
+            standard,
             symtab,
             state: TokenState::new(),
             buffer: Latin1String::empty(),
             source,
             cursor,
             final_comments: None,
+            collected_comments: None,
+            skipped: Vec::new(),
+            inactive_regions: Vec::new(),
+            translate_off_regions: Vec::new(),
             range_ident,
             reverse_range_ident,
         }
     }
 
+    pub fn standard(&self) -> VHDLStandard {
+        self.standard
+    }
+
     pub fn state(&self) -> TokenState {
         self.state
     }
 
     pub fn eof_error(&self) -> Message {
-        Message::error(self.source.pos(self.state.start, 1), "Unexpected EOF")
+        Message::syntax_error(self.source.pos(self.state.start, 1), "Unexpected EOF")
     }
 
     pub fn set_state(&mut self, state: TokenState) {
@@ -1174,11 +1402,17 @@ impl Tokenizer {
         self.cursor.idx = self.state.start;
     }
 
+    /// Continue tokenizing after the code of a tokenizing error
+    pub fn skip_error(&mut self, error: &Message) {
+        self.state.start = error.pos.start + std::cmp::max(error.pos.length, 1);
+        self.cursor.idx = self.state.start;
+    }
+
     pub fn parse_token(&mut self) -> Result<Option<(Kind, Value)>, Message> {
         macro_rules! error {
             ($message:expr) => {
                 let length = self.cursor.pos() - self.state.start;
-                let err = Err(Message::error(
+                let err = Err(Message::syntax_error(
                     &self.source.pos(self.state.start, length),
                     $message,
                 ));
@@ -1205,10 +1439,26 @@ impl Tokenizer {
                     b'+' => (Plus, Value::NoValue),
                     b'|' => (Bar, Value::NoValue),
                     b'.' => (Dot, Value::NoValue),
-                    b'&' => (Concat, Value::NoValue),
+                    b'&' => {
+                        if self.cursor.skip_if(b'&') {
+                            (ConcatConcat, Value::NoValue)
+                        } else {
+                            (Concat, Value::NoValue)
+                        }
+                    }
                     b',' => (Comma, Value::NoValue),
                     b'^' => (Circ, Value::NoValue),
                     b'@' => (CommAt, Value::NoValue),
+                    b'`' => (GraveAccent, Value::NoValue),
+                    b'{' => (LeftBrace, Value::NoValue),
+                    b'}' => (RightBrace, Value::NoValue),
+                    b'!' => {
+                        if self.cursor.skip_if(b'_') {
+                            (BangUnderscore, Value::NoValue)
+                        } else {
+                            (Bang, Value::NoValue)
+                        }
+                    }
                     b'=' => {
                         if self.cursor.skip_if(b'>') {
                             (RightArrow, Value::NoValue)
@@ -1249,6 +1499,10 @@ impl Tokenizer {
                         Some(b'=') => (LTE, Value::NoValue),
                         Some(b'>') => (BOX, Value::NoValue),
                         Some(b'<') => (LtLt, Value::NoValue),
+                        Some(b'-') if self.cursor.peek(0) == Some(b'>') => {
+                            self.cursor.pop();
+                            (LtMinusGT, Value::NoValue)
+                        }
                         _ => {
                             self.cursor.back();
                             (LT, Value::NoValue)
@@ -1287,7 +1541,13 @@ impl Tokenizer {
                             (Tick, Value::NoValue)
                         }
                     }
-                    b'-' => (Minus, Value::NoValue),
+                    b'-' => {
+                        if self.cursor.skip_if(b'>') {
+                            (MinusGT, Value::NoValue)
+                        } else {
+                            (Minus, Value::NoValue)
+                        }
+                    }
                     b'"' => {
                         self.cursor.back();
                         match parse_string(&mut self.buffer, &mut self.cursor) {
@@ -1360,16 +1620,101 @@ impl Tokenizer {
         }
     }
 
+    /// Set the byte ranges which are not tokenized and the inactive code regions among them
+    pub fn set_skipped(&mut self, skipped: Vec<(usize, usize)>, inactive_regions: Vec<SrcPos>) {
+        self.skipped = skipped;
+        self.inactive_regions = inactive_regions;
+    }
+
+    /// Code regions which are disabled by conditional analysis
+    pub fn inactive_regions(&self) -> &[SrcPos] {
+        &self.inactive_regions
+    }
+
+    /// Set the code regions between translate_off and translate_on pragmas
+    /// The byte ranges of skipped code are added to those skipped by conditional analysis
+    pub fn set_translate_off_regions(
+        &mut self,
+        translate_off_regions: Vec<SrcPos>,
+        skipped: Vec<(usize, usize)>,
+    ) {
+        self.translate_off_regions = translate_off_regions;
+        if !skipped.is_empty() {
+            let mut all_skipped = std::mem::take(&mut self.skipped);
+            all_skipped.extend(skipped);
+            all_skipped.sort();
+            self.skipped = merge_ranges(all_skipped);
+        }
+    }
+
+    /// Code regions between translate_off and translate_on pragmas
+    pub fn translate_off_regions(&self) -> &[SrcPos] {
+        &self.translate_off_regions
+    }
+
+    /// The number of bytes of the code
+    pub fn code_length(&self) -> usize {
+        self.cursor.code.bytes.len()
+    }
+
+    /// The raw bytes of the code
+    pub fn code_bytes(&self) -> &[u8] {
+        &self.cursor.code.bytes
+    }
+
+    pub fn source(&self) -> &Source {
+        &self.source
+    }
+
+    /// True if the position is within code skipped by conditional analysis
+    pub fn is_skipped(&self, pos: usize) -> bool {
+        self.skipped_end(pos).is_some()
+    }
+
+    /// True if there is a newline between the byte positions
+    pub fn newline_between(&self, start: usize, end: usize) -> bool {
+        self.cursor.code.bytes[start..end].contains(&b'\n')
+    }
+
+    /// The end of the skipped range which contains the position
+    fn skipped_end(&self, pos: usize) -> Option<usize> {
+        let idx = match self.skipped.binary_search_by_key(&pos, |(start, _)| *start) {
+            Ok(idx) => idx,
+            Err(0) => return None,
+            Err(idx) => idx - 1,
+        };
+        let (_, end) = self.skipped[idx];
+        if pos < end {
+            Some(end)
+        } else {
+            None
+        }
+    }
+
     pub fn pop(&mut self) -> ParseResult<Option<Token>> {
-        let leading_comments = get_leading_comments(&mut self.buffer, &mut self.cursor);
+        let psl = self.standard >= VHDL2008;
+        let mut leading_comments = get_leading_comments(&mut self.buffer, &mut self.cursor, psl);
+        while let Some(end) = self.skipped_end(self.cursor.pos()) {
+            self.cursor.set(end);
+            leading_comments.extend(get_leading_comments(
+                &mut self.buffer,
+                &mut self.cursor,
+                psl,
+            ));
+        }
         self.state.start = self.cursor.pos();
+        self.collect_comments(&leading_comments);
 
         match self.parse_token() {
             Ok(Some((kind, value))) => {
                 // Parsed a token.
                 let pos_start = self.state.start;
                 let length = self.cursor.pos() - self.state.start;
-                let trailing_comment = get_trailing_comment(&mut self.buffer, &mut self.cursor);
+                let trailing_comment =
+                    get_trailing_comment(&mut self.buffer, &mut self.cursor, psl);
+                if let Some(ref trailing_comment) = trailing_comment {
+                    self.collect_comments(std::slice::from_ref(trailing_comment));
+                }
                 let token_comments = if (!leading_comments.is_empty()) | trailing_comment.is_some()
                 {
                     Some(Box::new(TokenComments {
@@ -1403,6 +1748,32 @@ impl Tokenizer {
     pub fn get_final_comments(&self) -> Option<Vec<Comment>> {
         self.final_comments.clone()
     }
+
+    /// Collect all comments of the tokens which are tokenized from now on
+    pub fn start_collecting_comments(&mut self) {
+        self.collected_comments = Some(FnvHashMap::default());
+    }
+
+    /// The collected comments in the order of the code
+    pub fn take_collected_comments(&mut self) -> Vec<Comment> {
+        let mut comments: Vec<Comment> = self
+            .collected_comments
+            .take()
+            .map(|comments| comments.into_values().collect())
+            .unwrap_or_default();
+        comments.sort_by_key(|comment| comment.pos.start);
+        comments
+    }
+
+    fn collect_comments(&mut self, comments: &[Comment]) {
+        if let Some(ref mut collected_comments) = self.collected_comments {
+            for comment in comments.iter() {
+                collected_comments
+                    .entry(comment.pos.start)
+                    .or_insert_with(|| comment.clone());
+            }
+        }
+    }
 }
 
 /// Tokenize the code into a vector of tokens
@@ -1495,6 +1866,39 @@ mod tests {
         assert_eq!(kinds_tokenize("not"), vec![Not]);
     }
 
+    #[test]
+    fn tokenize_keywords_of_standard() {
+        let kinds_with_standard = |code: &str, standard: VHDLStandard| {
+            let symtab = Arc::new(SymbolTable::new());
+            let source = Source::from_str(code);
+            let contents = source.contents().unwrap();
+            let mut tokenizer = Tokenizer::with_standard(symtab, source, contents, standard);
+            let mut kinds = Vec::new();
+            while let Some(token) = tokenizer.pop().unwrap() {
+                kinds.push(token.kind);
+            }
+            kinds
+        };
+
+        let code = "context force release protected";
+        assert_eq!(
+            kinds_with_standard(code, VHDL1993),
+            vec![Identifier, Identifier, Identifier, Identifier]
+        );
+        assert_eq!(
+            kinds_with_standard(code, VHDL2002),
+            vec![Identifier, Identifier, Identifier, Protected]
+        );
+        assert_eq!(
+            kinds_with_standard(code, VHDL2008),
+            vec![Context, Force, Release, Protected]
+        );
+        assert_eq!(
+            kinds_tokenize(code),
+            vec![Context, Force, Release, Protected]
+        );
+    }
+
     #[test]
     fn tokenize_newline() {
         assert_eq!(
@@ -1648,7 +2052,7 @@ end entity"
         let (source, _, tokens, _) = tokenize_result("1e-1");
         assert_eq!(
             tokens,
-            vec![Err(Message::error(
+            vec![Err(Message::syntax_error(
                 &source.entire_pos(),
                 "Integer literals may not have negative exponent"
             ))]
@@ -1774,7 +2178,7 @@ end entity"
         let (source, _, tokens, _) = tokenize_result("\"str\ning\"");
         assert_eq!(
             tokens,
-            vec![Err(Message::error(
+            vec![Err(Message::syntax_error(
                 &source.entire_pos(),
                 "Multi line string"
             ))]
@@ -1787,7 +2191,7 @@ end entity"
         let (source, _, tokens, _) = tokenize_result("\"string");
         assert_eq!(
             tokens,
-            vec![Err(Message::error(
+            vec![Err(Message::syntax_error(
                 &source.entire_pos(),
                 "Reached EOF before end quote"
             ))]
@@ -1868,7 +2272,7 @@ end entity"
         let (source, _, tokens, _) = tokenize_result("1#0#");
         assert_eq!(
             tokens,
-            vec![Err(Message::error(
+            vec![Err(Message::syntax_error(
                 &source.entire_pos(),
                 "Base must be at least 2 and at most 16, got 1"
             ))]
@@ -1876,7 +2280,7 @@ end entity"
         let (source, _, tokens, _) = tokenize_result("17#f#");
         assert_eq!(
             tokens,
-            vec![Err(Message::error(
+            vec![Err(Message::syntax_error(
                 &source.entire_pos(),
                 "Base must be at least 2 and at most 16, got 17"
             ))]
@@ -1885,7 +2289,7 @@ end entity"
         let (source, _, tokens, _) = tokenize_result("3#3#");
         assert_eq!(
             tokens,
-            vec![Err(Message::error(
+            vec![Err(Message::syntax_error(
                 &source.entire_pos(),
                 "Illegal digit for base 3"
             ))]
@@ -1893,7 +2297,7 @@ end entity"
         let (source, _, tokens, _) = tokenize_result("15#f#");
         assert_eq!(
             tokens,
-            vec![Err(Message::error(
+            vec![Err(Message::syntax_error(
                 &source.entire_pos(),
                 "Illegal digit for base 15"
             ))]
@@ -2021,6 +2425,43 @@ end entity"
         assert_eq!(kinds_tokenize("[]"), vec![LeftSquare, RightSquare]);
     }
 
+    #[test]
+    fn tokenize_psl_delimiters() {
+        assert_eq!(
+            kinds_tokenize("{ } ! !_ && -> <-> a<=-b"),
+            vec![
+                LeftBrace,
+                RightBrace,
+                Bang,
+                BangUnderscore,
+                ConcatConcat,
+                MinusGT,
+                LtMinusGT,
+                Identifier,
+                LTE,
+                Minus,
+                Identifier
+            ]
+        );
+    }
+
+    #[test]
+    fn tokenize_psl_comment() {
+        let code = "
+-- psl assert a; -- trailing
+--psl
+-- psl2 is a comment
+";
+        assert_eq!(kinds_tokenize(code), vec![Assert, Identifier, SemiColon]);
+
+        let symtab = Arc::new(SymbolTable::new());
+        let source = Source::from_str(code);
+        let contents = source.contents().unwrap();
+        let mut tokenizer = Tokenizer::with_standard(symtab, source, contents, VHDL2002);
+        assert_eq!(tokenizer.pop(), Ok(None));
+        assert_eq!(tokenizer.get_final_comments().map(|c| c.len()), Some(3));
+    }
+
     #[test]
     fn tokenize_ignores_comments() {
         assert_eq!(
@@ -2050,7 +2491,7 @@ end entity"
         let (source, _, tokens, _) = tokenize_result(large_int);
         assert_eq!(
             tokens,
-            vec![Err(Message::error(
+            vec![Err(Message::syntax_error(
                 &source.entire_pos(),
                 "Integer too large for 64-bits signed"
             ))]
@@ -2060,7 +2501,7 @@ end entity"
         let (source, _, tokens, _) = tokenize_result(large_int);
         assert_eq!(
             tokens,
-            vec![Err(Message::error(
+            vec![Err(Message::syntax_error(
                 &source.entire_pos(),
                 "Integer too large for 64-bits signed"
             ))]
@@ -2070,7 +2511,7 @@ end entity"
         let (source, _, tokens, _) = tokenize_result(&large_int);
         assert_eq!(
             tokens,
-            vec![Err(Message::error(
+            vec![Err(Message::syntax_error(
                 &source.entire_pos(),
                 "Exponent too large for 32-bits signed"
             ))]
@@ -2080,7 +2521,7 @@ end entity"
         let (source, _, tokens, _) = tokenize_result(&large_int);
         assert_eq!(
             tokens,
-            vec![Err(Message::error(
+            vec![Err(Message::syntax_error(
                 &source.entire_pos(),
                 "Exponent too large for 32-bits signed"
             ))]
@@ -2090,7 +2531,7 @@ end entity"
         let (source, _, tokens, _) = tokenize_result(&large_int);
         assert_eq!(
             tokens,
-            vec![Err(Message::error(
+            vec![Err(Message::syntax_error(
                 &source.entire_pos(),
                 "Integer too large for 64-bits signed"
             ))]
@@ -2121,7 +2562,7 @@ end entity"
                     pos: source.first_substr_pos("begin"),
                     comments: None,
                 }),
-                Err(Message::error(
+                Err(Message::syntax_error(
                     &source.first_substr_pos("?"),
                     "Illegal token"
                 )),
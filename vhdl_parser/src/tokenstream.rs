@@ -7,6 +7,7 @@
 use crate::ast::Ident;
 use crate::message::{MessageHandler, ParseResult};
 use crate::tokenizer::{kinds_str, Kind, Kind::*, Token, TokenState, Tokenizer};
+use crate::vhdl_standard::VHDLStandard;
 
 pub struct TokenStream {
     pub tokenizer: Tokenizer,
@@ -21,6 +22,10 @@ impl TokenStream {
         self.tokenizer.state()
     }
 
+    pub fn standard(&self) -> VHDLStandard {
+        self.tokenizer.standard()
+    }
+
     pub fn set_state(&mut self, state: TokenState) {
         self.tokenizer.set_state(state);
     }
@@ -138,37 +143,10 @@ impl TokenStream {
 }
 
 pub trait Recover<T> {
-    fn or_recover_until(
-        self,
-        stream: &mut TokenStream,
-        msgs: &mut MessageHandler,
-        cond: fn(&Kind) -> bool,
-    ) -> ParseResult<T>;
-
     fn log(self, msgs: &mut MessageHandler);
 }
 
 impl<T: std::fmt::Debug> Recover<T> for ParseResult<T> {
-    fn or_recover_until(
-        self,
-        stream: &mut TokenStream,
-        msgs: &mut MessageHandler,
-        cond: fn(&Kind) -> bool,
-    ) -> ParseResult<T> {
-        if self.is_ok() {
-            return self;
-        }
-
-        let res = stream.skip_until(cond);
-        match res {
-            Ok(_) => self,
-            Err(err) => {
-                msgs.push(self.unwrap_err());
-                Err(err)
-            }
-        }
-    }
-
     fn log(self, msgs: &mut MessageHandler) {
         match self {
             Err(err) => msgs.push(err),
@@ -235,11 +213,11 @@ mod tests {
         assert_eq!(stream.expect(), Ok(tokens[0].clone()));
         assert_eq!(
             stream.peek_expect(),
-            Err(Message::error(&source.pos(5, 1), "Unexpected EOF"))
+            Err(Message::syntax_error(&source.pos(5, 1), "Unexpected EOF"))
         );
         assert_eq!(
             stream.expect(),
-            Err(Message::error(&source.pos(5, 1), "Unexpected EOF"))
+            Err(Message::syntax_error(&source.pos(5, 1), "Unexpected EOF"))
         );
     }
 
@@ -272,7 +250,7 @@ mod tests {
 
         assert_eq!(
             stream.expect(),
-            Err(Message::error(&source.pos(0, 1), "Unexpected EOF"))
+            Err(Message::syntax_error(&source.pos(0, 1), "Unexpected EOF"))
         );
     }
 
@@ -283,7 +261,7 @@ mod tests {
         stream.expect().unwrap();
         assert_eq!(
             stream.expect(),
-            Err(Message::error(&source.pos(3, 1), "Unexpected EOF"))
+            Err(Message::syntax_error(&source.pos(3, 1), "Unexpected EOF"))
         );
     }
 
@@ -294,7 +272,7 @@ mod tests {
         stream.expect().unwrap();
         assert_eq!(
             stream.expect(),
-            Err(Message::error(&source.pos(9, 1), "Unexpected EOF"))
+            Err(Message::syntax_error(&source.pos(9, 1), "Unexpected EOF"))
         );
     }
 
@@ -6,18 +6,20 @@
 
 use crate::ast::{
     AbstractLiteral, ArrayIndex, ElementDeclaration, EnumerationLiteral, Ident, Literal,
-    PhysicalTypeDeclaration, ProtectedTypeBody, ProtectedTypeDeclaration,
+    ObjectClass, PhysicalTypeDeclaration, ProtectedTypeBody, ProtectedTypeDeclaration,
     ProtectedTypeDeclarativeItem, Range, TypeDeclaration, TypeDefinition,
 };
-use crate::common::error_on_end_identifier_mismatch;
+use crate::common::{check_standard, error_on_end_identifier_mismatch};
 use crate::declarative_part::parse_declarative_part;
-use crate::message::{push_some, MessageHandler, ParseResult};
+use crate::message::{push_some, Message, MessageHandler, ParseResult};
 use crate::names::{parse_identifier_list, parse_selected_name};
+use crate::object_declaration::parse_object_declaration;
 use crate::range::{parse_array_index_constraint, parse_range};
 use crate::subprogram::parse_subprogram_declaration;
 use crate::subtype_indication::parse_subtype_indication;
 use crate::tokenizer::Kind::*;
 use crate::tokenstream::TokenStream;
+use crate::vhdl_standard::VHDLStandard;
 
 /// LRM 5.2.2 Enumeration types
 fn parse_enumeration_type_definition(stream: &mut TokenStream) -> ParseResult<TypeDefinition> {
@@ -78,12 +80,22 @@ fn parse_array_type_definition(stream: &mut TokenStream) -> ParseResult<TypeDefi
 /// LRM 5.3.3 Record types
 fn parse_record_type_definition(
     stream: &mut TokenStream,
+    messages: &mut dyn MessageHandler,
 ) -> ParseResult<(TypeDefinition, Option<Ident>)> {
     let mut elem_decls = Vec::new();
 
     loop {
         let token = stream.peek_expect()?;
         if token.kind == End {
+            if elem_decls.is_empty() {
+                check_standard(
+                    stream,
+                    &token,
+                    VHDLStandard::VHDL2019,
+                    "Empty record type",
+                    messages,
+                );
+            }
             stream.move_after(&token); // End
             stream.pop_if_kind(Record)?;
             let end_ident = stream.pop_optional_ident()?;
@@ -131,6 +143,19 @@ pub fn parse_protected_type_declaration(
             Impure | Function | Procedure => items.push(ProtectedTypeDeclarativeItem::Subprogram(
                 parse_subprogram_declaration(stream, messages)?,
             )),
+            Private => {
+                stream.move_after(&token);
+                let object_decls = parse_object_declaration(stream)?;
+                if object_decls.iter().any(|decl| decl.class != ObjectClass::Variable) {
+                    messages.push(Message::syntax_error(&token, "Only variables may be private"));
+                } else {
+                    items.extend(
+                        object_decls
+                            .into_iter()
+                            .map(ProtectedTypeDeclarativeItem::PrivateVariable),
+                    );
+                }
+            },
             End => {
                 stream.move_after(&token);
                 break;
@@ -236,7 +261,7 @@ pub fn parse_type_declaration(
             let constraint = parse_range(stream)?.item;
             try_token_kind!(
                 stream.expect()?,
-                SemiColon => TypeDefinition::Integer(constraint),
+                SemiColon => TypeDefinition::Range(constraint),
                 Units => {
                     let (def, end_ident) = parse_physical_type_definition(stream, constraint)?;
                     push_some(messages, error_on_end_identifier_mismatch(&ident, &end_ident));
@@ -256,8 +281,8 @@ pub fn parse_type_declaration(
                 let decl = parse_declarative_part(stream, messages, false)?;
                 stream.expect_kind(Protected)?;
                 stream.expect_kind(Body)?;
-                // @TODO check name
-                stream.pop_if_kind(Identifier)?;
+                let end_ident = stream.pop_optional_ident()?;
+                push_some(messages, error_on_end_identifier_mismatch(&ident, &end_ident));
                 stream.expect_kind(SemiColon)?;
                 TypeDefinition::ProtectedBody(ProtectedTypeBody {decl})
             } else {
@@ -275,7 +300,7 @@ pub fn parse_type_declaration(
         },
         Array => parse_array_type_definition(stream)?,
         Record =>  {
-            let (def, end_ident) = parse_record_type_definition(stream)?;
+            let (def, end_ident) = parse_record_type_definition(stream, messages)?;
             push_some(messages, error_on_end_identifier_mismatch(&ident, &end_ident));
             def
         },
@@ -290,8 +315,23 @@ pub fn parse_type_declaration(
 mod tests {
     use super::*;
 
-    use crate::ast::{DiscreteRange, Ident};
-    use crate::test_util::Code;
+    use crate::ast::{DiscreteRange, Ident, ObjectDeclaration};
+    use crate::message::MessageCode;
+    use crate::test_util::{check_messages, Code};
+
+    #[test]
+    fn parse_floating_point_type_definition() {
+        let code = Code::new("type foo is range -1.0 to 2.0 ** 10;");
+
+        let type_decl = TypeDeclaration {
+            ident: code.s1("foo").ident(),
+            def: TypeDefinition::Range(code.s1("-1.0 to 2.0 ** 10").range()),
+        };
+        assert_eq!(
+            code.with_stream_no_messages(parse_type_declaration),
+            type_decl
+        );
+    }
 
     #[test]
     fn parse_integer_scalar_type_definition() {
@@ -299,7 +339,7 @@ mod tests {
 
         let type_decl = TypeDeclaration {
             ident: code.s1("foo").ident(),
-            def: TypeDefinition::Integer(code.s1("0 to 1").range()),
+            def: TypeDefinition::Range(code.s1("0 to 1").range()),
         };
         assert_eq!(
             code.with_stream_no_messages(parse_type_declaration),
@@ -516,6 +556,43 @@ end record;",
         );
     }
 
+    #[test]
+    fn parse_empty_record_type_definition() {
+        let code = Code::with_standard(
+            "\
+type foo is record
+end record;",
+            VHDLStandard::VHDL2019,
+        );
+
+        assert_eq!(
+            code.with_stream_no_messages(parse_type_declaration),
+            TypeDeclaration {
+                ident: code.s1("foo").ident(),
+                def: TypeDefinition::Record(vec![]),
+            }
+        );
+    }
+
+    #[test]
+    fn empty_record_type_definition_requires_vhdl_2019() {
+        let code = Code::new(
+            "\
+type foo is record
+end record;",
+        );
+
+        let (_, messages) = code.with_stream_messages(parse_type_declaration);
+        assert_eq!(
+            messages,
+            vec![Message::error(
+                code.s1("end"),
+                "Empty record type requires VHDL-2019 or later, the selected standard is VHDL-2008",
+                MessageCode::UnsupportedInStandard,
+            )]
+        );
+    }
+
     #[test]
     fn parse_record_type_definition_many() {
         let code = Code::new(
@@ -666,6 +743,88 @@ end protected;
         )
     }
 
+    #[test]
+    fn test_protected_type_declaration_with_private_variable() {
+        let code = Code::with_standard(
+            "\
+type foo is protected
+  procedure proc;
+  private variable count : natural;
+end protected;
+",
+            VHDLStandard::VHDL2019,
+        );
+        let items = vec![
+            ProtectedTypeDeclarativeItem::Subprogram(code.s1("procedure proc").subprogram_decl()),
+            ProtectedTypeDeclarativeItem::PrivateVariable(ObjectDeclaration {
+                class: ObjectClass::Variable,
+                ident: code.s1("count").ident(),
+                subtype_indication: code.s1("natural").subtype_indication(),
+                signal_kind: None,
+                expression: None,
+            }),
+        ];
+
+        assert_eq!(
+            code.with_stream_no_messages(parse_type_declaration),
+            protected_decl(code.s1("foo").ident(), items)
+        )
+    }
+
+    #[test]
+    fn test_protected_type_declaration_private_signal_is_error() {
+        let code = Code::with_standard(
+            "\
+type foo is protected
+  private signal count : natural;
+  private variable total : natural;
+end protected;
+",
+            VHDLStandard::VHDL2019,
+        );
+        let items = vec![ProtectedTypeDeclarativeItem::PrivateVariable(
+            ObjectDeclaration {
+                class: ObjectClass::Variable,
+                ident: code.s1("total").ident(),
+                subtype_indication: code.s("natural", 2).subtype_indication(),
+                signal_kind: None,
+                expression: None,
+            },
+        )];
+
+        let (decl, messages) = code.with_stream_messages(parse_type_declaration);
+        assert_eq!(decl, protected_decl(code.s1("foo").ident(), items));
+        check_messages(
+            messages,
+            vec![Message::syntax_error(
+                code.s1("private"),
+                "Only variables may be private",
+            )],
+        );
+    }
+
+    #[test]
+    fn test_protected_type_body_end_identifier_mismatch() {
+        let code = Code::new(
+            "\
+type foo is protected body
+end protected body bar;
+",
+        );
+
+        let (_, messages) = code.with_stream_messages(parse_type_declaration);
+        assert_eq!(
+            messages,
+            vec![Message::error(
+                code.s1("bar"),
+                "End identifier mismatch, expected foo",
+                MessageCode::EndIdentifierMismatch,
+            )
+            .related(code.s1("foo"), "Opening identifier")
+            .with_replacement("foo")]
+        );
+    }
+
     #[test]
     fn test_protected_type_body() {
         let code = Code::new(
@@ -771,5 +930,4 @@ end units;
             }
         )
     }
-
 }
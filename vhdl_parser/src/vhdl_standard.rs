@@ -0,0 +1,81 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
+
+/// The revision of IEEE 1076 which a file is parsed and analyzed according to
+#[derive(PartialEq, Debug, Clone, Copy, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum VHDLStandard {
+    VHDL1993,
+    VHDL2002,
+    #[default]
+    VHDL2008,
+    VHDL2019,
+}
+
+impl VHDLStandard {
+    pub fn all() -> &'static [VHDLStandard] {
+        use VHDLStandard::*;
+        &[VHDL1993, VHDL2002, VHDL2008, VHDL2019]
+    }
+
+    /// The name used in the configuration such as 2008
+    pub fn name(self) -> &'static str {
+        use VHDLStandard::*;
+        match self {
+            VHDL1993 => "93",
+            VHDL2002 => "2002",
+            VHDL2008 => "2008",
+            VHDL2019 => "2019",
+        }
+    }
+}
+
+impl std::fmt::Display for VHDLStandard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "VHDL-{}", self.name())
+    }
+}
+
+impl std::str::FromStr for VHDLStandard {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<VHDLStandard, String> {
+        use VHDLStandard::*;
+        match string {
+            "93" | "1993" => Ok(VHDL1993),
+            "2002" => Ok(VHDL2002),
+            "2008" => Ok(VHDL2008),
+            "2019" => Ok(VHDL2019),
+            _ => Err(format!(
+                "Invalid standard '{}', expected '93', '2002', '2008' or '2019'",
+                string
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_standard() {
+        for standard in VHDLStandard::all() {
+            assert_eq!(standard.name().parse(), Ok(*standard));
+        }
+        assert_eq!("1993".parse(), Ok(VHDLStandard::VHDL1993));
+        assert_eq!(
+            "2007".parse::<VHDLStandard>(),
+            Err("Invalid standard '2007', expected '93', '2002', '2008' or '2019'".to_owned())
+        );
+    }
+
+    #[test]
+    fn standards_are_ordered() {
+        assert!(VHDLStandard::VHDL1993 < VHDLStandard::VHDL2002);
+        assert!(VHDLStandard::VHDL2008 < VHDLStandard::VHDL2019);
+        assert_eq!(VHDLStandard::default().to_string(), "VHDL-2008");
+    }
+}